@@ -42,7 +42,7 @@ pub mod nym_command {
     command!(CommandMetadata::build("nym", "Send NYM transaction to the Ledger.")
                 .add_required_param("did", "DID of new identity")
                 .add_optional_param("verkey", "Verification key of new identity")
-                .add_optional_param("role", "Role of identity. One of: STEWARD, TRUSTEE, TRUST_ANCHOR, NETWORK_MONITOR or empty in case of blacklisting NYM")
+                .add_optional_param("role", "Role of identity. One of: STEWARD, TRUSTEE, TRUST_ANCHOR, ENDORSER, NETWORK_MONITOR or empty in case of blacklisting NYM")
                 .add_optional_param("fees_inputs","The list of source inputs")
                 .add_optional_param("fees_outputs","The list of outputs in the following format: (recipient, amount)")
                 .add_optional_param("extra","Optional information for fees payment operation")
@@ -874,8 +874,10 @@ pub mod custom_command {
     command!(CommandMetadata::build("custom", "Send custom transaction to the Ledger.")
                 .add_main_param("txn", "Transaction json")
                 .add_optional_param("sign", "Is signature required")
+                .add_optional_param("type", "Name or code of custom transaction type registered by a plugin. If specified then txn is the transaction operation")
                 .add_example(r#"ledger custom {"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"105","dest":"V4SGRU86Z58d6TV7PBUe6f"},"protocolVersion":2}"#)
                 .add_example(r#"ledger custom {"reqId":2,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX"},"protocolVersion":2} sign=true"#)
+                .add_example(r#"ledger custom {"data":"some data"} type=MY_TXN sign=true"#)
                 .finalize()
     );
 
@@ -886,6 +888,13 @@ pub mod custom_command {
 
         let txn = get_str_param("txn", params).map_err(error_err!())?;
         let sign = get_opt_bool_param("sign", params).map_err(error_err!())?.unwrap_or(false);
+        let txn_type = get_opt_str_param("type", params).map_err(error_err!())?;
+
+        let txn = match txn_type {
+            Some(txn_type) => Ledger::build_custom_request(get_active_did(&ctx).as_ref().map(String::as_str), txn_type, txn)
+                .map_err(|err| handle_indy_error(err, None, None, None))?,
+            None => txn.to_string()
+        };
 
         let (mut submitter, mut wallet) = (String::new(), String::new());
 
@@ -896,9 +905,9 @@ pub mod custom_command {
             submitter = submitter_did.clone();
             wallet = wallet_name.clone();
 
-            Ledger::sign_and_submit_request(pool_handle, wallet_handle, &submitter_did, txn)
+            Ledger::sign_and_submit_request(pool_handle, wallet_handle, &submitter_did, &txn)
         } else {
-            Ledger::submit_request(pool_handle, txn)
+            Ledger::submit_request(pool_handle, &txn)
         };
 
         let response_json =
@@ -1218,6 +1227,280 @@ pub mod sign_multi_command {
     }
 }
 
+pub mod prepare_for_endorser_command {
+    use super::*;
+
+    command!(CommandMetadata::build("prepare-for-endorser", "Append endorser to transaction and sign it by current DID as author.")
+                .add_required_param("txn", "Transaction to prepare")
+                .add_required_param("endorser", "DID of the Endorser that will submit the transaction to the Ledger")
+                .add_example(r#"ledger prepare-for-endorser txn={"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX"},"protocolVersion":2} endorser=Th7MpTaRZVRYnPiabds81Y"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, _) = ensure_opened_wallet(&ctx)?;
+        let submitter_did = ensure_active_did(&ctx)?;
+
+        let txn = get_str_param("txn", params).map_err(error_err!())?;
+        let endorser = get_str_param("endorser", params).map_err(error_err!())?;
+
+        let request = Ledger::append_request_endorser(txn, endorser)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let res = match Ledger::multi_sign_request(wallet_handle, &submitter_did, &request) {
+            Ok(request) => {
+                println_succ!("Transaction has been prepared for Endorser \"{}\":", endorser);
+                println_succ!("{}", request);
+                Ok(())
+            }
+            Err(err) => {
+                match err.error_code {
+                    ErrorCode::WalletItemNotFound => Err(println_err!("Signer DID: \"{}\" not found", submitter_did)),
+                    _ => Err(handle_indy_error(err, Some(&submitter_did), None, None)),
+                }
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod endorse_command {
+    use super::*;
+
+    command!(CommandMetadata::build("endorse", "Endorse transaction prepared by author by signing it with current DID.")
+                .add_required_param("txn", "Transaction to endorse")
+                .add_optional_param("send", "Send endorsed transaction to the Ledger (false by default)")
+                .add_example(r#"ledger endorse txn={"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","endorser":"Th7MpTaRZVRYnPiabds81Y","operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX"},"protocolVersion":2,"signatures":{"V4SGRU86Z58d6TV7PBUe6f":"2Ak..."}}"#)
+                .add_example(r#"ledger endorse txn={"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","endorser":"Th7MpTaRZVRYnPiabds81Y","operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX"},"protocolVersion":2,"signatures":{"V4SGRU86Z58d6TV7PBUe6f":"2Ak..."}} send=true"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let submitter_did = ensure_active_did(&ctx)?;
+
+        let txn = get_str_param("txn", params).map_err(error_err!())?;
+        let send = get_opt_bool_param("send", params).map_err(error_err!())?.unwrap_or(false);
+
+        let endorser = serde_json::from_str::<serde_json::Value>(txn)
+            .map_err(|_| println_err!("Invalid transaction json"))?["endorser"].as_str().map(String::from)
+            .ok_or_else(|| println_err!("Transaction doesn't contain Endorser"))?;
+
+        if endorser != submitter_did {
+            return Err(println_err!("Transaction Endorser \"{}\" doesn't match the current DID \"{}\"", endorser, submitter_did));
+        }
+
+        let request = Ledger::multi_sign_request(wallet_handle, &submitter_did, txn)
+            .map_err(|err| match err.error_code {
+                ErrorCode::WalletItemNotFound => println_err!("Signer DID: \"{}\" not found", submitter_did),
+                _ => handle_indy_error(err, Some(&submitter_did), None, None),
+            })?;
+
+        if !send {
+            println_succ!("Transaction has been endorsed:");
+            println_succ!("{}", request);
+            trace!("execute << ");
+            return Ok(());
+        }
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+
+        let response_json = Ledger::submit_request(pool_handle, &request)
+            .map_err(|err| handle_indy_error(err, Some(&submitter_did), Some(&pool_name), Some(&wallet_name)))?;
+
+        let response = serde_json::from_str::<Response<serde_json::Value>>(&response_json)
+            .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+        let res = match response {
+            Response { op: ResponseType::REPLY, result: Some(_), reason: None } =>
+                Ok(println!("Endorsed transaction has been sent to Ledger.\nResponse: \n{}", response_json)),
+            Response { op: ResponseType::REQNACK, result: None, reason: Some(reason) } |
+            Response { op: ResponseType::REJECT, result: None, reason: Some(reason) } =>
+                Err(println_err!("Transaction has been rejected: {}", extract_error_message(&reason))),
+            _ => Err(println_err!("Invalid data has been received"))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod bundle_create_command {
+    use super::*;
+
+    command!(CommandMetadata::build("bundle-create", "Create signing bundle to collect signatures of transaction on offline machines.")
+                .add_required_param("txn", "Transaction to sign")
+                .add_required_param("signers", r#"DIDs expected to sign the transaction: {"<did>": {"verkey": string, "role": (optional) string}}"#)
+                .add_optional_param("constraint", "Auth rule constraint signatures must satisfy (all signers must sign if not set)")
+                .add_example(r#"ledger bundle-create txn={"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX"},"protocolVersion":2} signers={"V4SGRU86Z58d6TV7PBUe6f":{"verkey":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","role":"TRUSTEE"}}"#)
+                .add_example(r#"ledger bundle-create txn={"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX"},"protocolVersion":2} signers={"V4SGRU86Z58d6TV7PBUe6f":{"verkey":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","role":"TRUSTEE"}} constraint={"constraint_id":"ROLE","role":"0","sig_count":1}"#)
+                .finalize()
+    );
+
+    fn execute(_ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> params {:?}", params);
+
+        let txn = get_str_param("txn", params).map_err(error_err!())?;
+        let signers = get_str_param("signers", params).map_err(error_err!())?;
+        let constraint = get_opt_str_param("constraint", params).map_err(error_err!())?;
+
+        let res = match Ledger::build_signing_bundle(txn, signers, constraint) {
+            Ok(bundle) => {
+                println_succ!("Signing bundle has been created:");
+                println_succ!("{}", bundle);
+                Ok(())
+            }
+            Err(err) => Err(handle_indy_error(err, None, None, None))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod bundle_sign_command {
+    use super::*;
+
+    command!(CommandMetadata::build("bundle-sign", "Add signature by current DID to signing bundle. Doesn't require connected pool.")
+                .add_required_param("bundle", "Signing bundle created by `ledger bundle-create`")
+                .add_example(r#"ledger bundle-sign bundle={"ver":"1","request":{...},"signing_input":"...","signers":{...},"signatures":{}}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, _) = ensure_opened_wallet(&ctx)?;
+        let signer_did = ensure_active_did(&ctx)?;
+
+        let bundle = get_str_param("bundle", params).map_err(error_err!())?;
+
+        let res = match Ledger::sign_signing_bundle(wallet_handle, &signer_did, bundle) {
+            Ok(bundle) => {
+                println_succ!("Signing bundle has been signed:");
+                println_succ!("{}", bundle);
+                Ok(())
+            }
+            Err(err) => {
+                match err.error_code {
+                    ErrorCode::WalletItemNotFound => Err(println_err!("Signer DID: \"{}\" not found", signer_did)),
+                    _ => Err(handle_indy_error(err, Some(&signer_did), None, None)),
+                }
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod bundle_merge_command {
+    use super::*;
+
+    command!(CommandMetadata::build("bundle-merge", "Merge signatures collected in several signing bundles of the same transaction.")
+                .add_required_param("bundles", "Array of signing bundles")
+                .add_example(r#"ledger bundle-merge bundles=[{"ver":"1",...,"signatures":{"V4SGRU86Z58d6TV7PBUe6f":"..."}},{"ver":"1",...,"signatures":{"VsKV7grR1BUE29mG2Fm2kX":"..."}}]"#)
+                .finalize()
+    );
+
+    fn execute(_ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> params {:?}", params);
+
+        let bundles = get_str_param("bundles", params).map_err(error_err!())?;
+
+        let res = match Ledger::merge_signing_bundles(bundles) {
+            Ok(bundle) => {
+                println_succ!("Signing bundles have been merged:");
+                println_succ!("{}", bundle);
+                Ok(())
+            }
+            Err(err) => Err(handle_indy_error(err, None, None, None))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod bundle_check_command {
+    use super::*;
+
+    command!(CommandMetadata::build("bundle-check", "Verify signatures of signing bundle and check whether they are enough to send transaction.")
+                .add_required_param("bundle", "Signing bundle")
+                .add_optional_param("send", "Send transaction to the Ledger if the bundle is complete (false by default)")
+                .add_example(r#"ledger bundle-check bundle={"ver":"1","request":{...},"signing_input":"...","signers":{...},"signatures":{...}}"#)
+                .add_example(r#"ledger bundle-check bundle={"ver":"1","request":{...},"signing_input":"...","signers":{...},"signatures":{...}} send=true"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let bundle = get_str_param("bundle", params).map_err(error_err!())?;
+        let send = get_opt_bool_param("send", params).map_err(error_err!())?.unwrap_or(false);
+
+        let status = Ledger::check_signing_bundle(bundle)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let status = serde_json::from_str::<JSONValue>(&status)
+            .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+        println!("Signed by: {}", _join_dids(&status["signed"]));
+        println!("Not signed by: {}", _join_dids(&status["unsigned"]));
+
+        let request = match status["request"] {
+            JSONValue::Object(_) => status["request"].to_string(),
+            _ => {
+                if send {
+                    return Err(println_err!("Signing bundle isn't complete"));
+                }
+                println_warn!("Signing bundle isn't complete");
+                trace!("execute << ");
+                return Ok(());
+            }
+        };
+
+        if !send {
+            println_succ!("Signing bundle is complete. Transaction:");
+            println_succ!("{}", request);
+            trace!("execute << ");
+            return Ok(());
+        }
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+
+        let response_json = Ledger::submit_request(pool_handle, &request)
+            .map_err(|err| handle_indy_error(err, None, Some(&pool_name), None))?;
+
+        let response = serde_json::from_str::<Response<serde_json::Value>>(&response_json)
+            .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+        let res = match response {
+            Response { op: ResponseType::REPLY, result: Some(_), reason: None } =>
+                Ok(println!("Transaction has been sent to Ledger.\nResponse: \n{}", response_json)),
+            Response { op: ResponseType::REQNACK, result: None, reason: Some(reason) } |
+            Response { op: ResponseType::REJECT, result: None, reason: Some(reason) } =>
+                Err(println_err!("Transaction has been rejected: {}", extract_error_message(&reason))),
+            _ => Err(println_err!("Invalid data has been received"))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+
+    fn _join_dids(dids: &JSONValue) -> String {
+        dids.as_array()
+            .map(|dids| dids.iter().filter_map(JSONValue::as_str).collect::<Vec<&str>>().join(", "))
+            .unwrap_or_default()
+    }
+}
+
 pub mod auth_rule_command {
     use super::*;
 
@@ -1619,7 +1902,7 @@ pub mod tests {
     use super::*;
     use commands::wallet::tests::{create_and_open_wallet, close_and_delete_wallet, open_wallet, close_wallet};
     use commands::pool::tests::disconnect_and_delete_pool;
-    use commands::did::tests::{new_did, use_did, SEED_TRUSTEE, DID_TRUSTEE, DID_MY1, VERKEY_MY1, SEED_MY3, DID_MY3, VERKEY_MY3};
+    use commands::did::tests::{new_did, use_did, SEED_TRUSTEE, DID_TRUSTEE, VERKEY_TRUSTEE, SEED_MY1, DID_MY1, VERKEY_MY1, SEED_MY3, DID_MY3, VERKEY_MY3};
     #[cfg(feature = "nullpay_plugin")]
     use commands::common::tests::{load_null_payment_plugin, NULL_PAYMENT_METHOD};
     #[cfg(feature = "nullpay_plugin")]
@@ -2725,6 +3008,8 @@ pub mod tests {
 
     mod custom {
         use super::*;
+        use indy::future::Future;
+        use indy::ledger as indy_ledger;
 
         pub const TXN: &'static str = r#"{
                                             "reqId":1513241300414292814,
@@ -2834,6 +3119,38 @@ pub mod tests {
             tear_down_with_wallet_and_pool(&ctx);
         }
 
+        #[test]
+        pub fn custom_works_for_type_and_invalid_operation() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+
+            let txn_type = r#"{"name":"CLI_CUSTOM_TXN","code":"40001","kind":"write","schema":{"data":{"type":"string"}}}"#;
+            indy_ledger::register_custom_txn_type(txn_type).wait().unwrap();
+            {
+                let cmd = custom_command::new();
+                let mut params = CommandParams::new();
+                params.insert("sign", "true".to_string());
+                params.insert("type", "CLI_CUSTOM_TXN".to_string());
+                params.insert("txn", r#"{"data":1}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn custom_works_for_unknown_type() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = custom_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", "CLI_UNKNOWN_TXN".to_string());
+                params.insert("txn", r#"{"data":"some data"}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
         #[test]
         pub fn custom_works_for_unknown_submitter_did() {
             let ctx = setup_with_wallet_and_pool();
@@ -3647,6 +3964,199 @@ pub mod tests {
         }
     }
 
+    mod prepare_for_endorser {
+        use super::*;
+
+        #[test]
+        pub fn prepare_for_endorser_works() {
+            let ctx = setup_with_wallet_and_pool();
+            new_did(&ctx, SEED_MY3);
+            use_did(&ctx, DID_MY3);
+            {
+                let cmd = prepare_for_endorser_command::new();
+                let mut params = CommandParams::new();
+                params.insert("txn", build_nym_txn(DID_MY3));
+                params.insert("endorser", DID_TRUSTEE.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn prepare_for_endorser_works_for_no_active_did() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = prepare_for_endorser_command::new();
+                let mut params = CommandParams::new();
+                params.insert("txn", build_nym_txn(DID_MY3));
+                params.insert("endorser", DID_TRUSTEE.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
+    mod endorse {
+        use super::*;
+
+        #[test]
+        pub fn endorse_works() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            let (did, verkey) = create_new_did(&ctx);
+            send_nym(&ctx, &did, &verkey, None);
+            let txn = prepare_schema_for_endorser(&ctx, &did, DID_TRUSTEE);
+            {
+                let cmd = endorse_command::new();
+                let mut params = CommandParams::new();
+                params.insert("txn", txn);
+                params.insert("send", "true".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn endorse_works_for_other_endorser() {
+            let ctx = setup_with_wallet_and_pool();
+            new_did(&ctx, SEED_MY3);
+            use_trustee(&ctx);
+            let txn = prepare_schema_for_endorser(&ctx, DID_MY3, DID_MY1);
+            {
+                let cmd = endorse_command::new();
+                let mut params = CommandParams::new();
+                params.insert("txn", txn);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn endorse_works_for_no_endorser() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = endorse_command::new();
+                let mut params = CommandParams::new();
+                params.insert("txn", build_nym_txn(DID_MY3));
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
+    mod signing_bundle {
+        use super::*;
+
+        #[test]
+        pub fn bundle_create_works() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = bundle_create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("txn", build_nym_txn(DID_TRUSTEE));
+                params.insert("signers", _signers());
+                params.insert("constraint", r#"{"constraint_id":"ROLE","role":"0","sig_count":1}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn bundle_create_works_for_invalid_signers() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = bundle_create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("txn", build_nym_txn(DID_TRUSTEE));
+                params.insert("signers", "{}".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn bundle_sign_works() {
+            let ctx = setup_with_wallet();
+            use_trustee(&ctx);
+            {
+                let cmd = bundle_sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("bundle", _bundle());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn bundle_sign_works_for_not_a_signer() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY3);
+            use_did(&ctx, DID_MY3);
+            {
+                let cmd = bundle_sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("bundle", _bundle());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn bundle_merge_works() {
+            let ctx = setup_with_wallet();
+            let bundle = _bundle();
+            {
+                let cmd = bundle_merge_command::new();
+                let mut params = CommandParams::new();
+                params.insert("bundles", format!("[{},{}]", bundle, bundle));
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn bundle_check_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_TRUSTEE);
+            new_did(&ctx, SEED_MY1);
+            let (wallet_handle, _) = get_opened_wallet(&ctx).unwrap();
+            let bundle = Ledger::sign_signing_bundle(wallet_handle, DID_TRUSTEE, &_bundle()).unwrap();
+            let bundle = Ledger::sign_signing_bundle(wallet_handle, DID_MY1, &bundle).unwrap();
+            {
+                let cmd = bundle_check_command::new();
+                let mut params = CommandParams::new();
+                params.insert("bundle", bundle);
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn bundle_check_works_for_send_not_complete_bundle() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = bundle_check_command::new();
+                let mut params = CommandParams::new();
+                params.insert("bundle", _bundle());
+                params.insert("send", "true".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        fn _signers() -> String {
+            json!({
+                DID_TRUSTEE: {"verkey": VERKEY_TRUSTEE, "role": "TRUSTEE"},
+                DID_MY1: {"verkey": VERKEY_MY1, "role": "TRUSTEE"},
+            }).to_string()
+        }
+
+        fn _bundle() -> String {
+            Ledger::build_signing_bundle(&build_nym_txn(DID_TRUSTEE), &_signers(), None).unwrap()
+        }
+    }
+
     mod auth_rule {
         use super::*;
 
@@ -3767,6 +4277,18 @@ pub mod tests {
         seq_no.to_string()
     }
 
+    fn build_nym_txn(author_did: &str) -> String {
+        Ledger::build_nym_request(author_did, DID_MY1, None, None, None).unwrap()
+    }
+
+    fn prepare_schema_for_endorser(ctx: &CommandContext, author_did: &str, endorser_did: &str) -> String {
+        let (wallet_handle, _) = get_opened_wallet(ctx).unwrap();
+        let schema_data = r#"{"id":"id", "name":"cli_endorsed_gvt","version":"1.0","attrNames":["name"],"ver":"1.0"}"#;
+        let request = Ledger::build_schema_request(author_did, schema_data).unwrap();
+        let request = Ledger::append_request_endorser(&request, endorser_did).unwrap();
+        Ledger::multi_sign_request(wallet_handle, author_did, &request).unwrap()
+    }
+
     pub fn send_nym(ctx: &CommandContext, did: &str, verkey: &str, role: Option<&str>) {
         let cmd = nym_command::new();
         let mut params = CommandParams::new();
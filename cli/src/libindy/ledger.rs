@@ -25,6 +25,30 @@ impl Ledger {
         ledger::multi_sign_request(wallet_handle, submitter_did, request_json).wait()
     }
 
+    pub fn append_request_endorser(request_json: &str, endorser_did: &str) -> Result<String, IndyError> {
+        ledger::append_request_endorser(request_json, endorser_did).wait()
+    }
+
+    pub fn build_custom_request(submitter_did: Option<&str>, txn_type: &str, operation_json: &str) -> Result<String, IndyError> {
+        ledger::build_custom_request(submitter_did, txn_type, operation_json).wait()
+    }
+
+    pub fn build_signing_bundle(request_json: &str, signers_json: &str, constraint_json: Option<&str>) -> Result<String, IndyError> {
+        ledger::build_signing_bundle(request_json, signers_json, constraint_json).wait()
+    }
+
+    pub fn sign_signing_bundle(wallet_handle: i32, signer_did: &str, bundle_json: &str) -> Result<String, IndyError> {
+        ledger::sign_signing_bundle(wallet_handle, signer_did, bundle_json).wait()
+    }
+
+    pub fn merge_signing_bundles(bundles_json: &str) -> Result<String, IndyError> {
+        ledger::merge_signing_bundles(bundles_json).wait()
+    }
+
+    pub fn check_signing_bundle(bundle_json: &str) -> Result<String, IndyError> {
+        ledger::check_signing_bundle(bundle_json).wait()
+    }
+
     pub fn build_nym_request(submitter_did: &str, target_did: &str, verkey: Option<&str>,
                              data: Option<&str>, role: Option<&str>) -> Result<String, IndyError> {
         ledger::build_nym_request(submitter_did, target_did, verkey, data, role).wait()
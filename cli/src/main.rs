@@ -126,6 +126,12 @@ fn build_executor() -> CommandExecutor {
         .add_command(ledger::set_fees_prepare_command::new())
         .add_command(ledger::verify_payment_receipt_command::new())
         .add_command(ledger::sign_multi_command::new())
+        .add_command(ledger::prepare_for_endorser_command::new())
+        .add_command(ledger::endorse_command::new())
+        .add_command(ledger::bundle_create_command::new())
+        .add_command(ledger::bundle_sign_command::new())
+        .add_command(ledger::bundle_merge_command::new())
+        .add_command(ledger::bundle_check_command::new())
         .add_command(ledger::auth_rule_command::new())
         .add_command(ledger::get_auth_rule_command::new())
         .finalize_group()
@@ -55,6 +55,16 @@ extern "C" {
                                                                                  indy_error_t  err,
                                                                                  const char*   cred_offer_json)
                                                             );
+
+    extern indy_error_t indy_issuer_create_credential_offer_with_nonce_tracking(indy_handle_t command_handle,
+                                                                                indy_handle_t wallet_handle,
+                                                                                const char *  cred_def_id,
+                                                                                indy_u64_t    nonce_expires_at,
+
+                                                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                                                     indy_error_t  err,
+                                                                                                     const char*   cred_offer_json)
+                                                                                );
     
     extern indy_error_t indy_issuer_create_credential(indy_handle_t command_handle,
                                                       indy_handle_t wallet_handle,
@@ -70,6 +80,48 @@ extern "C" {
                                                                            const char*   cred_revoc_id,
                                                                            const char*   revoc_reg_delta_json)
                                                       );
+
+    extern indy_error_t indy_issuer_create_credential_with_options(indy_handle_t command_handle,
+                                                                   indy_handle_t wallet_handle,
+                                                                   const char *  cred_offer_json,
+                                                                   const char *  cred_req_json,
+                                                                   const char *  cred_values_json,
+                                                                   const char *  rev_reg_id,
+                                                                   indy_handle_t blob_storage_reader_handle,
+                                                                   const char *  options_json,
+
+                                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   cred_json,
+                                                                                        const char*   cred_revoc_id,
+                                                                                        const char*   revoc_reg_delta_json)
+                                                                   );
+
+    extern indy_error_t indy_issuer_search_issued_credentials(indy_handle_t command_handle,
+                                                              indy_handle_t wallet_handle,
+                                                              const char *  query_json,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   indy_handle_t search_handle,
+                                                                                   indy_u32_t    total_count)
+                                                              );
+
+    extern indy_error_t indy_issuer_fetch_issued_credentials(indy_handle_t command_handle,
+                                                             indy_handle_t search_handle,
+                                                             indy_u32_t    count,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   issued_credentials_json)
+                                                             );
+
+    extern indy_error_t indy_issuer_close_issued_credentials_search(indy_handle_t command_handle,
+                                                                    indy_handle_t search_handle,
+
+                                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                                         indy_error_t  err)
+                                                                    );
     
     extern indy_error_t indy_issuer_revoke_credential(indy_handle_t command_handle,
                                                       indy_handle_t wallet_handle,
@@ -249,6 +301,31 @@ extern "C" {
                                                    );
 
 
+    extern indy_error_t indy_verifier_track_proof_request_nonce(indy_handle_t command_handle,
+                                                                indy_handle_t wallet_handle,
+                                                                const char *  proof_request_json,
+                                                                indy_u64_t    nonce_expires_at,
+
+                                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                                     indy_error_t  err)
+                                                                );
+
+
+    extern indy_error_t indy_verifier_verify_proof_with_nonce_tracking(indy_handle_t command_handle,
+                                                                       indy_handle_t wallet_handle,
+                                                                       const char *  proof_request_json,
+                                                                       const char *  proof_json,
+                                                                       const char *  schemas_json,
+                                                                       const char *  credential_defs_jsons,
+                                                                       const char *  rev_reg_defs_json,
+                                                                       const char *  rev_regs_json,
+
+                                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                                            indy_error_t  err,
+                                                                                            indy_bool_t   valid )
+                                                                       );
+
+
     extern indy_error_t indy_create_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
                                                      const char *  rev_reg_def_json,
@@ -275,6 +352,73 @@ extern "C" {
                                                                           const char*   updated_rev_state_json)
                                                      );
 
+
+    extern indy_error_t indy_prover_store_revocation_state(indy_handle_t command_handle,
+                                                           indy_handle_t wallet_handle,
+                                                           const char *  rev_reg_id,
+                                                           const char *  cred_rev_id,
+                                                           const char *  rev_state_json,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err)
+                                                           );
+
+
+    extern indy_error_t indy_prover_get_revocation_state(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  rev_reg_id,
+                                                         const char *  cred_rev_id,
+                                                         indy_u64_t    timestamp,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   rev_state_json)
+                                                         );
+
+
+    extern indy_error_t indy_prover_update_revocation_states(indy_handle_t command_handle,
+                                                             indy_handle_t wallet_handle,
+                                                             indy_handle_t pool_handle,
+                                                             const char *  proof_req_json,
+                                                             const char *  requested_credentials_json,
+                                                             const char *  rev_reg_defs_json,
+                                                             const char *  blob_storage_reader_handles_json,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   rev_states_json,
+                                                                                  const char*   requested_credentials_json)
+                                                             );
+
+
+    extern indy_error_t indy_prover_get_credential_w3c(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  cred_id,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   w3c_credential_json)
+                                                       );
+
+
+    extern indy_error_t indy_prover_proof_to_w3c(indy_handle_t command_handle,
+                                                 const char *  proof_req_json,
+                                                 const char *  proof_json,
+
+                                                 void           (*cb)(indy_handle_t command_handle_,
+                                                                      indy_error_t  err,
+                                                                      const char*   w3c_presentation_json)
+                                                 );
+
+
+    extern indy_error_t indy_verifier_proof_from_w3c(indy_handle_t command_handle,
+                                                     const char *  w3c_presentation_json,
+
+                                                     void           (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          const char*   proof_json)
+                                                     );
+
 #ifdef __cplusplus
 }
 #endif
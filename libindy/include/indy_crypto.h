@@ -17,6 +17,9 @@ extern "C" {
     ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
     ///                                Can be UTF-8, base64 or hex string.
     ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+    ///     "derivation_path": string, (optional) SLIP-0010 path (like "m/44'/0'/0'") to derive key from the wallet master seed
+    ///                                (see indy_set_master_seed). Only hardened indexes and ed25519 keys are supported.
+    ///                                Can't be used together with seed.
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -40,6 +43,104 @@ extern "C" {
                                                                 const char *const vk)
                                        );
 
+    /// Creates BLS keys pair, stores it in the wallet and computes proof of possession for it.
+    /// The result can be used as "blskey" and "blskey_pop" of NODE transaction
+    /// (see indy_build_node_request and indy_build_node_request_with_bls_key).
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// key_json: Key information as json. Example:
+    /// {
+    ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
+    ///                                Can be UTF-8, base64 or hex string.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - blskey: Base58 encoded BLS ver key, also used as key identifier
+    /// - blskey_pop: Base58 encoded proof of possession for the BLS key
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_create_bls_key(indy_handle_t     command_handle,
+                                            indy_handle_t     wallet_handle,
+                                            const char *const key_json,
+
+                                            void              (*cb)(indy_handle_t     command_handle,
+                                                                    indy_error_t      err,
+                                                                    const char *const blskey,
+                                                                    const char *const blskey_pop)
+                                           );
+
+    /// Sets the master seed of the wallet used for deterministic derivation of keys and DIDs
+    /// (see derivation_path of indy_create_key and indy_create_and_store_my_did).
+    /// The master seed can be set only once for the wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// seed: (optional) hex encoded master seed from 16 to 64 bytes (if not set random 32 bytes seed will be created).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - seed: hex encoded master seed that should be backed up to restore derived keys.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_set_master_seed(indy_handle_t     command_handle,
+                                             indy_handle_t     wallet_handle,
+                                             const char *const seed,
+
+                                             void              (*cb)(indy_handle_t     command_handle,
+                                                                     indy_error_t      err,
+                                                                     const char *const seed)
+                                            );
+
+    /// Lists keys and DIDs derived from the wallet master seed (see derivation_path of indy_create_key,
+    /// indy_create_and_store_my_did and indy_replace_keys_start) with their derivation paths.
+    /// Listed keys can be re-derived in other wallet with the same master seed set.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - derived_keys: list of derived keys as json
+    /// [{
+    ///     "verkey": string, // verkey of derived key
+    ///     "derivation_path": string, // SLIP-0010 path the key has been derived with
+    ///     "did": string, // (optional) DID the key has been created for
+    /// }]
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_list_derived_keys(indy_handle_t     command_handle,
+                                               indy_handle_t     wallet_handle,
+
+                                               void              (*cb)(indy_handle_t     command_handle,
+                                                                       indy_error_t      err,
+                                                                       const char *const derived_keys)
+                                              );
+
     /// Saves/replaces the meta information for the giving key in the wallet.
     ///
     /// #Params
@@ -96,6 +197,72 @@ extern "C" {
                                                                       const char *const metadata)
                                              );
 
+    /// Exports a key stored in the wallet in one of standard formats.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// verkey: The key (verkey, key id) to export. Supported crypto types: ed25519, x25519.
+    /// format: Export format:
+    ///     "jwk" - JSON Web Key with OKP key type (RFC 8037), contains private key in plain form;
+    ///     "pkcs8" - PEM encoded encrypted PKCS#8 private key (PBES2 with PBKDF2-HMAC-SHA256 and AES-256-CBC).
+    /// protection_key: Passphrase used to encrypt the exported key. Required for pkcs8, must be null for jwk.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - exported_key - Exported key in requested format.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_export_key(indy_handle_t     command_handle,
+                                        indy_handle_t     wallet_handle,
+                                        const char *const verkey,
+                                        const char *const format,
+                                        const char *const protection_key,
+
+                                        void              (*cb)(indy_handle_t     command_handle,
+                                                                indy_error_t      err,
+                                                                const char *const exported_key)
+                                       );
+
+    /// Imports a key exported by indy_export_key (or by other tools in the same formats) and stores it in the wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// exported_key: Key data in the format set by format param.
+    /// format: Key data format: "jwk" (OKP key with Ed25519 or X25519 curve) or "pkcs8" (PEM encoded encrypted PKCS#8).
+    /// protection_key: Passphrase used to decrypt the key. Required for pkcs8, must be null for jwk.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - verkey: Ver key of imported key pair (with crypto type suffix for non-default crypto types).
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_import_key(indy_handle_t     command_handle,
+                                        indy_handle_t     wallet_handle,
+                                        const char *const exported_key,
+                                        const char *const format,
+                                        const char *const protection_key,
+
+                                        void              (*cb)(indy_handle_t     command_handle,
+                                                                indy_error_t      err,
+                                                                const char *const verkey)
+                                       );
+
 
 
 
@@ -165,6 +332,72 @@ extern "C" {
                                                                 indy_bool_t   valid )
                                           );
 
+    /// Creates JWS (RFC 7515) over arbitrary payload with a key from the wallet.
+    ///
+    /// Signature algorithm is selected by the key crypto type: EdDSA for ed25519 keys and ES256K for secp256k1 keys.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// signer_vk: id (verkey) of signer. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    /// payload_raw: a pointer to first byte of payload to be signed
+    /// payload_len: a payload length
+    /// options_json: (optional) signing options json:
+    /// {
+    ///     "kid": string, (optional) value of kid header parameter, signer_vk is used by default.
+    ///                               Can be DID key reference, for example "did:sov:<did>#key-1"
+    ///     "detached": bool, (optional) omit payload from the result JWS, false by default
+    ///     "serialization": string, (optional) "compact" (default) or "json" (flattened JWS JSON serialization)
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// jws: JWS string in the requested serialization
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_sign_jws(indy_handle_t      command_handle,
+                                      indy_handle_t      wallet_handle,
+                                      const char *       signer_vk,
+                                      const indy_u8_t *  payload_raw,
+                                      indy_u32_t         payload_len,
+                                      const char *       options_json,
+
+                                      void           (*cb)(indy_handle_t     command_handle_,
+                                                           indy_error_t      err,
+                                                           const char *const jws)
+                                     );
+
+    /// Verifies JWS created by indy_sign_jws (or any EdDSA/ES256K JWS) in compact or flattened JSON serialization.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// jws: JWS string
+    /// detached_payload_raw: (optional) a pointer to first byte of payload for JWS with detached payload
+    /// detached_payload_len: a detached payload length
+    /// signer_vk: verkey of the expected signer. Note that kid header parameter is not used to resolve the verkey
+    ///            as it is chosen by the signer. Resolve kid to a trusted verkey (e.g. with indy_key_for_did) instead.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// valid: true - if signature is valid, false - otherwise
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_verify_jws(indy_handle_t      command_handle,
+                                        const char *       jws,
+                                        const indy_u8_t *  detached_payload_raw,
+                                        indy_u32_t         detached_payload_len,
+                                        const char *       signer_vk,
+
+                                        void           (*cb)(indy_handle_t command_handle_,
+                                                             indy_error_t  err,
+                                                             indy_bool_t   valid )
+                                       );
+
     /// **** THIS FUNCTION WILL BE DEPRECATED USE indy_pack_message() INSTEAD ****
     /// Encrypt a message by authenticated-encryption scheme.
     ///
@@ -445,6 +678,285 @@ extern "C" {
                                                                  const indy_u8_t*  res_json_raw,
                                                                  indy_u32_t        res_json_len)
                                             );
+
+    /// Packs a message for receivers and wraps it into forward envelopes for each mediator on the route (Experimental)
+    ///
+    /// The message is packed as indy_pack_message does. Then for each routing key the packed message is put into
+    /// forward message and anoncrypted for this routing key:
+    /// {
+    ///     "@type": "https://didcomm.org/routing/1.0/forward",
+    ///     "@id": <uuid>,
+    ///     "to": <receiver verkey or previous routing key>,
+    ///     "msg": <packed message>
+    /// }
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// message: a pointer to the first byte of the message to be packed
+    /// message_len: the length of the message
+    /// receiver_keys: a string in the format of a json list which will contain the list of receiver's keys
+    ///                the message is being encrypted for. Only one receiver is allowed if routing keys are set
+    ///                as the route is specific to the receiver.
+    ///                Example:
+    ///                "[<receiver edge_agent verkey>]"
+    /// routing_keys: a string in the format of a json list of mediator keys ordered from the mediator
+    ///                closest to the receivers to the outermost one. Empty list means no routing.
+    ///                Example:
+    ///                "[<receiver mediator verkey>, <agency verkey>]"
+    /// sender: the sender's verkey as a string When null pointer is used in this parameter, anoncrypt is used
+    ///         for the inner message. Forward envelopes are always anoncrypted.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// a JWE of the outermost layer in the format described for indy_pack_message
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_pack_message_with_routing(indy_handle_t      command_handle,
+                                                       indy_handle_t      wallet_handle,
+                                                       const indy_u8_t*   message,
+                                                       indy_u32_t         message_len,
+                                                       const char *       receiver_keys,
+                                                       const char *       routing_keys,
+                                                       const char *       sender,
+
+                                                       void           (*cb)(indy_handle_t     command_handle_,
+                                                                            indy_error_t      err,
+                                                                            const indy_u8_t*  jwe_msg_raw,
+                                                                            indy_u32_t        jwe_msg_len)
+                                                       );
+
+    /// Unpacks a JWE-like formatted message and all forward envelopes inside of it addressed to keys from the wallet (Experimental)
+    ///
+    /// Unpacking stops at the first layer that is not a forward message or whose forwarded message
+    /// is not addressed to any key in the wallet (mediator case, the forward message itself is returned).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// jwe_data: a pointer to the first byte of the JWE to be unpacked
+    /// jwe_len: the length of the JWE message in bytes
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// {
+    ///     message: <decrypted message of the innermost unpacked layer>,
+    ///     sender_verkey: <sender_verkey of the innermost layer> (only if authcrypt was used),
+    ///     recipient_verkey: <recipient_verkey of the innermost layer>,
+    ///     layers: [ // from the outermost to the innermost layer
+    ///         {
+    ///             recipient_verkey: <recipient_verkey>,
+    ///             sender_verkey: <sender_verkey> (only if authcrypt was used),
+    ///             forward_to: <"to" field of forward message> (only for forward layers)
+    ///         }
+    ///     ]
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_unpack_message_with_routing(indy_handle_t      command_handle,
+                                                         indy_handle_t      wallet_handle,
+                                                         const indy_u8_t*   jwe_msg,
+                                                         indy_u32_t         jwe_len,
+
+                                                         void           (*cb)(indy_handle_t     command_handle_,
+                                                                              indy_error_t      err,
+                                                                              const indy_u8_t*  res_json_raw,
+                                                                              indy_u32_t        res_json_len)
+                                                         );
+
+    /// Starts streaming encryption of a large payload for a recipient (Experimental)
+    ///
+    /// Content key is generated for the stream and wrapped for the recipient the same way as in indy_pack_message:
+    /// with crypto_box (authcrypt) if sender key is passed or with sealed box (anoncrypt) otherwise.
+    /// The payload is encrypted with chacha20poly1305_ietf in chunks following STREAM construction: chunks use
+    /// sequential nonces and the last chunk is marked in associated data, so truncation and reordering are detected.
+    /// Stream that will not be finalized must be released with indy_crypto_stream_close.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// sender_vk: (optional) sender's verkey. When null pointer is used in this parameter, anoncrypt is used
+    /// recipient_vk: recipient's verkey
+    /// chunk_size: size of plaintext chunk in bytes, 0 means default size (64KB)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stream_handle: handle to use with indy_crypto_stream_encrypt_update and indy_crypto_stream_encrypt_finalize
+    /// header_json: stream header that must be passed to indy_crypto_stream_decrypt_init on the recipient side
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_stream_encrypt_init(indy_handle_t      command_handle,
+                                                        indy_handle_t      wallet_handle,
+                                                        const char *       sender_vk,
+                                                        const char *       recipient_vk,
+                                                        indy_u32_t         chunk_size,
+
+                                                        void           (*cb)(indy_handle_t     command_handle_,
+                                                                             indy_error_t      err,
+                                                                             indy_handle_t     stream_handle,
+                                                                             const char *      header_json)
+                                                        );
+
+    /// Encrypts next part of the payload (Experimental)
+    ///
+    /// Encrypted data is returned by full chunks, so the result can be empty if not enough data has been passed yet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_stream_encrypt_init).
+    /// data_raw: a pointer to first byte of data to be encrypted
+    /// data_len: a data length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// encrypted data that must be appended to the encrypted stream
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_stream_encrypt_update(indy_handle_t      command_handle,
+                                                          indy_handle_t      stream_handle,
+                                                          const indy_u8_t*   data_raw,
+                                                          indy_u32_t         data_len,
+
+                                                          void           (*cb)(indy_handle_t     command_handle_,
+                                                                               indy_error_t      err,
+                                                                               const indy_u8_t*  encrypted_raw,
+                                                                               indy_u32_t        encrypted_len)
+                                                          );
+
+    /// Finishes streaming encryption and releases the stream handle (Experimental)
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_stream_encrypt_init).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// the last part of encrypted data that must be appended to the encrypted stream
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_stream_encrypt_finalize(indy_handle_t      command_handle,
+                                                            indy_handle_t      stream_handle,
+
+                                                            void           (*cb)(indy_handle_t     command_handle_,
+                                                                                 indy_error_t      err,
+                                                                                 const indy_u8_t*  encrypted_raw,
+                                                                                 indy_u32_t        encrypted_len)
+                                                            );
+
+    /// Starts streaming decryption of a payload encrypted by indy_crypto_stream_encrypt_* functions (Experimental)
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// header_json: stream header returned by indy_crypto_stream_encrypt_init
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stream_handle: handle to use with indy_crypto_stream_decrypt_update and indy_crypto_stream_decrypt_finalize
+    /// info_json: {
+    ///     "recipient_verkey": <recipient verkey>,
+    ///     "sender_verkey": <sender verkey> (only if authcrypt was used)
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_stream_decrypt_init(indy_handle_t      command_handle,
+                                                        indy_handle_t      wallet_handle,
+                                                        const char *       header_json,
+
+                                                        void           (*cb)(indy_handle_t     command_handle_,
+                                                                             indy_error_t      err,
+                                                                             indy_handle_t     stream_handle,
+                                                                             const char *      info_json)
+                                                        );
+
+    /// Decrypts next part of the encrypted stream (Experimental)
+    ///
+    /// Decrypted data is returned only after the whole chunk is received and authenticated,
+    /// so the result can be empty if not enough data has been passed yet.
+    /// On decryption error the stream handle is released.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_stream_decrypt_init).
+    /// data_raw: a pointer to first byte of encrypted data
+    /// data_len: an encrypted data length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// decrypted data
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_stream_decrypt_update(indy_handle_t      command_handle,
+                                                          indy_handle_t      stream_handle,
+                                                          const indy_u8_t*   data_raw,
+                                                          indy_u32_t         data_len,
+
+                                                          void           (*cb)(indy_handle_t     command_handle_,
+                                                                               indy_error_t      err,
+                                                                               const indy_u8_t*  decrypted_raw,
+                                                                               indy_u32_t        decrypted_len)
+                                                          );
+
+    /// Finishes streaming decryption, checks that the stream is complete and releases the stream handle (Experimental)
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_stream_decrypt_init).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// the last part of decrypted data
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_stream_decrypt_finalize(indy_handle_t      command_handle,
+                                                            indy_handle_t      stream_handle,
+
+                                                            void           (*cb)(indy_handle_t     command_handle_,
+                                                                                 indy_error_t      err,
+                                                                                 const indy_u8_t*  decrypted_raw,
+                                                                                 indy_u32_t        decrypted_len)
+                                                            );
+
+    /// Releases encrypt or decrypt stream handle without finalization (Experimental)
+    ///
+    /// Must be called for streams that will not be finalized (e.g. on transfer abort) to release resources.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_stream_encrypt_init or indy_crypto_stream_decrypt_init).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_crypto_stream_close(indy_handle_t      command_handle,
+                                                 indy_handle_t      stream_handle,
+
+                                                 void           (*cb)(indy_handle_t     command_handle_,
+                                                                      indy_error_t      err)
+                                                 );
 #ifdef __cplusplus
 }
 #endif
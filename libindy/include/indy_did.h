@@ -25,6 +25,9 @@ extern "C" {
     ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
     ///               currently only 'ed25519' value is supported for this field)
     ///     "cid": bool, (optional; if not set then false is used;)
+    ///     "derivation_path": string, (optional) SLIP-0010 path (like "m/44'/0'/0'") to derive keys from the wallet master seed
+    ///                                (see indy_set_master_seed). Only hardened indexes and ed25519 keys are supported.
+    ///                                Can't be used together with seed.
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -63,6 +66,9 @@ extern "C" {
     ///                                Can be UTF-8, base64 or hex string.
     ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
     ///               currently only 'ed25519' value is supported for this field)
+    ///     "derivation_path": string, (optional) SLIP-0010 path (like "m/44'/0'/0'") to derive keys from the wallet master seed
+    ///                                (see indy_set_master_seed). Only hardened indexes and ed25519 keys are supported.
+    ///                                Can't be used together with seed.
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -90,6 +96,8 @@ extern "C" {
                                                );
 
     /// Apply temporary keys as main for an existing DID (owned by the caller of the library).
+    /// Replaced verkey is kept in the DID key history (see indy_get_did_key_history).
+    /// New verkey becomes valid in the history after its ledger transaction time is set by indy_set_did_key_ledger_info.
     ///
     /// #Params
     /// wallet_handle: wallet handler (created by open_wallet).
@@ -118,6 +126,8 @@ extern "C" {
 
     /// Saves their DID for a pairwise connection in a secured Wallet,
     /// so that it can be used to verify transaction.
+    /// If the DID is already stored with another verkey, the verkey is updated and the previous one
+    /// is kept in the DID key history (see indy_get_did_key_history).
     ///
     /// #Params
     /// wallet_handle: wallet handler (created by open_wallet).
@@ -286,6 +296,110 @@ extern "C" {
                                                                           const char *const transport_vk)
                                                  );
 
+    /// Set/replaces service entries for the given DID.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - The DID to set services for.
+    /// services_json - list of DID services:
+    ///   [{
+    ///       "id": optional<string>, - service id
+    ///       "type": string, - service type, e.g. "IndyAgent" or "did-communication"
+    ///       "priority": optional<int>, - lower value means higher priority (0 by default)
+    ///       "recipientKeys": optional<[string]>, - verkeys of the recipient
+    ///       "routingKeys": optional<[string]>, - verkeys of the mediators
+    ///       "serviceEndpoint": string - service URI
+    ///   }]
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_set_services_for_did(indy_handle_t     command_handle,
+                                                  indy_handle_t     wallet_handle,
+                                                  const char *const did,
+                                                  const char *const services_json,
+
+                                                  void              (*cb)(indy_handle_t     command_handle,
+                                                                          indy_error_t      err)
+                                                 );
+
+    /// Returns service entries for the given DID.
+    ///
+    /// Services are resolved from the wallet first. Endpoint set by indy_set_endpoint_for_did
+    /// is returned as a single service of "IndyAgent" type.
+    /// If there are no services in the wallet they are fetched from the ledger "service" ATTRIB.
+    /// If the DID has no "service" ATTRIB on the ledger its legacy "endpoint" ATTRIB is used.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// pool_handle: Pool handle (created by open_pool).
+    /// did - The DID to resolve services.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - services_json: list of DID services ordered by priority (see indy_set_services_for_did)
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_get_services_for_did(indy_handle_t     command_handle,
+                                                  indy_handle_t     wallet_handle,
+                                                  indy_handle_t     pool_handle,
+                                                  const char *const did,
+
+                                                  void              (*cb)(indy_handle_t     command_handle,
+                                                                          indy_error_t      err,
+                                                                          const char *const services_json)
+                                                 );
+
+    /// Builds an ATTRIB request to write service entries of the DID stored in the wallet to the ledger.
+    ///
+    /// Services are written as "service" raw attribute:
+    /// {"service": <services_json (see indy_set_services_for_did)>}
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// did - The DID to write services for.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - request_json: Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_build_did_services_attrib_request(indy_handle_t     command_handle,
+                                                               indy_handle_t     wallet_handle,
+                                                               const char *const submitter_did,
+                                                               const char *const did,
+
+                                                               void              (*cb)(indy_handle_t     command_handle,
+                                                                                       indy_error_t      err,
+                                                                                       const char *const request_json)
+                                                              );
+
     /// Saves/replaces the meta information for the giving DID in the wallet.
     ///
     /// #Params
@@ -424,6 +538,296 @@ extern "C" {
                                                                  const char *const verkey)
                                             );
 
+    /// Returns verkey rotation history of the DID.
+    ///
+    /// History is recorded by indy_replace_keys_apply and by indy_store_their_did called with new verkey for known DID.
+    /// Validity bounds are set by indy_set_did_key_ledger_info from the ledger transaction time.
+    /// For DIDs that have never been rotated the only entry is the current verkey without validity bounds.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did: DID (my or their) stored in the wallet
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - key_history_json: list of verkeys from the oldest to the current one
+    ///   [{
+    ///       "verkey": string,
+    ///       "validFrom": optional<int>, - ledger time (unix timestamp in seconds) the verkey has been written at
+    ///       "validTo": optional<int>, - ledger time (unix timestamp in seconds) the verkey has been replaced at
+    ///       "seqNo": optional<int>, - sequence number of the ledger transaction the verkey has been written with
+    ///   }]
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_get_did_key_history(indy_handle_t command_handle,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *const did,
+                                                 void          (*fn)(indy_handle_t command_handle_,
+                                                                     indy_error_t err,
+                                                                     const char *const key_history_json)
+                                                );
+
+    /// Saves ledger information of the transaction that has written the verkey of the DID.
+    ///
+    /// Verkey replaced by indy_replace_keys_apply or indy_store_their_did becomes valid in the DID key history
+    /// only after its ledger transaction time is set, the previous verkey is valid until this time.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did: DID (my or their) stored in the wallet
+    /// verkey: verkey from the DID key history
+    /// seq_no: sequence number of NYM transaction
+    /// txn_time: time of NYM transaction (txnTime field of NYM or GET_NYM reply)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_set_did_key_ledger_info(indy_handle_t command_handle,
+                                                     indy_handle_t wallet_handle,
+                                                     const char *const did,
+                                                     const char *const verkey,
+                                                     indy_u64_t seq_no,
+                                                     indy_u64_t txn_time,
+                                                     void          (*fn)(indy_handle_t command_handle_,
+                                                                         indy_error_t err)
+                                                    );
+
+    /// Verifies a signature made by the DID with the verkey that was valid at the given time.
+    ///
+    /// Verkey is resolved from the DID key history (see indy_get_did_key_history),
+    /// so messages signed before key rotation can still be attributed to the DID.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did: DID (my or their) stored in the wallet
+    /// timestamp: unix timestamp (in seconds) the message has been signed at
+    /// message_raw: a pointer to first byte of message to be verified
+    /// message_len: a message length
+    /// signature_raw: a pointer to first byte of signature to be verified
+    /// signature_len: a signature length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - valid: true - if signature is valid for the verkey of the DID at the given time, false - otherwise
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_verify_did_signature_at(indy_handle_t command_handle,
+                                                     indy_handle_t wallet_handle,
+                                                     const char *const did,
+                                                     indy_u64_t timestamp,
+                                                     const indy_u8_t *const message_raw,
+                                                     indy_u32_t message_len,
+                                                     const indy_u8_t *const signature_raw,
+                                                     indy_u32_t signature_len,
+                                                     void          (*fn)(indy_handle_t command_handle_,
+                                                                         indy_error_t err,
+                                                                         indy_bool_t valid)
+                                                    );
+
+    /// Replaces tags of my DID record.
+    ///
+    /// Tags can be used in indy_search_my_dids queries.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did: DID stored in the wallet
+    /// tags_json: the record tags used for search and storing meta information as json:
+    ///   {
+    ///     "tagName1": <str>, // string tag (will be stored encrypted)
+    ///     "~tagName2": <str>, // string tag (will be stored un-encrypted)
+    ///   }
+    ///   Note that "metadata" tag name is reserved and is always set to metadata of the DID.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_set_my_did_tags(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *const did,
+                                             const char *const tags_json,
+                                             void          (*fn)(indy_handle_t command_handle_,
+                                                                 indy_error_t err)
+                                            );
+
+    /// Search for my DIDs stored in the wallet.
+    ///
+    /// Instead of immediately returning of fetched records
+    /// this call returns search_handle that can be used later
+    /// to fetch records by small batches (with indy_fetch_my_dids_search).
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// query_json: Wql query filter for DIDs searching based on tags:
+    ///     {
+    ///         "metadata": string, // metadata set by indy_set_did_metadata
+    ///         <tag name set by indy_set_my_did_tags>: string,
+    ///     }
+    /// where query: indy-sdk/doc/design/011-wallet-query-language/README.md
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - search_handle: Search handle that can be used later to fetch records by small batches (with indy_fetch_my_dids_search)
+    /// - total_count: Total count of records
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_search_my_dids(indy_handle_t command_handle,
+                                            indy_handle_t wallet_handle,
+                                            const char *const query_json,
+                                            void          (*fn)(indy_handle_t command_handle_,
+                                                                indy_error_t err,
+                                                                indy_handle_t search_handle,
+                                                                indy_u32_t total_count)
+                                           );
+
+    /// Fetch next DIDs for search.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// search_handle: Search handle (created by indy_search_my_dids)
+    /// count: Count of records to fetch
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - dids: List of DIDs with verkeys and meta data in the same format as for indy_list_my_dids_with_meta.
+    ///   NOTE: The list of length less than the requested count means search iterator is completed.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_fetch_my_dids_search(indy_handle_t command_handle,
+                                                  indy_handle_t wallet_handle,
+                                                  indy_handle_t search_handle,
+                                                  indy_u32_t count,
+                                                  void          (*fn)(indy_handle_t command_handle_,
+                                                                      indy_error_t err,
+                                                                      const char *const dids)
+                                                 );
+
+    /// Close DIDs search (make search handle invalid)
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// search_handle: Search handle (created by indy_search_my_dids)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_close_my_dids_search(indy_handle_t command_handle,
+                                                  indy_handle_t search_handle,
+                                                  void          (*fn)(indy_handle_t command_handle_,
+                                                                      indy_error_t err)
+                                                 );
+
+    /// Deletes my DID from the wallet.
+    ///
+    /// Keys of the DID (including temporary and rotated ones), metadata, endpoint
+    /// and key history records are deleted as well.
+    /// DID used as my DID of some pairwise can't be deleted until the pairwise is deleted (CommonInvalidState).
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did: DID stored in the wallet
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_delete_my_did(indy_handle_t command_handle,
+                                           indy_handle_t wallet_handle,
+                                           const char *const did,
+                                           void          (*fn)(indy_handle_t command_handle_,
+                                                               indy_error_t err)
+                                          );
+
+    /// Deletes their DID from the wallet.
+    ///
+    /// Metadata, endpoint and key history records of the DID are deleted as well.
+    /// DID used as their DID of some pairwise can't be deleted until the pairwise is deleted (CommonInvalidState).
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did: DID stored in the wallet
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_delete_their_did(indy_handle_t command_handle,
+                                              indy_handle_t wallet_handle,
+                                              const char *const did,
+                                              void          (*fn)(indy_handle_t command_handle_,
+                                                                  indy_error_t err)
+                                             );
+
 #ifdef __cplusplus
 }
 #endif
@@ -104,6 +104,85 @@ extern "C" {
                                                                 const char*   request_result_json)
                                            );
 
+    /// Syncs all transactions of the ledger into the local ledger mirror.
+    ///
+    /// The mirror is an SQLite store in the pool directory indexed by txn type, author, dest and seqNo.
+    /// Sync is incremental: only transactions after the last mirrored one are requested.
+    /// Received transactions are verified with consistency proofs against the ledger state
+    /// confirmed by f+1 nodes.
+    /// POOL ledger is copied from the pool ledger cache that is verified on pool open/refresh.
+    /// Only one ledger of a pool can be synced at a time.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// ledger_type: type of the ledger to sync:
+    ///     DOMAIN,
+    ///     POOL,
+    ///     CONFIG
+    ///     any number
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Ledger mirror status json:
+    /// {
+    ///     "ledgerId": number,
+    ///     "txnCount": number, // number of transactions in the mirror
+    ///     "rootHash": string, // base58 encoded merkle root of the mirrored transactions
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    /// Pool*
+
+    extern indy_error_t indy_sync_ledger_mirror(indy_handle_t command_handle,
+                                                indy_handle_t pool_handle,
+                                                const char *  ledger_type,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   status_json)
+                                                );
+
+    /// Searches transactions in the local ledger mirror (see indy_sync_ledger_mirror).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// ledger_type: type of the mirrored ledger:
+    ///     DOMAIN,
+    ///     POOL,
+    ///     CONFIG
+    ///     any number
+    /// query_json: search query. All fields are optional:
+    /// {
+    ///     "type": string, // transaction type, for example "1" for NYM
+    ///     "author": string, // DID of the transaction author
+    ///     "dest": string, // target DID of the transaction
+    ///     "from": number, // min seqNo (inclusive)
+    ///     "to": number, // max seqNo (inclusive)
+    ///     "limit": number, // max number of returned transactions
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Json array of transactions ordered by seqNo as they are stored on the ledger.
+    ///
+    /// #Errors
+    /// Common*
+    /// Pool*
+
+    extern indy_error_t indy_search_ledger_mirror(indy_handle_t command_handle,
+                                                  indy_handle_t pool_handle,
+                                                  const char *  ledger_type,
+                                                  const char *  query_json,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   txns_json)
+                                                  );
+
     /// Signs request message.
     ///
     /// Adds submitter information to passed request json, signs it with submitter
@@ -140,6 +219,8 @@ extern "C" {
     ///
     /// Adds submitter information to passed request json, signs it with submitter
     /// sign key (see wallet_sign).
+    /// If the request has an endorser, signature added by indy_sign_request from the author
+    /// is moved to the signatures. Other co-signers can multi-sign the request as well.
     ///
     /// #Params
     /// command_handle: command handle to map callback to caller context.
@@ -167,6 +248,303 @@ extern "C" {
                                                                      const char*   signed_request_json)
                                                 );
 
+    /// Appends endorser to the request.
+    ///
+    /// Transaction author who isn't allowed to write the transaction directly
+    /// builds the request, appends endorser DID and multi-signs it (see indy_multi_sign_request).
+    /// Then the endorser multi-signs the request too and sends it to the ledger.
+    /// Endorser must be appended before the request is signed.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// request_json: Request data json.
+    /// endorser_did: DID of the endorser that will submit the transaction.
+    ///               The endorser's DID must be present on the ledger.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Updated request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_append_request_endorser(indy_handle_t command_handle,
+                                                     const char *   request_json,
+                                                     const char *   endorser_did,
+
+                                                     void           (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          const char*   out_request_json)
+                                                     );
+
+    /// Builds a signing bundle: portable container for collecting signatures of the request
+    /// on several (possibly air-gapped) machines.
+    ///
+    /// Bundle contains the request, its serialized signing input (the exact data signers sign),
+    /// the set of expected signers with their roles, an optional auth rule constraint that signatures must satisfy
+    /// and collected signatures.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// request_json: not signed request data json.
+    /// signers_json: DIDs expected to sign the request in the following format:
+    ///     {
+    ///         "<did>": {
+    ///             "verkey": <string> verkey of the DID (full or abbreviated),
+    ///             "role": <string> (optional) role of the DID: TRUSTEE, STEWARD, TRUST_ANCHOR, ENDORSER, NETWORK_MONITOR
+    ///                     or its code as used in auth rules. Not set for identity owner.
+    ///         }
+    ///     }
+    ///     Roles are declared by the caller and aren't verified against the ledger, so the constraint check
+    ///     only shows whether the expected signers would be enough. The ledger still checks actual roles on submission.
+    /// constraint_json: (optional) auth rule constraint for the request (see indy_build_auth_rule_request
+    ///                  and the result of GET_AUTH_RULE request). The bundle is complete when signatures satisfy it.
+    ///                  If not set all signers must sign the request.
+    ///                  Note that `need_to_be_owner` can't be checked offline and is ignored.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Signing bundle json:
+    ///     {
+    ///         "ver": "1",
+    ///         "request": <object> request,
+    ///         "signing_input": <string> serialized request to be signed,
+    ///         "signers": <object> normalized signers_json,
+    ///         "constraint": <object> (optional) constraint_json,
+    ///         "signatures": {"<did>": <string> base58 encoded signature}
+    ///     }
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_signing_bundle(indy_handle_t  command_handle,
+                                                  const char *   request_json,
+                                                  const char *   signers_json,
+                                                  const char *   constraint_json,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   bundle_json)
+                                                  );
+
+    /// Signs signing input of the bundle by the signer DID and adds the signature to the bundle.
+    /// Doesn't require a connection to the pool.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// signer_did: DID of the signer stored in the wallet. Must be one of the bundle signers.
+    /// bundle_json: signing bundle json (see indy_build_signing_bundle).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Signing bundle json with the added signature.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_sign_signing_bundle(indy_handle_t  command_handle,
+                                                 indy_handle_t  wallet_handle,
+                                                 const char *   signer_did,
+                                                 const char *   bundle_json,
+
+                                                 void           (*cb)(indy_handle_t command_handle_,
+                                                                      indy_error_t  err,
+                                                                      const char*   out_bundle_json)
+                                                 );
+
+    /// Merges signatures collected in several signing bundles of the same request.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// bundles_json: array of signing bundles jsons (see indy_build_signing_bundle).
+    ///               All bundles must be built for the same request, signers and constraint.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Signing bundle json with all collected signatures.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_merge_signing_bundles(indy_handle_t  command_handle,
+                                                   const char *   bundles_json,
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   bundle_json)
+                                                   );
+
+    /// Verifies signatures collected in the signing bundle and checks whether they are enough to send the request.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// bundle_json: signing bundle json (see indy_build_signing_bundle).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Bundle status json:
+    ///     {
+    ///         "complete": <bool> whether signatures satisfy the bundle constraint (or all signers signed if it's not set),
+    ///         "signed": <array> DIDs which signed the request,
+    ///         "unsigned": <array> DIDs which didn't sign the request,
+    ///         "request": <object> (only for complete bundle) multi-signed request ready to be sent to the ledger
+    ///     }
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_check_signing_bundle(indy_handle_t  command_handle,
+                                                  const char *   bundle_json,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   status_json)
+                                                  );
+
+    /// Prepares request built by indy_build_* functions for the opened pool.
+    ///
+    /// Sets PROTOCOL_VERSION of the pool (see protocol_version in indy_open_pool_ledger config)
+    /// to the request. It allows to work with pools of different PROTOCOL_VERSION at the same time.
+    /// indy_sign_and_submit_request, indy_submit_request and indy_submit_action prepare unsigned requests
+    /// automatically, so the function should be called only if the request is signed separately
+    /// (see indy_sign_request and indy_multi_sign_request). indy_sign_request_for_pool and
+    /// indy_multi_sign_request_for_pool prepare the request before signing.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: Request data json.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request with pool PROTOCOL_VERSION as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_prepare_request_for_pool(indy_handle_t command_handle,
+                                                      indy_handle_t pool_handle,
+                                                      const char *  request_json,
+
+                                                      void          (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          const char*   out_request_json)
+                                                      );
+
+    /// Signs request message for the opened pool.
+    ///
+    /// Sets PROTOCOL_VERSION of the pool to the request (see indy_prepare_request_for_pool) and signs it
+    /// with submitter sign key (see indy_sign_request),
+    /// so the signed request is accepted by the pool even if its PROTOCOL_VERSION differs
+    /// from the global one (see indy_set_protocol_version).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// request_json: Request data json.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Signed request json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+
+    extern indy_error_t indy_sign_request_for_pool(indy_handle_t command_handle,
+                                                   indy_handle_t pool_handle,
+                                                   indy_handle_t wallet_handle,
+                                                   const char *  submitter_did,
+                                                   const char *  request_json,
+
+                                                   void          (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   signed_request_json)
+                                                   );
+
+    /// Multi signs request message for the opened pool.
+    ///
+    /// Sets PROTOCOL_VERSION of the pool to the request (see indy_prepare_request_for_pool) and signs it
+    /// with submitter sign key (see indy_multi_sign_request),
+    /// so the signed request is accepted by the pool even if its PROTOCOL_VERSION differs
+    /// from the global one (see indy_set_protocol_version).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// request_json: Request data json.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Signed request json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+
+    extern indy_error_t indy_multi_sign_request_for_pool(indy_handle_t command_handle,
+                                                         indy_handle_t pool_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  submitter_did,
+                                                         const char *  request_json,
+
+                                                         void          (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   signed_request_json)
+                                                         );
+
+    /// Sets request id supplied by a caller to the request built by indy_build_* functions.
+    ///
+    /// By default builders generate unique request ids (see indy_generate_req_id).
+    /// A caller can set its own one, for example to resend the same request after a failure,
+    /// as the pool doesn't process a request with already known id twice.
+    /// Request id must be set before the request is signed.
+    /// The id doesn't affect ids generated by libindy, so the caller is responsible for its uniqueness.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// request_json: Request data json.
+    /// req_id: Request id to set.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request with the request id as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_set_request_req_id(indy_handle_t command_handle,
+                                                const char *   request_json,
+                                                indy_u64_t     req_id,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   out_request_json)
+                                                );
+
+    /// Generates a request id unique within the process.
+    ///
+    /// All indy_build_* functions use the same generator, so code which builds ledger requests itself
+    /// (like payment plugins) can use the function to avoid collisions with libindy requests.
+    /// Ids are based on the current time in nanoseconds and strictly increase.
+    ///
+    /// #Params
+    /// req_id_p: Pointer to store generated request id.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_generate_req_id(indy_u64_t* req_id_p);
+
     /// Builds a request to get a DDO.
     ///
     /// #Params
@@ -203,6 +581,7 @@ extern "C" {
     ///                             TRUSTEE
     ///                             STEWARD
     ///                             TRUST_ANCHOR
+    ///                             ENDORSER (same as TRUST_ANCHOR)
     ///                             empty string to reset role
     /// cb: Callback that takes command result as parameter.
     ///
@@ -282,6 +661,38 @@ extern "C" {
                                                                            const char*   request_json)
                                                      );
 
+    /// Parse a GET_ATTRIB response to get attribute data.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_attrib_response: response of GET_ATTRIB request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Attribute data json.
+    /// {
+    ///     did: DID the attribute belongs to.
+    ///     raw|hash|enc: name of the raw attribute, hash or encrypted data the attribute was requested by.
+    ///     data: attribute value as it's stored on the ledger
+    ///           (json string for raw attribute, hash for hashed attribute, encrypted data for encrypted attribute).
+    ///     seqNo: (Optional) sequence number of the ATTRIB transaction.
+    ///     txnTime: (Optional) the time when the ATTRIB transaction was ordered.
+    /// }
+    /// The same shape is returned for both formats of ledger replies.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    /// LedgerNotFound - if the attribute isn't found on the ledger.
+
+    extern indy_error_t indy_parse_get_attrib_response(indy_handle_t command_handle,
+                                                       const char *  get_attrib_response,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   attrib_json)
+                                                       );
+
     /// Builds a GET_NYM request. Request to get information about a DID (NYM).
     ///
     /// #Params
@@ -305,6 +716,39 @@ extern "C" {
                                                                         const char*   request_json)
                                                   );
 
+    /// Parse a GET_NYM response to get NYM data.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_nym_response: response of GET_NYM request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// NYM data json.
+    /// {
+    ///     did: DID as base58-encoded string for 16 or 32 bit DID value.
+    ///     verkey: verification key as base58-encoded string (null if not set).
+    ///     role: role associated with the DID (null if not set):
+    ///         "0" - TRUSTEE
+    ///         "2" - STEWARD
+    ///         "101" - ENDORSER
+    ///         "201" - NETWORK_MONITOR
+    ///     seqNo: (Optional) sequence number of the NYM transaction.
+    ///     txnTime: (Optional) the time when the NYM transaction was ordered.
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_nym_response(indy_handle_t command_handle,
+                                                    const char *  get_nym_response,
+
+                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                         indy_error_t  err,
+                                                                         const char*   nym_json)
+                                                    );
+
     /// Builds a SCHEMA request. Request to add Credential's schema.
     ///
     /// #Params
@@ -488,6 +932,7 @@ extern "C" {
     ///     alias: string - Node's alias
     ///     blskey: string - (Optional) BLS multi-signature key as base58-encoded string.
     ///     blskey_pop: string - (Optional) BLS key proof of possession as base58-encoded string.
+    ///                          If set together with blskey, it's checked to correspond to the key.
     ///     client_ip: string - (Optional) Node's client listener IP address.
     ///     client_port: string - (Optional) Node's client listener port.
     ///     node_ip: string - (Optional) The IP address other Nodes use to communicate with this Node.
@@ -512,6 +957,38 @@ extern "C" {
                                                                      const char*   request_json)
                                                );
 
+    /// Builds a NODE request with BLS key and its proof of possession taken from the wallet.
+    /// The BLS key must be created by indy_create_bls_key.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// target_did: Target Node's DID.  It differs from submitter_did field.
+    /// data: Data associated with the Node (see indy_build_node_request).
+    ///       "blskey" and "blskey_pop" fields are overwritten by values from the wallet.
+    /// blskey: BLS key stored in the wallet (as returned by indy_create_bls_key).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_build_node_request_with_bls_key(indy_handle_t command_handle,
+                                                             indy_handle_t wallet_handle,
+                                                             const char *  submitter_did,
+                                                             const char *  target_did,
+                                                             const char *  data,
+                                                             const char *  blskey,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   request_json)
+                                                            );
+
         /// Builds a GET_VALIDATOR_INFO request.
         ///
         /// #Params
@@ -532,9 +1009,98 @@ extern "C" {
                                                                             const char*   request_json)
                                                        );
 
+    /// Parse a GET_VALIDATOR_INFO response received from indy_submit_action.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_validator_info_response: response of GET_VALIDATOR_INFO action (replies by node alias).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Validator info json.
+    /// {
+    ///     nodes: { <node alias>: validator info returned by the node }
+    ///     errors: { <node alias>: reason why the node hasn't returned validator info (for example "timeout") }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_validator_info_response(indy_handle_t command_handle,
+                                                               const char *  get_validator_info_response,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   validator_info_json)
+                                                               );
+
+
+    /// Gets structured status of the validator pool.
+    ///
+    /// Sends GET_VALIDATOR_INFO request signed by the submitter to all nodes of the pool ledger
+    /// and combines their replies into a report about every node and the whole pool consensus.
+    /// Note that only DIDs with TRUSTEE, STEWARD or NETWORK_MONITOR role are allowed to get validator info.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the request sender.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Pool status json:
+    /// {
+    ///     "nodes": {
+    ///         <node alias>: {
+    ///             "address": Option<string> - client address of the node from the pool ledger,
+    ///             "reachable": bool - whether the node has replied in time,
+    ///             "error": Option<string> - reason why the node hasn't returned validator info,
+    ///             "mode": Option<string> - node mode (participating, syncing, discovering ...),
+    ///             "softwareVersion": Option<string> - version of indy-node,
+    ///             "lastOrdered3pc": Option<[view_no, pp_seq_no]> - last batch ordered by the master replica,
+    ///             "catchupStatus": Option<{<ledger id>: string}> - catchup status by ledger id,
+    ///             "viewNo": Option<int> - current view number,
+    ///             "viewChangeInProgress": Option<bool>,
+    ///             "primary": Option<string> - alias of the master primary the node knows about,
+    ///         },
+    ///         ...
+    ///     },
+    ///     "consensus": {
+    ///         "totalNodes": int - number of validators in the pool ledger (n),
+    ///         "f": int - number of faulty nodes the pool can tolerate,
+    ///         "requiredNodes": int - number of nodes required to reach consensus (n - f),
+    ///         "reachableNodes": int - number of pool ledger nodes that have replied,
+    ///         "fTolerated": bool - whether enough nodes are reachable to reach consensus,
+    ///         "viewNo": Option<int> - view number reported by the most of nodes,
+    ///         "primary": Option<string> - master primary reported by the most of nodes,
+    ///         "primaryAgreed": bool - whether all nodes agree on the view and the primary and no view change is in progress,
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+
+    extern indy_error_t indy_get_pool_status(indy_handle_t command_handle,
+                                             indy_handle_t pool_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  submitter_did,
+
+                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err,
+                                                                  const char*   pool_status_json)
+                                             );
 
     /// Builds a GET_TXN request. Request to get any transaction by its seq_no.
     ///
+    /// A reply of a single node is accepted if the returned audit path proves that the transaction is
+    /// included into the ledger with the root hash signed by the pool (BLS multi-signature).
+    /// Otherwise replies of f + 1 nodes are required.
+    ///
     /// #Params
     /// command_handle: command handle to map callback to caller context.
     /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
@@ -562,6 +1128,35 @@ extern "C" {
                                                                         const char*   request_json)
                                                    );
 
+    /// Parse a GET_TXN response to get the transaction in the same format for all ledger transaction versions.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_txn_response: response of GET_TXN request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Transaction json.
+    /// {
+    ///     seqNo: sequence number of the transaction.
+    ///     txnTime: (Optional) the time when the transaction was ordered.
+    ///     type: type of the transaction.
+    ///     from: (Optional) DID of the transaction author.
+    ///     data: transaction specific data.
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_txn_response(indy_handle_t command_handle,
+                                                    const char *  get_txn_response,
+
+                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                         indy_error_t  err,
+                                                                         const char*   txn_json)
+                                                    );
+
     /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
     ///
     /// #Params
@@ -912,6 +1507,70 @@ extern "C" {
                                                                );
 
 
+    /// Registers custom transaction type provided by a ledger plugin.
+    ///
+    /// Registered type can be used by its name in indy_build_custom_request, indy_build_auth_rule_request,
+    /// indy_build_get_auth_rule_request and indy_register_transaction_parser_for_sp.
+    /// Read requests of the type with registered state proof parser are sent to a single node,
+    /// write requests always require consensus of the pool.
+    /// A type can be registered again with the same name and code to update its kind and schema,
+    /// but neither its name nor its code can be reused for other type.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// txn_type_json: transaction type description:
+    /// {
+    ///     "name": string - transaction type name (must not coincide with default ones),
+    ///     "code": string - numeric transaction type code used in requests to the ledger,
+    ///     "kind": string - "read" or "write",
+    ///     "schema": Option<object> - schema of request `operation` fields:
+    ///         {
+    ///             <field name>: {
+    ///                 "type": "string" | "number" | "boolean" | "object" | "array" | "any",
+    ///                 "optional": Option<bool> - false by default
+    ///             },
+    ///             ...
+    ///         }
+    ///         Fields not described in the schema are rejected. `operation` isn't validated if the schema isn't set.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_register_custom_txn_type(indy_handle_t command_handle,
+                                                      const char *  txn_type_json,
+
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err)
+                                                      );
+
+    /// Builds a request of custom transaction type (see indy_register_custom_txn_type).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the request sender (required for write transactions).
+    ///                If not provided then default Libindy DID will be used for read request.
+    /// txn_type: name or code of registered custom transaction type.
+    /// operation_json: request operation fields (`type` is set automatically) validated against the transaction type schema.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_custom_request(indy_handle_t command_handle,
+                                                  const char *  submitter_did,
+                                                  const char *  txn_type,
+                                                  const char *  operation_json,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   request_json)
+                                                  );
+
     /// Parse transaction response to fetch metadata.
     /// The important use case for this method is validation of Node's response freshens.
     ///
@@ -134,6 +134,150 @@ extern "C" {
                                                                        indy_error_t   err)
                                                   );
 
+    /// Replaces tags of pairwise associated with Did.
+    ///
+    /// Tags are stored with pairwise record and can be used in indy_search_pairwise queries.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// command_handle: command handle to map callback to user context.
+    /// their_did: encoded Did
+    /// tags_json: the record tags used for search and storing meta information as json:
+    ///   {
+    ///     "tagName1": <str>, // string tag (will be stored encrypted)
+    ///     "~tagName2": <str>, // string tag (will be stored un-encrypted)
+    ///   }
+    ///   Note that "my_did" tag name is reserved and is always set to my DID of the pairwise.
+    ///   Empty json removes all tags.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_set_pairwise_tags(indy_handle_t command_handle,
+                                               indy_handle_t wallet_handle,
+                                               const char *  their_did,
+                                               const char *  tags_json,
+
+                                               void          (*cb)(indy_handle_t  command_handle_,
+                                                                   indy_error_t   err)
+                                              );
+
+    /// Deletes pairwise associated with Did.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// command_handle: command handle to map callback to user context.
+    /// their_did: encoded Did
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_delete_pairwise(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  their_did,
+
+                                             void          (*cb)(indy_handle_t  command_handle_,
+                                                                 indy_error_t   err)
+                                            );
+
+    /// Search for saved pairwise.
+    ///
+    /// Instead of immediately returning of fetched records
+    /// this call returns search_handle that can be used later
+    /// to fetch records by small batches (with indy_fetch_pairwise_search).
+    ///
+    /// #Params
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// command_handle: command handle to map callback to user context.
+    /// query_json: Wql query filter for pairwise searching based on tags:
+    ///     {
+    ///         "my_did": string,
+    ///         <tag name set by indy_set_pairwise_tags>: string,
+    ///     }
+    /// where query: indy-sdk/doc/design/011-wallet-query-language/README.md
+    /// Note that pairwise created before tags support get "my_did" tag on the first search in the opened wallet.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// search_handle: Search handle that can be used later to fetch records by small batches (with indy_fetch_pairwise_search)
+    /// total_count: Total count of records
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_search_pairwise(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  query_json,
+
+                                             void          (*cb)(indy_handle_t  command_handle_,
+                                                                 indy_error_t   err,
+                                                                 indy_handle_t  search_handle,
+                                                                 indy_u32_t     total_count)
+                                            );
+
+    /// Fetch next pairwise for search.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// search_handle: Search handle (created by indy_search_pairwise)
+    /// count: Count of records to fetch
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// list_pairwise_json: List of pairwise:
+    ///     [{
+    ///         "my_did": string,
+    ///         "their_did": string,
+    ///         "metadata": Optional<string>,
+    ///         "tags": Optional<{"tagName": string}>
+    ///     }]
+    /// NOTE: The list of length less than the requested count means search iterator is completed.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_fetch_pairwise_search(indy_handle_t command_handle,
+                                                   indy_handle_t search_handle,
+                                                   indy_u32_t    count,
+
+                                                   void          (*cb)(indy_handle_t  command_handle_,
+                                                                       indy_error_t   err,
+                                                                       const char *   list_pairwise_json)
+                                                  );
+
+    /// Close pairwise search (make search handle invalid)
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// search_handle: Search handle (created by indy_search_pairwise)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_close_pairwise_search(indy_handle_t command_handle,
+                                                   indy_handle_t search_handle,
+
+                                                   void          (*cb)(indy_handle_t  command_handle_,
+                                                                       indy_error_t   err)
+                                                  );
+
 
 #ifdef __cplusplus
 }
@@ -12,6 +12,7 @@ use domain::anoncreds::schema::{Schema, AttributeNames};
 use domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionConfig};
 use domain::anoncreds::credential_offer::CredentialOffer;
 use domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
+use domain::anoncreds::issued_credential::CreateCredentialOptions;
 use domain::anoncreds::credential::{Credential, AttributeValues};
 use domain::anoncreds::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition};
 use domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
@@ -20,6 +21,7 @@ use domain::anoncreds::proof_request::{ProofRequest, ProofRequestExtraQuery};
 use domain::anoncreds::requested_credential::RequestedCredentials;
 use domain::anoncreds::revocation_registry::RevocationRegistry;
 use domain::anoncreds::revocation_state::RevocationState;
+use domain::anoncreds::w3c::W3CPresentation;
 use utils::ctypes;
 
 use self::libc::c_char;
@@ -320,6 +322,7 @@ pub extern fn indy_issuer_create_credential_offer(command_handle: CommandHandle,
                 IssuerCommand::CreateCredentialOffer(
                     wallet_handle,
                     cred_def_id,
+                    None,
                     Box::new(move |result| {
                         let (err, cred_offer_json) = prepare_result_1!(result, String::new());
                         trace!("indy_issuer_create_credential_offer: cred_offer_json: {:?}", cred_offer_json);
@@ -335,6 +338,63 @@ pub extern fn indy_issuer_create_credential_offer(command_handle: CommandHandle,
     res
 }
 
+/// Same as indy_issuer_create_credential_offer but additionally records nonce of the offer
+/// in the wallet together with its expiry time. Credential for such offer can be issued only once
+/// and only before the expiry time (see `track_offer_nonce` option of indy_issuer_create_credential_with_options).
+/// Expired records are purged on the next call.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handler (created by open_wallet)
+/// cred_def_id: id of credential definition stored in the wallet
+/// nonce_expires_at: time (total number of seconds from Unix Epoch) after which credential offer is considered expired
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// credential offer json (see indy_issuer_create_credential_offer)
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_issuer_create_credential_offer_with_nonce_tracking(command_handle: CommandHandle,
+                                                                      wallet_handle: WalletHandle,
+                                                                      cred_def_id: *const c_char,
+                                                                      nonce_expires_at: u64,
+                                                                      cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                                           cred_offer_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_credential_offer_with_nonce_tracking: >>> wallet_handle: {:?}, cred_def_id: {:?}, nonce_expires_at: {:?}",
+           wallet_handle, cred_def_id, nonce_expires_at);
+
+    check_useful_c_str!(cred_def_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_issuer_create_credential_offer_with_nonce_tracking: entities >>> wallet_handle: {:?}, cred_def_id: {:?}, nonce_expires_at: {:?}",
+           wallet_handle, cred_def_id, nonce_expires_at);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateCredentialOffer(
+                    wallet_handle,
+                    cred_def_id,
+                    Some(nonce_expires_at),
+                    Box::new(move |result| {
+                        let (err, cred_offer_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_issuer_create_credential_offer_with_nonce_tracking: cred_offer_json: {:?}", cred_offer_json);
+                        let cred_offer_json = ctypes::string_to_cstring(cred_offer_json);
+                        cb(command_handle, err, cred_offer_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_credential_offer_with_nonce_tracking: <<< res: {:?}", res);
+
+    res
+}
+
 /// Check Cred Request for the given Cred Offer and issue Credential for the given Cred Request.
 ///
 /// Cred Request must match Cred Offer. The credential definition and revocation registry definition
@@ -415,6 +475,7 @@ pub extern fn indy_issuer_create_credential(command_handle: CommandHandle,
                     cred_values_json,
                     rev_reg_id,
                     blob_storage_reader_handle,
+                    CreateCredentialOptions::default(),
                     Box::new(move |result| {
                         let (err, cred_json, revoc_id, revoc_reg_delta_json) = prepare_result_3!(result, String::new(), None, None);
                         trace!("indy_issuer_create_credential: cred_json: {:?}, revoc_id: {:?}, revoc_reg_delta_json: {:?}",
@@ -435,6 +496,258 @@ pub extern fn indy_issuer_create_credential(command_handle: CommandHandle,
     res
 }
 
+/// Same as indy_issuer_create_credential but accepts additional issuance options.
+///
+/// If `track_offer_nonce` is set, call protects against credential offer replay.
+/// The offer must be created by indy_issuer_create_credential_offer_with_nonce_tracking and not be expired.
+/// Nonce of the credential offer is marked as used after credential is created,
+/// so another credential can't be issued for the same offer.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// cred_offer_json: a cred offer created by indy_issuer_create_credential_offer
+/// cred_req_json: a credential request created by indy_prover_create_credential_req
+/// cred_values_json: a credential containing attribute values for each of requested attribute names (see indy_issuer_create_credential)
+/// rev_reg_id: id of revocation registry stored in the wallet
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails
+/// options_json: issuance options
+///     {
+///         "track_offer_nonce": Optional<bool>, // enables credential offer replay protection. false by default
+///         "store_issued_credential": Optional<bool>, // store metadata of issued credential in the wallet
+///                                                    // (see indy_issuer_search_issued_credentials). false by default
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_json, cred_revoc_id, revoc_reg_delta_json (see indy_issuer_create_credential)
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_create_credential_with_options(command_handle: CommandHandle,
+                                                         wallet_handle: WalletHandle,
+                                                         cred_offer_json: *const c_char,
+                                                         cred_req_json: *const c_char,
+                                                         cred_values_json: *const c_char,
+                                                         rev_reg_id: *const c_char,
+                                                         blob_storage_reader_handle: IndyHandle,
+                                                         options_json: *const c_char,
+                                                         cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                              cred_json: *const c_char,
+                                                                              cred_revoc_id: *const c_char,
+                                                                              revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_credential_with_options: >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
+    blob_storage_reader_handle: {:?}, options_json: {:?}", wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle, options_json);
+
+    check_useful_json!(cred_offer_json, ErrorCode::CommonInvalidParam3, CredentialOffer);
+    check_useful_json!(cred_req_json, ErrorCode::CommonInvalidParam4, CredentialRequest);
+    check_useful_json!(cred_values_json, ErrorCode::CommonInvalidParam5, HashMap<String, AttributeValues>);
+    check_useful_opt_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam6);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam8, CreateCredentialOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    let blob_storage_reader_handle = if blob_storage_reader_handle != -1 { Some(blob_storage_reader_handle) } else { None };
+
+    trace!("indy_issuer_create_credential_with_options: entities >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
+    blob_storage_reader_handle: {:?}, options_json: {:?}", wallet_handle, cred_offer_json, secret!(&cred_req_json), secret!(&cred_values_json), secret!(&rev_reg_id),
+           blob_storage_reader_handle, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateCredential(
+                    wallet_handle,
+                    cred_offer_json,
+                    cred_req_json,
+                    cred_values_json,
+                    rev_reg_id,
+                    blob_storage_reader_handle,
+                    options_json,
+                    Box::new(move |result| {
+                        let (err, cred_json, revoc_id, revoc_reg_delta_json) = prepare_result_3!(result, String::new(), None, None);
+                        trace!("indy_issuer_create_credential_with_options: cred_json: {:?}, revoc_id: {:?}, revoc_reg_delta_json: {:?}",
+                               secret!(cred_json.as_str()), secret!(&revoc_id), revoc_reg_delta_json);
+                        let cred_json = ctypes::string_to_cstring(cred_json);
+                        let revoc_id = revoc_id.map(ctypes::string_to_cstring);
+                        let revoc_reg_delta_json = revoc_reg_delta_json.map(ctypes::string_to_cstring);
+                        cb(command_handle, err, cred_json.as_ptr(),
+                           revoc_id.as_ref().map(|id| id.as_ptr()).unwrap_or(ptr::null()),
+                           revoc_reg_delta_json.as_ref().map(|delta| delta.as_ptr()).unwrap_or(ptr::null()))
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_credential_with_options: <<< res: {:?}", res);
+
+    res
+}
+
+/// Search for metadata of issued credentials stored in the wallet
+/// (see `store_issued_credential` option of indy_issuer_create_credential_with_options).
+///
+/// Instead of immediately returning of fetched records
+/// this call returns search_handle that can be used later
+/// to fetch records by small batches (with indy_issuer_fetch_issued_credentials).
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// query_json: Wql query filter for issued credentials searching based on tags:
+///     {
+///         "schema_id": string,
+///         "cred_def_id": string,
+///         "rev_reg_id": string, // "None" for credentials without revocation support
+///         "cred_rev_id": string, // "None" for credentials without revocation support
+///         "holder_did": string,
+///         "values_hash": string,
+///         "revoked": "true" | "false",
+///         "~issued_at": string, // issue time padded with zeros to 20 digits, can be used with $gt/$lt
+///     }
+/// where query: indy-sdk/doc/design/011-wallet-query-language/README.md
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// search_handle: Search handle that can be used later to fetch records by small batches (with indy_issuer_fetch_issued_credentials)
+/// total_count: Total count of records
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_search_issued_credentials(command_handle: CommandHandle,
+                                                    wallet_handle: WalletHandle,
+                                                    query_json: *const c_char,
+                                                    cb: Option<extern fn(
+                                                        command_handle_: CommandHandle, err: ErrorCode,
+                                                        search_handle: SearchHandle,
+                                                        total_count: usize)>) -> ErrorCode {
+    trace!("indy_issuer_search_issued_credentials: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_search_issued_credentials: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::SearchIssuedCredentials(
+                    wallet_handle,
+                    query_json,
+                    Box::new(move |result| {
+                        let (err, handle, total_count) = prepare_result_2!(result, 0, 0);
+                        cb(command_handle, err, handle, total_count)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_search_issued_credentials: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetch next issued credentials for search.
+///
+/// #Params
+/// search_handle: Search handle (created by indy_issuer_search_issued_credentials)
+/// count: Count of records to fetch
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// issued_credentials_json: List of issued credentials metadata:
+///     [{
+///         "referent": string, // id of record in the wallet
+///         "schema_id": string,
+///         "cred_def_id": string,
+///         "rev_reg_id": Optional<string>,
+///         "cred_rev_id": Optional<string>,
+///         "values_hash": string, // hex encoded HMAC-SHA256 of raw credential values keyed with issuer wallet-held key
+///         "issued_at": int, // time represented as a total number of seconds from Unix Epoch
+///         "holder_did": string, // prover_did from credential request
+///         "revoked": bool
+///     }]
+/// NOTE: The list of length less than the requested count means search iterator is completed.
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_fetch_issued_credentials(command_handle: CommandHandle,
+                                                   search_handle: SearchHandle,
+                                                   count: usize,
+                                                   cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                        issued_credentials_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_fetch_issued_credentials: >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_fetch_issued_credentials: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::FetchIssuedCredentials(
+                    search_handle,
+                    count,
+                    Box::new(move |result| {
+                        let (err, issued_credentials_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_issuer_fetch_issued_credentials: issued_credentials_json: {:?}", issued_credentials_json);
+                        let issued_credentials_json = ctypes::string_to_cstring(issued_credentials_json);
+                        cb(command_handle, err, issued_credentials_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_fetch_issued_credentials: <<< res: {:?}", res);
+
+    res
+}
+
+/// Close issued credentials search (make search handle invalid)
+///
+/// #Params
+/// search_handle: Search handle (created by indy_issuer_search_issued_credentials)
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_close_issued_credentials_search(command_handle: CommandHandle,
+                                                          search_handle: SearchHandle,
+                                                          cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_issuer_close_issued_credentials_search: >>> search_handle: {:?}", search_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_issuer_close_issued_credentials_search: entities >>> search_handle: {:?}", search_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CloseIssuedCredentialsSearch(
+                    search_handle,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_issuer_close_issued_credentials_search:");
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_close_issued_credentials_search: <<< res: {:?}", res);
+
+    res
+}
+
 /// Revoke a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -1589,6 +1902,11 @@ pub extern fn indy_prover_create_proof(command_handle: CommandHandle,
 /// Verifies a proof (of multiple credential).
 /// All required schemas, public keys and revocation registries must be provided.
 ///
+/// Timestamp of every revocation registry used in the proof must lie inside of requested `non_revoked` interval
+/// (see `non_revoked_interval_tolerance` option of indy_set_runtime_config). If interval has no `from` bound
+/// proof is considered invalid when provided revocation registries contain a different accumulator
+/// for a later timestamp not exceeding `to`. In both cases `valid` is false.
+///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
 /// command_handle: command handle to map callback to user context.
@@ -1706,6 +2024,7 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
             credential_defs_json,
             rev_reg_defs_json,
             rev_regs_json,
+            None,
             Box::new(move |result| {
                 let (err, valid) = prepare_result_1!(result, false);
                 trace!("indy_verifier_verify_proof: valid: {:?}", valid);
@@ -1721,6 +2040,128 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
     res
 }
 
+/// Records nonce of the proof request in the wallet together with its expiry time,
+/// so proofs for the request can be verified by indy_verifier_verify_proof_with_nonce_tracking.
+/// Call it when the proof request is sent to a prover. Expired records are purged on the next call.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// proof_request_json: proof request json (see indy_verifier_verify_proof)
+/// nonce_expires_at: time (total number of seconds from Unix Epoch) after which proof request is considered expired
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_verifier_track_proof_request_nonce(command_handle: CommandHandle,
+                                                      wallet_handle: WalletHandle,
+                                                      proof_request_json: *const c_char,
+                                                      nonce_expires_at: u64,
+                                                      cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_verifier_track_proof_request_nonce: >>> wallet_handle: {:?}, proof_request_json: {:?}, nonce_expires_at: {:?}",
+           wallet_handle, proof_request_json, nonce_expires_at);
+
+    check_useful_json!(proof_request_json, ErrorCode::CommonInvalidParam3, ProofRequest);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_verifier_track_proof_request_nonce: entities >>> wallet_handle: {:?}, proof_request_json: {:?}, nonce_expires_at: {:?}",
+           wallet_handle, proof_request_json, nonce_expires_at);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::TrackProofRequestNonce(
+            wallet_handle,
+            proof_request_json,
+            nonce_expires_at,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_verifier_track_proof_request_nonce:");
+                cb(command_handle, err)
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_track_proof_request_nonce: <<< res: {:?}", res);
+
+    res
+}
+
+/// Same as indy_verifier_verify_proof but additionally protects against proof replay.
+/// Nonce of the proof request must be recorded by indy_verifier_track_proof_request_nonce and not be expired.
+/// It is marked as used after successful verification, so another proof for the same request is rejected.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// proof_request_json: proof request json (see indy_verifier_verify_proof)
+/// proof_json: created for request proof json (see indy_verifier_verify_proof)
+/// schemas_json: all schema jsons participating in the proof
+/// credential_defs_json: all credential definitions json participating in the proof
+/// rev_reg_defs_json: all revocation registry definitions json participating in the proof
+/// rev_regs_json: all revocation registries json participating in the proof
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if signature is valid, false - otherwise
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_with_nonce_tracking(command_handle: CommandHandle,
+                                                             wallet_handle: WalletHandle,
+                                                             proof_request_json: *const c_char,
+                                                             proof_json: *const c_char,
+                                                             schemas_json: *const c_char,
+                                                             credential_defs_json: *const c_char,
+                                                             rev_reg_defs_json: *const c_char,
+                                                             rev_regs_json: *const c_char,
+                                                             cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                                  valid: bool)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_with_nonce_tracking: >>> wallet_handle: {:?}, proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", wallet_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json,
+           rev_regs_json);
+
+    check_useful_json!(proof_request_json, ErrorCode::CommonInvalidParam3, ProofRequest);
+    check_useful_json!(proof_json, ErrorCode::CommonInvalidParam4, Proof);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam5, HashMap<String, Schema>);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam6, HashMap<String, CredentialDefinition>);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam7, HashMap<String, RevocationRegistryDefinition>);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam8, HashMap<String, HashMap<u64, RevocationRegistry>>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_verifier_verify_proof_with_nonce_tracking: entities >>> wallet_handle: {:?}, proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", wallet_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json,
+           rev_regs_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProof(
+            proof_request_json,
+            proof_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            Some(wallet_handle),
+            Box::new(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_verifier_verify_proof_with_nonce_tracking: valid: {:?}", valid);
+
+                cb(command_handle, err, valid)
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_with_nonce_tracking: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create revocation state for a credential in the particular time moment.
 ///
 /// #Params
@@ -1857,3 +2298,404 @@ pub extern fn indy_update_revocation_state(command_handle: CommandHandle,
 
     res
 }
+
+/// Stores revocation state of a credential in the wallet.
+/// Stored states are keyed by revocation registry id, credential revocation id and timestamp
+/// and are reused by indy_prover_update_revocation_states.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handler (created by open_wallet).
+/// rev_reg_id: revocation registry id
+/// cred_rev_id: user credential revocation id in revocation registry
+/// rev_state_json: revocation state json
+///     {
+///         "rev_reg": <revocation registry>,
+///         "witness": <witness>,
+///         "timestamp" : integer
+///     }
+/// cb: Callback that takes command result as parameter
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_prover_store_revocation_state(command_handle: CommandHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 rev_reg_id: *const c_char,
+                                                 cred_rev_id: *const c_char,
+                                                 rev_state_json: *const c_char,
+                                                 cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_prover_store_revocation_state: >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_rev_id: {:?}, rev_state_json: {:?}",
+           wallet_handle, rev_reg_id, cred_rev_id, rev_state_json);
+
+    check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(cred_rev_id, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(rev_state_json, ErrorCode::CommonInvalidParam5, RevocationState);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_prover_store_revocation_state: entities >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_rev_id: {:?}, rev_state_json: {:?}",
+           wallet_handle, rev_reg_id, cred_rev_id, rev_state_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::StoreRevocationState(
+            wallet_handle,
+            rev_reg_id,
+            cred_rev_id,
+            rev_state_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_prover_store_revocation_state:");
+                cb(command_handle, err)
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_store_revocation_state: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets revocation state of a credential stored in the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handler (created by open_wallet).
+/// rev_reg_id: revocation registry id
+/// cred_rev_id: user credential revocation id in revocation registry
+/// timestamp: time represented as a total number of seconds from Unix Epoch
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// revocation state json:
+///     {
+///         "rev_reg": <revocation registry>,
+///         "witness": <witness>,
+///         "timestamp" : integer
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_prover_get_revocation_state(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               rev_reg_id: *const c_char,
+                                               cred_rev_id: *const c_char,
+                                               timestamp: u64,
+                                               cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                    rev_state_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_get_revocation_state: >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_rev_id: {:?}, timestamp: {:?}",
+           wallet_handle, rev_reg_id, cred_rev_id, timestamp);
+
+    check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(cred_rev_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_prover_get_revocation_state: entities >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_rev_id: {:?}, timestamp: {:?}",
+           wallet_handle, rev_reg_id, cred_rev_id, timestamp);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::GetRevocationState(
+            wallet_handle,
+            rev_reg_id,
+            cred_rev_id,
+            timestamp,
+            Box::new(move |result| {
+                let (err, rev_state_json) = prepare_result_1!(result, String::new());
+                trace!("indy_prover_get_revocation_state: rev_state_json: {:?}", rev_state_json);
+                let rev_state_json = ctypes::string_to_cstring(rev_state_json);
+                cb(command_handle, err, rev_state_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_get_revocation_state: <<< res: {:?}", res);
+
+    res
+}
+
+/// Prepares revocation states needed to create a proof for the given proof request.
+///
+/// For every requested credential that supports revocation and is covered by a `non_revoked` interval
+/// (global or per-referent) the state is taken from the wallet if one with a timestamp inside the interval
+/// is already stored. Otherwise the revocation registry delta is fetched from the ledger (GET_REVOC_REG_DELTA)
+/// and the freshest stored state is updated (or a new one is created). Resulting states are stored in the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handler (created by open_wallet).
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// proof_req_json: proof request json (see indy_prover_create_proof)
+/// requested_credentials_json: requested credentials json (see indy_prover_create_proof), timestamps are ignored
+/// rev_reg_defs_json: revocation registry definitions of requested credentials
+///     {
+///         "rev_reg_def1_id": <rev_reg_def1_json>,
+///         "rev_reg_def2_id": <rev_reg_def2_json>,
+///     }
+/// blob_storage_reader_handles_json: blob storage reader handles to read tails of revocation registries
+///     {
+///         "rev_reg_def1_id": <blob_storage_reader_handle>,
+///         "rev_reg_def2_id": <blob_storage_reader_handle>,
+///     }
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// rev_states_json: revocation states to pass to indy_prover_create_proof
+///     {
+///         "rev_reg_def1_id": {
+///             "timestamp1": <rev_state1>,
+///             "timestamp2": <rev_state2>,
+///         },
+///         "rev_reg_def2_id": {
+///             "timestamp3": <rev_state3>
+///         },
+///     }
+/// requested_credentials_json: requested credentials json with timestamps set according to the revocation states
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_prover_update_revocation_states(command_handle: CommandHandle,
+                                                   wallet_handle: WalletHandle,
+                                                   pool_handle: IndyHandle,
+                                                   proof_req_json: *const c_char,
+                                                   requested_credentials_json: *const c_char,
+                                                   rev_reg_defs_json: *const c_char,
+                                                   blob_storage_reader_handles_json: *const c_char,
+                                                   cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                        rev_states_json: *const c_char,
+                                                                        requested_credentials_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_update_revocation_states: >>> wallet_handle: {:?}, pool_handle: {:?}, proof_req_json: {:?}, requested_credentials_json: {:?}, \
+    rev_reg_defs_json: {:?}, blob_storage_reader_handles_json: {:?}",
+           wallet_handle, pool_handle, proof_req_json, requested_credentials_json, rev_reg_defs_json, blob_storage_reader_handles_json);
+
+    check_useful_json!(proof_req_json, ErrorCode::CommonInvalidParam4, ProofRequest);
+    check_useful_json!(requested_credentials_json, ErrorCode::CommonInvalidParam5, RequestedCredentials);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, HashMap<String, RevocationRegistryDefinition>);
+    check_useful_json!(blob_storage_reader_handles_json, ErrorCode::CommonInvalidParam7, HashMap<String, IndyHandle>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_prover_update_revocation_states: entities >>> wallet_handle: {:?}, pool_handle: {:?}, proof_req_json: {:?}, requested_credentials_json: {:?}, \
+    rev_reg_defs_json: {:?}, blob_storage_reader_handles_json: {:?}",
+           wallet_handle, pool_handle, proof_req_json, requested_credentials_json, rev_reg_defs_json, blob_storage_reader_handles_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::UpdateRevocationStates(
+            wallet_handle,
+            pool_handle,
+            proof_req_json,
+            requested_credentials_json,
+            rev_reg_defs_json,
+            blob_storage_reader_handles_json,
+            Box::new(move |result| {
+                let (err, rev_states_json, requested_credentials_json) = prepare_result_2!(result, String::new(), String::new());
+                trace!("indy_prover_update_revocation_states: rev_states_json: {:?}, requested_credentials_json: {:?}", rev_states_json, requested_credentials_json);
+                let rev_states_json = ctypes::string_to_cstring(rev_states_json);
+                let requested_credentials_json = ctypes::string_to_cstring(requested_credentials_json);
+                cb(command_handle, err, rev_states_json.as_ptr(), requested_credentials_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_update_revocation_states: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets credential stored in the wallet as W3C Verifiable Credential (JSON-LD).
+/// CL signature of the credential is carried in the `proof` object.
+/// CL credentials are bound to the master secret rather than to a DID, so `credentialSubject.id`
+/// is a random URN generated on every call.
+/// Credentials with an attribute named `id` can't be exported.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handler (created by open_wallet).
+/// cred_id: Identifier by which requested credential is stored in the wallet
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// verifiable credential json:
+///     {
+///         "@context": ["https://www.w3.org/2018/credentials/v1", {"@vocab": "urn:indy:anoncreds:"}],
+///         "type": ["VerifiableCredential", "IndyCredential"],
+///         "issuer": "did:sov:<issuer_did>",
+///         "issuanceDate": <time of export, RFC 3339>,
+///         "credentialSchema": {"id": <schema_id>, "type": "IndyCredentialSchema"},
+///         "credentialSubject": {"id": "urn:uuid:<random uuid>", "key1":"raw_value1", "key2":"raw_value2"},
+///         "proof": {
+///             "type": "CLSignature2019",
+///             "credentialDefinition": <cred_def_id>,
+///             "revocationRegistry": Optional<rev_reg_id>,
+///             "encoding": {"key1":"encoded_value1", "key2":"encoded_value2"},
+///             "signature": <credential_signature>,
+///             "signatureCorrectnessProof": <signature_correctness_proof>
+///         }
+///     }
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_get_credential_w3c(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             cred_id: *const c_char,
+                                             cb: Option<extern fn(
+                                                 command_handle_: CommandHandle, err: ErrorCode,
+                                                 w3c_credential_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_get_credential_w3c: >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+    check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_prover_get_credential_w3c: entities >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::GetCredentialW3C(
+            wallet_handle,
+            cred_id,
+            Box::new(move |result| {
+                let (err, w3c_credential_json) = prepare_result_1!(result, String::new());
+                trace!("indy_prover_get_credential_w3c: w3c_credential_json: {:?}", w3c_credential_json);
+                let w3c_credential_json = ctypes::string_to_cstring(w3c_credential_json);
+                cb(command_handle, err, w3c_credential_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_get_credential_w3c: <<< res: {:?}", res);
+
+    res
+}
+
+/// Converts a proof created by indy_prover_create_proof to W3C Verifiable Presentation (JSON-LD).
+/// Every credential used in the proof is rendered as verifiable credential with corresponding CL sub proof
+/// and revealed attributes as credential subject. Aggregated proof and requested proof are carried in the
+/// presentation `proof` object, so presentation can be converted back by indy_verifier_proof_from_w3c.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// proof_req_json: proof request json the proof was created for (see indy_prover_create_proof)
+/// proof_json: proof json (see indy_prover_create_proof)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// verifiable presentation json:
+///     {
+///         "@context": ["https://www.w3.org/2018/credentials/v1", {"@vocab": "urn:indy:anoncreds:"}],
+///         "type": ["VerifiablePresentation", "IndyPresentation"],
+///         "verifiableCredential": [{
+///             "@context": [...],
+///             "type": ["VerifiableCredential", "IndyCredential"],
+///             "issuer": "did:sov:<issuer_did>",
+///             "issuanceDate": <time of export, RFC 3339>,
+///             "credentialSchema": {"id": <schema_id>, "type": "IndyCredentialSchema"},
+///             "credentialSubject": {"id": "urn:uuid:<random uuid>", "attr_name1": "raw_value1"},
+///             "proof": {
+///                 "type": "CLSubProof2019",
+///                 "credentialDefinition": <cred_def_id>,
+///                 "revocationRegistry": Optional<rev_reg_id>,
+///                 "timestamp": Optional<integer>,
+///                 "subProofIndex": integer,
+///                 "proofValue": <sub_proof>
+///             }
+///         }],
+///         "proof": {
+///             "type": "CLAggregatedProof2019",
+///             "requestedProof": <requested_proof>,
+///             "proofValue": <aggregated_proof>
+///         }
+///     }
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_prover_proof_to_w3c(command_handle: CommandHandle,
+                                       proof_req_json: *const c_char,
+                                       proof_json: *const c_char,
+                                       cb: Option<extern fn(
+                                           command_handle_: CommandHandle, err: ErrorCode,
+                                           w3c_presentation_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_proof_to_w3c: >>> proof_req_json: {:?}, proof_json: {:?}", proof_req_json, proof_json);
+
+    check_useful_json!(proof_req_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_prover_proof_to_w3c: entities >>> proof_req_json: {:?}, proof_json: {:?}", proof_req_json, proof_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::ProofToW3C(
+            proof_req_json,
+            proof_json,
+            Box::new(move |result| {
+                let (err, w3c_presentation_json) = prepare_result_1!(result, String::new());
+                trace!("indy_prover_proof_to_w3c: w3c_presentation_json: {:?}", w3c_presentation_json);
+                let w3c_presentation_json = ctypes::string_to_cstring(w3c_presentation_json);
+                cb(command_handle, err, w3c_presentation_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_proof_to_w3c: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parses W3C Verifiable Presentation created by indy_prover_proof_to_w3c
+/// into the proof json accepted by indy_verifier_verify_proof.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// w3c_presentation_json: verifiable presentation json (see indy_prover_proof_to_w3c)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// proof json (see indy_prover_create_proof)
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_verifier_proof_from_w3c(command_handle: CommandHandle,
+                                           w3c_presentation_json: *const c_char,
+                                           cb: Option<extern fn(
+                                               command_handle_: CommandHandle, err: ErrorCode,
+                                               proof_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_proof_from_w3c: >>> w3c_presentation_json: {:?}", w3c_presentation_json);
+
+    check_useful_json!(w3c_presentation_json, ErrorCode::CommonInvalidParam2, W3CPresentation);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_verifier_proof_from_w3c: entities >>> w3c_presentation_json: {:?}", w3c_presentation_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::ProofFromW3C(
+            w3c_presentation_json,
+            Box::new(move |result| {
+                let (err, proof_json) = prepare_result_1!(result, String::new());
+                trace!("indy_verifier_proof_from_w3c: proof_json: {:?}", proof_json);
+                let proof_json = ctypes::string_to_cstring(proof_json);
+                cb(command_handle, err, proof_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_proof_from_w3c: <<< res: {:?}", res);
+
+    res
+}
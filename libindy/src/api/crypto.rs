@@ -1,14 +1,18 @@
 extern crate libc;
+extern crate zeroize;
 
-use api::{ErrorCode, CommandHandle, WalletHandle};
+use api::{ErrorCode, CommandHandle, IndyHandle, WalletHandle};
 use commands::{Command, CommandExecutor};
 use commands::crypto::CryptoCommand;
-use domain::crypto::key::KeyInfo;
+use domain::crypto::jws::JwsOptions;
+use domain::crypto::key::{BlsKeyInfo, KeyExportFormat, KeyInfo};
+use domain::crypto::stream::StreamHeader;
 use errors::prelude::*;
 use utils::ctypes;
 
 use serde_json;
 use self::libc::c_char;
+use self::zeroize::Zeroize;
 
 
 /// Creates keys pair and stores in the wallet.
@@ -20,7 +24,12 @@ use self::libc::c_char;
 /// {
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
-///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+///     "crypto_type": string, // Optional (if not set then ed25519 curve is used);
+///                            // Supported values: 'ed25519', 'secp256k1' (signing only) and 'x25519' (key agreement only).
+///                            // For non-default types the verkey gets ':<crypto_type>' suffix.
+///     "derivation_path": string, (optional) SLIP-0010 path (like "m/44'/0'/0'") to derive key from the wallet master seed
+///                                (see indy_set_master_seed). Only hardened indexes and ed25519 keys are supported.
+///                                Can't be used together with seed.
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -68,6 +77,175 @@ pub extern fn indy_create_key(command_handle: CommandHandle,
     res
 }
 
+/// Creates BLS keys pair, stores it in the wallet and computes proof of possession for it.
+/// The result can be used as "blskey" and "blskey_pop" of NODE transaction
+/// (see indy_build_node_request and indy_build_node_request_with_bls_key).
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// key_json: Key information as json. Example:
+/// {
+///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
+///                                Can be UTF-8, base64 or hex string.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: command handle to map callback to caller context.
+/// - err: Error code.
+/// - blskey: Base58 encoded BLS ver key, also used as key identifier
+/// - blskey_pop: Base58 encoded proof of possession for the BLS key
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_create_bls_key(command_handle: CommandHandle,
+                                  wallet_handle: WalletHandle,
+                                  key_json: *const c_char,
+                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                       err: ErrorCode,
+                                                       blskey: *const c_char,
+                                                       blskey_pop: *const c_char)>) -> ErrorCode {
+    trace!("indy_create_bls_key: >>> wallet_handle: {:?}, key_json: {:?}", wallet_handle, key_json);
+
+    check_useful_json!(key_json, ErrorCode::CommonInvalidParam3, BlsKeyInfo);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_create_bls_key: entities >>> wallet_handle: {:?}, key_json: {:?}", wallet_handle, secret!(&key_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::CreateBlsKey(
+            wallet_handle,
+            key_json,
+            Box::new(move |result| {
+                let (err, blskey, blskey_pop) = prepare_result_2!(result, String::new(), String::new());
+                trace!("indy_create_bls_key: blskey: {:?}, blskey_pop: {:?}", blskey, blskey_pop);
+                let blskey = ctypes::string_to_cstring(blskey);
+                let blskey_pop = ctypes::string_to_cstring(blskey_pop);
+                cb(command_handle, err, blskey.as_ptr(), blskey_pop.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_create_bls_key: <<< res: {:?}", res);
+
+    res
+}
+
+/// Sets the master seed of the wallet used for deterministic derivation of keys and DIDs
+/// (see derivation_path of indy_create_key and indy_create_and_store_my_did).
+/// The master seed can be set only once for the wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// seed: (optional) hex encoded master seed from 16 to 64 bytes (if not set random 32 bytes seed will be created).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: command handle to map callback to caller context.
+/// - err: Error code.
+/// - seed: hex encoded master seed that should be backed up to restore derived keys.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_set_master_seed(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   seed: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        seed: *const c_char)>) -> ErrorCode {
+    trace!("indy_set_master_seed: >>> wallet_handle: {:?}, seed: {:?}", wallet_handle, seed);
+
+    check_useful_opt_c_str!(seed, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_set_master_seed: entities >>> wallet_handle: {:?}, seed: {:?}", wallet_handle, secret!(&seed));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::SetMasterSeed(
+            wallet_handle,
+            seed,
+            Box::new(move |result| {
+                let (err, seed) = prepare_result_1!(result, String::new());
+                trace!("indy_set_master_seed: seed: {:?}", secret!(&seed));
+                let seed = ctypes::string_to_cstring(seed);
+                cb(command_handle, err, seed.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_master_seed: <<< res: {:?}", res);
+
+    res
+}
+
+/// Lists keys and DIDs derived from the wallet master seed (see derivation_path of indy_create_key,
+/// indy_create_and_store_my_did and indy_replace_keys_start) with their derivation paths.
+/// Listed keys can be re-derived in other wallet with the same master seed set.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: command handle to map callback to caller context.
+/// - err: Error code.
+/// - derived_keys: list of derived keys as json
+/// [{
+///     "verkey": string, // verkey of derived key
+///     "derivation_path": string, // SLIP-0010 path the key has been derived with
+///     "did": string, // (optional) DID the key has been created for
+/// }]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_list_derived_keys(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode,
+                                                          derived_keys: *const c_char)>) -> ErrorCode {
+    trace!("indy_list_derived_keys: >>> wallet_handle: {:?}", wallet_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_list_derived_keys: entities >>> wallet_handle: {:?}", wallet_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ListDerivedKeys(
+            wallet_handle,
+            Box::new(move |result| {
+                let (err, derived_keys) = prepare_result_1!(result, String::new());
+                trace!("indy_list_derived_keys: derived_keys: {:?}", derived_keys);
+                let derived_keys = ctypes::string_to_cstring(derived_keys);
+                cb(command_handle, err, derived_keys.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_list_derived_keys: <<< res: {:?}", res);
+
+    res
+}
+
 /// Saves/replaces the meta information for the giving key in the wallet.
 ///
 /// #Params
@@ -173,6 +351,139 @@ pub  extern fn indy_get_key_metadata(command_handle: CommandHandle,
     res
 }
 
+/// Exports a key stored in the wallet in one of standard formats.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// verkey: The key (verkey, key id) to export. Supported crypto types: ed25519, x25519.
+/// format: Export format:
+///     "jwk" - JSON Web Key with OKP key type (RFC 8037), contains private key in plain form;
+///     "pkcs8" - PEM encoded encrypted PKCS#8 private key (PBES2 with PBKDF2-HMAC-SHA256 and AES-256-CBC).
+/// protection_key: Passphrase used to encrypt the exported key. Required for pkcs8, must be null for jwk.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - exported_key - Exported key in requested format.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_export_key(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               verkey: *const c_char,
+                               format: *const c_char,
+                               protection_key: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode,
+                                                    exported_key: *const c_char)>) -> ErrorCode {
+    trace!("indy_export_key: >>> wallet_handle: {:?}, verkey: {:?}, format: {:?}", wallet_handle, verkey, format);
+
+    check_useful_c_str!(verkey, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(format, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(protection_key, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let format = match format.parse::<KeyExportFormat>() {
+        Ok(format) => format,
+        Err(_) => return err_msg(ErrorCode::CommonInvalidParam4.into(), "Unsupported key export format").into()
+    };
+
+    trace!("indy_export_key: entities >>> wallet_handle: {:?}, verkey: {:?}, format: {:?}", wallet_handle, verkey, format);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ExportKey(
+            wallet_handle,
+            verkey,
+            format,
+            protection_key,
+            Box::new(move |result| {
+                let (err, exported_key) = prepare_result_1!(result, String::new());
+                trace!("indy_export_key: exported_key: ***");
+                let exported_key = ctypes::string_to_cstring(exported_key);
+                cb(command_handle, err, exported_key.as_ptr());
+                exported_key.into_bytes().zeroize();
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_export_key: <<< res: {:?}", res);
+
+    res
+}
+
+/// Imports a key exported by indy_export_key (or by other tools in the same formats) and stores it in the wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// exported_key: Key data in the format set by format param.
+/// format: Key data format: "jwk" (OKP key with Ed25519 or X25519 curve) or "pkcs8" (PEM encoded encrypted PKCS#8).
+/// protection_key: Passphrase used to decrypt the key. Required for pkcs8, must be null for jwk.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - verkey: Ver key of imported key pair (with crypto type suffix for non-default crypto types).
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_import_key(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               exported_key: *const c_char,
+                               format: *const c_char,
+                               protection_key: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode,
+                                                    verkey: *const c_char)>) -> ErrorCode {
+    trace!("indy_import_key: >>> wallet_handle: {:?}, format: {:?}", wallet_handle, format);
+
+    check_useful_c_str!(exported_key, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(format, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(protection_key, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let format = match format.parse::<KeyExportFormat>() {
+        Ok(format) => format,
+        Err(_) => return err_msg(ErrorCode::CommonInvalidParam4.into(), "Unsupported key export format").into()
+    };
+
+    trace!("indy_import_key: entities >>> wallet_handle: {:?}, format: {:?}", wallet_handle, format);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ImportKey(
+            wallet_handle,
+            exported_key,
+            format,
+            protection_key,
+            Box::new(move |result| {
+                let (err, verkey) = prepare_result_1!(result, String::new());
+                trace!("indy_import_key: verkey: {:?}", verkey);
+                let verkey = ctypes::string_to_cstring(verkey);
+                cb(command_handle, err, verkey.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_import_key: <<< res: {:?}", res);
+
+    res
+}
+
 /// Signs a message with a key.
 ///
 /// Note to use DID keys with this function you can call indy_key_for_did to get key id (verkey)
@@ -182,6 +493,7 @@ pub  extern fn indy_get_key_metadata(command_handle: CommandHandle,
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handler (created by open_wallet).
 /// signer_vk: id (verkey) of message signer. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+///            The signature scheme is selected by the verkey crypto type suffix (ed25519 or secp256k1).
 /// message_raw: a pointer to first byte of message to be signed
 /// message_len: a message length
 /// cb: Callback that takes command result as parameter.
@@ -240,7 +552,7 @@ pub  extern fn indy_crypto_sign(command_handle: CommandHandle,
 ///
 /// #Params
 /// command_handle: command handle to map callback to user context.
-/// signer_vk: verkey of the message signer
+/// signer_vk: verkey of the message signer (ed25519 or secp256k1, selected by the verkey crypto type suffix)
 /// message_raw: a pointer to first byte of message that has been signed
 /// message_len: a message length
 /// signature_raw: a pointer to first byte of signature to be verified
@@ -295,6 +607,135 @@ pub  extern fn indy_crypto_verify(command_handle: CommandHandle,
     res
 }
 
+/// Creates JWS (RFC 7515) over arbitrary payload with a key from the wallet.
+///
+/// Signature algorithm is selected by the key crypto type: EdDSA for ed25519 keys and ES256K for secp256k1 keys.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// signer_vk: id (verkey) of signer. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+/// payload_raw: a pointer to first byte of payload to be signed
+/// payload_len: a payload length
+/// options_json: (optional) signing options json:
+/// {
+///     "kid": string, (optional) value of kid header parameter, signer_vk is used by default.
+///                               Can be DID key reference, for example "did:sov:<did>#key-1"
+///     "detached": bool, (optional) omit payload from the result JWS, false by default
+///     "serialization": string, (optional) "compact" (default) or "json" (flattened JWS JSON serialization)
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// jws: JWS string in the requested serialization
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_sign_jws(command_handle: CommandHandle,
+                             wallet_handle: WalletHandle,
+                             signer_vk: *const c_char,
+                             payload_raw: *const u8,
+                             payload_len: u32,
+                             options_json: *const c_char,
+                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                  err: ErrorCode,
+                                                  jws: *const c_char)>) -> ErrorCode {
+    trace!("indy_sign_jws: >>> wallet_handle: {:?}, signer_vk: {:?}, payload_raw: {:?}, payload_len: {:?}, options_json: {:?}",
+           wallet_handle, signer_vk, payload_raw, payload_len, options_json);
+
+    check_useful_c_str!(signer_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_byte_array!(payload_raw, payload_len, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_json!(options_json, ErrorCode::CommonInvalidParam6, JwsOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_sign_jws: entities >>> wallet_handle: {:?}, signer_vk: {:?}, payload_raw: {:?}, payload_len: {:?}, options_json: {:?}",
+           wallet_handle, signer_vk, payload_raw, payload_len, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::SignJws(
+            wallet_handle,
+            signer_vk,
+            payload_raw,
+            options_json.unwrap_or_default(),
+            Box::new(move |result| {
+                let (err, jws) = prepare_result_1!(result, String::new());
+                trace!("indy_sign_jws: jws: {:?}", jws);
+                let jws = ctypes::string_to_cstring(jws);
+                cb(command_handle, err, jws.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_sign_jws: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies JWS created by indy_sign_jws (or any EdDSA/ES256K JWS) in compact or flattened JSON serialization.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// jws: JWS string
+/// detached_payload_raw: (optional) a pointer to first byte of payload for JWS with detached payload
+/// detached_payload_len: a detached payload length
+/// signer_vk: verkey of the expected signer. Note that kid header parameter is not used to resolve the verkey
+///            as it is chosen by the signer. Resolve kid to a trusted verkey (e.g. with indy_key_for_did) instead.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if signature is valid, false - otherwise
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_verify_jws(command_handle: CommandHandle,
+                               jws: *const c_char,
+                               detached_payload_raw: *const u8,
+                               detached_payload_len: u32,
+                               signer_vk: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode,
+                                                    valid: bool)>) -> ErrorCode {
+    trace!("indy_verify_jws: >>> jws: {:?}, detached_payload_raw: {:?}, detached_payload_len: {:?}, signer_vk: {:?}",
+           jws, detached_payload_raw, detached_payload_len, signer_vk);
+
+    check_useful_c_str!(jws, ErrorCode::CommonInvalidParam2);
+    let detached_payload = if detached_payload_raw.is_null() {
+        None
+    } else {
+        check_useful_c_byte_array!(detached_payload_raw, detached_payload_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+        Some(detached_payload_raw)
+    };
+    check_useful_c_str!(signer_vk, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_verify_jws: entities >>> jws: {:?}, detached_payload: {:?}, signer_vk: {:?}",
+           jws, detached_payload, signer_vk);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::VerifyJws(
+            jws,
+            detached_payload,
+            signer_vk,
+            Box::new(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_verify_jws: valid: {:?}", valid);
+                cb(command_handle, err, valid)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verify_jws: <<< res: {:?}", res);
+
+    res
+}
+
 /// **** THIS FUNCTION WILL BE DEPRECATED USE indy_pack_message() INSTEAD ****
 /// Encrypt a message by authenticated-encryption scheme.
 ///
@@ -754,3 +1195,523 @@ pub extern fn indy_unpack_message(
 
     res
 }
+
+/// Packs a message for receivers and wraps it into forward envelopes for each mediator on the route (Experimental)
+///
+/// The message is packed as indy_pack_message does. Then for each routing key the packed message is put into
+/// forward message and anoncrypted for this routing key:
+/// {
+///     "@type": "https://didcomm.org/routing/1.0/forward",
+///     "@id": <uuid>,
+///     "to": <receiver verkey or previous routing key>,
+///     "msg": <packed message>
+/// }
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// message: a pointer to the first byte of the message to be packed
+/// message_len: the length of the message
+/// receiver_keys: a string in the format of a json list which will contain the list of receiver's keys
+///                the message is being encrypted for. Only one receiver is allowed if routing keys are set
+///                as the route is specific to the receiver.
+///                Example:
+///                "[<receiver edge_agent verkey>]"
+/// routing_keys: a string in the format of a json list of mediator keys ordered from the mediator
+///                closest to the receivers to the outermost one. Empty list means no routing.
+///                Example:
+///                "[<receiver mediator verkey>, <agency verkey>]"
+/// sender: the sender's verkey as a string When null pointer is used in this parameter, anoncrypt is used
+///         for the inner message. Forward envelopes are always anoncrypted.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// a JWE of the outermost layer in the format described for indy_pack_message
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_pack_message_with_routing(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    message: *const u8,
+    message_len: u32,
+    receiver_keys: *const c_char,
+    routing_keys: *const c_char,
+    sender: *const c_char,
+    cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode, jwe_data: *const u8, jwe_len: u32)>,
+) -> ErrorCode {
+    trace!("indy_pack_message_with_routing: >>> wallet_handle: {:?}, message: {:?}, message_len {:?},\
+            receiver_keys: {:?}, routing_keys: {:?}, sender: {:?}", wallet_handle, message, message_len, receiver_keys, routing_keys, sender);
+
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(receiver_keys, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(routing_keys, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(sender, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_pack_message_with_routing: entities >>> wallet_handle: {:?}, message: {:?}, message_len {:?},\
+            receiver_keys: {:?}, routing_keys: {:?}, sender: {:?}", wallet_handle, message, message_len, receiver_keys, routing_keys, sender);
+
+    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::PackMessageWithRouting(
+        message,
+        receiver_keys,
+        routing_keys,
+        sender,
+        wallet_handle,
+        Box::new(move |result| {
+            let (err, jwe) = prepare_result_1!(result, Vec::new());
+            trace!("indy_pack_message_with_routing: jwe: {:?}", jwe);
+            let (jwe_data, jwe_len) = ctypes::vec_to_pointer(&jwe);
+            cb(command_handle, err, jwe_data, jwe_len)
+        }),
+    )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_pack_message_with_routing: <<< res: {:?}", res);
+
+    res
+}
+
+/// Unpacks a JWE-like formatted message and all forward envelopes inside of it addressed to keys from the wallet (Experimental)
+///
+/// Unpacking stops at the first layer that is not a forward message or whose forwarded message
+/// is not addressed to any key in the wallet (mediator case, the forward message itself is returned).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// jwe_data: a pointer to the first byte of the JWE to be unpacked
+/// jwe_len: the length of the JWE message in bytes
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// {
+///     message: <decrypted message of the innermost unpacked layer>,
+///     sender_verkey: <sender_verkey of the innermost layer> (only if authcrypt was used),
+///     recipient_verkey: <recipient_verkey of the innermost layer>,
+///     layers: [ // from the outermost to the innermost layer
+///         {
+///             recipient_verkey: <recipient_verkey>,
+///             sender_verkey: <sender_verkey> (only if authcrypt was used),
+///             forward_to: <"to" field of forward message> (only for forward layers)
+///         }
+///     ]
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_unpack_message_with_routing(
+    command_handle: i32,
+    wallet_handle: WalletHandle,
+    jwe_data: *const u8,
+    jwe_len: u32,
+    cb: Option<
+        extern fn(
+            xcommand_handle: i32,
+            err: ErrorCode,
+            res_json_data : *const u8,
+            res_json_len : u32
+        ),
+    >,
+) -> ErrorCode {
+    trace!(
+        "indy_unpack_message_with_routing: >>> wallet_handle: {:?}, jwe_data: {:?}, jwe_len {:?}",
+        wallet_handle,
+        jwe_data,
+        jwe_len
+    );
+
+    check_useful_c_byte_array!(jwe_data, jwe_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!(
+        "indy_unpack_message_with_routing: entities >>> wallet_handle: {:?}, jwe_data: {:?}, jwe_len {:?}",
+        wallet_handle,
+        jwe_data,
+        jwe_len
+    );
+
+    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::UnpackMessageWithRouting(
+        jwe_data,
+        wallet_handle,
+        Box::new(move |result| {
+            let (err, res_json) = prepare_result_1!(result, Vec::new());
+            trace!("indy_unpack_message_with_routing: cb command_handle: {:?}, err: {:?}, res_json: {:?}",
+                command_handle, err, res_json
+            );
+            let (res_json_data, res_json_len) = ctypes::vec_to_pointer(&res_json);
+            cb(command_handle, err, res_json_data, res_json_len)
+        }),
+    )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unpack_message_with_routing: <<< res: {:?}", res);
+
+    res
+}
+
+/// Starts streaming encryption of a large payload for a recipient (Experimental)
+///
+/// Content key is generated for the stream and wrapped for the recipient the same way as in indy_pack_message:
+/// with crypto_box (authcrypt) if sender key is passed or with sealed box (anoncrypt) otherwise.
+/// The payload is encrypted with chacha20poly1305_ietf in chunks following STREAM construction: chunks use
+/// sequential nonces and the last chunk is marked in associated data, so truncation and reordering are detected.
+/// Stream that will not be finalized must be released with indy_crypto_stream_close.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// sender_vk: (optional) sender's verkey. When null pointer is used in this parameter, anoncrypt is used
+/// recipient_vk: recipient's verkey
+/// chunk_size: size of plaintext chunk in bytes, 0 means default size (64KB)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stream_handle: handle to use with indy_crypto_stream_encrypt_update and indy_crypto_stream_encrypt_finalize
+/// header_json: stream header that must be passed to indy_crypto_stream_decrypt_init on the recipient side
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_crypto_stream_encrypt_init(command_handle: CommandHandle,
+                                              wallet_handle: WalletHandle,
+                                              sender_vk: *const c_char,
+                                              recipient_vk: *const c_char,
+                                              chunk_size: u32,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode,
+                                                                   stream_handle: IndyHandle,
+                                                                   header_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_crypto_stream_encrypt_init: >>> wallet_handle: {:?}, sender_vk: {:?}, recipient_vk: {:?}, chunk_size: {:?}",
+           wallet_handle, sender_vk, recipient_vk, chunk_size);
+
+    check_useful_opt_c_str!(sender_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(recipient_vk, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let chunk_size = if chunk_size == 0 { None } else { Some(chunk_size as usize) };
+
+    trace!("indy_crypto_stream_encrypt_init: entities >>> wallet_handle: {:?}, sender_vk: {:?}, recipient_vk: {:?}, chunk_size: {:?}",
+           wallet_handle, sender_vk, recipient_vk, chunk_size);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::StreamEncryptInit(
+            wallet_handle,
+            sender_vk,
+            recipient_vk,
+            chunk_size,
+            Box::new(move |result| {
+                let (err, stream_handle, header_json) = prepare_result_2!(result, 0, String::new());
+                trace!("indy_crypto_stream_encrypt_init: stream_handle: {:?}, header_json: {:?}", stream_handle, header_json);
+                let header_json = ctypes::string_to_cstring(header_json);
+                cb(command_handle, err, stream_handle, header_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_stream_encrypt_init: <<< res: {:?}", res);
+
+    res
+}
+
+/// Encrypts next part of the payload (Experimental)
+///
+/// Encrypted data is returned by full chunks, so the result can be empty if not enough data has been passed yet.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_stream_encrypt_init).
+/// data_raw: a pointer to first byte of data to be encrypted
+/// data_len: a data length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// encrypted data that must be appended to the encrypted stream
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_crypto_stream_encrypt_update(command_handle: CommandHandle,
+                                                stream_handle: IndyHandle,
+                                                data_raw: *const u8,
+                                                data_len: u32,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode,
+                                                                     encrypted_raw: *const u8,
+                                                                     encrypted_len: u32)>) -> ErrorCode {
+    trace!("indy_crypto_stream_encrypt_update: >>> stream_handle: {:?}, data_raw: {:?}, data_len: {:?}",
+           stream_handle, data_raw, data_len);
+
+    check_useful_c_byte_array!(data_raw, data_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_crypto_stream_encrypt_update: entities >>> stream_handle: {:?}, data_len: {:?}", stream_handle, data_raw.len());
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::StreamEncryptUpdate(
+            stream_handle,
+            data_raw,
+            Box::new(move |result| {
+                let (err, encrypted) = prepare_result_1!(result, Vec::new());
+                trace!("indy_crypto_stream_encrypt_update: encrypted len: {:?}", encrypted.len());
+                let (encrypted_raw, encrypted_len) = ctypes::vec_to_pointer(&encrypted);
+                cb(command_handle, err, encrypted_raw, encrypted_len)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_stream_encrypt_update: <<< res: {:?}", res);
+
+    res
+}
+
+/// Finishes streaming encryption and releases the stream handle (Experimental)
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_stream_encrypt_init).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// the last part of encrypted data that must be appended to the encrypted stream
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_crypto_stream_encrypt_finalize(command_handle: CommandHandle,
+                                                  stream_handle: IndyHandle,
+                                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                       err: ErrorCode,
+                                                                       encrypted_raw: *const u8,
+                                                                       encrypted_len: u32)>) -> ErrorCode {
+    trace!("indy_crypto_stream_encrypt_finalize: >>> stream_handle: {:?}", stream_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_stream_encrypt_finalize: entities >>> stream_handle: {:?}", stream_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::StreamEncryptFinalize(
+            stream_handle,
+            Box::new(move |result| {
+                let (err, encrypted) = prepare_result_1!(result, Vec::new());
+                trace!("indy_crypto_stream_encrypt_finalize: encrypted len: {:?}", encrypted.len());
+                let (encrypted_raw, encrypted_len) = ctypes::vec_to_pointer(&encrypted);
+                cb(command_handle, err, encrypted_raw, encrypted_len)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_stream_encrypt_finalize: <<< res: {:?}", res);
+
+    res
+}
+
+/// Starts streaming decryption of a payload encrypted by indy_crypto_stream_encrypt_* functions (Experimental)
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// header_json: stream header returned by indy_crypto_stream_encrypt_init
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stream_handle: handle to use with indy_crypto_stream_decrypt_update and indy_crypto_stream_decrypt_finalize
+/// info_json: {
+///     "recipient_verkey": <recipient verkey>,
+///     "sender_verkey": <sender verkey> (only if authcrypt was used)
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_crypto_stream_decrypt_init(command_handle: CommandHandle,
+                                              wallet_handle: WalletHandle,
+                                              header_json: *const c_char,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode,
+                                                                   stream_handle: IndyHandle,
+                                                                   info_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_crypto_stream_decrypt_init: >>> wallet_handle: {:?}, header_json: {:?}", wallet_handle, header_json);
+
+    check_useful_json!(header_json, ErrorCode::CommonInvalidParam3, StreamHeader);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_crypto_stream_decrypt_init: entities >>> wallet_handle: {:?}, header_json: {:?}", wallet_handle, header_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::StreamDecryptInit(
+            wallet_handle,
+            header_json,
+            Box::new(move |result| {
+                let (err, stream_handle, info_json) = prepare_result_2!(result, 0, String::new());
+                trace!("indy_crypto_stream_decrypt_init: stream_handle: {:?}, info_json: {:?}", stream_handle, info_json);
+                let info_json = ctypes::string_to_cstring(info_json);
+                cb(command_handle, err, stream_handle, info_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_stream_decrypt_init: <<< res: {:?}", res);
+
+    res
+}
+
+/// Decrypts next part of the encrypted stream (Experimental)
+///
+/// Decrypted data is returned only after the whole chunk is received and authenticated,
+/// so the result can be empty if not enough data has been passed yet.
+/// On decryption error the stream handle is released.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_stream_decrypt_init).
+/// data_raw: a pointer to first byte of encrypted data
+/// data_len: an encrypted data length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// decrypted data
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_crypto_stream_decrypt_update(command_handle: CommandHandle,
+                                                stream_handle: IndyHandle,
+                                                data_raw: *const u8,
+                                                data_len: u32,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode,
+                                                                     decrypted_raw: *const u8,
+                                                                     decrypted_len: u32)>) -> ErrorCode {
+    trace!("indy_crypto_stream_decrypt_update: >>> stream_handle: {:?}, data_raw: {:?}, data_len: {:?}",
+           stream_handle, data_raw, data_len);
+
+    check_useful_c_byte_array!(data_raw, data_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_crypto_stream_decrypt_update: entities >>> stream_handle: {:?}, data_len: {:?}", stream_handle, data_raw.len());
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::StreamDecryptUpdate(
+            stream_handle,
+            data_raw,
+            Box::new(move |result| {
+                let (err, decrypted) = prepare_result_1!(result, Vec::new());
+                trace!("indy_crypto_stream_decrypt_update: decrypted len: {:?}", decrypted.len());
+                let (decrypted_raw, decrypted_len) = ctypes::vec_to_pointer(&decrypted);
+                cb(command_handle, err, decrypted_raw, decrypted_len)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_stream_decrypt_update: <<< res: {:?}", res);
+
+    res
+}
+
+/// Finishes streaming decryption, checks that the stream is complete and releases the stream handle (Experimental)
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_stream_decrypt_init).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// the last part of decrypted data
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_crypto_stream_decrypt_finalize(command_handle: CommandHandle,
+                                                  stream_handle: IndyHandle,
+                                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                       err: ErrorCode,
+                                                                       decrypted_raw: *const u8,
+                                                                       decrypted_len: u32)>) -> ErrorCode {
+    trace!("indy_crypto_stream_decrypt_finalize: >>> stream_handle: {:?}", stream_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_stream_decrypt_finalize: entities >>> stream_handle: {:?}", stream_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::StreamDecryptFinalize(
+            stream_handle,
+            Box::new(move |result| {
+                let (err, decrypted) = prepare_result_1!(result, Vec::new());
+                trace!("indy_crypto_stream_decrypt_finalize: decrypted len: {:?}", decrypted.len());
+                let (decrypted_raw, decrypted_len) = ctypes::vec_to_pointer(&decrypted);
+                cb(command_handle, err, decrypted_raw, decrypted_len)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_stream_decrypt_finalize: <<< res: {:?}", res);
+
+    res
+}
+
+/// Releases encrypt or decrypt stream handle without finalization (Experimental)
+///
+/// Must be called for streams that will not be finalized (e.g. on transfer abort) to release resources.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_stream_encrypt_init or indy_crypto_stream_decrypt_init).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_crypto_stream_close(command_handle: CommandHandle,
+                                       stream_handle: IndyHandle,
+                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                            err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_crypto_stream_close: >>> stream_handle: {:?}", stream_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_stream_close: entities >>> stream_handle: {:?}", stream_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::StreamClose(
+            stream_handle,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_crypto_stream_close: ");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_stream_close: <<< res: {:?}", res);
+
+    res
+}
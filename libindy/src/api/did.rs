@@ -1,10 +1,11 @@
 extern crate libc;
 
-use api::{ErrorCode, CommandHandle, WalletHandle, PoolHandle};
+use api::{ErrorCode, CommandHandle, WalletHandle, PoolHandle, SearchHandle};
 use commands::{Command, CommandExecutor};
 use commands::did::DidCommand;
 use domain::crypto::did::{MyDidInfo, TheirDidInfo};
 use domain::crypto::key::KeyInfo;
+use domain::wallet::Tags;
 use errors::prelude::*;
 use utils::ctypes;
 
@@ -12,7 +13,7 @@ use serde_json;
 use self::libc::c_char;
 
 use std::ptr;
-use domain::ledger::attrib::Endpoint;
+use domain::ledger::attrib::{Endpoint, Service};
 
 
 /// Creates keys (signing and encryption keys) for a new
@@ -34,8 +35,12 @@ use domain::ledger::attrib::Endpoint;
 ///     "seed": string, (optional) Seed that allows deterministic did creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-///               currently only 'ed25519' value is supported for this field)
+///               supported values: 'ed25519', 'secp256k1' (signing only) and 'x25519' (key agreement only);
+///               for non-default types the verkey gets ':<crypto_type>' suffix)
 ///     "cid": bool, (optional; if not set then false is used;)
+///     "derivation_path": string, (optional) SLIP-0010 path (like "m/44'/0'/0'") to derive keys from the wallet master seed
+///                                (see indy_set_master_seed). Only hardened indexes and ed25519 keys are supported.
+///                                Can't be used together with seed.
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -98,7 +103,11 @@ pub  extern fn indy_create_and_store_my_did(command_handle: CommandHandle,
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-///               currently only 'ed25519' value is supported for this field)
+///               supported values: 'ed25519', 'secp256k1' (signing only) and 'x25519' (key agreement only);
+///               for non-default types the verkey gets ':<crypto_type>' suffix)
+///     "derivation_path": string, (optional) SLIP-0010 path (like "m/44'/0'/0'") to derive keys from the wallet master seed
+///                                (see indy_set_master_seed). Only hardened indexes and ed25519 keys are supported.
+///                                Can't be used together with seed.
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -151,6 +160,8 @@ pub  extern fn indy_replace_keys_start(command_handle: CommandHandle,
 }
 
 /// Apply temporary keys as main for an existing DID (owned by the caller of the library).
+/// Replaced verkey is kept in the DID key history (see indy_get_did_key_history).
+/// New verkey becomes valid in the history after its ledger transaction time is set by indy_set_did_key_ledger_info.
 ///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
@@ -201,6 +212,8 @@ pub  extern fn indy_replace_keys_apply(command_handle: CommandHandle,
 
 /// Saves their DID for a pairwise connection in a secured Wallet,
 /// so that it can be used to verify transaction.
+/// If the DID is already stored with another verkey, the verkey is updated and the previous one
+/// is kept in the DID key history (see indy_get_did_key_history).
 ///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
@@ -497,6 +510,186 @@ pub extern fn indy_get_endpoint_for_did(command_handle: CommandHandle,
     res
 }
 
+/// Set/replaces service entries for the given DID.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID to set services for.
+/// services_json - list of DID services:
+///   [{
+///       "id": optional<string>, - service id
+///       "type": string, - service type, e.g. "IndyAgent" or "did-communication"
+///       "priority": optional<int>, - lower value means higher priority (0 by default)
+///       "recipientKeys": optional<[string]>, - verkeys of the recipient
+///       "routingKeys": optional<[string]>, - verkeys of the mediators
+///       "serviceEndpoint": string - service URI
+///   }]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_set_services_for_did(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        did: *const c_char,
+                                        services_json: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_services_for_did: >>> wallet_handle: {:?}, did: {:?}, services_json: {:?}", wallet_handle, did, services_json);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(services_json, ErrorCode::CommonInvalidParam4, Vec<Service>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_set_services_for_did: entities >>> wallet_handle: {:?}, did: {:?}, services_json: {:?}", wallet_handle, did, services_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::SetServicesForDid(
+            wallet_handle,
+            did,
+            services_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_set_services_for_did:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_services_for_did: <<< res: {:?}", res);
+
+    res
+}
+
+/// Returns service entries for the given DID.
+///
+/// Services are resolved from the wallet first. Endpoint set by indy_set_endpoint_for_did
+/// is returned as a single service of "IndyAgent" type.
+/// If there are no services in the wallet they are fetched from the ledger "service" ATTRIB.
+/// If the DID has no "service" ATTRIB on the ledger its legacy "endpoint" ATTRIB is used.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// pool_handle: Pool handle (created by open_pool).
+/// did - The DID to resolve services.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - services_json: list of DID services ordered by priority (see indy_set_services_for_did)
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_get_services_for_did(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        pool_handle: PoolHandle,
+                                        did: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             services_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_services_for_did: >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_get_services_for_did: entities >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::GetServicesForDid(
+            wallet_handle,
+            pool_handle,
+            did,
+            Box::new(move |result| {
+                let (err, services_json) = prepare_result_1!(result, String::new());
+                trace!("indy_get_services_for_did: services_json: {:?}", services_json);
+                let services_json = ctypes::string_to_cstring(services_json);
+                cb(command_handle, err, services_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_services_for_did: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds an ATTRIB request to write service entries of the DID stored in the wallet to the ledger.
+///
+/// Services are written as "service" raw attribute:
+/// {"service": <services_json (see indy_set_services_for_did)>}
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// did - The DID to write services for.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - request_json: Request result as json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_build_did_services_attrib_request(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     submitter_did: *const c_char,
+                                                     did: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                          err: ErrorCode,
+                                                                          request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_did_services_attrib_request: >>> wallet_handle: {:?}, submitter_did: {:?}, did: {:?}", wallet_handle, submitter_did, did);
+
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_build_did_services_attrib_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, did: {:?}", wallet_handle, submitter_did, did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::BuildDidServicesAttribRequest(
+            wallet_handle,
+            submitter_did,
+            did,
+            Box::new(move |result| {
+                let (err, request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_build_did_services_attrib_request: request_json: {:?}", request_json);
+                let request_json = ctypes::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_did_services_attrib_request: <<< res: {:?}", res);
+
+    res
+}
+
 /// Saves/replaces the meta information for the giving DID in the wallet.
 ///
 /// #Params
@@ -763,3 +956,524 @@ pub  extern fn indy_abbreviate_verkey(command_handle: CommandHandle,
 
     res
 }
+
+/// Returns verkey rotation history of the DID.
+///
+/// History is recorded by indy_replace_keys_apply and by indy_store_their_did called with new verkey for known DID.
+/// Validity bounds are set by indy_set_did_key_ledger_info from the ledger transaction time.
+/// For DIDs that have never been rotated the only entry is the current verkey without validity bounds.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did: DID (my or their) stored in the wallet
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - key_history_json: list of verkeys from the oldest to the current one
+///   [{
+///       "verkey": string,
+///       "validFrom": optional<int>, - ledger time (unix timestamp in seconds) the verkey has been written at
+///       "validTo": optional<int>, - ledger time (unix timestamp in seconds) the verkey has been replaced at
+///       "seqNo": optional<int>, - sequence number of the ledger transaction the verkey has been written with
+///   }]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_get_did_key_history(command_handle: CommandHandle,
+                                       wallet_handle: WalletHandle,
+                                       did: *const c_char,
+                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                            err: ErrorCode,
+                                                            key_history_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_did_key_history: >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_get_did_key_history: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::GetDidKeyHistory(
+            wallet_handle,
+            did,
+            Box::new(move |result| {
+                let (err, key_history_json) = prepare_result_1!(result, String::new());
+                trace!("indy_get_did_key_history: key_history_json: {:?}", key_history_json);
+                let key_history_json = ctypes::string_to_cstring(key_history_json);
+                cb(command_handle, err, key_history_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_did_key_history: <<< res: {:?}", res);
+
+    res
+}
+
+/// Saves ledger information of the transaction that has written the verkey of the DID.
+///
+/// Verkey replaced by indy_replace_keys_apply or indy_store_their_did becomes valid in the DID key history
+/// only after its ledger transaction time is set, the previous verkey is valid until this time.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did: DID (my or their) stored in the wallet
+/// verkey: verkey from the DID key history
+/// seq_no: sequence number of NYM transaction
+/// txn_time: time of NYM transaction (txnTime field of NYM or GET_NYM reply)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_set_did_key_ledger_info(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           did: *const c_char,
+                                           verkey: *const c_char,
+                                           seq_no: u64,
+                                           txn_time: u64,
+                                           cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_did_key_ledger_info: >>> wallet_handle: {:?}, did: {:?}, verkey: {:?}, seq_no: {:?}, txn_time: {:?}",
+           wallet_handle, did, verkey, seq_no, txn_time);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(verkey, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_set_did_key_ledger_info: entities >>> wallet_handle: {:?}, did: {:?}, verkey: {:?}, seq_no: {:?}, txn_time: {:?}",
+           wallet_handle, did, verkey, seq_no, txn_time);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::SetDidKeyLedgerInfo(
+            wallet_handle,
+            did,
+            verkey,
+            seq_no,
+            txn_time,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_set_did_key_ledger_info:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_did_key_ledger_info: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies a signature made by the DID with the verkey that was valid at the given time.
+///
+/// Verkey is resolved from the DID key history (see indy_get_did_key_history),
+/// so messages signed before key rotation can still be attributed to the DID.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did: DID (my or their) stored in the wallet
+/// timestamp: unix timestamp (in seconds) the message has been signed at
+/// message_raw: a pointer to first byte of message to be verified
+/// message_len: a message length
+/// signature_raw: a pointer to first byte of signature to be verified
+/// signature_len: a signature length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - valid: true - if signature is valid for the verkey of the DID at the given time, false - otherwise
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_verify_did_signature_at(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           did: *const c_char,
+                                           timestamp: u64,
+                                           message_raw: *const u8,
+                                           message_len: u32,
+                                           signature_raw: *const u8,
+                                           signature_len: u32,
+                                           cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode,
+                                                                valid: bool)>) -> ErrorCode {
+    trace!("indy_verify_did_signature_at: >>> wallet_handle: {:?}, did: {:?}, timestamp: {:?}, message_raw: {:?}, message_len: {:?}, signature_raw: {:?}, signature_len: {:?}",
+           wallet_handle, did, timestamp, message_raw, message_len, signature_raw, signature_len);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_byte_array!(message_raw, message_len, ErrorCode::CommonInvalidParam5, ErrorCode::CommonInvalidParam6);
+    check_useful_c_byte_array!(signature_raw, signature_len, ErrorCode::CommonInvalidParam7, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_verify_did_signature_at: entities >>> wallet_handle: {:?}, did: {:?}, timestamp: {:?}, message_raw: {:?}, signature_raw: {:?}",
+           wallet_handle, did, timestamp, message_raw, signature_raw);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::VerifyDidSignatureAt(
+            wallet_handle,
+            did,
+            timestamp,
+            message_raw,
+            signature_raw,
+            Box::new(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_verify_did_signature_at: valid: {:?}", valid);
+                cb(command_handle, err, valid)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verify_did_signature_at: <<< res: {:?}", res);
+
+    res
+}
+
+/// Replaces tags of my DID record.
+///
+/// Tags can be used in indy_search_my_dids queries.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did: DID stored in the wallet
+/// tags_json: the record tags used for search and storing meta information as json:
+///   {
+///     "tagName1": <str>, // string tag (will be stored encrypted)
+///     "~tagName2": <str>, // string tag (will be stored un-encrypted)
+///   }
+///   Note that "metadata" tag name is reserved and is always set to metadata of the DID.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_set_my_did_tags(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   did: *const c_char,
+                                   tags_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_my_did_tags: >>> wallet_handle: {:?}, did: {:?}, tags_json: {:?}", wallet_handle, did, tags_json);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(tags_json, ErrorCode::CommonInvalidParam4, Tags);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_set_my_did_tags: entities >>> wallet_handle: {:?}, did: {:?}, tags_json: {:?}", wallet_handle, did, tags_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::SetMyDidTags(
+            wallet_handle,
+            did,
+            tags_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_set_my_did_tags:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_my_did_tags: <<< res: {:?}", res);
+
+    res
+}
+
+/// Search for my DIDs stored in the wallet.
+///
+/// Instead of immediately returning of fetched records
+/// this call returns search_handle that can be used later
+/// to fetch records by small batches (with indy_fetch_my_dids_search).
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// query_json: Wql query filter for DIDs searching based on tags:
+///     {
+///         "metadata": string, // metadata set by indy_set_did_metadata
+///         <tag name set by indy_set_my_did_tags>: string,
+///     }
+/// where query: indy-sdk/doc/design/011-wallet-query-language/README.md
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - search_handle: Search handle that can be used later to fetch records by small batches (with indy_fetch_my_dids_search)
+/// - total_count: Total count of records
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_search_my_dids(command_handle: CommandHandle,
+                                  wallet_handle: WalletHandle,
+                                  query_json: *const c_char,
+                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                       err: ErrorCode,
+                                                       search_handle: SearchHandle,
+                                                       total_count: usize)>) -> ErrorCode {
+    trace!("indy_search_my_dids: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_search_my_dids: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::SearchMyDids(
+            wallet_handle,
+            query_json,
+            Box::new(move |result| {
+                let (err, handle, total_count) = prepare_result_2!(result, 0, 0);
+                trace!("indy_search_my_dids: handle: {:?}, total_count: {:?}", handle, total_count);
+                cb(command_handle, err, handle, total_count)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_search_my_dids: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetch next DIDs for search.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// search_handle: Search handle (created by indy_search_my_dids)
+/// count: Count of records to fetch
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - dids: List of DIDs with verkeys and meta data in the same format as for indy_list_my_dids_with_meta.
+///   NOTE: The list of length less than the requested count means search iterator is completed.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_fetch_my_dids_search(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        search_handle: SearchHandle,
+                                        count: usize,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             dids: *const c_char)>) -> ErrorCode {
+    trace!("indy_fetch_my_dids_search: >>> wallet_handle: {:?}, search_handle: {:?}, count: {:?}", wallet_handle, search_handle, count);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_fetch_my_dids_search: entities >>> wallet_handle: {:?}, search_handle: {:?}, count: {:?}", wallet_handle, search_handle, count);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::FetchMyDidsSearch(
+            wallet_handle,
+            search_handle,
+            count,
+            Box::new(move |result| {
+                let (err, dids) = prepare_result_1!(result, String::new());
+                trace!("indy_fetch_my_dids_search: dids: {:?}", dids);
+                let dids = ctypes::string_to_cstring(dids);
+                cb(command_handle, err, dids.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_fetch_my_dids_search: <<< res: {:?}", res);
+
+    res
+}
+
+/// Close DIDs search (make search handle invalid)
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// search_handle: Search handle (created by indy_search_my_dids)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_close_my_dids_search(command_handle: CommandHandle,
+                                        search_handle: SearchHandle,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_close_my_dids_search: >>> search_handle: {:?}", search_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_close_my_dids_search: entities >>> search_handle: {:?}", search_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::CloseMyDidsSearch(
+            search_handle,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_close_my_dids_search:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_close_my_dids_search: <<< res: {:?}", res);
+
+    res
+}
+
+/// Deletes my DID from the wallet.
+///
+/// Keys of the DID (including temporary and rotated ones), metadata, endpoint
+/// and key history records are deleted as well.
+/// DID used as my DID of some pairwise can't be deleted until the pairwise is deleted (CommonInvalidState).
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did: DID stored in the wallet
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_delete_my_did(command_handle: CommandHandle,
+                                 wallet_handle: WalletHandle,
+                                 did: *const c_char,
+                                 cb: Option<extern fn(command_handle_: CommandHandle,
+                                                      err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_delete_my_did: >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_delete_my_did: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::DeleteMyDid(
+            wallet_handle,
+            did,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_delete_my_did:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_delete_my_did: <<< res: {:?}", res);
+
+    res
+}
+
+/// Deletes their DID from the wallet.
+///
+/// Metadata, endpoint and key history records of the DID are deleted as well.
+/// DID used as their DID of some pairwise can't be deleted until the pairwise is deleted (CommonInvalidState).
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did: DID stored in the wallet
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_delete_their_did(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    did: *const c_char,
+                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                         err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_delete_their_did: >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_delete_their_did: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::DeleteTheirDid(
+            wallet_handle,
+            did,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_delete_their_did:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_delete_their_did: <<< res: {:?}", res);
+
+    res
+}
@@ -8,10 +8,15 @@ use domain::anoncreds::credential_definition::CredentialDefinition;
 use domain::anoncreds::schema::Schema;
 use domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinition;
 use domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
+use domain::ledger::auth_rule::Constraint;
+use domain::ledger::custom_txn::CustomTxnType;
 use domain::ledger::node::NodeOperationData;
+use domain::ledger::signing_bundle::{SignerInfo, SigningBundle};
 use utils::ctypes;
+use utils::sequence;
 
 use serde_json;
+use std::collections::HashMap;
 use self::libc::c_char;
 
 /// Signs and submits request message to validator pool.
@@ -190,6 +195,135 @@ pub extern fn indy_submit_action(command_handle: CommandHandle,
     res
 }
 
+/// Syncs all transactions of the ledger into the local ledger mirror.
+///
+/// The mirror is an SQLite store in the pool directory indexed by txn type, author, dest and seqNo.
+/// Sync is incremental: only transactions after the last mirrored one are requested.
+/// Received transactions are verified with consistency proofs against the ledger state
+/// confirmed by f+1 nodes.
+/// POOL ledger is copied from the pool ledger cache that is verified on pool open/refresh.
+/// Only one ledger of a pool can be synced at a time.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// ledger_type: type of the ledger to sync:
+///     DOMAIN,
+///     POOL,
+///     CONFIG
+///     any number
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Ledger mirror status json:
+/// {
+///     "ledgerId": number,
+///     "txnCount": number, // number of transactions in the mirror
+///     "rootHash": string, // base58 encoded merkle root of the mirrored transactions
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+/// Pool*
+#[no_mangle]
+pub extern fn indy_sync_ledger_mirror(command_handle: CommandHandle,
+                                      pool_handle: PoolHandle,
+                                      ledger_type: *const c_char,
+                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                           err: ErrorCode,
+                                                           status_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_sync_ledger_mirror: >>> pool_handle: {:?}, ledger_type: {:?}", pool_handle, ledger_type);
+
+    check_useful_c_str!(ledger_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_sync_ledger_mirror: entities >>> pool_handle: {:?}, ledger_type: {:?}", pool_handle, ledger_type);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::SyncLedgerMirror(
+                pool_handle,
+                ledger_type,
+                Box::new(move |result| {
+                    let (err, status_json) = prepare_result_1!(result, String::new());
+                    trace!("indy_sync_ledger_mirror: status_json: {:?}", status_json);
+                    let status_json = ctypes::string_to_cstring(status_json);
+                    cb(command_handle, err, status_json.as_ptr())
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_sync_ledger_mirror: <<< res: {:?}", res);
+
+    res
+}
+
+/// Searches transactions in the local ledger mirror (see indy_sync_ledger_mirror).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// ledger_type: type of the mirrored ledger:
+///     DOMAIN,
+///     POOL,
+///     CONFIG
+///     any number
+/// query_json: search query. All fields are optional:
+/// {
+///     "type": string, // transaction type, for example "1" for NYM
+///     "author": string, // DID of the transaction author
+///     "dest": string, // target DID of the transaction
+///     "from": number, // min seqNo (inclusive)
+///     "to": number, // max seqNo (inclusive)
+///     "limit": number, // max number of returned transactions
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Json array of transactions ordered by seqNo as they are stored on the ledger.
+///
+/// #Errors
+/// Common*
+/// Pool*
+#[no_mangle]
+pub extern fn indy_search_ledger_mirror(command_handle: CommandHandle,
+                                        pool_handle: PoolHandle,
+                                        ledger_type: *const c_char,
+                                        query_json: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             txns_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_search_ledger_mirror: >>> pool_handle: {:?}, ledger_type: {:?}, query_json: {:?}", pool_handle, ledger_type, query_json);
+
+    check_useful_c_str!(ledger_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(query_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_search_ledger_mirror: entities >>> pool_handle: {:?}, ledger_type: {:?}, query_json: {:?}", pool_handle, ledger_type, query_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::SearchLedgerMirror(
+                pool_handle,
+                ledger_type,
+                query_json,
+                Box::new(move |result| {
+                    let (err, txns_json) = prepare_result_1!(result, String::new());
+                    trace!("indy_search_ledger_mirror: txns_json: {:?}", txns_json);
+                    let txns_json = ctypes::string_to_cstring(txns_json);
+                    cb(command_handle, err, txns_json.as_ptr())
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_search_ledger_mirror: <<< res: {:?}", res);
+
+    res
+}
+
 /// Signs request message.
 ///
 /// Adds submitter information to passed request json, signs it with submitter
@@ -251,6 +385,9 @@ pub extern fn indy_sign_request(command_handle: CommandHandle,
 /// Adds submitter information to passed request json, signs it with submitter
 /// sign key (see wallet_sign).
 ///
+/// If the request has an endorser, signature added by indy_sign_request from the author
+/// is moved to the signatures. Other co-signers can multi-sign the request as well.
+///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
 /// wallet_handle: wallet handle (created by open_wallet).
@@ -301,6 +438,537 @@ pub extern fn indy_multi_sign_request(command_handle: CommandHandle,
     res
 }
 
+/// Appends endorser to the request.
+///
+/// Transaction author who isn't allowed to write the transaction directly
+/// builds the request, appends endorser DID and multi-signs it (see indy_multi_sign_request).
+/// Then the endorser multi-signs the request too and sends it to the ledger.
+/// Endorser must be appended before the request is signed.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// request_json: Request data json.
+/// endorser_did: DID of the endorser that will submit the transaction.
+///               The endorser's DID must be present on the ledger.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Updated request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_append_request_endorser(command_handle: CommandHandle,
+                                           request_json: *const c_char,
+                                           endorser_did: *const c_char,
+                                           cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                out_request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_append_request_endorser: >>> request_json: {:?}, endorser_did: {:?}", request_json, endorser_did);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(endorser_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_append_request_endorser: entities >>> request_json: {:?}, endorser_did: {:?}", request_json, endorser_did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::AppendRequestEndorser(
+            request_json,
+            endorser_did,
+            Box::new(move |result| {
+                let (err, out_request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_append_request_endorser: out_request_json: {:?}", out_request_json);
+                let out_request_json = ctypes::string_to_cstring(out_request_json);
+                cb(command_handle, err, out_request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_append_request_endorser: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a signing bundle: portable container for collecting signatures of the request
+/// on several (possibly air-gapped) machines.
+///
+/// Bundle contains the request, its serialized signing input (the exact data signers sign),
+/// the set of expected signers with their roles, an optional auth rule constraint that signatures must satisfy
+/// and collected signatures.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// request_json: not signed request data json.
+/// signers_json: DIDs expected to sign the request in the following format:
+///     {
+///         "<did>": {
+///             "verkey": <string> verkey of the DID (full or abbreviated),
+///             "role": <string> (optional) role of the DID: TRUSTEE, STEWARD, TRUST_ANCHOR, ENDORSER, NETWORK_MONITOR
+///                     or its code as used in auth rules. Not set for identity owner.
+///         }
+///     }
+///     Roles are declared by the caller and aren't verified against the ledger, so the constraint check
+///     only shows whether the expected signers would be enough. The ledger still checks actual roles on submission.
+/// constraint_json: (optional) auth rule constraint for the request (see indy_build_auth_rule_request
+///                  and the result of GET_AUTH_RULE request). The bundle is complete when signatures satisfy it.
+///                  If not set all signers must sign the request.
+///                  Note that `need_to_be_owner` can't be checked offline and is ignored.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Signing bundle json:
+///     {
+///         "ver": "1",
+///         "request": <object> request,
+///         "signing_input": <string> serialized request to be signed,
+///         "signers": <object> normalized signers_json,
+///         "constraint": <object> (optional) constraint_json,
+///         "signatures": {"<did>": <string> base58 encoded signature}
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_signing_bundle(command_handle: CommandHandle,
+                                        request_json: *const c_char,
+                                        signers_json: *const c_char,
+                                        constraint_json: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             bundle_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_signing_bundle: >>> request_json: {:?}, signers_json: {:?}, constraint_json: {:?}", request_json, signers_json, constraint_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_json!(signers_json, ErrorCode::CommonInvalidParam3, HashMap<String, SignerInfo>);
+    check_useful_opt_json!(constraint_json, ErrorCode::CommonInvalidParam4, Constraint);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_build_signing_bundle: entities >>> request_json: {:?}, signers_json: {:?}, constraint_json: {:?}", request_json, signers_json, constraint_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildSigningBundle(
+            request_json,
+            signers_json,
+            constraint_json,
+            Box::new(move |result| {
+                let (err, bundle_json) = prepare_result_1!(result, String::new());
+                trace!("indy_build_signing_bundle: bundle_json: {:?}", bundle_json);
+                let bundle_json = ctypes::string_to_cstring(bundle_json);
+                cb(command_handle, err, bundle_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_signing_bundle: <<< res: {:?}", res);
+
+    res
+}
+
+/// Signs signing input of the bundle by the signer DID and adds the signature to the bundle.
+/// Doesn't require a connection to the pool.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// signer_did: DID of the signer stored in the wallet. Must be one of the bundle signers.
+/// bundle_json: signing bundle json (see indy_build_signing_bundle).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Signing bundle json with the added signature.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_sign_signing_bundle(command_handle: CommandHandle,
+                                       wallet_handle: WalletHandle,
+                                       signer_did: *const c_char,
+                                       bundle_json: *const c_char,
+                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                            err: ErrorCode,
+                                                            out_bundle_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_sign_signing_bundle: >>> wallet_handle: {:?}, signer_did: {:?}, bundle_json: {:?}", wallet_handle, signer_did, bundle_json);
+
+    check_useful_c_str!(signer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(bundle_json, ErrorCode::CommonInvalidParam4, SigningBundle);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_sign_signing_bundle: entities >>> wallet_handle: {:?}, signer_did: {:?}, bundle_json: {:?}", wallet_handle, signer_did, bundle_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::SignSigningBundle(
+            wallet_handle,
+            signer_did,
+            bundle_json,
+            Box::new(move |result| {
+                let (err, out_bundle_json) = prepare_result_1!(result, String::new());
+                trace!("indy_sign_signing_bundle: out_bundle_json: {:?}", out_bundle_json);
+                let out_bundle_json = ctypes::string_to_cstring(out_bundle_json);
+                cb(command_handle, err, out_bundle_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_sign_signing_bundle: <<< res: {:?}", res);
+
+    res
+}
+
+/// Merges signatures collected in several signing bundles of the same request.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// bundles_json: array of signing bundles jsons (see indy_build_signing_bundle).
+///               All bundles must be built for the same request, signers and constraint.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Signing bundle json with all collected signatures.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_merge_signing_bundles(command_handle: CommandHandle,
+                                         bundles_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              bundle_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_merge_signing_bundles: >>> bundles_json: {:?}", bundles_json);
+
+    check_useful_json!(bundles_json, ErrorCode::CommonInvalidParam2, Vec<SigningBundle>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_merge_signing_bundles: entities >>> bundles_json: {:?}", bundles_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::MergeSigningBundles(
+            bundles_json,
+            Box::new(move |result| {
+                let (err, bundle_json) = prepare_result_1!(result, String::new());
+                trace!("indy_merge_signing_bundles: bundle_json: {:?}", bundle_json);
+                let bundle_json = ctypes::string_to_cstring(bundle_json);
+                cb(command_handle, err, bundle_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_merge_signing_bundles: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies signatures collected in the signing bundle and checks whether they are enough to send the request.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// bundle_json: signing bundle json (see indy_build_signing_bundle).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Bundle status json:
+///     {
+///         "complete": <bool> whether signatures satisfy the bundle constraint (or all signers signed if it's not set),
+///         "signed": <array> DIDs which signed the request,
+///         "unsigned": <array> DIDs which didn't sign the request,
+///         "request": <object> (only for complete bundle) multi-signed request ready to be sent to the ledger
+///     }
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_check_signing_bundle(command_handle: CommandHandle,
+                                        bundle_json: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             status_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_check_signing_bundle: >>> bundle_json: {:?}", bundle_json);
+
+    check_useful_json!(bundle_json, ErrorCode::CommonInvalidParam2, SigningBundle);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_check_signing_bundle: entities >>> bundle_json: {:?}", bundle_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::CheckSigningBundle(
+            bundle_json,
+            Box::new(move |result| {
+                let (err, status_json) = prepare_result_1!(result, String::new());
+                trace!("indy_check_signing_bundle: status_json: {:?}", status_json);
+                let status_json = ctypes::string_to_cstring(status_json);
+                cb(command_handle, err, status_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_check_signing_bundle: <<< res: {:?}", res);
+
+    res
+}
+
+/// Prepares request built by indy_build_* functions for the opened pool.
+///
+/// Sets PROTOCOL_VERSION of the pool (see protocol_version in indy_open_pool_ledger config)
+/// to the request. It allows to work with pools of different PROTOCOL_VERSION at the same time.
+/// indy_sign_and_submit_request, indy_submit_request and indy_submit_action prepare unsigned requests
+/// automatically, so the function should be called only if the request is signed separately
+/// (see indy_sign_request and indy_multi_sign_request). indy_sign_request_for_pool and
+/// indy_multi_sign_request_for_pool prepare the request before signing.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// request_json: Request data json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request with pool PROTOCOL_VERSION as json.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_prepare_request_for_pool(command_handle: CommandHandle,
+                                            pool_handle: PoolHandle,
+                                            request_json: *const c_char,
+                                            cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                 out_request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prepare_request_for_pool: >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_prepare_request_for_pool: entities >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::PrepareRequestForPool(
+            pool_handle,
+            request_json,
+            Box::new(move |result| {
+                let (err, out_request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_prepare_request_for_pool: out_request_json: {:?}", out_request_json);
+                let out_request_json = ctypes::string_to_cstring(out_request_json);
+                cb(command_handle, err, out_request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prepare_request_for_pool: <<< res: {:?}", res);
+
+    res
+}
+
+/// Signs request message for the opened pool.
+///
+/// Sets PROTOCOL_VERSION of the pool to the request (see indy_prepare_request_for_pool) and signs it
+/// with submitter sign key (see indy_sign_request),
+/// so the signed request is accepted by the pool even if its PROTOCOL_VERSION differs
+/// from the global one (see indy_set_protocol_version).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// request_json: Request data json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Signed request json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_sign_request_for_pool(command_handle: CommandHandle,
+                                         pool_handle: PoolHandle,
+                                         wallet_handle: WalletHandle,
+                                         submitter_did: *const c_char,
+                                         request_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                              signed_request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_sign_request_for_pool: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", pool_handle, wallet_handle, submitter_did, request_json);
+
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_sign_request_for_pool: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", pool_handle, wallet_handle, submitter_did, request_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::SignRequestForPool(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            request_json,
+            Box::new(move |result| {
+                let (err, signed_request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_sign_request_for_pool: signed_request_json: {:?}", signed_request_json);
+                let signed_request_json = ctypes::string_to_cstring(signed_request_json);
+                cb(command_handle, err, signed_request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_sign_request_for_pool: <<< res: {:?}", res);
+
+    res
+}
+
+/// Multi signs request message for the opened pool.
+///
+/// Sets PROTOCOL_VERSION of the pool to the request (see indy_prepare_request_for_pool) and signs it
+/// with submitter sign key (see indy_multi_sign_request),
+/// so the signed request is accepted by the pool even if its PROTOCOL_VERSION differs
+/// from the global one (see indy_set_protocol_version).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// request_json: Request data json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Signed request json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_multi_sign_request_for_pool(command_handle: CommandHandle,
+                                               pool_handle: PoolHandle,
+                                               wallet_handle: WalletHandle,
+                                               submitter_did: *const c_char,
+                                               request_json: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                    signed_request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_multi_sign_request_for_pool: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", pool_handle, wallet_handle, submitter_did, request_json);
+
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_multi_sign_request_for_pool: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", pool_handle, wallet_handle, submitter_did, request_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::MultiSignRequestForPool(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            request_json,
+            Box::new(move |result| {
+                let (err, signed_request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_multi_sign_request_for_pool: signed_request_json: {:?}", signed_request_json);
+                let signed_request_json = ctypes::string_to_cstring(signed_request_json);
+                cb(command_handle, err, signed_request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_multi_sign_request_for_pool: <<< res: {:?}", res);
+
+    res
+}
+
+
+/// Sets request id supplied by a caller to the request built by indy_build_* functions.
+///
+/// By default builders generate unique request ids (see indy_generate_req_id).
+/// A caller can set its own one, for example to resend the same request after a failure,
+/// as the pool doesn't process a request with already known id twice.
+/// Request id must be set before the request is signed.
+/// The id doesn't affect ids generated by libindy, so the caller is responsible for its uniqueness.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// request_json: Request data json.
+/// req_id: Request id to set.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request with the request id as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_set_request_req_id(command_handle: CommandHandle,
+                                      request_json: *const c_char,
+                                      req_id: u64,
+                                      cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                           out_request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_set_request_req_id: >>> request_json: {:?}, req_id: {:?}", request_json, req_id);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_set_request_req_id: entities >>> request_json: {:?}, req_id: {:?}", request_json, req_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::SetRequestReqId(
+            request_json,
+            req_id,
+            Box::new(move |result| {
+                let (err, out_request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_set_request_req_id: out_request_json: {:?}", out_request_json);
+                let out_request_json = ctypes::string_to_cstring(out_request_json);
+                cb(command_handle, err, out_request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_request_req_id: <<< res: {:?}", res);
+
+    res
+}
+
+/// Generates a request id unique within the process.
+///
+/// All indy_build_* functions use the same generator, so code which builds ledger requests itself
+/// (like payment plugins) can use the function to avoid collisions with libindy requests.
+/// Ids are based on the current time in nanoseconds and strictly increase.
+///
+/// #Params
+/// req_id_p: Pointer to store generated request id.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_generate_req_id(req_id_p: *mut u64) -> ErrorCode {
+    trace!("indy_generate_req_id: >>> req_id_p: {:?}", req_id_p);
+
+    if req_id_p.is_null() {
+        return err_msg(ErrorCode::CommonInvalidParam1.into(), "Invalid pointer has been passed").into();
+    }
+
+    let req_id = sequence::get_next_req_id();
+
+    unsafe { *req_id_p = req_id; }
+
+    trace!("indy_generate_req_id: req_id: {:?}", req_id);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_generate_req_id: <<< res: {:?}", res);
+
+    res
+}
 
 /// Builds a request to get a DDO.
 ///
@@ -363,6 +1031,7 @@ pub extern fn indy_build_get_ddo_request(command_handle: CommandHandle,
 ///                             TRUSTEE
 ///                             STEWARD
 ///                             TRUST_ANCHOR
+///                             ENDORSER (same as TRUST_ANCHOR)
 ///                             NETWORK_MONITOR
 ///                             empty string to reset role
 /// cb: Callback that takes command result as parameter.
@@ -431,35 +1100,90 @@ pub extern fn indy_build_nym_request(command_handle: CommandHandle,
 /// #Errors
 /// Common*
 #[no_mangle]
-pub extern fn indy_build_get_nym_request(command_handle: CommandHandle,
-                                         submitter_did: *const c_char,
-                                         target_did: *const c_char,
-                                         cb: Option<extern fn(command_handle_: CommandHandle,
-                                                              err: ErrorCode,
-                                                              request_json: *const c_char)>) -> ErrorCode {
-    trace!("indy_build_get_nym_request: >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+pub extern fn indy_build_get_nym_request(command_handle: CommandHandle,
+                                         submitter_did: *const c_char,
+                                         target_did: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_nym_request: >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+
+    check_useful_opt_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(target_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_build_get_nym_request: entities >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildGetNymRequest(
+            submitter_did,
+            target_did,
+            Box::new(move |result| {
+                let (err, request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_build_get_nym_request: >>> request_json: {:?}", request_json);
+                let request_json = ctypes::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_nym_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parse a GET_NYM response to get NYM data.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_nym_response: response of GET_NYM request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// NYM data json.
+/// {
+///     did: DID as base58-encoded string for 16 or 32 bit DID value.
+///     verkey: verification key as base58-encoded string (null if not set).
+///     role: role associated with the DID (null if not set):
+///         "0" - TRUSTEE
+///         "2" - STEWARD
+///         "101" - ENDORSER
+///         "201" - NETWORK_MONITOR
+///     seqNo: (Optional) sequence number of the NYM transaction.
+///     txnTime: (Optional) the time when the NYM transaction was ordered.
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_nym_response(command_handle: CommandHandle,
+                                          get_nym_response: *const c_char,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               nym_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_nym_response: >>> get_nym_response: {:?}", get_nym_response);
 
-    check_useful_opt_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
-    check_useful_c_str!(target_did, ErrorCode::CommonInvalidParam3);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(get_nym_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
-    trace!("indy_build_get_nym_request: entities >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+    trace!("indy_parse_get_nym_response: entities >>> get_nym_response: {:?}", get_nym_response);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetNymRequest(
-            submitter_did,
-            target_did,
+        .send(Command::Ledger(LedgerCommand::ParseGetNymResponse(
+            get_nym_response,
             Box::new(move |result| {
-                let (err, request_json) = prepare_result_1!(result, String::new());
-                trace!("indy_build_get_nym_request: >>> request_json: {:?}", request_json);
-                let request_json = ctypes::string_to_cstring(request_json);
-                cb(command_handle, err, request_json.as_ptr())
+                let (err, nym_json) = prepare_result_1!(result, String::new());
+                trace!("indy_parse_get_nym_response: nym_json: {:?}", nym_json);
+                let nym_json = ctypes::string_to_cstring(nym_json);
+                cb(command_handle, err, nym_json.as_ptr())
             })
         )));
 
     let res = prepare_result!(result);
 
-    trace!("indy_build_get_nym_request: <<< res: {:?}", res);
+    trace!("indy_parse_get_nym_response: <<< res: {:?}", res);
 
     res
 }
@@ -586,6 +1310,60 @@ pub extern fn indy_build_get_attrib_request(command_handle: CommandHandle,
     res
 }
 
+/// Parse a GET_ATTRIB response to get attribute data.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_attrib_response: response of GET_ATTRIB request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Attribute data json.
+/// {
+///     did: DID the attribute belongs to.
+///     raw|hash|enc: name of the raw attribute, hash or encrypted data the attribute was requested by.
+///     data: attribute value as it's stored on the ledger
+///           (json string for raw attribute, hash for hashed attribute, encrypted data for encrypted attribute).
+///     seqNo: (Optional) sequence number of the ATTRIB transaction.
+///     txnTime: (Optional) the time when the ATTRIB transaction was ordered.
+/// }
+/// The same shape is returned for both formats of ledger replies.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+/// LedgerNotFound - if the attribute isn't found on the ledger.
+#[no_mangle]
+pub extern fn indy_parse_get_attrib_response(command_handle: CommandHandle,
+                                             get_attrib_response: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode,
+                                                                  attrib_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_attrib_response: >>> get_attrib_response: {:?}", get_attrib_response);
+
+    check_useful_c_str!(get_attrib_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_attrib_response: entities >>> get_attrib_response: {:?}", get_attrib_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetAttribResponse(
+            get_attrib_response,
+            Box::new(move |result| {
+                let (err, attrib_json) = prepare_result_1!(result, String::new());
+                trace!("indy_parse_get_attrib_response: attrib_json: {:?}", attrib_json);
+                let attrib_json = ctypes::string_to_cstring(attrib_json);
+                cb(command_handle, err, attrib_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_attrib_response: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a SCHEMA request. Request to add Credential's schema.
 ///
 /// #Params
@@ -912,6 +1690,7 @@ pub extern fn indy_parse_get_cred_def_response(command_handle: CommandHandle,
 ///     alias: string - Node's alias
 ///     blskey: string - (Optional) BLS multi-signature key as base58-encoded string.
 ///     blskey_pop: string - (Optional) BLS key proof of possession as base58-encoded string.
+///                          If set together with blskey, it's checked to correspond to the key.
 ///     client_ip: string - (Optional) Node's client listener IP address.
 ///     client_port: string - (Optional) Node's client listener port.
 ///     node_ip: string - (Optional) The IP address other Nodes use to communicate with this Node.
@@ -962,6 +1741,69 @@ pub extern fn indy_build_node_request(command_handle: CommandHandle,
     res
 }
 
+/// Builds a NODE request with BLS key and its proof of possession taken from the wallet.
+/// The BLS key must be created by indy_create_bls_key.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// target_did: Target Node's DID.  It differs from submitter_did field.
+/// data: Data associated with the Node (see indy_build_node_request).
+///       "blskey" and "blskey_pop" fields are overwritten by values from the wallet.
+/// blskey: BLS key stored in the wallet (as returned by indy_create_bls_key).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_build_node_request_with_bls_key(command_handle: CommandHandle,
+                                                   wallet_handle: WalletHandle,
+                                                   submitter_did: *const c_char,
+                                                   target_did: *const c_char,
+                                                   data: *const c_char,
+                                                   blskey: *const c_char,
+                                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                        err: ErrorCode,
+                                                                        request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_node_request_with_bls_key: >>> wallet_handle: {:?}, submitter_did: {:?}, target_did: {:?}, data: {:?}, blskey: {:?}",
+           wallet_handle, submitter_did, target_did, data, blskey);
+
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(target_did, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(data, ErrorCode::CommonInvalidParam5, NodeOperationData);
+    check_useful_c_str!(blskey, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_build_node_request_with_bls_key: entities >>> wallet_handle: {:?}, submitter_did: {:?}, target_did: {:?}, data: {:?}, blskey: {:?}",
+           wallet_handle, submitter_did, target_did, data, blskey);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildNodeRequestWithBlsKey(
+            wallet_handle,
+            submitter_did,
+            target_did,
+            data,
+            blskey,
+            Box::new(move |result| {
+                let (err, request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_build_node_request_with_bls_key: request_json: {:?}", request_json);
+                let request_json = ctypes::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_node_request_with_bls_key: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a GET_VALIDATOR_INFO request.
 ///
 /// #Params
@@ -995,8 +1837,143 @@ pub extern fn indy_build_get_validator_info_request(command_handle: CommandHandl
     prepare_result!(result)
 }
 
+/// Parse a GET_VALIDATOR_INFO response received from indy_submit_action.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_validator_info_response: response of GET_VALIDATOR_INFO action (replies by node alias).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Validator info json.
+/// {
+///     nodes: { <node alias>: validator info returned by the node }
+///     errors: { <node alias>: reason why the node hasn't returned validator info (for example "timeout") }
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_validator_info_response(command_handle: CommandHandle,
+                                                     get_validator_info_response: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                          err: ErrorCode,
+                                                                          validator_info_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_validator_info_response: >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+    check_useful_c_str!(get_validator_info_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_validator_info_response: entities >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetValidatorInfoResponse(
+            get_validator_info_response,
+            Box::new(move |result| {
+                let (err, validator_info_json) = prepare_result_1!(result, String::new());
+                trace!("indy_parse_get_validator_info_response: validator_info_json: {:?}", validator_info_json);
+                let validator_info_json = ctypes::string_to_cstring(validator_info_json);
+                cb(command_handle, err, validator_info_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_validator_info_response: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets structured status of the validator pool.
+///
+/// Sends GET_VALIDATOR_INFO request signed by the submitter to all nodes of the pool ledger
+/// and combines their replies into a report about every node and the whole pool consensus.
+/// Note that only DIDs with TRUSTEE, STEWARD or NETWORK_MONITOR role are allowed to get validator info.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the request sender.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Pool status json:
+/// {
+///     "nodes": {
+///         <node alias>: {
+///             "address": Option<string> - client address of the node from the pool ledger,
+///             "reachable": bool - whether the node has replied in time,
+///             "error": Option<string> - reason why the node hasn't returned validator info,
+///             "mode": Option<string> - node mode (participating, syncing, discovering ...),
+///             "softwareVersion": Option<string> - version of indy-node,
+///             "lastOrdered3pc": Option<[view_no, pp_seq_no]> - last batch ordered by the master replica,
+///             "catchupStatus": Option<{<ledger id>: string}> - catchup status by ledger id,
+///             "viewNo": Option<int> - current view number,
+///             "viewChangeInProgress": Option<bool>,
+///             "primary": Option<string> - alias of the master primary the node knows about,
+///         },
+///         ...
+///     },
+///     "consensus": {
+///         "totalNodes": int - number of validators in the pool ledger (n),
+///         "f": int - number of faulty nodes the pool can tolerate,
+///         "requiredNodes": int - number of nodes required to reach consensus (n - f),
+///         "reachableNodes": int - number of pool ledger nodes that have replied,
+///         "fTolerated": bool - whether enough nodes are reachable to reach consensus,
+///         "viewNo": Option<int> - view number reported by the most of nodes,
+///         "primary": Option<string> - master primary reported by the most of nodes,
+///         "primaryAgreed": bool - whether all nodes agree on the view and the primary and no view change is in progress,
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_get_pool_status(command_handle: CommandHandle,
+                                   pool_handle: PoolHandle,
+                                   wallet_handle: WalletHandle,
+                                   submitter_did: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        pool_status_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_pool_status: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}", pool_handle, wallet_handle, submitter_did);
+
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_get_pool_status: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}", pool_handle, wallet_handle, submitter_did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::GetPoolStatus(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            Box::new(move |result| {
+                let (err, pool_status_json) = prepare_result_1!(result, String::new());
+                trace!("indy_get_pool_status: pool_status_json: {:?}", pool_status_json);
+                let pool_status_json = ctypes::string_to_cstring(pool_status_json);
+                cb(command_handle, err, pool_status_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_pool_status: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a GET_TXN request. Request to get any transaction by its seq_no.
 ///
+/// A reply of a single node is accepted if the returned audit path proves that the transaction is
+/// included into the ledger with the root hash signed by the pool (BLS multi-signature).
+/// Otherwise replies of f + 1 nodes are required.
+///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
 /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
@@ -1049,6 +2026,57 @@ pub extern fn indy_build_get_txn_request(command_handle: CommandHandle,
     res
 }
 
+/// Parse a GET_TXN response to get the transaction in the same format for all ledger transaction versions.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_txn_response: response of GET_TXN request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Transaction json.
+/// {
+///     seqNo: sequence number of the transaction.
+///     txnTime: (Optional) the time when the transaction was ordered.
+///     type: type of the transaction.
+///     from: (Optional) DID of the transaction author.
+///     data: transaction specific data.
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_txn_response(command_handle: CommandHandle,
+                                          get_txn_response: *const c_char,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               txn_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_txn_response: >>> get_txn_response: {:?}", get_txn_response);
+
+    check_useful_c_str!(get_txn_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_txn_response: entities >>> get_txn_response: {:?}", get_txn_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetTxnResponse(
+            get_txn_response,
+            Box::new(move |result| {
+                let (err, txn_json) = prepare_result_1!(result, String::new());
+                trace!("indy_parse_get_txn_response: txn_json: {:?}", txn_json);
+                let txn_json = ctypes::string_to_cstring(txn_json);
+                cb(command_handle, err, txn_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_txn_response: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
 ///
 /// #Params
@@ -1723,7 +2751,8 @@ pub type CustomFree = extern fn(data: *const c_char) -> ErrorCode;
 ///
 /// # params
 /// command_handle: command handle to map callback to caller context.
-/// txn_type: type of transaction to apply `parse` callback.
+/// txn_type: type of transaction to apply `parse` callback (code or name of custom transaction type registered
+///           by indy_register_custom_txn_type).
 /// parse: required callback to parse reply for state proof.
 /// free: required callback to deallocate memory.
 /// cb: Callback that takes command result as parameter.
@@ -1769,6 +2798,116 @@ pub extern fn indy_register_transaction_parser_for_sp(command_handle: CommandHan
     res
 }
 
+/// Registers custom transaction type provided by a ledger plugin.
+///
+/// Registered type can be used by its name in indy_build_custom_request, indy_build_auth_rule_request,
+/// indy_build_get_auth_rule_request and indy_register_transaction_parser_for_sp.
+/// Read requests of the type with registered state proof parser are sent to a single node,
+/// write requests always require consensus of the pool.
+/// A type can be registered again with the same name and code to update its kind and schema,
+/// but neither its name nor its code can be reused for other type.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// txn_type_json: transaction type description:
+/// {
+///     "name": string - transaction type name (must not coincide with default ones),
+///     "code": string - numeric transaction type code used in requests to the ledger,
+///     "kind": string - "read" or "write",
+///     "schema": Option<object> - schema of request `operation` fields:
+///         {
+///             <field name>: {
+///                 "type": "string" | "number" | "boolean" | "object" | "array" | "any",
+///                 "optional": Option<bool> - false by default
+///             },
+///             ...
+///         }
+///         Fields not described in the schema are rejected. `operation` isn't validated if the schema isn't set.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_register_custom_txn_type(command_handle: CommandHandle,
+                                            txn_type_json: *const c_char,
+                                            cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_custom_txn_type: >>> txn_type_json: {:?}", txn_type_json);
+
+    check_useful_json!(txn_type_json, ErrorCode::CommonInvalidParam2, CustomTxnType);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_register_custom_txn_type: entities >>> txn_type_json: {:?}", txn_type_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::RegisterCustomTxnType(
+            txn_type_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_custom_txn_type: err: {:?}", err);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_custom_txn_type: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a request of custom transaction type (see indy_register_custom_txn_type).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the request sender (required for write transactions).
+///                If not provided then default Libindy DID will be used for read request.
+/// txn_type: name or code of registered custom transaction type.
+/// operation_json: request operation fields (`type` is set automatically) validated against the transaction type schema.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_custom_request(command_handle: CommandHandle,
+                                        submitter_did: *const c_char,
+                                        txn_type: *const c_char,
+                                        operation_json: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_custom_request: >>> submitter_did: {:?}, txn_type: {:?}, operation_json: {:?}", submitter_did, txn_type, operation_json);
+
+    check_useful_opt_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(operation_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_build_custom_request: entities >>> submitter_did: {:?}, txn_type: {:?}, operation_json: {:?}", submitter_did, txn_type, operation_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildCustomRequest(
+            submitter_did,
+            txn_type,
+            operation_json,
+            Box::new(move |result| {
+                let (err, request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_build_custom_request: request_json: {:?}", request_json);
+                let request_json = ctypes::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_custom_request: <<< res: {:?}", res);
+
+    res
+}
+
 /// Parse transaction response to fetch metadata.
 /// The important use case for this method is validation of Node's response freshens.
 ///
@@ -257,6 +257,8 @@ pub enum ErrorCode
 ///     "collect_backtrace": Optional<bool> - whether errors backtrace should be collected.
 ///         Capturing of backtrace can affect library performance.
 ///         NOTE: must be set before invocation of any other API functions.
+///     "non_revoked_interval_tolerance": Optional<int> - number of seconds revocation registry timestamp used in proof
+///         may lie outside of requested `non_revoked` interval during proof verification. (0 by default)
 /// }
 ///
 /// #Errors
@@ -1,11 +1,14 @@
 extern crate libc;
 
-use api::{ErrorCode, CommandHandle, WalletHandle};
+use api::{ErrorCode, CommandHandle, SearchHandle, WalletHandle};
 use commands::{Command, CommandExecutor};
 use commands::pairwise::PairwiseCommand;
+use domain::wallet::Tags;
 use errors::prelude::*;
 use utils::ctypes;
 
+use serde_json;
+
 use self::libc::c_char;
 
 
@@ -246,3 +249,257 @@ pub  extern fn indy_set_pairwise_metadata(command_handle: CommandHandle,
 
     res
 }
+
+/// Replaces tags of pairwise associated with Did.
+///
+/// Tags are stored with pairwise record and can be used in indy_search_pairwise queries.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// their_did: encoded Did
+/// tags_json: the record tags used for search and storing meta information as json:
+///   {
+///     "tagName1": <str>, // string tag (will be stored encrypted)
+///     "~tagName2": <str>, // string tag (will be stored un-encrypted)
+///   }
+///   Note that "my_did" tag name is reserved and is always set to my DID of the pairwise.
+///   Empty json removes all tags.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_set_pairwise_tags(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     their_did: *const c_char,
+                                     tags_json: *const c_char,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_pairwise_tags: >>> wallet_handle: {:?}, their_did: {:?}, tags_json: {:?}", wallet_handle, their_did, tags_json);
+
+    check_useful_c_str!(their_did, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(tags_json, ErrorCode::CommonInvalidParam4, Tags);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_set_pairwise_tags: entities >>> wallet_handle: {:?}, their_did: {:?}, tags_json: {:?}", wallet_handle, their_did, tags_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::SetPairwiseTags(
+            wallet_handle,
+            their_did,
+            tags_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_set_pairwise_tags:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_pairwise_tags: <<< res: {:?}", res);
+
+    res
+}
+
+/// Deletes pairwise associated with Did.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// their_did: encoded Did
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_delete_pairwise(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   their_did: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_delete_pairwise: >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
+
+    check_useful_c_str!(their_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_delete_pairwise: entities >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::DeletePairwise(
+            wallet_handle,
+            their_did,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_delete_pairwise:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_delete_pairwise: <<< res: {:?}", res);
+
+    res
+}
+
+/// Search for saved pairwise.
+///
+/// Instead of immediately returning of fetched records
+/// this call returns search_handle that can be used later
+/// to fetch records by small batches (with indy_fetch_pairwise_search).
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// query_json: Wql query filter for pairwise searching based on tags:
+///     {
+///         "my_did": string,
+///         <tag name set by indy_set_pairwise_tags>: string,
+///     }
+/// where query: indy-sdk/doc/design/011-wallet-query-language/README.md
+/// Note that pairwise created before tags support get "my_did" tag on the first search in the opened wallet.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// search_handle: Search handle that can be used later to fetch records by small batches (with indy_fetch_pairwise_search)
+/// total_count: Total count of records
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_search_pairwise(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   query_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        search_handle: SearchHandle,
+                                                        total_count: usize)>) -> ErrorCode {
+    trace!("indy_search_pairwise: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_search_pairwise: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::SearchPairwise(
+            wallet_handle,
+            query_json,
+            Box::new(move |result| {
+                let (err, handle, total_count) = prepare_result_2!(result, 0, 0);
+                trace!("indy_search_pairwise: handle: {:?}, total_count: {:?}", handle, total_count);
+                cb(command_handle, err, handle, total_count)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_search_pairwise: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetch next pairwise for search.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// search_handle: Search handle (created by indy_search_pairwise)
+/// count: Count of records to fetch
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// list_pairwise_json: List of pairwise:
+///     [{
+///         "my_did": string,
+///         "their_did": string,
+///         "metadata": Optional<string>,
+///         "tags": Optional<{"tagName": string}>
+///     }]
+/// NOTE: The list of length less than the requested count means search iterator is completed.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_fetch_pairwise_search(command_handle: CommandHandle,
+                                         search_handle: SearchHandle,
+                                         count: usize,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              list_pairwise_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_fetch_pairwise_search: >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_fetch_pairwise_search: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::FetchPairwiseSearch(
+            search_handle,
+            count,
+            Box::new(move |result| {
+                let (err, list_pairwise_json) = prepare_result_1!(result, String::new());
+                trace!("indy_fetch_pairwise_search: list_pairwise_json: {:?}", list_pairwise_json);
+                let list_pairwise_json = ctypes::string_to_cstring(list_pairwise_json);
+                cb(command_handle, err, list_pairwise_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_fetch_pairwise_search: <<< res: {:?}", res);
+
+    res
+}
+
+/// Close pairwise search (make search handle invalid)
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// search_handle: Search handle (created by indy_search_pairwise)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_close_pairwise_search(command_handle: CommandHandle,
+                                         search_handle: SearchHandle,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_close_pairwise_search: >>> search_handle: {:?}", search_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_close_pairwise_search: entities >>> search_handle: {:?}", search_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::ClosePairwiseSearch(
+            search_handle,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_close_pairwise_search:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_close_pairwise_search: <<< res: {:?}", res);
+
+    res
+}
@@ -73,6 +73,9 @@ pub extern fn indy_create_pool_ledger_config(command_handle: CommandHandle,
 ///     "preordered_nodes": array<string> -  (optional), names of nodes which will have a priority during request sending:
 ///         ["name_of_1st_prior_node",  "name_of_2nd_prior_node", .... ]
 ///         Note: Not specified nodes will be placed in a random way.
+///     "protocol_version": int (optional), PROTOCOL_VERSION of the pool (1 or 2).
+///         If not set, then the version matching the format of the pool genesis transactions will be used.
+///         Requests sent to the pool get this version (see indy_prepare_request_for_pool).
 /// }
 ///
 /// #Returns
@@ -272,6 +275,11 @@ pub extern fn indy_delete_pool_ledger_config(command_handle: CommandHandle,
 ///
 /// There is a global property PROTOCOL_VERSION that used in every request to the pool and
 /// specified version of Indy Node which Libindy works.
+/// Each opened pool has its own version (see indy_open_pool_ledger) and unsigned requests are
+/// switched to the version of the pool they are submitted to. This version is used by the
+/// request builders, for the format of credential definition ids and for the pools with
+/// empty genesis transactions. Use indy_sign_request_for_pool and indy_multi_sign_request_for_pool
+/// to sign requests for a pool of another version.
 ///
 /// By default PROTOCOL_VERSION=1.
 ///
@@ -8,6 +8,7 @@ use indy_crypto::cl::{
     Witness,
 };
 use indy_crypto::cl::{CredentialKeyCorrectnessProof, CredentialPrivateKey};
+use named_type::NamedType;
 
 use commands::{Command, CommandExecutor};
 use commands::anoncreds::AnoncredsCommand;
@@ -23,6 +24,13 @@ use domain::anoncreds::credential_definition::{
 };
 use domain::anoncreds::credential_offer::CredentialOffer;
 use domain::anoncreds::credential_request::CredentialRequest;
+use domain::anoncreds::issued_credential::{
+    CreateCredentialOptions,
+    IssuedCredential,
+    IssuedCredentialInfo,
+    IssuedCredentialValuesKey,
+    ISSUED_CREDENTIAL_VALUES_KEY_ID,
+};
 use domain::anoncreds::revocation_registry::{
     RevocationRegistry,
     RevocationRegistryV1,
@@ -42,6 +50,8 @@ use domain::anoncreds::revocation_registry_delta::{
     RevocationRegistryDeltaV1,
 };
 use domain::anoncreds::schema::{AttributeNames, Schema, SchemaV1, MAX_ATTRIBUTES_COUNT};
+use domain::anoncreds::tracked_nonce::CREDENTIAL_OFFER_NONCE;
+use domain::ledger::request::ProtocolVersion;
 use domain::wallet::Tags;
 use errors::prelude::*;
 use services::anoncreds::AnoncredsService;
@@ -49,8 +59,11 @@ use services::anoncreds::helpers::parse_cred_rev_id;
 use services::blob_storage::BlobStorageService;
 use services::crypto::CryptoService;
 use services::pool::PoolService;
-use services::wallet::{RecordOptions, WalletService};
+use services::wallet::{RecordOptions, SearchOptions, WalletSearch, WalletService};
+use utils::crypto::{base58, hmacsha256};
+use utils::sequence;
 
+use super::nonces::{check_nonce_unused, consume_nonce, track_nonce};
 use super::tails::{SDKTailsAccessor, store_tails_from_generator};
 use api::{WalletHandle, CallbackHandle};
 
@@ -97,6 +110,7 @@ pub enum IssuerCommand {
     CreateCredentialOffer(
         WalletHandle,
         String, // credential definition id
+        Option<u64>, // offer nonce expiry time if nonce tracking is enabled
         Box<Fn(IndyResult<String>) + Send>),
     CreateCredential(
         WalletHandle,
@@ -105,6 +119,7 @@ pub enum IssuerCommand {
         HashMap<String, AttributeValues>, // credential values
         Option<String>, // revocation registry id
         Option<i32>, // blob storage reader config handle
+        CreateCredentialOptions, // options
         Box<Fn(IndyResult<(String, Option<String>, Option<String>)>) + Send>),
     RevokeCredential(
         WalletHandle,
@@ -122,6 +137,17 @@ pub enum IssuerCommand {
         RevocationRegistryDelta, //revocation registry delta
         RevocationRegistryDelta, //other revocation registry delta
         Box<Fn(IndyResult<String>) + Send>),
+    SearchIssuedCredentials(
+        WalletHandle,
+        Option<String>, // query json
+        Box<Fn(IndyResult<(i32, usize)>) + Send>),
+    FetchIssuedCredentials(
+        i32, // search handle
+        usize, // count
+        Box<Fn(IndyResult<String>) + Send>),
+    CloseIssuedCredentialsSearch(
+        i32, // search handle
+        Box<Fn(IndyResult<()>) + Send>),
 }
 
 pub struct IssuerCommandExecutor {
@@ -131,6 +157,7 @@ pub struct IssuerCommandExecutor {
     pub wallet_service: Rc<WalletService>,
     pub crypto_service: Rc<CryptoService>,
     pending_callbacks: RefCell<HashMap<i32, Box<Fn(IndyResult<(String, String)>) + Send>>>,
+    issued_credentials_searches: RefCell<HashMap<i32, Box<WalletSearch>>>,
 }
 
 impl IssuerCommandExecutor {
@@ -146,6 +173,7 @@ impl IssuerCommandExecutor {
             wallet_service,
             crypto_service,
             pending_callbacks: RefCell::new(HashMap::new()),
+            issued_credentials_searches: RefCell::new(HashMap::new()),
         }
     }
 
@@ -178,13 +206,13 @@ impl IssuerCommandExecutor {
                                                              &config,
                                                              tails_writer_handle));
             }
-            IssuerCommand::CreateCredentialOffer(wallet_handle, cred_def_id, cb) => {
+            IssuerCommand::CreateCredentialOffer(wallet_handle, cred_def_id, nonce_expires_at, cb) => {
                 info!(target: "issuer_command_executor", "CreateCredentialOffer command received");
-                cb(self.create_credential_offer(wallet_handle, &cred_def_id));
+                cb(self.create_credential_offer(wallet_handle, &cred_def_id, nonce_expires_at));
             }
-            IssuerCommand::CreateCredential(wallet_handle, cred_offer, cred_req, cred_values, rev_reg_id, blob_storage_reader_handle, cb) => {
+            IssuerCommand::CreateCredential(wallet_handle, cred_offer, cred_req, cred_values, rev_reg_id, blob_storage_reader_handle, options, cb) => {
                 info!(target: "issuer_command_executor", "CreateCredential command received");
-                cb(self.new_credential(wallet_handle, &cred_offer, &cred_req, &cred_values, rev_reg_id.as_ref().map(String::as_str), blob_storage_reader_handle));
+                cb(self.new_credential(wallet_handle, &cred_offer, &cred_req, &cred_values, rev_reg_id.as_ref().map(String::as_str), blob_storage_reader_handle, &options));
             }
             IssuerCommand::RevokeCredential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id, cb) => {
                 info!(target: "issuer_command_executor", "RevokeCredential command received");
@@ -199,6 +227,18 @@ impl IssuerCommandExecutor {
                 cb(self.merge_revocation_registry_deltas(&mut RevocationRegistryDeltaV1::from(rev_reg_delta),
                                                          &RevocationRegistryDeltaV1::from(other_rev_reg_delta)));
             }
+            IssuerCommand::SearchIssuedCredentials(wallet_handle, query_json, cb) => {
+                info!(target: "issuer_command_executor", "SearchIssuedCredentials command received");
+                cb(self.search_issued_credentials(wallet_handle, query_json.as_ref().map(String::as_str)));
+            }
+            IssuerCommand::FetchIssuedCredentials(search_handle, count, cb) => {
+                info!(target: "issuer_command_executor", "FetchIssuedCredentials command received");
+                cb(self.fetch_issued_credentials(search_handle, count));
+            }
+            IssuerCommand::CloseIssuedCredentialsSearch(search_handle, cb) => {
+                info!(target: "issuer_command_executor", "CloseIssuedCredentialsSearch command received");
+                cb(self.close_issued_credentials_search(search_handle));
+            }
         };
     }
 
@@ -328,7 +368,7 @@ impl IssuerCommandExecutor {
 
         let schema_id = schema.seq_no.map(|n| n.to_string()).unwrap_or(schema.id.clone());
 
-        let cred_def_id = CredentialDefinition::cred_def_id(issuer_did, &schema_id, &signature_type.to_str(), tag);
+        let cred_def_id = CredentialDefinition::cred_def_id(issuer_did, &schema_id, &signature_type.to_str(), tag, ProtocolVersion::get());
 
         if self.wallet_service.record_exists::<CredentialDefinition>(wallet_handle, &cred_def_id)? {
             return Err(err_msg(IndyErrorKind::CredDefAlreadyExists, format!("CredentialDefinition for cred_def_id: {:?} already exists", cred_def_id)));
@@ -468,8 +508,9 @@ impl IssuerCommandExecutor {
 
     fn create_credential_offer(&self,
                                wallet_handle: WalletHandle,
-                               cred_def_id: &str) -> IndyResult<String> {
-        debug!("create_credential_offer >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+                               cred_def_id: &str,
+                               nonce_expires_at: Option<u64>) -> IndyResult<String> {
+        debug!("create_credential_offer >>> wallet_handle: {:?}, cred_def_id: {:?}, nonce_expires_at: {:?}", wallet_handle, cred_def_id, nonce_expires_at);
 
         let cred_def_correctness_proof: CredentialDefinitionCorrectnessProof =
             self.wallet_service.get_indy_object(wallet_handle, &cred_def_id, &RecordOptions::id_value())?;
@@ -485,6 +526,10 @@ impl IssuerCommandExecutor {
             nonce,
         };
 
+        if let Some(expires_at) = nonce_expires_at {
+            track_nonce(&self.wallet_service, wallet_handle, CREDENTIAL_OFFER_NONCE, &credential_offer.nonce.to_dec()?, expires_at)?;
+        }
+
         let credential_offer_json = serde_json::to_string(&credential_offer)
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialOffer")?;
 
@@ -499,9 +544,19 @@ impl IssuerCommandExecutor {
                       cred_request: &CredentialRequest,
                       cred_values: &HashMap<String, AttributeValues>,
                       rev_reg_id: Option<&str>,
-                      blob_storage_reader_handle: Option<i32>) -> IndyResult<(String, Option<String>, Option<String>)> {
-        debug!("new_credential >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}",
-               wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values), rev_reg_id, blob_storage_reader_handle);
+                      blob_storage_reader_handle: Option<i32>,
+                      options: &CreateCredentialOptions) -> IndyResult<(String, Option<String>, Option<String>)> {
+        debug!("new_credential >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}, \
+               options: {:?}",
+               wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values), rev_reg_id, blob_storage_reader_handle, options);
+
+        let offer_nonce = if options.track_offer_nonce {
+            let offer_nonce = cred_offer.nonce.to_dec()?;
+            check_nonce_unused(&self.wallet_service, wallet_handle, CREDENTIAL_OFFER_NONCE, &offer_nonce, IndyErrorKind::InvalidStructure)?;
+            Some(offer_nonce)
+        } else {
+            None
+        };
 
         let cred_def: CredentialDefinitionV1 =
             CredentialDefinitionV1::from(
@@ -607,8 +662,16 @@ impl IssuerCommandExecutor {
             self.wallet_service.update_indy_object(wallet_handle, &r_reg_id, &r_reg_info)?;
         };
 
+        if let Some(offer_nonce) = offer_nonce {
+            consume_nonce(&self.wallet_service, wallet_handle, CREDENTIAL_OFFER_NONCE, &offer_nonce)?;
+        }
+
         let cred_rev_id = rev_reg_info.map(|r_reg_info| r_reg_info.curr_id.to_string());
 
+        if options.store_issued_credential {
+            self._wallet_store_issued_credential(wallet_handle, &credential.schema_id, cred_request, cred_values, rev_reg_id, cred_rev_id.as_ref().map(String::as_str))?;
+        }
+
         debug!("new_credential <<< cred_json: {:?}, cred_rev_id: {:?}, rev_reg_delta_json: {:?}", secret!(&cred_json), secret!(&cred_rev_id), rev_reg_delta_json);
 
         Ok((cred_json, cred_rev_id, rev_reg_delta_json))
@@ -668,11 +731,152 @@ impl IssuerCommandExecutor {
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id, &rev_reg)?;
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id, &rev_reg_info)?;
 
+        self._wallet_set_issued_credential_revoked(wallet_handle, rev_reg_id, &cred_revoc_id.to_string(), true)?;
+
         debug!("revoke_credential <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
 
         Ok(rev_reg_delta_json)
     }
 
+    fn search_issued_credentials(&self,
+                                 wallet_handle: WalletHandle,
+                                 query_json: Option<&str>) -> IndyResult<(i32, usize)> {
+        debug!("search_issued_credentials >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+        let issued_credentials_search =
+            self.wallet_service.search_indy_records::<IssuedCredential>(wallet_handle, query_json.unwrap_or("{}"), &SearchOptions::id_value())?;
+
+        let total_count = issued_credentials_search.get_total_count()?.unwrap_or(0);
+
+        let handle = sequence::get_next_id();
+
+        self.issued_credentials_searches.borrow_mut().insert(handle, Box::new(issued_credentials_search));
+
+        let res = (handle, total_count);
+
+        debug!("search_issued_credentials <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn fetch_issued_credentials(&self,
+                                search_handle: i32,
+                                count: usize) -> IndyResult<String> {
+        trace!("fetch_issued_credentials >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+        let mut searches = self.issued_credentials_searches.borrow_mut();
+        let search = searches.get_mut(&search_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown IssuedCredentialsSearch handle: {}", search_handle)))?;
+
+        let mut issued_credentials_info: Vec<IssuedCredentialInfo> = Vec::new();
+
+        for _ in 0..count {
+            match search.fetch_next_record()? {
+                Some(record) => {
+                    let issued_credential: IssuedCredential = serde_json::from_str(
+                        record.get_value().ok_or(err_msg(IndyErrorKind::InvalidStructure, "IssuedCredential not found for id"))?)
+                        .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize IssuedCredential")?;
+
+                    issued_credentials_info.push(issued_credential.to_info(record.get_id().to_string()));
+                }
+                None => break
+            }
+        }
+
+        let issued_credentials_info_json = serde_json::to_string(&issued_credentials_info)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize list of IssuedCredentialInfo")?;
+
+        trace!("fetch_issued_credentials <<< issued_credentials_info_json: {:?}", issued_credentials_info_json);
+
+        Ok(issued_credentials_info_json)
+    }
+
+    fn close_issued_credentials_search(&self, search_handle: i32) -> IndyResult<()> {
+        trace!("close_issued_credentials_search >>> search_handle: {:?}", search_handle);
+
+        let res = match self.issued_credentials_searches.borrow_mut().remove(&search_handle) {
+            Some(_) => Ok(()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown IssuedCredentialsSearch handle: {}", search_handle)))
+        }?;
+
+        trace!("close_issued_credentials_search <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _wallet_store_issued_credential(&self,
+                                       wallet_handle: WalletHandle,
+                                       schema_id: &str,
+                                       cred_request: &CredentialRequest,
+                                       cred_values: &HashMap<String, AttributeValues>,
+                                       rev_reg_id: Option<&str>,
+                                       cred_rev_id: Option<&str>) -> IndyResult<()> {
+        let issued_credential = IssuedCredential {
+            schema_id: schema_id.to_string(),
+            cred_def_id: cred_request.cred_def_id.clone(),
+            rev_reg_id: rev_reg_id.map(String::from),
+            cred_rev_id: cred_rev_id.map(String::from),
+            values_hash: self.anoncreds_service.issuer.hash_credential_values(cred_values, &self._wallet_get_issued_credential_values_key(wallet_handle)?)?,
+            issued_at: time::get_time().sec as u64,
+            holder_did: cred_request.prover_did.clone(),
+            revoked: false,
+        };
+
+        let tags = self.anoncreds_service.issuer.build_issued_credential_tags(&issued_credential);
+
+        self.wallet_service.add_indy_object(wallet_handle, &uuid::Uuid::new_v4().to_string(), &issued_credential, &tags)?;
+
+        Ok(())
+    }
+
+    fn _wallet_get_issued_credential_values_key(&self, wallet_handle: WalletHandle) -> IndyResult<hmacsha256::Key> {
+        let values_key = self.wallet_service.get_indy_opt_object::<IssuedCredentialValuesKey>(wallet_handle, ISSUED_CREDENTIAL_VALUES_KEY_ID, &RecordOptions::id_value())?;
+
+        match values_key {
+            Some(values_key) => hmacsha256::Key::from_slice(&base58::decode(&values_key.value)?),
+            None => {
+                let key = hmacsha256::gen_key();
+
+                let values_key = IssuedCredentialValuesKey { value: base58::encode(&key[..]) };
+
+                self.wallet_service.add_indy_object(wallet_handle, ISSUED_CREDENTIAL_VALUES_KEY_ID, &values_key, &Tags::new())?;
+
+                Ok(key)
+            }
+        }
+    }
+
+    fn _wallet_set_issued_credential_revoked(&self,
+                                             wallet_handle: WalletHandle,
+                                             rev_reg_id: &str,
+                                             cred_rev_id: &str,
+                                             revoked: bool) -> IndyResult<()> {
+        let query_json = json!({"rev_reg_id": rev_reg_id, "cred_rev_id": cred_rev_id}).to_string();
+
+        let mut search = self.wallet_service.search_indy_records::<IssuedCredential>(wallet_handle, &query_json, &SearchOptions::id_value())?;
+
+        let mut issued_credentials: Vec<(String, IssuedCredential)> = Vec::new();
+
+        while let Some(record) = search.fetch_next_record()? {
+            let issued_credential: IssuedCredential = serde_json::from_str(
+                record.get_value().ok_or(err_msg(IndyErrorKind::InvalidStructure, "IssuedCredential not found for id"))?)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize IssuedCredential")?;
+
+            issued_credentials.push((record.get_id().to_string(), issued_credential));
+        }
+
+        for (id, mut issued_credential) in issued_credentials {
+            issued_credential.revoked = revoked;
+
+            let tags = self.anoncreds_service.issuer.build_issued_credential_tags(&issued_credential);
+
+            self.wallet_service.update_indy_object(wallet_handle, &id, &issued_credential)?;
+            self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix(IssuedCredential::short_type_name()), &id, &tags)?;
+        }
+
+        Ok(())
+    }
+
     fn _recovery_credential(&self,
                             wallet_handle: WalletHandle,
                             blob_storage_reader_handle: i32,
@@ -727,6 +931,8 @@ impl IssuerCommandExecutor {
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id, &rev_reg)?;
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id, &rev_reg_info)?;
 
+        self._wallet_set_issued_credential_revoked(wallet_handle, rev_reg_id, &cred_revoc_id.to_string(), false)?;
+
         debug!("recovery_credential <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
 
         Ok(rev_reg_delta_json)
@@ -773,3 +979,107 @@ impl IssuerCommandExecutor {
         self.wallet_service.get_indy_object(wallet_handle, &key, &RecordOptions::id_value())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use domain::wallet::{Config, Credentials, KeyDerivationMethod};
+    use services::wallet::KeyDerivationData;
+
+    const REV_REG_ID: &str = "NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:TAG:CL_ACCUM:TAG_1";
+    const CRED_REV_ID: &str = "1";
+
+    #[test]
+    fn issued_credential_revoked_flag_is_cleared_on_recovery() {
+        let config = _config("issued_credential_revoked_flag_recovery");
+        let wallet_service = Rc::new(WalletService::new());
+        let wallet_handle = _create_and_open_wallet(&wallet_service, &config);
+
+        let executor = IssuerCommandExecutor::new(Rc::new(AnoncredsService::new()),
+                                                  Rc::new(PoolService::new()),
+                                                  Rc::new(BlobStorageService::new()),
+                                                  wallet_service.clone(),
+                                                  Rc::new(CryptoService::new()));
+
+        let issued_credential = IssuedCredential {
+            schema_id: "NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string(),
+            cred_def_id: "NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:TAG".to_string(),
+            rev_reg_id: Some(REV_REG_ID.to_string()),
+            cred_rev_id: Some(CRED_REV_ID.to_string()),
+            values_hash: "hash".to_string(),
+            issued_at: 1_500_000_000,
+            holder_did: "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW".to_string(),
+            revoked: false,
+        };
+        let tags = executor.anoncreds_service.issuer.build_issued_credential_tags(&issued_credential);
+        wallet_service.add_indy_object(wallet_handle, "issued_credential_id", &issued_credential, &tags).unwrap();
+
+        // revoke_credential
+        executor._wallet_set_issued_credential_revoked(wallet_handle, REV_REG_ID, CRED_REV_ID, true).unwrap();
+        assert_eq!(vec![true], _search_revoked_flags(&executor, wallet_handle, r#"{"revoked": "true"}"#));
+
+        // recovery_credential
+        executor._wallet_set_issued_credential_revoked(wallet_handle, REV_REG_ID, CRED_REV_ID, false).unwrap();
+        assert!(_search_revoked_flags(&executor, wallet_handle, r#"{"revoked": "true"}"#).is_empty());
+        assert_eq!(vec![false], _search_revoked_flags(&executor, wallet_handle, r#"{"revoked": "false"}"#));
+
+        _close_and_delete_wallet(&wallet_service, wallet_handle, &config);
+    }
+
+    fn _search_revoked_flags(executor: &IssuerCommandExecutor, wallet_handle: WalletHandle, query_json: &str) -> Vec<bool> {
+        let (search_handle, total_count) = executor.search_issued_credentials(wallet_handle, Some(query_json)).unwrap();
+
+        let issued_credentials: Vec<IssuedCredentialInfo> =
+            serde_json::from_str(&executor.fetch_issued_credentials(search_handle, total_count + 1).unwrap()).unwrap();
+
+        executor.close_issued_credentials_search(search_handle).unwrap();
+
+        issued_credentials.into_iter().map(|issued_credential| issued_credential.revoked).collect()
+    }
+
+    fn _config(id: &str) -> Config {
+        Config {
+            id: id.to_string(),
+            storage_type: None,
+            storage_config: None,
+        }
+    }
+
+    fn _credentials() -> Credentials {
+        Credentials {
+            key: "6nxtSiXFvBd593Y2DCed2dYvRY1PGK9WMtxCBjLzKgbw".to_string(),
+            rekey: None,
+            storage_credentials: None,
+            key_derivation_method: KeyDerivationMethod::RAW,
+            rekey_derivation_method: KeyDerivationMethod::RAW,
+        }
+    }
+
+    fn _create_and_open_wallet(wallet_service: &WalletService, config: &Config) -> WalletHandle {
+        let credentials = _credentials();
+
+        let key_data = KeyDerivationData::from_passphrase_with_new_salt(&credentials.key, &credentials.key_derivation_method);
+        let master_key = key_data.calc_master_key().unwrap();
+
+        let _ = _delete_wallet(wallet_service, config);
+        wallet_service.create_wallet(config, &credentials, (&key_data, &master_key)).unwrap();
+
+        let (wallet_handle, key_data, _) = wallet_service.open_wallet_prepare(config, &credentials).unwrap();
+        let master_key = key_data.calc_master_key().unwrap();
+        wallet_service.open_wallet_continue(wallet_handle, (&master_key, None)).unwrap()
+    }
+
+    fn _close_and_delete_wallet(wallet_service: &WalletService, wallet_handle: WalletHandle, config: &Config) {
+        wallet_service.close_wallet(wallet_handle).unwrap();
+        _delete_wallet(wallet_service, config).unwrap();
+    }
+
+    fn _delete_wallet(wallet_service: &WalletService, config: &Config) -> IndyResult<()> {
+        let credentials = _credentials();
+
+        let (metadata, key_data) = wallet_service.delete_wallet_prepare(config, &credentials)?;
+        let master_key = key_data.calc_master_key()?;
+        wallet_service.delete_wallet_continue(config, &credentials, &metadata, &master_key)
+    }
+}
@@ -1,6 +1,7 @@
 pub mod issuer;
 pub mod prover;
 pub mod verifier;
+mod nonces;
 mod tails;
 
 use commands::anoncreds::issuer::{IssuerCommand, IssuerCommandExecutor};
@@ -9,6 +10,7 @@ use commands::anoncreds::verifier::{VerifierCommand, VerifierCommandExecutor};
 
 use services::anoncreds::AnoncredsService;
 use services::blob_storage::BlobStorageService;
+use services::ledger::LedgerService;
 use services::pool::PoolService;
 use services::wallet::WalletService;
 use services::crypto::CryptoService;
@@ -32,15 +34,16 @@ impl AnoncredsCommandExecutor {
                blob_storage_service: Rc<BlobStorageService>,
                pool_service: Rc<PoolService>,
                wallet_service: Rc<WalletService>,
-               crypto_service: Rc<CryptoService>) -> AnoncredsCommandExecutor {
+               crypto_service: Rc<CryptoService>,
+               ledger_service: Rc<LedgerService>) -> AnoncredsCommandExecutor {
         AnoncredsCommandExecutor {
             issuer_command_cxecutor: IssuerCommandExecutor::new(
                 anoncreds_service.clone(), pool_service.clone(),
                 blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone()),
             prover_command_cxecutor: ProverCommandExecutor::new(
-                anoncreds_service.clone(), wallet_service.clone(), crypto_service.clone(), blob_storage_service.clone()),
+                anoncreds_service.clone(), wallet_service.clone(), crypto_service.clone(), blob_storage_service.clone(), ledger_service.clone(), pool_service.clone()),
             verifier_command_cxecutor: VerifierCommandExecutor::new(
-                anoncreds_service.clone()),
+                anoncreds_service.clone(), wallet_service.clone()),
         }
     }
 
@@ -0,0 +1,111 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use api::WalletHandle;
+use domain::anoncreds::tracked_nonce::TrackedNonce;
+use errors::prelude::*;
+use services::wallet::{RecordOptions, SearchOptions, WalletService};
+
+/// Records nonce of just created credential offer or proof request together with its expiry time.
+/// Only tracked nonces are accepted later, so a nonce purged after expiry can't be replayed.
+pub fn track_nonce(wallet_service: &WalletService,
+                   wallet_handle: WalletHandle,
+                   context: &str,
+                   nonce: &str,
+                   expires_at: u64) -> IndyResult<()> {
+    trace!("track_nonce >>> wallet_handle: {:?}, context: {:?}, nonce: {:?}, expires_at: {:?}", wallet_handle, context, nonce, expires_at);
+
+    let now = _get_cur_time();
+
+    if now > expires_at {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Nonce {} for {} is already expired at {}", nonce, context, expires_at)));
+    }
+
+    purge_expired_nonces(wallet_service, wallet_handle, now)?;
+
+    let tracked_nonce = TrackedNonce { context: context.to_string(), expires_at, used: false };
+
+    wallet_service.add_indy_object(wallet_handle, &TrackedNonce::id(context, nonce), &tracked_nonce, &tracked_nonce.tags())?;
+
+    trace!("track_nonce <<<");
+
+    Ok(())
+}
+
+/// Fails with `reject_kind` if nonce isn't tracked in the given context, was already consumed or its expiry time has passed.
+pub fn check_nonce_unused(wallet_service: &WalletService,
+                          wallet_handle: WalletHandle,
+                          context: &str,
+                          nonce: &str,
+                          reject_kind: IndyErrorKind) -> IndyResult<()> {
+    trace!("check_nonce_unused >>> wallet_handle: {:?}, context: {:?}, nonce: {:?}", wallet_handle, context, nonce);
+
+    let now = _get_cur_time();
+
+    purge_expired_nonces(wallet_service, wallet_handle, now)?;
+
+    let tracked_nonce: TrackedNonce = match wallet_service.get_indy_object(wallet_handle, &TrackedNonce::id(context, nonce), &RecordOptions::id_value()) {
+        Ok(tracked_nonce) => tracked_nonce,
+        Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound =>
+            return Err(err_msg(reject_kind, format!("Nonce {} for {} isn't tracked or has expired", nonce, context))),
+        Err(err) => return Err(err)
+    };
+
+    if now > tracked_nonce.expires_at {
+        return Err(err_msg(reject_kind, format!("Nonce {} for {} expired at {}", nonce, context, tracked_nonce.expires_at)));
+    }
+
+    if tracked_nonce.used {
+        return Err(err_msg(reject_kind, format!("Nonce {} for {} has been already used", nonce, context)));
+    }
+
+    trace!("check_nonce_unused <<<");
+
+    Ok(())
+}
+
+/// Marks tracked nonce as consumed. Record is kept until expiry time only.
+pub fn consume_nonce(wallet_service: &WalletService,
+                     wallet_handle: WalletHandle,
+                     context: &str,
+                     nonce: &str) -> IndyResult<()> {
+    trace!("consume_nonce >>> wallet_handle: {:?}, context: {:?}, nonce: {:?}", wallet_handle, context, nonce);
+
+    let id = TrackedNonce::id(context, nonce);
+
+    let mut tracked_nonce: TrackedNonce = wallet_service.get_indy_object(wallet_handle, &id, &RecordOptions::id_value())?;
+    tracked_nonce.used = true;
+
+    wallet_service.update_indy_object(wallet_handle, &id, &tracked_nonce)?;
+
+    trace!("consume_nonce <<<");
+
+    Ok(())
+}
+
+pub fn purge_expired_nonces(wallet_service: &WalletService,
+                            wallet_handle: WalletHandle,
+                            now: u64) -> IndyResult<()> {
+    trace!("purge_expired_nonces >>> wallet_handle: {:?}, now: {:?}", wallet_handle, now);
+
+    let query_json = json!({"~expires_at": {"$lt": TrackedNonce::expires_at_tag(now)}}).to_string();
+
+    let mut search = wallet_service.search_indy_records::<TrackedNonce>(wallet_handle, &query_json, &SearchOptions::id_value())?;
+
+    let mut expired_ids: Vec<String> = Vec::new();
+
+    while let Some(record) = search.fetch_next_record()? {
+        expired_ids.push(record.get_id().to_string());
+    }
+
+    for id in expired_ids {
+        wallet_service.delete_indy_record::<TrackedNonce>(wallet_handle, &id)?;
+    }
+
+    trace!("purge_expired_nonces <<<");
+
+    Ok(())
+}
+
+fn _get_cur_time() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).expect("Time has gone backwards").as_secs()
+}
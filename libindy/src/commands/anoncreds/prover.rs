@@ -1,26 +1,34 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use indy_crypto::cl::{new_nonce, RevocationRegistry, Witness};
 
+use commands::{Command, CommandExecutor};
+use commands::anoncreds::AnoncredsCommand;
+use commands::ledger::LedgerCommand;
 use domain::anoncreds::credential::{Credential, CredentialInfo};
 use domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinition, CredentialDefinitionV1};
 use domain::anoncreds::credential_for_proof_request::{CredentialsForProofRequest, RequestedCredential};
 use domain::anoncreds::credential_offer::CredentialOffer;
 use domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
 use domain::anoncreds::master_secret::MasterSecret;
+use domain::anoncreds::proof::Proof;
 use domain::anoncreds::proof_request::{NonRevocedInterval, PredicateInfo, ProofRequest, ProofRequestExtraQuery};
 use domain::anoncreds::requested_credential::RequestedCredentials;
-use domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
+use domain::anoncreds::revocation_registry_definition::{rev_reg_defs_map_to_rev_reg_defs_v1_map, RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
 use domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use domain::anoncreds::revocation_state::RevocationState;
 use domain::anoncreds::schema::{Schema, schemas_map_to_schemas_v1_map, SchemaV1};
 use errors::prelude::*;
 use services::anoncreds::AnoncredsService;
 use services::anoncreds::helpers::parse_cred_rev_id;
+use services::anoncreds::prover::RevocationStateSource;
 use services::blob_storage::BlobStorageService;
 use services::crypto::CryptoService;
+use services::ledger::LedgerService;
+use services::pool::PoolService;
 use services::wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
 use utils::sequence;
 
@@ -55,6 +63,10 @@ pub enum ProverCommand {
         WalletHandle,
         String, // credential id
         Box<Fn(IndyResult<String>) + Send>),
+    GetCredentialW3C(
+        WalletHandle,
+        String, // credential id
+        Box<Fn(IndyResult<String>) + Send>),
     SearchCredentials(
         WalletHandle,
         Option<String>, // query json
@@ -92,6 +104,10 @@ pub enum ProverCommand {
         HashMap<String, CredentialDefinition>, // credential defs
         HashMap<String, HashMap<u64, RevocationState>>, // revocation states
         Box<Fn(IndyResult<String>) + Send>),
+    ProofToW3C(
+        ProofRequest, // proof request
+        Proof, // proof
+        Box<Fn(IndyResult<String>) + Send>),
     CreateRevocationState(
         i32, // blob storage reader handle
         RevocationRegistryDefinition, // revocation registry definition
@@ -106,7 +122,32 @@ pub enum ProverCommand {
         RevocationRegistryDelta, // revocation registry delta
         u64, //timestamp
         String, //credential revocation id
-        Box<Fn(IndyResult<String>) + Send>)
+        Box<Fn(IndyResult<String>) + Send>),
+    StoreRevocationState(
+        WalletHandle,
+        String, // revocation registry id
+        String, // credential revocation id
+        RevocationState, // revocation state
+        Box<Fn(IndyResult<()>) + Send>),
+    GetRevocationState(
+        WalletHandle,
+        String, // revocation registry id
+        String, // credential revocation id
+        u64, // timestamp
+        Box<Fn(IndyResult<String>) + Send>),
+    UpdateRevocationStates(
+        WalletHandle,
+        i32, // pool handle
+        ProofRequest, // proof request
+        RequestedCredentials, // requested credentials
+        HashMap<String, RevocationRegistryDefinition>, // revocation registry definitions
+        HashMap<String, i32>, // blob storage reader handles
+        Box<Fn(IndyResult<(String, String)>) + Send>),
+    // Internal commands
+    GetRevocRegDeltaAck(
+        i32, // revocation states update id
+        IndyResult<String>, // GetRevocRegDelta Result
+    ),
 }
 
 struct SearchForProofRequest {
@@ -127,27 +168,55 @@ impl SearchForProofRequest {
     }
 }
 
+struct RevocationStateRequest {
+    rev_reg_id: String,
+    cred_rev_id: String,
+    to: u64,
+    source: RevocationStateSource,
+    rev_state: Option<RevocationState>,
+    referents: Vec<String>,
+}
+
+struct RevocationStatesUpdate {
+    wallet_handle: WalletHandle,
+    pool_handle: i32,
+    requested_credentials: RequestedCredentials,
+    rev_reg_defs: HashMap<String, RevocationRegistryDefinitionV1>,
+    blob_storage_reader_handles: HashMap<String, i32>,
+    requests: Vec<RevocationStateRequest>,
+    current: usize,
+    cb: Box<Fn(IndyResult<(String, String)>) + Send>,
+}
+
 pub struct ProverCommandExecutor {
     anoncreds_service: Rc<AnoncredsService>,
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
     blob_storage_service: Rc<BlobStorageService>,
+    ledger_service: Rc<LedgerService>,
+    pool_service: Rc<PoolService>,
     searches: RefCell<HashMap<i32, Box<WalletSearch>>>,
     searches_for_proof_requests: RefCell<HashMap<i32, Box<HashMap<String, SearchForProofRequest>>>>,
+    revocation_states_updates: RefCell<HashMap<i32, RevocationStatesUpdate>>,
 }
 
 impl ProverCommandExecutor {
     pub fn new(anoncreds_service: Rc<AnoncredsService>,
                wallet_service: Rc<WalletService>,
                crypto_service: Rc<CryptoService>,
-               blob_storage_service: Rc<BlobStorageService>) -> ProverCommandExecutor {
+               blob_storage_service: Rc<BlobStorageService>,
+               ledger_service: Rc<LedgerService>,
+               pool_service: Rc<PoolService>) -> ProverCommandExecutor {
         ProverCommandExecutor {
             anoncreds_service,
             wallet_service,
             crypto_service,
             blob_storage_service,
+            ledger_service,
+            pool_service,
             searches: RefCell::new(HashMap::new()),
             searches_for_proof_requests: RefCell::new(HashMap::new()),
+            revocation_states_updates: RefCell::new(HashMap::new()),
         }
     }
 
@@ -178,6 +247,10 @@ impl ProverCommandExecutor {
                 info!(target: "prover_command_executor", "GetCredential command received");
                 cb(self.get_credential(wallet_handle, &cred_id));
             }
+            ProverCommand::GetCredentialW3C(wallet_handle, cred_id, cb) => {
+                info!(target: "prover_command_executor", "GetCredentialW3C command received");
+                cb(self.get_credential_w3c(wallet_handle, &cred_id));
+            }
             ProverCommand::SearchCredentials(wallet_handle, query_json, cb) => {
                 info!(target: "prover_command_executor", "SearchCredentials command received");
                 cb(self.search_credentials(wallet_handle, query_json.as_ref().map(String::as_str)));
@@ -214,6 +287,10 @@ impl ProverCommandExecutor {
                                      &cred_defs_map_to_cred_defs_v1_map(cred_defs),
                                      &rev_states));
             }
+            ProverCommand::ProofToW3C(proof_req, proof, cb) => {
+                info!(target: "prover_command_executor", "ProofToW3C command received");
+                cb(self.proof_to_w3c(&proof_req, proof));
+            }
             ProverCommand::CreateRevocationState(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id, cb) => {
                 info!(target: "prover_command_executor", "CreateRevocationState command received");
                 cb(self.create_revocation_state(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
@@ -222,6 +299,24 @@ impl ProverCommandExecutor {
                 info!(target: "prover_command_executor", "UpdateRevocationState command received");
                 cb(self.update_revocation_state(blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
             }
+            ProverCommand::StoreRevocationState(wallet_handle, rev_reg_id, cred_rev_id, rev_state, cb) => {
+                info!(target: "prover_command_executor", "StoreRevocationState command received");
+                cb(self.store_revocation_state(wallet_handle, &rev_reg_id, &cred_rev_id, &rev_state));
+            }
+            ProverCommand::GetRevocationState(wallet_handle, rev_reg_id, cred_rev_id, timestamp, cb) => {
+                info!(target: "prover_command_executor", "GetRevocationState command received");
+                cb(self.get_revocation_state(wallet_handle, &rev_reg_id, &cred_rev_id, timestamp));
+            }
+            ProverCommand::UpdateRevocationStates(wallet_handle, pool_handle, proof_req, requested_credentials, rev_reg_defs, blob_storage_reader_handles, cb) => {
+                info!(target: "prover_command_executor", "UpdateRevocationStates command received");
+                self.update_revocation_states(wallet_handle, pool_handle, &proof_req, requested_credentials,
+                                              rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                              blob_storage_reader_handles, cb);
+            }
+            ProverCommand::GetRevocRegDeltaAck(update_id, result) => {
+                info!(target: "prover_command_executor", "GetRevocRegDeltaAck command received");
+                self.get_revoc_reg_delta_ack(update_id, result);
+            }
         };
     }
 
@@ -366,6 +461,23 @@ impl ProverCommandExecutor {
         Ok(credential_info_json)
     }
 
+    fn get_credential_w3c(&self,
+                          wallet_handle: WalletHandle,
+                          cred_id: &str) -> IndyResult<String> {
+        debug!("get_credential_w3c >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+        let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, &cred_id, &RecordOptions::id_value())?;
+
+        let w3c_credential = self.anoncreds_service.prover.credential_to_w3c(credential)?;
+
+        let w3c_credential_json = serde_json::to_string(&w3c_credential)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize W3CCredential")?;
+
+        debug!("get_credential_w3c <<< w3c_credential_json: {:?}", w3c_credential_json);
+
+        Ok(w3c_credential_json)
+    }
+
     fn search_credentials(&self,
                           wallet_handle: WalletHandle,
                           query_json: Option<&str>) -> IndyResult<(i32, usize)> {
@@ -601,6 +713,21 @@ impl ProverCommandExecutor {
         Ok(proof_json)
     }
 
+    fn proof_to_w3c(&self,
+                    proof_req: &ProofRequest,
+                    proof: Proof) -> IndyResult<String> {
+        debug!("proof_to_w3c >>> proof_req: {:?}, proof: {:?}", proof_req, proof);
+
+        let presentation = self.anoncreds_service.prover.proof_to_w3c(proof, proof_req)?;
+
+        let presentation_json = serde_json::to_string(&presentation)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize W3CPresentation")?;
+
+        debug!("proof_to_w3c <<< presentation_json: {:?}", presentation_json);
+
+        Ok(presentation_json)
+    }
+
     fn create_revocation_state(&self,
                                blob_storage_reader_handle: i32,
                                revoc_reg_def: RevocationRegistryDefinition,
@@ -610,23 +737,11 @@ impl ProverCommandExecutor {
         debug!("create_revocation_state >>> , blob_storage_reader_handle: {:?}, revoc_reg_def: {:?}, rev_reg_delta: {:?}, timestamp: {:?}, cred_rev_id: {:?}",
                blob_storage_reader_handle, revoc_reg_def, rev_reg_delta, timestamp, cred_rev_id);
 
-        let revoc_reg_def = RevocationRegistryDefinitionV1::from(revoc_reg_def);
-
-        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
-
-        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
-                                                       blob_storage_reader_handle,
-                                                       &revoc_reg_def)?;
-
-        let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
-
-        let witness = Witness::new(rev_idx, revoc_reg_def.value.max_cred_num, revoc_reg_def.value.issuance_type.to_bool(), &rev_reg_delta.value, &sdk_tails_accessor)?;
-
-        let revocation_state = RevocationState {
-            witness,
-            rev_reg: RevocationRegistry::from(rev_reg_delta.value),
-            timestamp,
-        };
+        let revocation_state = self._create_revocation_state(blob_storage_reader_handle,
+                                                             &RevocationRegistryDefinitionV1::from(revoc_reg_def),
+                                                             RevocationRegistryDeltaV1::from(rev_reg_delta),
+                                                             timestamp,
+                                                             cred_rev_id)?;
 
         let revocation_state_json = serde_json::to_string(&revocation_state)
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
@@ -638,7 +753,7 @@ impl ProverCommandExecutor {
 
     fn update_revocation_state(&self,
                                blob_storage_reader_handle: i32,
-                               mut rev_state: RevocationState,
+                               rev_state: RevocationState,
                                rev_reg_def: RevocationRegistryDefinition,
                                rev_reg_delta: RevocationRegistryDelta,
                                timestamp: u64,
@@ -646,29 +761,107 @@ impl ProverCommandExecutor {
         debug!("update_revocation_state >>> blob_storage_reader_handle: {:?}, rev_state: {:?}, rev_reg_def: {:?}, rev_reg_delta: {:?}, timestamp: {:?}, cred_rev_id: {:?}",
                blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id);
 
-        let revocation_registry_definition = RevocationRegistryDefinitionV1::from(rev_reg_def);
+        let rev_state = self._update_revocation_state(blob_storage_reader_handle,
+                                                      rev_state,
+                                                      &RevocationRegistryDefinitionV1::from(rev_reg_def),
+                                                      RevocationRegistryDeltaV1::from(rev_reg_delta),
+                                                      timestamp,
+                                                      cred_rev_id)?;
 
-        let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
+        let rev_state_json = serde_json::to_string(&rev_state)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
 
-        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
+        debug!("update_revocation_state <<< rev_state: {:?}", rev_state_json);
 
-        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
-                                                       blob_storage_reader_handle,
-                                                       &revocation_registry_definition)?;
+        Ok(rev_state_json)
+    }
 
-        rev_state.witness.update(rev_idx, revocation_registry_definition.value.max_cred_num, &rev_reg_delta.value, &sdk_tails_accessor)?;
+    fn store_revocation_state(&self,
+                              wallet_handle: WalletHandle,
+                              rev_reg_id: &str,
+                              cred_rev_id: &str,
+                              rev_state: &RevocationState) -> IndyResult<()> {
+        debug!("store_revocation_state >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_rev_id: {:?}, rev_state: {:?}",
+               wallet_handle, rev_reg_id, cred_rev_id, rev_state);
 
-        rev_state.rev_reg = RevocationRegistry::from(rev_reg_delta.value);
-        rev_state.timestamp = timestamp;
+        parse_cred_rev_id(cred_rev_id)?;
+
+        self._wallet_store_revocation_state(wallet_handle, rev_reg_id, cred_rev_id, rev_state)?;
+
+        debug!("store_revocation_state <<<");
+
+        Ok(())
+    }
+
+    fn get_revocation_state(&self,
+                            wallet_handle: WalletHandle,
+                            rev_reg_id: &str,
+                            cred_rev_id: &str,
+                            timestamp: u64) -> IndyResult<String> {
+        debug!("get_revocation_state >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_rev_id: {:?}, timestamp: {:?}",
+               wallet_handle, rev_reg_id, cred_rev_id, timestamp);
+
+        let rev_state: RevocationState =
+            self.wallet_service.get_indy_object(wallet_handle, &_revocation_state_id(rev_reg_id, cred_rev_id, timestamp), &RecordOptions::id_value())?;
 
         let rev_state_json = serde_json::to_string(&rev_state)
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
 
-        debug!("update_revocation_state <<< rev_state: {:?}", rev_state_json);
+        debug!("get_revocation_state <<< rev_state_json: {:?}", rev_state_json);
 
         Ok(rev_state_json)
     }
 
+    fn update_revocation_states(&self,
+                                wallet_handle: WalletHandle,
+                                pool_handle: i32,
+                                proof_req: &ProofRequest,
+                                requested_credentials: RequestedCredentials,
+                                rev_reg_defs: HashMap<String, RevocationRegistryDefinitionV1>,
+                                blob_storage_reader_handles: HashMap<String, i32>,
+                                cb: Box<Fn(IndyResult<(String, String)>) + Send>) {
+        debug!("update_revocation_states >>> wallet_handle: {:?}, pool_handle: {:?}, proof_req: {:?}, requested_credentials: {:?}, \
+               rev_reg_defs: {:?}, blob_storage_reader_handles: {:?}",
+               wallet_handle, pool_handle, proof_req, requested_credentials, rev_reg_defs, blob_storage_reader_handles);
+
+        let requests = try_cb!(self._prepare_revocation_state_requests(wallet_handle, proof_req, &requested_credentials), cb);
+
+        let update = RevocationStatesUpdate {
+            wallet_handle,
+            pool_handle,
+            requested_credentials,
+            rev_reg_defs,
+            blob_storage_reader_handles,
+            requests,
+            current: 0,
+            cb,
+        };
+
+        self._continue_revocation_states_update(sequence::get_next_id(), update);
+    }
+
+    fn get_revoc_reg_delta_ack(&self, update_id: i32, get_revoc_reg_delta_reply_result: IndyResult<String>) {
+        trace!("get_revoc_reg_delta_ack >>> update_id: {:?}, get_revoc_reg_delta_reply_result: {:?}", update_id, get_revoc_reg_delta_reply_result);
+
+        let update = self.revocation_states_updates.borrow_mut().remove(&update_id);
+
+        let mut update = match update {
+            Some(update) => update,
+            None => {
+                error!("No revocation states update for id: {}", update_id);
+                return;
+            }
+        };
+
+        match self._apply_revoc_reg_delta(&mut update, get_revoc_reg_delta_reply_result) {
+            Ok(()) => {
+                update.current += 1;
+                self._continue_revocation_states_update(update_id, update);
+            }
+            Err(err) => (update.cb)(Err(err))
+        }
+    }
+
     fn _get_credential_info(&self,
                             referent: &str,
                             credential: Credential) -> CredentialInfo {
@@ -758,9 +951,286 @@ impl ProverCommandExecutor {
         Ok(credentials)
     }
 
+    fn _create_revocation_state(&self,
+                                blob_storage_reader_handle: i32,
+                                rev_reg_def: &RevocationRegistryDefinitionV1,
+                                rev_reg_delta: RevocationRegistryDeltaV1,
+                                timestamp: u64,
+                                cred_rev_id: &str) -> IndyResult<RevocationState> {
+        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
+
+        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                       blob_storage_reader_handle,
+                                                       rev_reg_def)?;
+
+        let witness = Witness::new(rev_idx, rev_reg_def.value.max_cred_num, rev_reg_def.value.issuance_type.to_bool(), &rev_reg_delta.value, &sdk_tails_accessor)?;
+
+        Ok(RevocationState {
+            witness,
+            rev_reg: RevocationRegistry::from(rev_reg_delta.value),
+            timestamp,
+        })
+    }
+
+    fn _update_revocation_state(&self,
+                                blob_storage_reader_handle: i32,
+                                mut rev_state: RevocationState,
+                                rev_reg_def: &RevocationRegistryDefinitionV1,
+                                rev_reg_delta: RevocationRegistryDeltaV1,
+                                timestamp: u64,
+                                cred_rev_id: &str) -> IndyResult<RevocationState> {
+        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
+
+        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                       blob_storage_reader_handle,
+                                                       rev_reg_def)?;
+
+        rev_state.witness.update(rev_idx, rev_reg_def.value.max_cred_num, &rev_reg_delta.value, &sdk_tails_accessor)?;
+
+        rev_state.rev_reg = RevocationRegistry::from(rev_reg_delta.value);
+        rev_state.timestamp = timestamp;
+
+        Ok(rev_state)
+    }
+
+    fn _prepare_revocation_state_requests(&self,
+                                          wallet_handle: WalletHandle,
+                                          proof_req: &ProofRequest,
+                                          requested_credentials: &RequestedCredentials) -> IndyResult<Vec<RevocationStateRequest>> {
+        trace!("_prepare_revocation_state_requests >>> wallet_handle: {:?}, proof_req: {:?}, requested_credentials: {:?}",
+               wallet_handle, proof_req, requested_credentials);
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot get current time")?
+            .as_secs();
+
+        let mut referents: Vec<(String, String, Option<NonRevocedInterval>)> = Vec::new();
+
+        for (referent, requested_attr) in requested_credentials.requested_attributes.iter() {
+            let attr_info = proof_req.requested_attributes.get(referent)
+                .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Attribute not found in ProofRequest for referent: {}", referent)))?;
+
+            let interval = self.anoncreds_service.prover.get_non_revoc_interval(&proof_req.non_revoked, &attr_info.non_revoked);
+            referents.push((referent.to_string(), requested_attr.cred_id.to_string(), interval));
+        }
+
+        for (referent, requested_predicate) in requested_credentials.requested_predicates.iter() {
+            let predicate_info = proof_req.requested_predicates.get(referent)
+                .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Predicate not found in ProofRequest for referent: {}", referent)))?;
+
+            let interval = self.anoncreds_service.prover.get_non_revoc_interval(&proof_req.non_revoked, &predicate_info.non_revoked);
+            referents.push((referent.to_string(), requested_predicate.cred_id.to_string(), interval));
+        }
+
+        let mut requests: Vec<RevocationStateRequest> = Vec::new();
+
+        for (referent, cred_id, interval) in referents {
+            let interval = match interval {
+                Some(interval) => interval,
+                None => continue
+            };
+
+            let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, &cred_id, &RecordOptions::id_value())?;
+
+            let (rev_reg_id, cred_rev_id) = match (credential.rev_reg_id.as_ref(), credential.signature.extract_index()) {
+                (Some(rev_reg_id), Some(cred_rev_id)) => (rev_reg_id.to_string(), cred_rev_id.to_string()),
+                _ => continue
+            };
+
+            let to = interval.to.unwrap_or(now);
+
+            if let Some(request) = requests.iter_mut()
+                .find(|request| request.rev_reg_id == rev_reg_id && request.cred_rev_id == cred_rev_id && request.to == to) {
+                request.referents.push(referent);
+                continue;
+            }
+
+            let rev_states = self._wallet_get_revocation_states(wallet_handle, &rev_reg_id, &cred_rev_id)?;
+            let timestamps = rev_states.iter().map(|rev_state| rev_state.timestamp).collect::<Vec<u64>>();
+
+            let source = self.anoncreds_service.prover.get_revocation_state_source(&timestamps, &interval, to);
+
+            let rev_state = match source {
+                RevocationStateSource::Cached(timestamp) | RevocationStateSource::Update(timestamp) =>
+                    rev_states.into_iter().find(|rev_state| rev_state.timestamp == timestamp),
+                RevocationStateSource::Create => None
+            };
+
+            requests.push(RevocationStateRequest {
+                rev_reg_id,
+                cred_rev_id,
+                to,
+                source,
+                rev_state,
+                referents: vec![referent],
+            });
+        }
+
+        trace!("_prepare_revocation_state_requests <<< requests: {:?}", requests.len());
+
+        Ok(requests)
+    }
+
+    fn _continue_revocation_states_update(&self, update_id: i32, mut update: RevocationStatesUpdate) {
+        while update.current < update.requests.len() {
+            let source = update.requests[update.current].source;
+
+            let from = match source {
+                RevocationStateSource::Cached(_) => {
+                    update.current += 1;
+                    continue;
+                }
+                RevocationStateSource::Update(timestamp) => Some(timestamp as i64),
+                RevocationStateSource::Create => None
+            };
+
+            let get_revoc_reg_delta_request = {
+                let request = &update.requests[update.current];
+                self.pool_service.get_protocol_version(update.pool_handle)
+                    .and_then(|protocol_version| self.ledger_service.build_get_revoc_reg_delta_request(None, &request.rev_reg_id, from, request.to as i64, protocol_version))
+            };
+
+            let get_revoc_reg_delta_request = match get_revoc_reg_delta_request {
+                Ok(get_revoc_reg_delta_request) => get_revoc_reg_delta_request,
+                Err(err) => return (update.cb)(Err(err))
+            };
+
+            let pool_handle = update.pool_handle;
+
+            // Defer the rest of the update until revocation registry delta is fetched from ledger.
+            self.revocation_states_updates.borrow_mut().insert(update_id, update);
+
+            CommandExecutor::instance()
+                .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                    pool_handle,
+                    get_revoc_reg_delta_request,
+                    Box::new(move |result| {
+                        CommandExecutor::instance()
+                            .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::GetRevocRegDeltaAck(
+                                update_id,
+                                result,
+                            )))).unwrap();
+                    }),
+                ))).unwrap();
+
+            return;
+        }
+
+        let res = self._finish_revocation_states_update(&mut update);
+        (update.cb)(res)
+    }
+
+    fn _apply_revoc_reg_delta(&self, update: &mut RevocationStatesUpdate, get_revoc_reg_delta_reply_result: IndyResult<String>) -> IndyResult<()> {
+        let get_revoc_reg_delta_reply = get_revoc_reg_delta_reply_result?;
+
+        let (_, rev_reg_delta_json, timestamp) =
+            self.ledger_service.parse_get_revoc_reg_delta_response(&get_revoc_reg_delta_reply)?;
+
+        let rev_reg_delta: RevocationRegistryDelta = serde_json::from_str(&rev_reg_delta_json)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDelta")?;
+
+        let request = &mut update.requests[update.current];
+
+        let rev_reg_def = update.rev_reg_defs.get(&request.rev_reg_id)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("RevocationRegistryDefinition not found for id: {}", request.rev_reg_id)))?;
+
+        let blob_storage_reader_handle = *update.blob_storage_reader_handles.get(&request.rev_reg_id)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Blob storage reader handle not found for id: {}", request.rev_reg_id)))?;
+
+        let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
+
+        let rev_state = match request.rev_state.take() {
+            Some(rev_state) => self._update_revocation_state(blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, &request.cred_rev_id)?,
+            None => self._create_revocation_state(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, &request.cred_rev_id)?
+        };
+
+        self._wallet_store_revocation_state(update.wallet_handle, &request.rev_reg_id, &request.cred_rev_id, &rev_state)?;
+
+        request.rev_state = Some(rev_state);
+
+        Ok(())
+    }
+
+    fn _finish_revocation_states_update(&self, update: &mut RevocationStatesUpdate) -> IndyResult<(String, String)> {
+        let mut rev_states: HashMap<String, HashMap<u64, &RevocationState>> = HashMap::new();
+
+        for request in update.requests.iter() {
+            let rev_state = request.rev_state.as_ref()
+                .ok_or(err_msg(IndyErrorKind::InvalidState, format!("RevocationState not resolved for id: {}", request.rev_reg_id)))?;
+
+            for referent in request.referents.iter() {
+                if let Some(requested_attr) = update.requested_credentials.requested_attributes.get_mut(referent) {
+                    requested_attr.timestamp = Some(rev_state.timestamp);
+                }
+                if let Some(requested_predicate) = update.requested_credentials.requested_predicates.get_mut(referent) {
+                    requested_predicate.timestamp = Some(rev_state.timestamp);
+                }
+            }
+
+            rev_states.entry(request.rev_reg_id.clone())
+                .or_insert(HashMap::new())
+                .insert(rev_state.timestamp, rev_state);
+        }
+
+        let rev_states_json = serde_json::to_string(&rev_states)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationStates")?;
+
+        let requested_credentials_json = serde_json::to_string(&update.requested_credentials)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RequestedCredentials")?;
+
+        debug!("update_revocation_states <<< rev_states_json: {:?}, requested_credentials_json: {:?}", rev_states_json, requested_credentials_json);
+
+        Ok((rev_states_json, requested_credentials_json))
+    }
+
+    fn _wallet_store_revocation_state(&self,
+                                      wallet_handle: WalletHandle,
+                                      rev_reg_id: &str,
+                                      cred_rev_id: &str,
+                                      rev_state: &RevocationState) -> IndyResult<()> {
+        let id = _revocation_state_id(rev_reg_id, cred_rev_id, rev_state.timestamp);
+
+        if self.wallet_service.record_exists::<RevocationState>(wallet_handle, &id)? {
+            self.wallet_service.update_indy_object(wallet_handle, &id, rev_state)?;
+        } else {
+            let tags = self.anoncreds_service.prover.build_revocation_state_tags(rev_reg_id, cred_rev_id, rev_state.timestamp);
+            self.wallet_service.add_indy_object(wallet_handle, &id, rev_state, &tags)?;
+        }
+
+        Ok(())
+    }
+
+    fn _wallet_get_revocation_states(&self,
+                                     wallet_handle: WalletHandle,
+                                     rev_reg_id: &str,
+                                     cred_rev_id: &str) -> IndyResult<Vec<RevocationState>> {
+        let query_json = json!({
+            "rev_reg_id": rev_reg_id,
+            "cred_rev_id": cred_rev_id
+        }).to_string();
+
+        let mut rev_states_search =
+            self.wallet_service.search_indy_records::<RevocationState>(wallet_handle, &query_json, &SearchOptions::id_value())?;
+
+        let mut rev_states: Vec<RevocationState> = Vec::new();
+
+        while let Some(rev_state_record) = rev_states_search.fetch_next_record()? {
+            let rev_state: RevocationState = rev_state_record.get_value()
+                .ok_or(err_msg(IndyErrorKind::InvalidState, format!("RevocationState not found for id: {}", rev_state_record.get_id())))
+                .and_then(|value| serde_json::from_str(value)
+                    .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationState"))?;
+
+            rev_states.push(rev_state);
+        }
+
+        Ok(rev_states)
+    }
 
     fn _wallet_get_master_secret(&self, wallet_handle: WalletHandle, key: &str) -> IndyResult<MasterSecret> {
         self.wallet_service.get_indy_object(wallet_handle, &key, &RecordOptions::id_value())
     }
 }
 
+fn _revocation_state_id(rev_reg_id: &str, cred_rev_id: &str, timestamp: u64) -> String {
+    format!("{}:{}:{}", rev_reg_id, cred_rev_id, timestamp)
+}
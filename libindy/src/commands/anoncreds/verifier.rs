@@ -7,8 +7,14 @@ use domain::anoncreds::proof_request::ProofRequest;
 use domain::anoncreds::revocation_registry::{rev_regs_map_to_rev_regs_local_map, RevocationRegistry, RevocationRegistryV1};
 use domain::anoncreds::revocation_registry_definition::{rev_reg_defs_map_to_rev_reg_defs_v1_map, RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
 use domain::anoncreds::schema::{Schema, schemas_map_to_schemas_v1_map, SchemaV1};
+use domain::anoncreds::tracked_nonce::PROOF_REQUEST_NONCE;
+use domain::anoncreds::w3c::W3CPresentation;
 use errors::prelude::*;
 use services::anoncreds::AnoncredsService;
+use services::wallet::WalletService;
+use api::WalletHandle;
+
+use super::nonces::{check_nonce_unused, consume_nonce, track_nonce};
 
 pub enum VerifierCommand {
     VerifyProof(
@@ -18,29 +24,50 @@ pub enum VerifierCommand {
         HashMap<String, CredentialDefinition>, // credential defs
         HashMap<String, RevocationRegistryDefinition>, // rev reg defs
         HashMap<String, HashMap<u64, RevocationRegistry>>, // rev reg entries
-        Box<Fn(IndyResult<bool>) + Send>)
+        Option<WalletHandle>, // wallet the proof request nonce is tracked in
+        Box<Fn(IndyResult<bool>) + Send>),
+    TrackProofRequestNonce(
+        WalletHandle,
+        ProofRequest, // proof request
+        u64, // nonce expiry time
+        Box<Fn(IndyResult<()>) + Send>),
+    ProofFromW3C(
+        W3CPresentation, // presentation
+        Box<Fn(IndyResult<String>) + Send>)
 }
 
 pub struct VerifierCommandExecutor {
     anoncreds_service: Rc<AnoncredsService>,
+    wallet_service: Rc<WalletService>,
 }
 
 impl VerifierCommandExecutor {
-    pub fn new(anoncreds_service: Rc<AnoncredsService>) -> VerifierCommandExecutor {
+    pub fn new(anoncreds_service: Rc<AnoncredsService>,
+               wallet_service: Rc<WalletService>) -> VerifierCommandExecutor {
         VerifierCommandExecutor {
             anoncreds_service,
+            wallet_service,
         }
     }
 
     pub fn execute(&self, command: VerifierCommand) {
         match command {
-            VerifierCommand::VerifyProof(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
+            VerifierCommand::VerifyProof(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, nonce_tracking, cb) => {
                 info!(target: "verifier_command_executor", "VerifyProof command received");
                 cb(self.verify_proof(proof_request, proof,
                                      &schemas_map_to_schemas_v1_map(schemas),
                                      &cred_defs_map_to_cred_defs_v1_map(credential_defs),
                                      &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
-                                     &rev_regs_map_to_rev_regs_local_map(rev_regs)));
+                                     &rev_regs_map_to_rev_regs_local_map(rev_regs),
+                                     nonce_tracking));
+            }
+            VerifierCommand::TrackProofRequestNonce(wallet_handle, proof_request, nonce_expires_at, cb) => {
+                info!(target: "verifier_command_executor", "TrackProofRequestNonce command received");
+                cb(self.track_proof_request_nonce(wallet_handle, &proof_request, nonce_expires_at));
+            }
+            VerifierCommand::ProofFromW3C(presentation, cb) => {
+                info!(target: "verifier_command_executor", "ProofFromW3C command received");
+                cb(self.proof_from_w3c(presentation));
             }
         };
     }
@@ -51,10 +78,20 @@ impl VerifierCommandExecutor {
                     schemas: &HashMap<String, SchemaV1>,
                     cred_defs: &HashMap<String, CredentialDefinitionV1>,
                     rev_reg_defs: &HashMap<String, RevocationRegistryDefinitionV1>,
-                    rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
+                    rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>,
+                    nonce_tracking: Option<WalletHandle>) -> IndyResult<bool> {
         debug!("verify_proof >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
-               rev_reg_defs: {:?}, rev_regs: {:?}",
-               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
+               rev_reg_defs: {:?}, rev_regs: {:?}, nonce_tracking: {:?}",
+               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs, nonce_tracking);
+
+        let nonce_tracking = match nonce_tracking {
+            Some(wallet_handle) => {
+                let nonce = proof_req.nonce.to_dec()?;
+                check_nonce_unused(&self.wallet_service, wallet_handle, PROOF_REQUEST_NONCE, &nonce, IndyErrorKind::ProofRejected)?;
+                Some((wallet_handle, nonce))
+            }
+            None => None
+        };
 
         let requested_attrs: HashSet<String> =
             proof_req.requested_attributes
@@ -123,8 +160,38 @@ impl VerifierCommandExecutor {
                                                             rev_reg_defs,
                                                             rev_regs)?;
 
+        if let (true, Some((wallet_handle, nonce))) = (result, nonce_tracking) {
+            consume_nonce(&self.wallet_service, wallet_handle, PROOF_REQUEST_NONCE, &nonce)?;
+        }
+
         debug!("verify_proof <<< result: {:?}", result);
 
         Ok(result)
     }
-}
\ No newline at end of file
+
+    fn track_proof_request_nonce(&self,
+                                 wallet_handle: WalletHandle,
+                                 proof_req: &ProofRequest,
+                                 nonce_expires_at: u64) -> IndyResult<()> {
+        debug!("track_proof_request_nonce >>> wallet_handle: {:?}, proof_req: {:?}, nonce_expires_at: {:?}", wallet_handle, proof_req, nonce_expires_at);
+
+        track_nonce(&self.wallet_service, wallet_handle, PROOF_REQUEST_NONCE, &proof_req.nonce.to_dec()?, nonce_expires_at)?;
+
+        debug!("track_proof_request_nonce <<<");
+
+        Ok(())
+    }
+
+    fn proof_from_w3c(&self, presentation: W3CPresentation) -> IndyResult<String> {
+        debug!("proof_from_w3c >>> presentation: {:?}", presentation);
+
+        let proof = self.anoncreds_service.verifier.proof_from_w3c(presentation)?;
+
+        let proof_json = serde_json::to_string(&proof)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Proof")?;
+
+        debug!("proof_from_w3c <<< proof_json: {:?}", proof_json);
+
+        Ok(proof_json)
+    }
+}
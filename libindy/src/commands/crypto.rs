@@ -2,18 +2,23 @@ extern crate indy_crypto;
 extern crate serde_json;
 extern crate zeroize;
 
+use std::cell::RefCell;
 use std::collections::HashMap;
 
-use domain::crypto::key::{Key, KeyInfo, KeyMetadata};
+use domain::crypto::jws::JwsOptions;
+use domain::crypto::key::{BlsKeyInfo, DerivedKey, Key, KeyExportFormat, KeyInfo, KeyMetadata, MASTER_SEED_ID, MasterSeed};
 use domain::crypto::pack::*;
+use domain::crypto::stream::{StreamDecryptInfo, StreamHeader};
 use errors::prelude::*;
 use services::crypto::CryptoService;
-use services::wallet::{RecordOptions, WalletService};
+use services::crypto::stream::{DEFAULT_STREAM_CHUNK_SIZE, MAX_STREAM_CHUNK_SIZE, StreamDecryptor, StreamEncryptor};
+use services::wallet::{RecordOptions, SearchOptions, WalletService};
 
 use std::rc::Rc;
 use std::str;
 use utils::crypto::base64;
 use utils::crypto::chacha20poly1305_ietf;
+use utils::sequence;
 use domain::crypto::combo_box::ComboBox;
 use api::WalletHandle;
 
@@ -23,6 +28,20 @@ pub enum CryptoCommand {
         KeyInfo, // key info
         Box<Fn(IndyResult<String /*verkey*/>) + Send>,
     ),
+    CreateBlsKey(
+        WalletHandle,
+        BlsKeyInfo, // key info
+        Box<Fn(IndyResult<(String /*blskey*/, String /*blskey pop*/)>) + Send>,
+    ),
+    SetMasterSeed(
+        WalletHandle,
+        Option<String>, // master seed
+        Box<Fn(IndyResult<String /*master seed*/>) + Send>,
+    ),
+    ListDerivedKeys(
+        WalletHandle,
+        Box<Fn(IndyResult<String /*derived keys*/>) + Send>,
+    ),
     SetKeyMetadata(
         WalletHandle,
         String, // verkey
@@ -34,6 +53,20 @@ pub enum CryptoCommand {
         String, // verkey
         Box<Fn(IndyResult<String>) + Send>,
     ),
+    ExportKey(
+        WalletHandle,
+        String, // verkey
+        KeyExportFormat,
+        Option<String>, // protection key
+        Box<Fn(IndyResult<String>) + Send>,
+    ),
+    ImportKey(
+        WalletHandle,
+        String, // exported key
+        KeyExportFormat,
+        Option<String>, // protection key
+        Box<Fn(IndyResult<String /*verkey*/>) + Send>,
+    ),
     CryptoSign(
         WalletHandle,
         String,  // my vk
@@ -46,6 +79,19 @@ pub enum CryptoCommand {
         Vec<u8>, // signature
         Box<Fn(IndyResult<bool>) + Send>,
     ),
+    SignJws(
+        WalletHandle,
+        String,  // my vk
+        Vec<u8>, // payload
+        JwsOptions,
+        Box<Fn(IndyResult<String>) + Send>,
+    ),
+    VerifyJws(
+        String,  // jws
+        Option<Vec<u8>>, // detached payload
+        String, // their vk
+        Box<Fn(IndyResult<bool>) + Send>,
+    ),
     AuthenticatedEncrypt(
         WalletHandle,
         String,  // my vk
@@ -82,11 +128,60 @@ pub enum CryptoCommand {
         WalletHandle,
         Box<Fn(IndyResult<Vec<u8>>) + Send>,
     ),
+    PackMessageWithRouting(
+        Vec<u8>, // plaintext message
+        String,  // list of receiver's keys
+        String,  // list of routing keys
+        Option<String>,  // senders verkey
+        WalletHandle,
+        Box<Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
+    UnpackMessageWithRouting(
+        Vec<u8>, // JWE
+        WalletHandle,
+        Box<Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
+    StreamEncryptInit(
+        WalletHandle,
+        Option<String>, // sender vk
+        String, // recipient vk
+        Option<usize>, // chunk size
+        Box<Fn(IndyResult<(i32 /*stream handle*/, String /*header*/)>) + Send>,
+    ),
+    StreamEncryptUpdate(
+        i32, // stream handle
+        Vec<u8>, // data
+        Box<Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
+    StreamEncryptFinalize(
+        i32, // stream handle
+        Box<Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
+    StreamDecryptInit(
+        WalletHandle,
+        StreamHeader,
+        Box<Fn(IndyResult<(i32 /*stream handle*/, String /*info*/)>) + Send>,
+    ),
+    StreamDecryptUpdate(
+        i32, // stream handle
+        Vec<u8>, // data
+        Box<Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
+    StreamDecryptFinalize(
+        i32, // stream handle
+        Box<Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
+    StreamClose(
+        i32, // stream handle
+        Box<Fn(IndyResult<()>) + Send>,
+    ),
 }
 
 pub struct CryptoCommandExecutor {
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
+    encrypt_streams: RefCell<HashMap<i32, StreamEncryptor>>,
+    decrypt_streams: RefCell<HashMap<i32, StreamDecryptor>>,
 }
 
 impl CryptoCommandExecutor {
@@ -97,6 +192,8 @@ impl CryptoCommandExecutor {
         CryptoCommandExecutor {
             wallet_service,
             crypto_service,
+            encrypt_streams: RefCell::new(HashMap::new()),
+            decrypt_streams: RefCell::new(HashMap::new()),
         }
     }
 
@@ -106,6 +203,18 @@ impl CryptoCommandExecutor {
                 info!("CreateKey command received");
                 cb(self.create_key(wallet_handle, &key_info));
             }
+            CryptoCommand::CreateBlsKey(wallet_handle, key_info, cb) => {
+                info!("CreateBlsKey command received");
+                cb(self.create_bls_key(wallet_handle, &key_info));
+            }
+            CryptoCommand::SetMasterSeed(wallet_handle, seed, cb) => {
+                info!("SetMasterSeed command received");
+                cb(self.set_master_seed(wallet_handle, seed.as_ref().map(String::as_str)));
+            }
+            CryptoCommand::ListDerivedKeys(wallet_handle, cb) => {
+                info!("ListDerivedKeys command received");
+                cb(self.list_derived_keys(wallet_handle));
+            }
             CryptoCommand::SetKeyMetadata(wallet_handle, verkey, metadata, cb) => {
                 info!("SetKeyMetadata command received");
                 cb(self.set_key_metadata(wallet_handle, &verkey, &metadata));
@@ -114,6 +223,14 @@ impl CryptoCommandExecutor {
                 info!("GetKeyMetadata command received");
                 cb(self.get_key_metadata(wallet_handle, &verkey));
             }
+            CryptoCommand::ExportKey(wallet_handle, verkey, format, protection_key, cb) => {
+                info!("ExportKey command received");
+                cb(self.export_key(wallet_handle, &verkey, format, protection_key.as_ref().map(String::as_str)));
+            }
+            CryptoCommand::ImportKey(wallet_handle, key_data, format, protection_key, cb) => {
+                info!("ImportKey command received");
+                cb(self.import_key(wallet_handle, &key_data, format, protection_key.as_ref().map(String::as_str)));
+            }
             CryptoCommand::CryptoSign(wallet_handle, my_vk, msg, cb) => {
                 info!("CryptoSign command received");
                 cb(self.crypto_sign(wallet_handle, &my_vk, &msg));
@@ -122,6 +239,14 @@ impl CryptoCommandExecutor {
                 info!("CryptoVerify command received");
                 cb(self.crypto_verify(&their_vk, &msg, &signature));
             }
+            CryptoCommand::SignJws(wallet_handle, my_vk, payload, options, cb) => {
+                info!("SignJws command received");
+                cb(self.sign_jws(wallet_handle, &my_vk, &payload, &options));
+            }
+            CryptoCommand::VerifyJws(jws, detached_payload, their_vk, cb) => {
+                info!("VerifyJws command received");
+                cb(self.verify_jws(&jws, detached_payload.as_ref().map(Vec::as_slice), &their_vk));
+            }
             CryptoCommand::AuthenticatedEncrypt(wallet_handle, my_vk, their_vk, msg, cb) => {
                 info!("AuthenticatedEncrypt command received");
                 cb(self.authenticated_encrypt(wallet_handle, &my_vk, &their_vk, &msg));
@@ -146,6 +271,42 @@ impl CryptoCommandExecutor {
                 info!("UnpackMessage command received");
                 cb(self.unpack_msg(jwe_json, wallet_handle));
             }
+            CryptoCommand::PackMessageWithRouting(message, receivers, routing_keys, sender_vk, wallet_handle, cb) => {
+                info!("PackMessageWithRouting command received");
+                cb(self.pack_msg_with_routing(message, &receivers, &routing_keys, sender_vk, wallet_handle));
+            }
+            CryptoCommand::UnpackMessageWithRouting(jwe_json, wallet_handle, cb) => {
+                info!("UnpackMessageWithRouting command received");
+                cb(self.unpack_msg_with_routing(jwe_json, wallet_handle));
+            }
+            CryptoCommand::StreamEncryptInit(wallet_handle, sender_vk, recipient_vk, chunk_size, cb) => {
+                info!("StreamEncryptInit command received");
+                cb(self.stream_encrypt_init(wallet_handle, sender_vk.as_ref().map(String::as_str), &recipient_vk, chunk_size));
+            }
+            CryptoCommand::StreamEncryptUpdate(stream_handle, data, cb) => {
+                info!("StreamEncryptUpdate command received");
+                cb(self.stream_encrypt_update(stream_handle, &data));
+            }
+            CryptoCommand::StreamEncryptFinalize(stream_handle, cb) => {
+                info!("StreamEncryptFinalize command received");
+                cb(self.stream_encrypt_finalize(stream_handle));
+            }
+            CryptoCommand::StreamDecryptInit(wallet_handle, header, cb) => {
+                info!("StreamDecryptInit command received");
+                cb(self.stream_decrypt_init(wallet_handle, &header));
+            }
+            CryptoCommand::StreamDecryptUpdate(stream_handle, data, cb) => {
+                info!("StreamDecryptUpdate command received");
+                cb(self.stream_decrypt_update(stream_handle, &data));
+            }
+            CryptoCommand::StreamDecryptFinalize(stream_handle, cb) => {
+                info!("StreamDecryptFinalize command received");
+                cb(self.stream_decrypt_finalize(stream_handle));
+            }
+            CryptoCommand::StreamClose(stream_handle, cb) => {
+                info!("StreamClose command received");
+                cb(self.stream_close(stream_handle));
+            }
         };
     }
 
@@ -156,15 +317,87 @@ impl CryptoCommandExecutor {
             secret!(key_info)
         );
 
-        let key = self.crypto_service.create_key(key_info)?;
+        let derivation_path = key_info.derivation_path.clone();
+
+        let key_info = KeyInfo {
+            seed: resolve_seed(&self.wallet_service,
+                               &self.crypto_service,
+                               wallet_handle,
+                               key_info.seed.as_ref().map(String::as_str),
+                               key_info.crypto_type.as_ref().map(String::as_str),
+                               key_info.derivation_path.as_ref().map(String::as_str))?,
+            crypto_type: key_info.crypto_type.clone(),
+            derivation_path: None,
+        };
+
+        let key = self.crypto_service.create_key(&key_info)?;
         self.wallet_service
             .add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
 
+        if let Some(ref derivation_path) = derivation_path {
+            store_derived_key(&self.wallet_service, wallet_handle, &key.verkey, derivation_path, None)?;
+        }
+
         let res = key.verkey.to_string();
         debug!("create_key <<< res: {:?}", res);
         Ok(res)
     }
 
+    fn create_bls_key(&self, wallet_handle: WalletHandle, key_info: &BlsKeyInfo) -> IndyResult<(String, String)> {
+        debug!("create_bls_key >>> wallet_handle: {:?}, key_info: {:?}", wallet_handle, secret!(key_info));
+
+        let bls_key = self.crypto_service.create_bls_key(key_info)?;
+        let pop = self.crypto_service.create_bls_key_pop(&bls_key)?;
+
+        self.wallet_service
+            .add_indy_object(wallet_handle, &bls_key.verkey, &bls_key, &HashMap::new())?;
+
+        let res = (bls_key.verkey.to_string(), pop);
+        debug!("create_bls_key <<< res: {:?}", res);
+        Ok(res)
+    }
+
+    fn set_master_seed(&self, wallet_handle: WalletHandle, seed: Option<&str>) -> IndyResult<String> {
+        debug!("set_master_seed >>> wallet_handle: {:?}, seed: {:?}", wallet_handle, secret!(seed));
+
+        let master_seed = self.crypto_service.create_master_seed(seed)?;
+
+        self.wallet_service.add_indy_object(wallet_handle, MASTER_SEED_ID, &master_seed, &HashMap::new())?;
+
+        let res = master_seed.seed.clone();
+
+        debug!("set_master_seed <<< res: {:?}", secret!(&res));
+
+        Ok(res)
+    }
+
+    fn list_derived_keys(&self, wallet_handle: WalletHandle) -> IndyResult<String> {
+        debug!("list_derived_keys >>> wallet_handle: {:?}", wallet_handle);
+
+        let mut search =
+            self.wallet_service.search_indy_records::<DerivedKey>(wallet_handle, "{}", &SearchOptions::id_value())?;
+
+        let mut derived_keys: Vec<DerivedKey> = Vec::new();
+
+        while let Some(record) = search.fetch_next_record()? {
+            let derived_key_id = record.get_id();
+
+            let derived_key: DerivedKey = record.get_value()
+                .ok_or(err_msg(IndyErrorKind::InvalidState, "No value for DerivedKey record"))
+                .and_then(|derived_key_json| serde_json::from_str(derived_key_json)
+                    .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize DerivedKey: {:?}", derived_key_id)))?;
+
+            derived_keys.push(derived_key);
+        }
+
+        let res = serde_json::to_string(&derived_keys)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize derived keys list")?;
+
+        debug!("list_derived_keys <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn crypto_sign(&self, wallet_handle: WalletHandle, my_vk: &str, msg: &[u8]) -> IndyResult<Vec<u8>> {
         trace!(
             "crypto_sign >>> wallet_handle: {:?}, sender_vk: {:?}, msg: {:?}",
@@ -204,6 +437,43 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn sign_jws(&self, wallet_handle: WalletHandle, my_vk: &str, payload: &[u8], options: &JwsOptions) -> IndyResult<String> {
+        trace!(
+            "sign_jws >>> wallet_handle: {:?}, my_vk: {:?}, payload: {:?}, options: {:?}",
+            wallet_handle, my_vk, payload, options
+        );
+
+        self.crypto_service.validate_key(my_vk)?;
+
+        let key: Key = self.wallet_service.get_indy_object(
+            wallet_handle,
+            &my_vk,
+            &RecordOptions::id_value(),
+        )?;
+
+        let res = self.crypto_service.sign_jws(&key, payload, options)?;
+
+        trace!("sign_jws <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_jws(&self,
+                  jws: &str,
+                  detached_payload: Option<&[u8]>,
+                  their_vk: &str) -> IndyResult<bool> {
+        trace!(
+            "verify_jws >>> jws: {:?}, detached_payload: {:?}, their_vk: {:?}",
+            jws, detached_payload, their_vk
+        );
+
+        let res = self.crypto_service.verify_jws(jws, detached_payload, their_vk)?;
+
+        trace!("verify_jws <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     //TODO begin deprecation process this function. It will be replaced by pack
     fn authenticated_encrypt(
         &self,
@@ -356,6 +626,237 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn export_key(&self, wallet_handle: WalletHandle, verkey: &str, format: KeyExportFormat, protection_key: Option<&str>) -> IndyResult<String> {
+        debug!(
+            "export_key >>> wallet_handle: {:?}, verkey: {:?}, format: {:?}",
+            wallet_handle, verkey, format
+        );
+
+        self.crypto_service.validate_key(verkey)?;
+
+        let key: Key = self.wallet_service.get_indy_object(
+            wallet_handle,
+            &verkey,
+            &RecordOptions::id_value(),
+        )?;
+
+        let res = self.crypto_service.export_key(&key, format, protection_key)?;
+
+        debug!("export_key <<<");
+
+        Ok(res)
+    }
+
+    fn import_key(&self, wallet_handle: WalletHandle, key_data: &str, format: KeyExportFormat, protection_key: Option<&str>) -> IndyResult<String> {
+        debug!(
+            "import_key >>> wallet_handle: {:?}, format: {:?}",
+            wallet_handle, format
+        );
+
+        let key = self.crypto_service.import_key(key_data, format, protection_key)?;
+
+        self.wallet_service
+            .add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
+
+        let res = key.verkey.to_string();
+
+        debug!("import_key <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn stream_encrypt_init(&self,
+                           wallet_handle: WalletHandle,
+                           sender_vk: Option<&str>,
+                           recipient_vk: &str,
+                           chunk_size: Option<usize>) -> IndyResult<(i32, String)> {
+        trace!("stream_encrypt_init >>> wallet_handle: {:?}, sender_vk: {:?}, recipient_vk: {:?}, chunk_size: {:?}",
+               wallet_handle, sender_vk, recipient_vk, chunk_size);
+
+        self.crypto_service.validate_key(recipient_vk)?;
+
+        let chunk_size = chunk_size.unwrap_or(DEFAULT_STREAM_CHUNK_SIZE);
+
+        if chunk_size == 0 || chunk_size > MAX_STREAM_CHUNK_SIZE {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid stream chunk size: {}", chunk_size)));
+        }
+
+        let cek = chacha20poly1305_ietf::gen_key();
+        let nonce = chacha20poly1305_ietf::gen_nonce();
+
+        let (alg, encrypted_key, iv, sender) = match sender_vk {
+            Some(sender_vk) => {
+                self.crypto_service.validate_key(sender_vk)?;
+
+                let my_key: Key = self.wallet_service.get_indy_object(
+                    wallet_handle,
+                    sender_vk,
+                    &RecordOptions::id_value(),
+                )?;
+
+                let (enc_cek, iv) = self.crypto_service.crypto_box(&my_key, recipient_vk, &cek[..])?;
+                let enc_sender = self.crypto_service.crypto_box_seal(recipient_vk, sender_vk.as_bytes())?;
+
+                ("Authcrypt", enc_cek, Some(base64::encode_urlsafe(&iv)), Some(base64::encode_urlsafe(&enc_sender)))
+            }
+            None => {
+                let enc_cek = self.crypto_service.crypto_box_seal(recipient_vk, &cek[..])?;
+                ("Anoncrypt", enc_cek, None, None)
+            }
+        };
+
+        let header = StreamHeader {
+            enc: "chacha20poly1305_ietf".to_string(),
+            alg: alg.to_string(),
+            recipient: recipient_vk.to_string(),
+            encrypted_key: base64::encode_urlsafe(&encrypted_key),
+            iv,
+            sender,
+            nonce: base64::encode_urlsafe(&nonce[..]),
+            chunk_size,
+        };
+
+        let header = serde_json::to_string(&header)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize stream header")?;
+
+        let stream_handle = sequence::get_next_id();
+
+        self.encrypt_streams.borrow_mut().insert(stream_handle, StreamEncryptor::new(cek, nonce, chunk_size));
+
+        trace!("stream_encrypt_init <<< stream_handle: {:?}, header: {:?}", stream_handle, header);
+
+        Ok((stream_handle, header))
+    }
+
+    fn stream_encrypt_update(&self, stream_handle: i32, data: &[u8]) -> IndyResult<Vec<u8>> {
+        trace!("stream_encrypt_update >>> stream_handle: {:?}, data len: {:?}", stream_handle, data.len());
+
+        let mut streams = self.encrypt_streams.borrow_mut();
+
+        let stream = streams.get_mut(&stream_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidParam(2), format!("Unknown encrypt stream handle: {}", stream_handle)))?;
+
+        let res = stream.update(data)?;
+
+        trace!("stream_encrypt_update <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn stream_encrypt_finalize(&self, stream_handle: i32) -> IndyResult<Vec<u8>> {
+        trace!("stream_encrypt_finalize >>> stream_handle: {:?}", stream_handle);
+
+        let stream = self.encrypt_streams.borrow_mut().remove(&stream_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidParam(2), format!("Unknown encrypt stream handle: {}", stream_handle)))?;
+
+        let res = stream.finalize()?;
+
+        trace!("stream_encrypt_finalize <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn stream_decrypt_init(&self, wallet_handle: WalletHandle, header: &StreamHeader) -> IndyResult<(i32, String)> {
+        trace!("stream_decrypt_init >>> wallet_handle: {:?}, header: {:?}", wallet_handle, header);
+
+        if header.chunk_size == 0 || header.chunk_size > MAX_STREAM_CHUNK_SIZE {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid stream chunk size: {}", header.chunk_size)));
+        }
+
+        let my_key: Key = self.wallet_service.get_indy_object(
+            wallet_handle,
+            &header.recipient,
+            &RecordOptions::id_value(),
+        )?;
+
+        let encrypted_key = base64::decode_urlsafe(&header.encrypted_key)?;
+
+        let (cek, sender_vk) = match (header.sender.as_ref(), header.iv.as_ref()) {
+            (Some(sender), Some(iv)) => {
+                let sender_vk = self.crypto_service.crypto_box_seal_open(&my_key, &base64::decode_urlsafe(sender)?)?;
+                let sender_vk = String::from_utf8(sender_vk)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Failed to utf-8 encode sender_vk")?;
+
+                let cek = self.crypto_service.crypto_box_open(&my_key, &sender_vk, &encrypted_key, &base64::decode_urlsafe(iv)?)?;
+
+                (cek, Some(sender_vk))
+            }
+            (None, None) => (self.crypto_service.crypto_box_seal_open(&my_key, &encrypted_key)?, None),
+            _ => return Err(err_msg(IndyErrorKind::InvalidStructure, "Stream header must contain both sender and iv or none of them"))
+        };
+
+        let cek = chacha20poly1305_ietf::Key::from_slice(&cek)?;
+        let nonce = chacha20poly1305_ietf::Nonce::from_slice(&base64::decode_urlsafe(&header.nonce)?)?;
+
+        let info = StreamDecryptInfo {
+            recipient_verkey: header.recipient.clone(),
+            sender_verkey: sender_vk,
+        };
+
+        let info = serde_json::to_string(&info)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize stream info")?;
+
+        let stream_handle = sequence::get_next_id();
+
+        self.decrypt_streams.borrow_mut().insert(stream_handle, StreamDecryptor::new(cek, nonce, header.chunk_size));
+
+        trace!("stream_decrypt_init <<< stream_handle: {:?}, info: {:?}", stream_handle, info);
+
+        Ok((stream_handle, info))
+    }
+
+    fn stream_decrypt_update(&self, stream_handle: i32, data: &[u8]) -> IndyResult<Vec<u8>> {
+        trace!("stream_decrypt_update >>> stream_handle: {:?}, data len: {:?}", stream_handle, data.len());
+
+        let mut streams = self.decrypt_streams.borrow_mut();
+
+        let res = {
+            let stream = streams.get_mut(&stream_handle)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidParam(2), format!("Unknown decrypt stream handle: {}", stream_handle)))?;
+
+            stream.update(data)
+        };
+
+        // Broken stream can't be continued
+        if res.is_err() {
+            streams.remove(&stream_handle);
+        }
+
+        let res = res?;
+
+        trace!("stream_decrypt_update <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn stream_decrypt_finalize(&self, stream_handle: i32) -> IndyResult<Vec<u8>> {
+        trace!("stream_decrypt_finalize >>> stream_handle: {:?}", stream_handle);
+
+        let stream = self.decrypt_streams.borrow_mut().remove(&stream_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidParam(2), format!("Unknown decrypt stream handle: {}", stream_handle)))?;
+
+        let res = stream.finalize()?;
+
+        trace!("stream_decrypt_finalize <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn stream_close(&self, stream_handle: i32) -> IndyResult<()> {
+        trace!("stream_close >>> stream_handle: {:?}", stream_handle);
+
+        let encrypt_stream = self.encrypt_streams.borrow_mut().remove(&stream_handle);
+        let decrypt_stream = self.decrypt_streams.borrow_mut().remove(&stream_handle);
+
+        if encrypt_stream.is_none() && decrypt_stream.is_none() {
+            return Err(err_msg(IndyErrorKind::InvalidParam(2), format!("Unknown stream handle: {}", stream_handle)));
+        }
+
+        trace!("stream_close <<<");
+
+        Ok(())
+    }
+
     //TODO: Refactor pack to be more modular to version changes or crypto_scheme changes
     //this match statement is super messy, but the easiest way to comply with current architecture
     pub fn pack_msg(
@@ -504,8 +1005,19 @@ impl CryptoCommandExecutor {
     }
 
     pub fn unpack_msg(&self, jwe_json: Vec<u8>, wallet_handle: WalletHandle) -> IndyResult<Vec<u8>> {
+        let res = self._unpack_msg(&jwe_json, wallet_handle)?;
+
+        serde_json::to_vec(&res).map_err(|err| {
+            err_msg(IndyErrorKind::InvalidStructure, format!(
+                "Failed to serialize message {}",
+                err
+            ))
+        })
+    }
+
+    fn _unpack_msg(&self, jwe_json: &[u8], wallet_handle: WalletHandle) -> IndyResult<UnpackMessage> {
         //serialize JWE to struct
-        let jwe_struct: JWE = serde_json::from_slice(jwe_json.as_slice()).map_err(|err| {
+        let jwe_struct: JWE = serde_json::from_slice(jwe_json).map_err(|err| {
             err_msg(IndyErrorKind::InvalidStructure, format!(
                 "Failed to deserialize JWE {}",
                 err
@@ -546,19 +1058,112 @@ impl CryptoCommandExecutor {
             &cek,
         )?;
 
-        //serialize and return decrypted message
-        let res = UnpackMessage {
+        Ok(UnpackMessage {
             message,
             sender_verkey: sender_verkey_option,
             recipient_verkey: recipient.header.kid
+        })
+    }
+
+    /// Packs message for receivers and wraps it into anoncrypted forward envelope for each routing key.
+    /// Routing keys are ordered from the mediator closest to receivers to the outermost one.
+    /// Routed message can have only one receiver as every route is specific to its receiver.
+    pub fn pack_msg_with_routing(
+        &self,
+        message: Vec<u8>,
+        receivers: &str,
+        routing_keys: &str,
+        sender_vk: Option<String>,
+        wallet_handle: WalletHandle,
+    ) -> IndyResult<Vec<u8>> {
+        let routing_keys: Vec<String> = serde_json::from_str(routing_keys).map_err(|err| {
+            err_msg(IndyErrorKind::InvalidStructure, format!(
+                "Failed to deserialize routing keys {}",
+                err
+            ))
+        })?;
+
+        let receiver_list: Vec<String> = serde_json::from_str(receivers)
+            .to_indy(IndyErrorKind::InvalidStructure, "Failed to deserialize receiver list of keys")?;
+
+        // forward message has single `to` field, so receivers behind different routes can't share envelope
+        if !routing_keys.is_empty() && receiver_list.len() > 1 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               "Message with routing keys can be packed for a single receiver only"));
+        }
+
+        let mut packed = self.pack_msg(message, receivers, sender_vk, wallet_handle)?;
+
+        // pack_msg already checked that receivers is non empty list of keys
+        let mut to = receiver_list[0].clone();
+
+        for routing_key in routing_keys {
+            self.crypto_service.validate_key(&routing_key)?;
+
+            let msg = serde_json::from_slice(&packed)
+                .to_indy(IndyErrorKind::InvalidState, "Failed to deserialize packed message")?;
+
+            let forward = serde_json::to_vec(&ForwardMessage::new(to, msg))
+                .to_indy(IndyErrorKind::InvalidState, "Failed to serialize forward message")?;
+
+            let routing_key_list = serde_json::to_string(&vec![&routing_key])
+                .to_indy(IndyErrorKind::InvalidState, "Failed to serialize routing key")?;
+
+            packed = self.pack_msg(forward, &routing_key_list, None, wallet_handle)?;
+            to = routing_key;
+        }
+
+        Ok(packed)
+    }
+
+    /// Unpacks message and continues with forwarded messages while they are addressed to keys from the wallet.
+    pub fn unpack_msg_with_routing(&self, jwe_json: Vec<u8>, wallet_handle: WalletHandle) -> IndyResult<Vec<u8>> {
+        let mut unpacked = self._unpack_msg(&jwe_json, wallet_handle)?;
+        let mut layers: Vec<UnpackedLayer> = Vec::new();
+
+        loop {
+            let forward = serde_json::from_str::<ForwardMessage>(&unpacked.message).ok()
+                .and_then(|forward| if forward.is_forward() { Some(forward) } else { None });
+
+            layers.push(UnpackedLayer {
+                recipient_verkey: unpacked.recipient_verkey.clone(),
+                sender_verkey: unpacked.sender_verkey.clone(),
+                forward_to: forward.as_ref().map(|forward| forward.to.clone()),
+            });
+
+            let forward = match forward {
+                Some(forward) => forward,
+                None => break
+            };
+
+            if serde_json::from_value::<JWE>(forward.msg.clone()).is_err() {
+                break;
+            }
+
+            let inner_jwe = serde_json::to_vec(&forward.msg)
+                .to_indy(IndyErrorKind::InvalidState, "Failed to serialize forwarded message")?;
+
+            match self._unpack_msg(&inner_jwe, wallet_handle) {
+                Ok(inner) => unpacked = inner,
+                // forwarded message is addressed to somebody else, we are mediator
+                Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => break,
+                Err(err) => return Err(err)
+            }
+        }
+
+        let res = UnpackRoutedMessage {
+            message: unpacked.message,
+            recipient_verkey: unpacked.recipient_verkey,
+            sender_verkey: unpacked.sender_verkey,
+            layers,
         };
 
-        return serde_json::to_vec(&res).map_err(|err| {
+        serde_json::to_vec(&res).map_err(|err| {
             err_msg(IndyErrorKind::InvalidStructure, format!(
                 "Failed to serialize message {}",
                 err
             ))
-        });
+        })
     }
 
     fn _find_correct_recipient(&self, protected_struct: Protected, wallet_handle: WalletHandle) -> IndyResult<(Recipient, bool)>{
@@ -638,3 +1243,45 @@ impl CryptoCommandExecutor {
     }
 
 }
+
+// Derivation path is resolved to the seed derived from the wallet master seed
+pub fn resolve_seed(wallet_service: &WalletService,
+                    crypto_service: &CryptoService,
+                    wallet_handle: WalletHandle,
+                    seed: Option<&str>,
+                    crypto_type: Option<&str>,
+                    derivation_path: Option<&str>) -> IndyResult<Option<String>> {
+    let derivation_path = match derivation_path {
+        Some(derivation_path) => derivation_path,
+        None => return Ok(seed.map(String::from))
+    };
+
+    if seed.is_some() {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Either seed or derivation path can be specified"));
+    }
+
+    let master_seed: MasterSeed = wallet_service.get_indy_object(wallet_handle, MASTER_SEED_ID, &RecordOptions::id_value())
+        .map_err(|err| match err.kind() {
+            IndyErrorKind::WalletItemNotFound => err_msg(IndyErrorKind::WalletItemNotFound, "Master seed isn't set for the wallet"),
+            _ => err
+        })?;
+
+    crypto_service.derive_seed(&master_seed, crypto_type, derivation_path).map(Some)
+}
+
+// Derivation path is kept with the derived key, so the key can be re-derived from the backed up master seed
+pub fn store_derived_key(wallet_service: &WalletService,
+                         wallet_handle: WalletHandle,
+                         verkey: &str,
+                         derivation_path: &str,
+                         did: Option<&str>) -> IndyResult<()> {
+    let derived_key = DerivedKey {
+        verkey: verkey.to_string(),
+        derivation_path: derivation_path.to_string(),
+        did: did.map(String::from),
+    };
+
+    wallet_service.add_indy_object(wallet_handle, verkey, &derived_key, &HashMap::new())?;
+
+    Ok(())
+}
@@ -1,25 +1,31 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::str;
 
 use serde_json;
 
 use commands::{Command, CommandExecutor};
+use commands::crypto::{resolve_seed, store_derived_key};
 use commands::ledger::LedgerCommand;
-use domain::crypto::did::{Did, DidMetadata, DidWithMeta, MyDidInfo, TemporaryDid, TheirDid, TheirDidInfo};
-use domain::crypto::key::KeyInfo;
-use domain::ledger::attrib::{AttribData, Endpoint, GetAttrReplyResult};
+use domain::crypto::did::{Did, DidKeyHistory, DidMetadata, DidWithMeta, METADATA_TAG, MyDidInfo, TemporaryDid, TheirDid, TheirDidInfo};
+use domain::crypto::key::{DerivedKey, Key, KeyInfo};
+use domain::ledger::attrib::{AttribData, DidServices, Endpoint, ENDPOINT_ATTRIB, GetAttrReplyResult, Service, SERVICE_ATTRIB};
 use domain::ledger::nym::{GetNymReplyResult, GetNymResultDataV0};
+use domain::ledger::request::ProtocolVersion;
 use domain::ledger::response::Reply;
+use domain::pairwise::{MY_DID_TAG, Pairwise};
+use domain::wallet::Tags;
 use errors::prelude::*;
 use services::crypto::CryptoService;
 use services::ledger::LedgerService;
-use services::wallet::{RecordOptions, SearchOptions, WalletService};
+use services::pool::PoolService;
+use services::wallet::{RecordOptions, SearchOptions, WalletSearch, WalletService};
 use utils::crypto::base58;
 use utils::sequence;
 use api::WalletHandle;
 
+use named_type::NamedType;
 pub enum DidCommand {
     CreateAndStoreMyDid(
         WalletHandle,
@@ -45,6 +51,31 @@ pub enum DidCommand {
     ListMyDidsWithMeta(
         WalletHandle,
         Box<Fn(IndyResult<String>) + Send>),
+    SetMyDidTags(
+        WalletHandle,
+        String, // my did
+        Tags, // tags
+        Box<Fn(IndyResult<()>) + Send>),
+    SearchMyDids(
+        WalletHandle,
+        Option<String>, // query json
+        Box<Fn(IndyResult<(i32, usize)>) + Send>),
+    FetchMyDidsSearch(
+        WalletHandle,
+        i32, // search handle
+        usize, // count
+        Box<Fn(IndyResult<String>) + Send>),
+    CloseMyDidsSearch(
+        i32, // search handle
+        Box<Fn(IndyResult<()>) + Send>),
+    DeleteMyDid(
+        WalletHandle,
+        String, // my did
+        Box<Fn(IndyResult<()>) + Send>),
+    DeleteTheirDid(
+        WalletHandle,
+        String, // their did
+        Box<Fn(IndyResult<()>) + Send>),
     KeyForDid(
         i32, // pool handle
         WalletHandle,
@@ -64,6 +95,21 @@ pub enum DidCommand {
         i32, // pool handle
         String, // did
         Box<Fn(IndyResult<(String, Option<String>)>) + Send>),
+    SetServicesForDid(
+        WalletHandle,
+        String, // did
+        Vec<Service>, // services
+        Box<Fn(IndyResult<()>) + Send>),
+    GetServicesForDid(
+        WalletHandle,
+        i32, // pool handle
+        String, // did
+        Box<Fn(IndyResult<String>) + Send>),
+    BuildDidServicesAttribRequest(
+        WalletHandle,
+        String, // submitter did
+        String, // did
+        Box<Fn(IndyResult<String>) + Send>),
     SetDidMetadata(
         WalletHandle,
         String, // did
@@ -77,6 +123,24 @@ pub enum DidCommand {
         String, // did
         String, // verkey
         Box<Fn(IndyResult<String>) + Send>),
+    GetDidKeyHistory(
+        WalletHandle,
+        String, // did
+        Box<Fn(IndyResult<String>) + Send>),
+    SetDidKeyLedgerInfo(
+        WalletHandle,
+        String, // did
+        String, // verkey
+        u64, // seq no
+        u64, // txn time
+        Box<Fn(IndyResult<()>) + Send>),
+    VerifyDidSignatureAt(
+        WalletHandle,
+        String, // did (my or their)
+        u64, // timestamp
+        Vec<u8>, // message
+        Vec<u8>, // signature
+        Box<Fn(IndyResult<bool>) + Send>),
     // Internal commands
     GetNymAck(
         WalletHandle,
@@ -86,6 +150,7 @@ pub enum DidCommand {
     // Internal commands
     GetAttribAck(
         WalletHandle,
+        String, // attrib name
         IndyResult<String>, // GetAttrib Result
         i32, // deferred cmd id
     ),
@@ -104,18 +169,25 @@ pub struct DidCommandExecutor {
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
     ledger_service: Rc<LedgerService>,
+    pool_service: Rc<PoolService>,
     deferred_commands: RefCell<HashMap<i32, DidCommand>>,
+    searches: RefCell<HashMap<i32, Box<WalletSearch>>>,
+    tagged_wallets: RefCell<HashSet<WalletHandle>>,
 }
 
 impl DidCommandExecutor {
     pub fn new(wallet_service: Rc<WalletService>,
                crypto_service: Rc<CryptoService>,
-               ledger_service: Rc<LedgerService>) -> DidCommandExecutor {
+               ledger_service: Rc<LedgerService>,
+               pool_service: Rc<PoolService>) -> DidCommandExecutor {
         DidCommandExecutor {
             wallet_service,
             crypto_service,
             ledger_service,
+            pool_service,
             deferred_commands: RefCell::new(HashMap::new()),
+            searches: RefCell::new(HashMap::new()),
+            tagged_wallets: RefCell::new(HashSet::new()),
         }
     }
 
@@ -145,6 +217,30 @@ impl DidCommandExecutor {
                 info!("ListMyDidsWithMeta command received");
                 cb(self.list_my_dids_with_meta(wallet_handle));
             }
+            DidCommand::SetMyDidTags(wallet_handle, my_did, tags, cb) => {
+                info!("SetMyDidTags command received");
+                cb(self.set_my_did_tags(wallet_handle, &my_did, &tags));
+            }
+            DidCommand::SearchMyDids(wallet_handle, query_json, cb) => {
+                info!("SearchMyDids command received");
+                cb(self.search_my_dids(wallet_handle, query_json.as_ref().map(String::as_str)));
+            }
+            DidCommand::FetchMyDidsSearch(wallet_handle, search_handle, count, cb) => {
+                info!("FetchMyDidsSearch command received");
+                cb(self.fetch_my_dids_search(wallet_handle, search_handle, count));
+            }
+            DidCommand::CloseMyDidsSearch(search_handle, cb) => {
+                info!("CloseMyDidsSearch command received");
+                cb(self.close_my_dids_search(search_handle));
+            }
+            DidCommand::DeleteMyDid(wallet_handle, my_did, cb) => {
+                info!("DeleteMyDid command received");
+                cb(self.delete_my_did(wallet_handle, &my_did));
+            }
+            DidCommand::DeleteTheirDid(wallet_handle, their_did, cb) => {
+                info!("DeleteTheirDid command received");
+                cb(self.delete_their_did(wallet_handle, &their_did));
+            }
             DidCommand::KeyForDid(pool_handle, wallet_handle, did, cb) => {
                 info!("KeyForDid command received");
                 self.key_for_did(pool_handle, wallet_handle, did, cb);
@@ -161,6 +257,18 @@ impl DidCommandExecutor {
                 info!("GetEndpointForDid command received");
                 self.get_endpoint_for_did(wallet_handle, pool_handle, did, cb);
             }
+            DidCommand::SetServicesForDid(wallet_handle, did, services, cb) => {
+                info!("SetServicesForDid command received");
+                cb(self.set_services_for_did(wallet_handle, &did, services));
+            }
+            DidCommand::GetServicesForDid(wallet_handle, pool_handle, did, cb) => {
+                info!("GetServicesForDid command received");
+                self.get_services_for_did(wallet_handle, pool_handle, did, cb);
+            }
+            DidCommand::BuildDidServicesAttribRequest(wallet_handle, submitter_did, did, cb) => {
+                info!("BuildDidServicesAttribRequest command received");
+                cb(self.build_did_services_attrib_request(wallet_handle, &submitter_did, &did));
+            }
             DidCommand::SetDidMetadata(wallet_handle, did, metadata, cb) => {
                 info!("SetDidMetadata command received");
                 cb(self.set_did_metadata(wallet_handle, &did, metadata));
@@ -173,13 +281,25 @@ impl DidCommandExecutor {
                 info!("AbbreviateVerkey command received");
                 cb(self.abbreviate_verkey(&did, verkey));
             }
+            DidCommand::GetDidKeyHistory(wallet_handle, did, cb) => {
+                info!("GetDidKeyHistory command received");
+                cb(self.get_did_key_history(wallet_handle, &did));
+            }
+            DidCommand::SetDidKeyLedgerInfo(wallet_handle, did, verkey, seq_no, txn_time, cb) => {
+                info!("SetDidKeyLedgerInfo command received");
+                cb(self.set_did_key_ledger_info(wallet_handle, &did, &verkey, seq_no, txn_time));
+            }
+            DidCommand::VerifyDidSignatureAt(wallet_handle, did, timestamp, msg, signature, cb) => {
+                info!("VerifyDidSignatureAt command received");
+                cb(self.verify_did_signature_at(wallet_handle, &did, timestamp, &msg, &signature));
+            }
             DidCommand::GetNymAck(wallet_handle, result, deferred_cmd_id) => {
                 info!("GetNymAck command received");
                 self.get_nym_ack(wallet_handle, result, deferred_cmd_id);
             }
-            DidCommand::GetAttribAck(wallet_handle, result, deferred_cmd_id) => {
+            DidCommand::GetAttribAck(wallet_handle, attrib_name, result, deferred_cmd_id) => {
                 info!("GetAttribAck command received");
-                self.get_attrib_ack(wallet_handle, result, deferred_cmd_id);
+                self.get_attrib_ack(wallet_handle, &attrib_name, result, deferred_cmd_id);
             }
         };
     }
@@ -189,6 +309,19 @@ impl DidCommandExecutor {
                                my_did_info: &MyDidInfo) -> IndyResult<(String, String)> {
         debug!("create_and_store_my_did >>> wallet_handle: {:?}, my_did_info_json: {:?}", wallet_handle, secret!(my_did_info));
 
+        let derivation_path = my_did_info.derivation_path.clone();
+
+        let my_did_info = MyDidInfo {
+            seed: resolve_seed(&self.wallet_service,
+                               &self.crypto_service,
+                               wallet_handle,
+                               my_did_info.seed.as_ref().map(String::as_str),
+                               my_did_info.crypto_type.as_ref().map(String::as_str),
+                               my_did_info.derivation_path.as_ref().map(String::as_str))?,
+            derivation_path: None,
+            ..my_did_info.clone()
+        };
+
         let (did, key) = self.crypto_service.create_my_did(&my_did_info)?;
 
         if self.wallet_service.record_exists::<Did>(wallet_handle, &did.did)? {
@@ -198,6 +331,10 @@ impl DidCommandExecutor {
         self.wallet_service.add_indy_object(wallet_handle, &did.did, &did, &HashMap::new())?;
         self.wallet_service.add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
 
+        if let Some(ref derivation_path) = derivation_path {
+            store_derived_key(&self.wallet_service, wallet_handle, &key.verkey, derivation_path, Some(&did.did))?;
+        }
+
         let res = (did.did, did.verkey);
 
         debug!("create_and_store_my_did <<< res: {:?}", res);
@@ -215,12 +352,29 @@ impl DidCommandExecutor {
 
         let my_did = self._wallet_get_my_did(wallet_handle, my_did)?;
 
+        let derivation_path = key_info.derivation_path.clone();
+
+        let key_info = KeyInfo {
+            seed: resolve_seed(&self.wallet_service,
+                               &self.crypto_service,
+                               wallet_handle,
+                               key_info.seed.as_ref().map(String::as_str),
+                               key_info.crypto_type.as_ref().map(String::as_str),
+                               key_info.derivation_path.as_ref().map(String::as_str))?,
+            crypto_type: key_info.crypto_type.clone(),
+            derivation_path: None,
+        };
+
         let temporary_key = self.crypto_service.create_key(&key_info)?;
         let my_temporary_did = TemporaryDid { did: my_did.did, verkey: temporary_key.verkey.clone() };
 
         self.wallet_service.add_indy_object(wallet_handle, &temporary_key.verkey, &temporary_key, &HashMap::new())?;
         self.wallet_service.add_indy_object(wallet_handle, &my_temporary_did.did, &my_temporary_did, &HashMap::new())?;
 
+        if let Some(ref derivation_path) = derivation_path {
+            store_derived_key(&self.wallet_service, wallet_handle, &temporary_key.verkey, derivation_path, Some(&my_temporary_did.did))?;
+        }
+
         let res = my_temporary_did.verkey;
 
         debug!("replace_keys_start <<< res: {:?}", res);
@@ -239,9 +393,17 @@ impl DidCommandExecutor {
         let my_temporary_did: TemporaryDid =
             self.wallet_service.get_indy_object(wallet_handle, &my_did.did, &RecordOptions::id_value())?;
 
+        let key_history_id = DidKeyHistory::my_id(&my_did.did);
+
+        let mut key_history = self.wallet_service.get_indy_opt_object::<DidKeyHistory>(wallet_handle, &key_history_id, &RecordOptions::id_value())?
+            .unwrap_or_else(|| DidKeyHistory::new(my_did.did.clone(), my_did.verkey.clone()));
+
+        key_history.rotate(my_temporary_did.verkey.clone());
+
         let my_did = Did::from(my_temporary_did);
 
         self.wallet_service.update_indy_object(wallet_handle, &my_did.did, &my_did)?;
+        self.wallet_service.upsert_indy_object(wallet_handle, &key_history_id, &key_history)?;
         self.wallet_service.delete_indy_record::<TemporaryDid>(wallet_handle, &my_did.did)?;
 
         debug!("replace_keys_apply <<<");
@@ -256,7 +418,23 @@ impl DidCommandExecutor {
 
         let their_did = self.crypto_service.create_their_did(their_did_info)?;
 
-        self.wallet_service.add_indy_object(wallet_handle, &their_did.did, &their_did, &HashMap::new())?;
+        match self.wallet_service.get_indy_opt_object::<TheirDid>(wallet_handle, &their_did.did, &RecordOptions::id_value())? {
+            // Verkey update of known DID is recorded in the DID key history
+            Some(ref stored_did) if stored_did.verkey != their_did.verkey => {
+                let key_history_id = DidKeyHistory::their_id(&their_did.did);
+
+                let mut key_history = self.wallet_service.get_indy_opt_object::<DidKeyHistory>(wallet_handle, &key_history_id, &RecordOptions::id_value())?
+                    .unwrap_or_else(|| DidKeyHistory::new(stored_did.did.clone(), stored_did.verkey.clone()));
+                key_history.rotate(their_did.verkey.clone());
+
+                self.wallet_service.update_indy_object(wallet_handle, &their_did.did, &their_did)?;
+                self.wallet_service.upsert_indy_object(wallet_handle, &key_history_id, &key_history)?;
+            }
+            Some(_) => return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("Their DID {} is already stored", their_did.did))),
+            None => {
+                self.wallet_service.add_indy_object(wallet_handle, &their_did.did, &their_did, &HashMap::new())?;
+            }
+        }
 
         debug!("store_their_did <<<");
 
@@ -269,15 +447,7 @@ impl DidCommandExecutor {
         self.crypto_service.validate_did(&my_did)?;
 
         let did = self.wallet_service.get_indy_object::<Did>(wallet_handle, &my_did, &RecordOptions::id_value())?;
-        let metadata = self.wallet_service.get_indy_opt_object::<DidMetadata>(wallet_handle, &did.did, &RecordOptions::id_value())?;
-        let temp_verkey = self.wallet_service.get_indy_opt_object::<TemporaryDid>(wallet_handle, &did.did, &RecordOptions::id_value())?;
-
-        let did_with_meta = DidWithMeta {
-            did: did.did,
-            verkey: did.verkey,
-            temp_verkey: temp_verkey.map(|tv| tv.verkey),
-            metadata: metadata.map(|m| m.value),
-        };
+        let did_with_meta = self._wallet_get_did_with_meta(wallet_handle, did)?;
 
         let res = serde_json::to_string(&did_with_meta)
             .to_indy(IndyErrorKind::InvalidState, "Can't serialize DID")?;
@@ -303,27 +473,183 @@ impl DidCommandExecutor {
                 .and_then(|tags_json| serde_json::from_str(&tags_json)
                     .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize Did: {:?}", did_id)))?;
 
-            let metadata = self.wallet_service.get_indy_opt_object::<DidMetadata>(wallet_handle, &did.did, &RecordOptions::id_value())?;
-            let temp_verkey = self.wallet_service.get_indy_opt_object::<TemporaryDid>(wallet_handle, &did.did, &RecordOptions::id_value())?;
+            dids.push(self._wallet_get_did_with_meta(wallet_handle, did)?);
+        }
+
+        let res = serde_json::to_string(&dids)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize DIDs list")?;
+
+        debug!("list_my_dids_with_meta <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn set_my_did_tags(&self,
+                       wallet_handle: WalletHandle,
+                       my_did: &str,
+                       tags: &Tags) -> IndyResult<()> {
+        debug!("set_my_did_tags >>> wallet_handle: {:?}, my_did: {:?}, tags: {:?}", wallet_handle, my_did, tags);
+
+        self.crypto_service.validate_did(my_did)?;
+
+        if tags.contains_key(METADATA_TAG) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Tag name \"{}\" is reserved", METADATA_TAG)));
+        }
+
+        let mut tags = tags.clone();
+
+        if let Some(metadata) = self.wallet_service.get_indy_opt_object::<DidMetadata>(wallet_handle, my_did, &RecordOptions::id_value())? {
+            tags.insert(METADATA_TAG.to_string(), metadata.value);
+        }
+
+        self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix(Did::short_type_name()), my_did, &tags)?;
+
+        debug!("set_my_did_tags <<<");
+
+        Ok(())
+    }
+
+    fn search_my_dids(&self,
+                      wallet_handle: WalletHandle,
+                      query_json: Option<&str>) -> IndyResult<(i32, usize)> {
+        debug!("search_my_dids >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
 
-            let did_with_meta = DidWithMeta {
-                did: did.did,
-                verkey: did.verkey,
-                temp_verkey: temp_verkey.map(|tv| tv.verkey),
-                metadata: metadata.map(|m| m.value),
-            };
+        self._backfill_metadata_tags(wallet_handle)?;
 
-            dids.push(did_with_meta);
+        let did_search =
+            self.wallet_service.search_indy_records::<Did>(wallet_handle, query_json.unwrap_or("{}"), &SearchOptions::id_value())?;
+
+        let total_count = did_search.get_total_count()?.unwrap_or(0);
+
+        let handle = sequence::get_next_id();
+
+        self.searches.borrow_mut().insert(handle, Box::new(did_search));
+
+        let res = (handle, total_count);
+
+        debug!("search_my_dids <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn fetch_my_dids_search(&self,
+                            wallet_handle: WalletHandle,
+                            search_handle: i32,
+                            count: usize) -> IndyResult<String> {
+        trace!("fetch_my_dids_search >>> wallet_handle: {:?}, search_handle: {:?}, count: {:?}", wallet_handle, search_handle, count);
+
+        let mut searches = self.searches.borrow_mut();
+        let did_search = searches.get_mut(&search_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown DidsSearch handle: {}", search_handle)))?;
+
+        let mut dids: Vec<DidWithMeta> = Vec::new();
+
+        for _ in 0..count {
+            match did_search.fetch_next_record()? {
+                Some(did_record) => {
+                    let did_id = did_record.get_id();
+
+                    let did: Did = did_record.get_value()
+                        .ok_or(err_msg(IndyErrorKind::InvalidState, "No value for DID record"))
+                        .and_then(|did_json| serde_json::from_str(&did_json)
+                            .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize Did: {:?}", did_id)))?;
+
+                    dids.push(self._wallet_get_did_with_meta(wallet_handle, did)?);
+                }
+                None => break
+            }
         }
 
         let res = serde_json::to_string(&dids)
             .to_indy(IndyErrorKind::InvalidState, "Can't serialize DIDs list")?;
 
-        debug!("list_my_dids_with_meta <<< res: {:?}", res);
+        trace!("fetch_my_dids_search <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn close_my_dids_search(&self, search_handle: i32) -> IndyResult<()> {
+        trace!("close_my_dids_search >>> search_handle: {:?}", search_handle);
+
+        let res = match self.searches.borrow_mut().remove(&search_handle) {
+            Some(_) => Ok(()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown DidsSearch handle: {}", search_handle)))
+        }?;
+
+        trace!("close_my_dids_search <<< res: {:?}", res);
 
         Ok(res)
     }
 
+    fn delete_my_did(&self,
+                     wallet_handle: WalletHandle,
+                     my_did: &str) -> IndyResult<()> {
+        debug!("delete_my_did >>> wallet_handle: {:?}, my_did: {:?}", wallet_handle, my_did);
+
+        self.crypto_service.validate_did(my_did)?;
+
+        let my_did = self._wallet_get_my_did(wallet_handle, my_did)?;
+
+        if self._wallet_my_did_has_pairwise(wallet_handle, &my_did.did)? {
+            return Err(err_msg(IndyErrorKind::InvalidState, format!("DID is used in pairwise and can't be deleted: {}", my_did.did)));
+        }
+
+        let mut verkeys = vec![my_did.verkey.clone()];
+
+        if let Some(temporary_did) = self.wallet_service.get_indy_opt_object::<TemporaryDid>(wallet_handle, &my_did.did, &RecordOptions::id_value())? {
+            verkeys.push(temporary_did.verkey);
+        }
+
+        if let Some(key_history) = self.wallet_service.get_indy_opt_object::<DidKeyHistory>(wallet_handle, &DidKeyHistory::my_id(&my_did.did), &RecordOptions::id_value())? {
+            verkeys.extend(key_history.keys.into_iter().map(|entry| entry.verkey));
+        }
+
+        verkeys.sort();
+        verkeys.dedup();
+
+        for verkey in verkeys.iter() {
+            self._wallet_delete_opt_record::<Key>(wallet_handle, verkey)?;
+            self._wallet_delete_opt_record::<DerivedKey>(wallet_handle, verkey)?;
+        }
+
+        self._wallet_delete_opt_record::<TemporaryDid>(wallet_handle, &my_did.did)?;
+        self._wallet_delete_opt_record::<DidKeyHistory>(wallet_handle, &DidKeyHistory::my_id(&my_did.did))?;
+        self.wallet_service.delete_indy_record::<Did>(wallet_handle, &my_did.did)?;
+
+        // Metadata and endpoint records are shared with their DID of the same value
+        if !self.wallet_service.record_exists::<TheirDid>(wallet_handle, &my_did.did)? {
+            self._wallet_delete_did_records(wallet_handle, &my_did.did)?;
+        }
+
+        debug!("delete_my_did <<<");
+
+        Ok(())
+    }
+
+    fn delete_their_did(&self,
+                        wallet_handle: WalletHandle,
+                        their_did: &str) -> IndyResult<()> {
+        debug!("delete_their_did >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
+
+        self.crypto_service.validate_did(their_did)?;
+
+        if self.wallet_service.record_exists::<Pairwise>(wallet_handle, their_did)? {
+            return Err(err_msg(IndyErrorKind::InvalidState, format!("DID is used in pairwise and can't be deleted: {}", their_did)));
+        }
+
+        self.wallet_service.delete_indy_record::<TheirDid>(wallet_handle, their_did)?;
+        self._wallet_delete_opt_record::<DidKeyHistory>(wallet_handle, &DidKeyHistory::their_id(their_did))?;
+
+        // Metadata and endpoint records are shared with my DID of the same value
+        if !self.wallet_service.record_exists::<Did>(wallet_handle, their_did)? {
+            self._wallet_delete_did_records(wallet_handle, their_did)?;
+        }
+
+        debug!("delete_their_did <<<");
+
+        Ok(())
+    }
+
     fn key_for_did(&self,
                    pool_handle: i32,
                    wallet_handle: WalletHandle,
@@ -420,6 +746,7 @@ impl DidCommandExecutor {
             Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => return self._fetch_attrib_from_ledger(wallet_handle,
                                                                                                                  pool_handle,
                                                                                                                  &did,
+                                                                                                                 ENDPOINT_ATTRIB,
                                                                                                                  DidCommand::GetEndpointForDid(
                                                                                                                      wallet_handle,
                                                                                                                      pool_handle,
@@ -429,6 +756,83 @@ impl DidCommandExecutor {
         };
     }
 
+    fn set_services_for_did(&self,
+                            wallet_handle: WalletHandle,
+                            did: &str,
+                            services: Vec<Service>) -> IndyResult<()> {
+        debug!("set_services_for_did >>> wallet_handle: {:?}, did: {:?}, services: {:?}", wallet_handle, did, services);
+
+        self.crypto_service.validate_did(did)?;
+
+        for service in services.iter() {
+            if service.type_.is_empty() || service.service_endpoint.is_empty() {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Service type and endpoint must be specified"));
+            }
+
+            for key in service.recipient_keys.iter().chain(service.routing_keys.iter()) {
+                self.crypto_service.validate_key(key)?;
+            }
+        }
+
+        self.wallet_service.upsert_indy_object(wallet_handle, did, &DidServices::new(services))?;
+
+        debug!("set_services_for_did <<<");
+
+        Ok(())
+    }
+
+    fn get_services_for_did(&self,
+                            wallet_handle: WalletHandle,
+                            pool_handle: i32,
+                            did: String,
+                            cb: Box<Fn(IndyResult<String>) + Send>) {
+        debug!("get_services_for_did >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+        try_cb!(self.crypto_service.validate_did(&did), cb);
+
+        match self._wallet_get_did_services(wallet_handle, &did) {
+            Ok(Some(services)) => {
+                let res = serde_json::to_string(&services)
+                    .to_indy(IndyErrorKind::InvalidState, "Can't serialize DID services");
+
+                debug!("get_services_for_did <<< res: {:?}", res);
+
+                cb(res)
+            }
+            Ok(None) => self._fetch_attrib_from_ledger(wallet_handle,
+                                                       pool_handle,
+                                                       &did,
+                                                       SERVICE_ATTRIB,
+                                                       DidCommand::GetServicesForDid(
+                                                           wallet_handle,
+                                                           pool_handle,
+                                                           did.clone(),
+                                                           cb)),
+            Err(err) => cb(Err(err)),
+        };
+    }
+
+    fn build_did_services_attrib_request(&self,
+                                         wallet_handle: WalletHandle,
+                                         submitter_did: &str,
+                                         did: &str) -> IndyResult<String> {
+        debug!("build_did_services_attrib_request >>> wallet_handle: {:?}, submitter_did: {:?}, did: {:?}", wallet_handle, submitter_did, did);
+
+        self.crypto_service.validate_did(submitter_did)?;
+        self.crypto_service.validate_did(did)?;
+
+        let services = self._wallet_get_did_services(wallet_handle, did)?
+            .ok_or(err_msg(IndyErrorKind::WalletItemNotFound, format!("No services are set for DID {}", did)))?;
+
+        let raw = json!({ SERVICE_ATTRIB: services }).to_string();
+
+        let res = self.ledger_service.build_attrib_request(submitter_did, did, None, Some(&raw), None, ProtocolVersion::get())?;
+
+        debug!("build_did_services_attrib_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn set_did_metadata(&self,
                         wallet_handle: WalletHandle,
                         did: &str,
@@ -441,6 +845,17 @@ impl DidCommandExecutor {
 
         self.wallet_service.upsert_indy_object(wallet_handle, &did, &metadata)?;
 
+        if self.wallet_service.record_exists::<Did>(wallet_handle, did)? {
+            let mut tags = self.wallet_service.get_indy_record::<Did>(wallet_handle, did, &json!({"retrieveTags": true}).to_string())?
+                .get_tags()
+                .cloned()
+                .unwrap_or_default();
+
+            tags.insert(METADATA_TAG.to_string(), metadata.value);
+
+            self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix(Did::short_type_name()), did, &tags)?;
+        }
+
         debug!("set_did_metadata >>>");
 
         Ok(())
@@ -486,6 +901,71 @@ impl DidCommandExecutor {
         Ok(res)
     }
 
+    fn get_did_key_history(&self,
+                           wallet_handle: WalletHandle,
+                           did: &str) -> IndyResult<String> {
+        debug!("get_did_key_history >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+        self.crypto_service.validate_did(did)?;
+
+        let (_, key_history) = self._wallet_get_did_key_history(wallet_handle, did)?;
+
+        let res = serde_json::to_string(&key_history.keys)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize DID key history")?;
+
+        debug!("get_did_key_history <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn set_did_key_ledger_info(&self,
+                               wallet_handle: WalletHandle,
+                               did: &str,
+                               verkey: &str,
+                               seq_no: u64,
+                               txn_time: u64) -> IndyResult<()> {
+        debug!("set_did_key_ledger_info >>> wallet_handle: {:?}, did: {:?}, verkey: {:?}, seq_no: {:?}, txn_time: {:?}",
+               wallet_handle, did, verkey, seq_no, txn_time);
+
+        self.crypto_service.validate_did(did)?;
+        self.crypto_service.validate_key(verkey)?;
+
+        let (key_history_id, mut key_history) = self._wallet_get_did_key_history(wallet_handle, did)?;
+
+        if !key_history.confirm(verkey, seq_no, txn_time) {
+            return Err(err_msg(IndyErrorKind::WalletItemNotFound, format!("Verkey {} has never been used by DID {}", verkey, did)));
+        }
+
+        self.wallet_service.upsert_indy_object(wallet_handle, &key_history_id, &key_history)?;
+
+        debug!("set_did_key_ledger_info <<<");
+
+        Ok(())
+    }
+
+    fn verify_did_signature_at(&self,
+                               wallet_handle: WalletHandle,
+                               did: &str,
+                               timestamp: u64,
+                               msg: &[u8],
+                               signature: &[u8]) -> IndyResult<bool> {
+        debug!("verify_did_signature_at >>> wallet_handle: {:?}, did: {:?}, timestamp: {:?}, msg: {:?}, signature: {:?}",
+               wallet_handle, did, timestamp, msg, signature);
+
+        self.crypto_service.validate_did(did)?;
+
+        let (_, key_history) = self._wallet_get_did_key_history(wallet_handle, did)?;
+
+        let res = match key_history.verkey_at(timestamp) {
+            Some(verkey) => self.crypto_service.verify(verkey, msg, signature)?,
+            None => false
+        };
+
+        debug!("verify_did_signature_at <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn get_nym_ack(&self,
                    wallet_handle: WalletHandle,
                    get_nym_reply_result: IndyResult<String>,
@@ -527,9 +1007,34 @@ impl DidCommandExecutor {
 
     fn get_attrib_ack(&self,
                       wallet_handle: WalletHandle,
+                      attrib_name: &str,
                       get_attrib_reply_result: IndyResult<String>,
                       deferred_cmd_id: i32) {
         let res = self._get_attrib_ack(wallet_handle, get_attrib_reply_result);
+
+        // DIDs that have published only the legacy endpoint have no service attrib on the ledger
+        if let Err(ref err) = res {
+            if err.kind() == IndyErrorKind::LedgerItemNotFound && attrib_name == SERVICE_ATTRIB {
+                let deferred_cmd = self.deferred_commands.borrow_mut().remove(&deferred_cmd_id);
+
+                if let Some(DidCommand::GetServicesForDid(wallet_handle, pool_handle, did, cb)) = deferred_cmd {
+                    return self._fetch_attrib_from_ledger(wallet_handle,
+                                                          pool_handle,
+                                                          &did,
+                                                          ENDPOINT_ATTRIB,
+                                                          DidCommand::GetServicesForDid(
+                                                              wallet_handle,
+                                                              pool_handle,
+                                                              did.clone(),
+                                                              cb));
+                }
+
+                if let Some(deferred_cmd) = deferred_cmd {
+                    self.deferred_commands.borrow_mut().insert(deferred_cmd_id, deferred_cmd);
+                }
+            }
+        }
+
         self._execute_deferred_command(deferred_cmd_id, res.err());
     }
 
@@ -538,20 +1043,33 @@ impl DidCommandExecutor {
 
         let get_attrib_reply = get_attrib_reply_result?;
 
-        let get_attrib_reply: Reply<GetAttrReplyResult> = serde_json::from_str(&get_attrib_reply)
-            .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttrReplyResult json")?;
+        let get_attrib_reply: Reply<GetAttrReplyResult> = LedgerService::parse_response(&get_attrib_reply)?;
 
         let (raw, did) = match get_attrib_reply.result() {
-            GetAttrReplyResult::GetAttrReplyResultV0(res) => (res.data, res.dest),
-            GetAttrReplyResult::GetAttrReplyResultV1(res) => (res.txn.data.raw, res.txn.data.did)
+            GetAttrReplyResult::GetAttrReplyResultV0(res) => (
+                res.data.ok_or(err_msg(IndyErrorKind::LedgerItemNotFound, "Attribute isn't found on the ledger"))?,
+                res.dest
+            ),
+            GetAttrReplyResult::GetAttrReplyResultV1(res) => (
+                res.txn.data.raw.ok_or(err_msg(IndyErrorKind::LedgerItemNotFound, "Attribute isn't found on the ledger"))?,
+                res.txn.data.did
+            )
         };
 
         let attrib_data: AttribData = serde_json::from_str(&raw)
             .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttReply json")?;
 
-        let endpoint = Endpoint::new(attrib_data.endpoint.ha, attrib_data.endpoint.verkey);
+        match (attrib_data.endpoint, attrib_data.service) {
+            (_, Some(services)) => {
+                self.wallet_service.upsert_indy_object(wallet_handle, &did, &DidServices::new(services))?;
+            }
+            (Some(endpoint), None) => {
+                let endpoint = Endpoint::new(endpoint.ha, endpoint.verkey);
 
-        self.wallet_service.add_indy_object(wallet_handle, &did, &endpoint, &HashMap::new())?;
+                self.wallet_service.add_indy_object(wallet_handle, &did, &endpoint, &HashMap::new())?;
+            }
+            (None, None) => return Err(err_msg(IndyErrorKind::InvalidState, "Invalid GetAttReply json"))
+        }
 
         trace!("_get_attrib_ack <<<");
 
@@ -596,6 +1114,9 @@ impl DidCommandExecutor {
             DidCommand::GetEndpointForDid(_, _, _, cb) => {
                 return cb(Err(err));
             }
+            DidCommand::GetServicesForDid(_, _, _, cb) => {
+                return cb(Err(err));
+            }
             _ => {}
         }
     }
@@ -607,8 +1128,13 @@ impl DidCommandExecutor {
         let deferred_cmd_id = self._defer_command(deferred_cmd);
 
         // TODO we need passing of my_did as identifier
-        // TODO: FIXME: Remove this unwrap by sending GetNymAck with the error.
-        let get_nym_request = self.ledger_service.build_get_nym_request(None, did).unwrap();
+        let get_nym_request = self.pool_service.get_protocol_version(pool_handle)
+            .and_then(|protocol_version| self.ledger_service.build_get_nym_request(None, did, protocol_version));
+
+        let get_nym_request = match get_nym_request {
+            Ok(get_nym_request) => get_nym_request,
+            Err(err) => return self.get_nym_ack(wallet_handle, Err(err), deferred_cmd_id)
+        };
 
         CommandExecutor::instance()
             .send(Command::Ledger(LedgerCommand::SubmitRequest(
@@ -627,13 +1153,20 @@ impl DidCommandExecutor {
 
     fn _fetch_attrib_from_ledger(&self,
                                  wallet_handle: WalletHandle, pool_handle: i32,
-                                 did: &str, deferred_cmd: DidCommand) {
+                                 did: &str, raw: &str, deferred_cmd: DidCommand) {
         // Defer this command until their did is fetched from ledger.
         let deferred_cmd_id = self._defer_command(deferred_cmd);
 
         // TODO we need passing of my_did as identifier
-        // TODO: FIXME: Remove this unwrap by sending GetAttribAck with the error.
-        let get_attrib_request = self.ledger_service.build_get_attrib_request(None, did, Some("endpoint"), None, None).unwrap();
+        let get_attrib_request = self.pool_service.get_protocol_version(pool_handle)
+            .and_then(|protocol_version| self.ledger_service.build_get_attrib_request(None, did, Some(raw), None, None, protocol_version));
+
+        let get_attrib_request = match get_attrib_request {
+            Ok(get_attrib_request) => get_attrib_request,
+            Err(err) => return self.get_attrib_ack(wallet_handle, raw, Err(err), deferred_cmd_id)
+        };
+
+        let attrib_name = raw.to_string();
 
         CommandExecutor::instance()
             .send(Command::Ledger(LedgerCommand::SubmitRequest(
@@ -643,6 +1176,7 @@ impl DidCommandExecutor {
                     CommandExecutor::instance()
                         .send(Command::Did(DidCommand::GetAttribAck(
                             wallet_handle,
+                            attrib_name.clone(),
                             result,
                             deferred_cmd_id,
                         ))).unwrap();
@@ -657,4 +1191,126 @@ impl DidCommandExecutor {
     fn _wallet_get_their_did(&self, wallet_handle: WalletHandle, their_did: &str) -> IndyResult<TheirDid> {
         self.wallet_service.get_indy_object(wallet_handle, &their_did, &RecordOptions::id_value())
     }
+
+    fn _wallet_get_did_with_meta(&self, wallet_handle: WalletHandle, did: Did) -> IndyResult<DidWithMeta> {
+        let metadata = self.wallet_service.get_indy_opt_object::<DidMetadata>(wallet_handle, &did.did, &RecordOptions::id_value())?;
+        let temp_verkey = self.wallet_service.get_indy_opt_object::<TemporaryDid>(wallet_handle, &did.did, &RecordOptions::id_value())?;
+
+        Ok(DidWithMeta {
+            did: did.did,
+            verkey: did.verkey,
+            temp_verkey: temp_verkey.map(|tv| tv.verkey),
+            metadata: metadata.map(|m| m.value),
+        })
+    }
+
+    // Legacy endpoint is represented as a single service if no services are set
+    fn _wallet_get_did_services(&self, wallet_handle: WalletHandle, did: &str) -> IndyResult<Option<Vec<Service>>> {
+        if let Some(did_services) = self.wallet_service.get_indy_opt_object::<DidServices>(wallet_handle, did, &RecordOptions::id_value())? {
+            return Ok(Some(did_services.services));
+        }
+
+        let endpoint = self.wallet_service.get_indy_opt_object::<Endpoint>(wallet_handle, did, &RecordOptions::id_value())?;
+
+        Ok(endpoint.map(|endpoint| vec![Service::from(endpoint)]))
+    }
+
+    // Pairwise records created before tags support have no "my_did" tag, so all of them are checked.
+    // Pairwise records created before tags support have no "my_did" tag, so they are matched as well and checked by value.
+    fn _wallet_my_did_has_pairwise(&self, wallet_handle: WalletHandle, my_did: &str) -> IndyResult<bool> {
+        let query_json = json!({"$or": [
+            {MY_DID_TAG: my_did},
+            {"$not": {MY_DID_TAG: {"$neq": ""}}}
+        ]}).to_string();
+
+        let mut pairwise_search =
+            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, &query_json, &RecordOptions::id_value())?;
+
+        while let Some(pairwise_record) = pairwise_search.fetch_next_record()? {
+            let pairwise_id = pairwise_record.get_id();
+
+            let pairwise: Pairwise = pairwise_record.get_value()
+                .ok_or(err_msg(IndyErrorKind::InvalidState, "No value for Pairwise record"))
+                .and_then(|pairwise_json| serde_json::from_str(pairwise_json)
+                    .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize Pairwise: {:?}", pairwise_id)))?;
+
+            if pairwise.my_did == my_did {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    // DID metadata set before it became searchable isn't present in tags of my DID record.
+    // Set the reserved tag of such records once per opened wallet before the first search.
+    fn _backfill_metadata_tags(&self, wallet_handle: WalletHandle) -> IndyResult<()> {
+        if self.tagged_wallets.borrow().contains(&wallet_handle) {
+            return Ok(());
+        }
+
+        // Search is finished before tags are updated as storage can't be modified during iteration
+        let untagged = {
+            let mut did_search =
+                self.wallet_service.search_indy_records::<Did>(wallet_handle, "{}", &json!({"retrieveValue": false, "retrieveTags": true}).to_string())?;
+
+            let mut untagged: Vec<(String, Tags)> = Vec::new();
+
+            while let Some(did_record) = did_search.fetch_next_record()? {
+                let mut tags = did_record.get_tags().cloned().unwrap_or_default();
+
+                if tags.contains_key(METADATA_TAG) {
+                    continue;
+                }
+
+                let did_id = did_record.get_id();
+
+                if let Some(metadata) = self.wallet_service.get_indy_opt_object::<DidMetadata>(wallet_handle, did_id, &RecordOptions::id_value())? {
+                    tags.insert(METADATA_TAG.to_string(), metadata.value);
+                    untagged.push((did_id.to_string(), tags));
+                }
+            }
+
+            untagged
+        };
+
+        let type_ = self.wallet_service.add_prefix(Did::short_type_name());
+
+        for (did, tags) in untagged {
+            self.wallet_service.update_record_tags(wallet_handle, &type_, &did, &tags)?;
+        }
+
+        self.tagged_wallets.borrow_mut().insert(wallet_handle);
+
+        Ok(())
+    }
+
+    fn _wallet_delete_did_records(&self, wallet_handle: WalletHandle, did: &str) -> IndyResult<()> {
+        self._wallet_delete_opt_record::<DidMetadata>(wallet_handle, did)?;
+        self._wallet_delete_opt_record::<Endpoint>(wallet_handle, did)?;
+        self._wallet_delete_opt_record::<DidServices>(wallet_handle, did)?;
+        Ok(())
+    }
+
+    fn _wallet_delete_opt_record<T>(&self, wallet_handle: WalletHandle, id: &str) -> IndyResult<()> where T: NamedType {
+        match self.wallet_service.delete_indy_record::<T>(wallet_handle, id) {
+            Ok(()) => Ok(()),
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+
+    // DIDs that have never been rotated have no stored history, their current key is valid at any time.
+    // As in key_for_local_did my DID takes precedence over their DID of the same value.
+    fn _wallet_get_did_key_history(&self, wallet_handle: WalletHandle, did: &str) -> IndyResult<(String, DidKeyHistory)> {
+        let (key_history_id, verkey) = match self.wallet_service.get_indy_opt_object::<Did>(wallet_handle, did, &RecordOptions::id_value())? {
+            Some(my_did) => (DidKeyHistory::my_id(did), my_did.verkey),
+            None => (DidKeyHistory::their_id(did), self._wallet_get_their_did(wallet_handle, did)?.verkey)
+        };
+
+        let key_history = self.wallet_service.get_indy_opt_object::<DidKeyHistory>(wallet_handle, &key_history_id, &RecordOptions::id_value())?
+            .unwrap_or_else(|| DidKeyHistory::new(did.to_string(), verkey));
+
+        Ok((key_history_id, key_history))
+    }
 }
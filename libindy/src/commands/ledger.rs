@@ -11,8 +11,13 @@ use domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefini
 use domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use domain::anoncreds::schema::{Schema, SchemaV1};
 use domain::crypto::did::Did;
-use domain::crypto::key::Key;
+use domain::crypto::key::{BlsKey, Key};
+use domain::ledger::auth_rule::Constraint;
+use domain::ledger::custom_txn::{CustomTxnKind, CustomTxnType};
 use domain::ledger::node::NodeOperationData;
+use domain::ledger::request::ProtocolVersion;
+use domain::ledger::signing_bundle::{SignerInfo, SigningBundle};
+use domain::pool::LedgerMirrorQuery;
 use errors::prelude::*;
 use services::crypto::CryptoService;
 use services::ledger::LedgerService;
@@ -23,6 +28,7 @@ use services::pool::{
 use services::wallet::{RecordOptions, WalletService};
 use utils::crypto::base58;
 use utils::crypto::signature_serializer::serialize_signature;
+use utils::crypto::verkey_builder::build_full_verkey;
 use api::WalletHandle;
 
 pub enum LedgerCommand {
@@ -46,6 +52,15 @@ pub enum LedgerCommand {
         Option<String>, // nodes
         Option<i32>, // timeout
         Box<Fn(IndyResult<String>) + Send>),
+    SyncLedgerMirror(
+        i32, // pool handle
+        String, // ledger type
+        Box<Fn(IndyResult<String>) + Send>),
+    SearchLedgerMirror(
+        i32, // pool handle
+        String, // ledger type
+        String, // query json
+        Box<Fn(IndyResult<String>) + Send>),
     SignRequest(
         WalletHandle,
         String, // submitter did
@@ -56,6 +71,46 @@ pub enum LedgerCommand {
         String, // submitter did
         String, // request json
         Box<Fn(IndyResult<String>) + Send>),
+    PrepareRequestForPool(
+        i32, // pool handle
+        String, // request json
+        Box<Fn(IndyResult<String>) + Send>),
+    SignRequestForPool(
+        i32, // pool handle
+        WalletHandle,
+        String, // submitter did
+        String, // request json
+        Box<Fn(IndyResult<String>) + Send>),
+    MultiSignRequestForPool(
+        i32, // pool handle
+        WalletHandle,
+        String, // submitter did
+        String, // request json
+        Box<Fn(IndyResult<String>) + Send>),
+    SetRequestReqId(
+        String, // request json
+        u64, // req id
+        Box<Fn(IndyResult<String>) + Send>),
+    AppendRequestEndorser(
+        String, // request json
+        String, // endorser did
+        Box<Fn(IndyResult<String>) + Send>),
+    BuildSigningBundle(
+        String, // request json
+        HashMap<String, SignerInfo>, // signers
+        Option<Constraint>, // constraint
+        Box<Fn(IndyResult<String>) + Send>),
+    SignSigningBundle(
+        WalletHandle,
+        String, // signer did
+        SigningBundle, // bundle
+        Box<Fn(IndyResult<String>) + Send>),
+    MergeSigningBundles(
+        Vec<SigningBundle>, // bundles
+        Box<Fn(IndyResult<String>) + Send>),
+    CheckSigningBundle(
+        SigningBundle, // bundle
+        Box<Fn(IndyResult<String>) + Send>),
     BuildGetDdoRequest(
         Option<String>, // submitter did
         String, // target did
@@ -112,6 +167,13 @@ pub enum LedgerCommand {
         String, // target_did
         NodeOperationData, // data
         Box<Fn(IndyResult<String>) + Send>),
+    BuildNodeRequestWithBlsKey(
+        WalletHandle,
+        String, // submitter did
+        String, // target_did
+        NodeOperationData, // data
+        String, // blskey
+        Box<Fn(IndyResult<String>) + Send>),
     BuildGetValidatorInfoRequest(
         String, // submitter did
         Box<Fn(IndyResult<String>) + Send>),
@@ -177,11 +239,36 @@ pub enum LedgerCommand {
     ParseGetRevocRegDeltaResponse(
         String, // get revocation registry delta response
         Box<Fn(IndyResult<(String, String, u64)>) + Send>),
+    ParseGetNymResponse(
+        String, // get nym response
+        Box<Fn(IndyResult<String>) + Send>),
+    ParseGetAttribResponse(
+        String, // get attrib response
+        Box<Fn(IndyResult<String>) + Send>),
+    ParseGetTxnResponse(
+        String, // get txn response
+        Box<Fn(IndyResult<String>) + Send>),
+    ParseGetValidatorInfoResponse(
+        String, // get validator info response
+        Box<Fn(IndyResult<String>) + Send>),
+    GetPoolStatus(
+        i32, // pool handle
+        WalletHandle,
+        String, // submitter did
+        Box<Fn(IndyResult<String>) + Send>),
     RegisterSPParser(
         String, // txn type
         CustomTransactionParser,
         CustomFree,
         Box<Fn(IndyResult<()>) + Send>),
+    RegisterCustomTxnType(
+        CustomTxnType,
+        Box<Fn(IndyResult<()>) + Send>),
+    BuildCustomRequest(
+        Option<String>, // submitter did
+        String, // txn type
+        String, // operation json
+        Box<Fn(IndyResult<String>) + Send>),
     GetResponseMetadata(
         String, // response
         Box<Fn(IndyResult<String>) + Send>),
@@ -251,10 +338,26 @@ impl LedgerCommandExecutor {
                 info!(target: "ledger_command_executor", "SubmitRequest command received");
                 self.submit_action(handle, &request_json, nodes.as_ref().map(String::as_str), timeout, cb);
             }
+            LedgerCommand::SyncLedgerMirror(handle, ledger_type, cb) => {
+                info!(target: "ledger_command_executor", "SyncLedgerMirror command received");
+                self.sync_ledger_mirror(handle, &ledger_type, cb);
+            }
+            LedgerCommand::SearchLedgerMirror(handle, ledger_type, query_json, cb) => {
+                info!(target: "ledger_command_executor", "SearchLedgerMirror command received");
+                cb(self.search_ledger_mirror(handle, &ledger_type, &query_json));
+            }
             LedgerCommand::RegisterSPParser(txn_type, parser, free, cb) => {
                 info!(target: "ledger_command_executor", "RegisterSPParser command received");
                 cb(self.register_sp_parser(&txn_type, parser, free));
             }
+            LedgerCommand::RegisterCustomTxnType(txn_type, cb) => {
+                info!(target: "ledger_command_executor", "RegisterCustomTxnType command received");
+                cb(self.register_custom_txn_type(txn_type));
+            }
+            LedgerCommand::BuildCustomRequest(submitter_did, txn_type, operation, cb) => {
+                info!(target: "ledger_command_executor", "BuildCustomRequest command received");
+                cb(self.build_custom_request(submitter_did.as_ref().map(String::as_str), &txn_type, &operation));
+            }
             LedgerCommand::SignRequest(wallet_handle, submitter_did, request_json, cb) => {
                 info!(target: "ledger_command_executor", "SignRequest command received");
                 cb(self.sign_request(wallet_handle, &submitter_did, &request_json));
@@ -263,6 +366,42 @@ impl LedgerCommandExecutor {
                 info!(target: "ledger_command_executor", "MultiSignRequest command received");
                 cb(self.multi_sign_request(wallet_handle, &submitter_did, &request_json));
             }
+            LedgerCommand::PrepareRequestForPool(pool_handle, request_json, cb) => {
+                info!(target: "ledger_command_executor", "PrepareRequestForPool command received");
+                cb(self.prepare_request_for_pool(pool_handle, &request_json));
+            }
+            LedgerCommand::SignRequestForPool(pool_handle, wallet_handle, submitter_did, request_json, cb) => {
+                info!(target: "ledger_command_executor", "SignRequestForPool command received");
+                cb(self.sign_request_for_pool(pool_handle, wallet_handle, &submitter_did, &request_json));
+            }
+            LedgerCommand::MultiSignRequestForPool(pool_handle, wallet_handle, submitter_did, request_json, cb) => {
+                info!(target: "ledger_command_executor", "MultiSignRequestForPool command received");
+                cb(self.multi_sign_request_for_pool(pool_handle, wallet_handle, &submitter_did, &request_json));
+            }
+            LedgerCommand::SetRequestReqId(request_json, req_id, cb) => {
+                info!(target: "ledger_command_executor", "SetRequestReqId command received");
+                cb(self.set_request_req_id(&request_json, req_id));
+            }
+            LedgerCommand::AppendRequestEndorser(request_json, endorser_did, cb) => {
+                info!(target: "ledger_command_executor", "AppendRequestEndorser command received");
+                cb(self.append_request_endorser(&request_json, &endorser_did));
+            }
+            LedgerCommand::BuildSigningBundle(request_json, signers, constraint, cb) => {
+                info!(target: "ledger_command_executor", "BuildSigningBundle command received");
+                cb(self.build_signing_bundle(&request_json, &signers, constraint.as_ref()));
+            }
+            LedgerCommand::SignSigningBundle(wallet_handle, signer_did, bundle, cb) => {
+                info!(target: "ledger_command_executor", "SignSigningBundle command received");
+                cb(self.sign_signing_bundle(wallet_handle, &signer_did, bundle));
+            }
+            LedgerCommand::MergeSigningBundles(bundles, cb) => {
+                info!(target: "ledger_command_executor", "MergeSigningBundles command received");
+                cb(self.merge_signing_bundles(bundles));
+            }
+            LedgerCommand::CheckSigningBundle(bundle, cb) => {
+                info!(target: "ledger_command_executor", "CheckSigningBundle command received");
+                cb(self.check_signing_bundle(&bundle));
+            }
             LedgerCommand::BuildGetDdoRequest(submitter_did, target_did, cb) => {
                 info!(target: "ledger_command_executor", "BuildGetDdoRequest command received");
                 cb(self.build_get_ddo_request(submitter_did.as_ref().map(String::as_str), &target_did));
@@ -320,6 +459,10 @@ impl LedgerCommandExecutor {
                 info!(target: "ledger_command_executor", "BuildNodeRequest command received");
                 cb(self.build_node_request(&submitter_did, &target_did, data));
             }
+            LedgerCommand::BuildNodeRequestWithBlsKey(wallet_handle, submitter_did, target_did, data, blskey, cb) => {
+                info!(target: "ledger_command_executor", "BuildNodeRequestWithBlsKey command received");
+                cb(self.build_node_request_with_bls_key(wallet_handle, &submitter_did, &target_did, data, &blskey));
+            }
             LedgerCommand::BuildGetValidatorInfoRequest(submitter_did, cb) => {
                 info!(target: "ledger_command_executor", "BuildGetValidatorInfoRequest command received");
                 cb(self.build_get_validator_info_request(&submitter_did));
@@ -375,6 +518,26 @@ impl LedgerCommandExecutor {
                 info!(target: "ledger_command_executor", "ParseGetRevocRegDeltaResponse command received");
                 cb(self.parse_revoc_reg_delta_response(&get_revoc_reg_delta_response));
             }
+            LedgerCommand::ParseGetNymResponse(get_nym_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetNymResponse command received");
+                cb(self.parse_get_nym_response(&get_nym_response));
+            }
+            LedgerCommand::ParseGetAttribResponse(get_attrib_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetAttribResponse command received");
+                cb(self.parse_get_attrib_response(&get_attrib_response));
+            }
+            LedgerCommand::ParseGetTxnResponse(get_txn_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetTxnResponse command received");
+                cb(self.parse_get_txn_response(&get_txn_response));
+            }
+            LedgerCommand::ParseGetValidatorInfoResponse(get_validator_info_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetValidatorInfoResponse command received");
+                cb(self.parse_get_validator_info_response(&get_validator_info_response));
+            }
+            LedgerCommand::GetPoolStatus(pool_handle, wallet_handle, submitter_did, cb) => {
+                info!(target: "ledger_command_executor", "GetPoolStatus command received");
+                self.get_pool_status(pool_handle, wallet_handle, &submitter_did, cb);
+            }
             LedgerCommand::GetResponseMetadata(response, cb) => {
                 info!(target: "ledger_command_executor", "GetResponseMetadata command received");
                 cb(self.get_response_metadata(&response));
@@ -400,10 +563,44 @@ impl LedgerCommandExecutor {
         debug!("register_sp_parser >>> txn_type: {:?}, parser: {:?}, free: {:?}",
                txn_type, parser, free);
 
-        PoolService::register_sp_parser(txn_type, parser, free)
+        // Replies contain transaction type code so the parser is registered by code of custom transaction type
+        let txn_type = match LedgerService::get_custom_txn_type(txn_type) {
+            Some(ref custom_txn_type) if custom_txn_type.kind == CustomTxnKind::Write =>
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("State proof parser can't be registered for write transaction type {}", custom_txn_type.name))),
+            Some(custom_txn_type) => custom_txn_type.code,
+            None => txn_type.to_string()
+        };
+
+        PoolService::register_sp_parser(&txn_type, parser, free)
             .map_err(IndyError::from)
     }
 
+    fn register_custom_txn_type(&self, txn_type: CustomTxnType) -> IndyResult<()> {
+        debug!("register_custom_txn_type >>> txn_type: {:?}", txn_type);
+
+        LedgerService::register_custom_txn_type(txn_type)?;
+
+        debug!("register_custom_txn_type <<<");
+
+        Ok(())
+    }
+
+    fn build_custom_request(&self,
+                            submitter_did: Option<&str>,
+                            txn_type: &str,
+                            operation: &str) -> IndyResult<String> {
+        debug!("build_custom_request >>> submitter_did: {:?}, txn_type: {:?}, operation: {:?}", submitter_did, txn_type, operation);
+
+        self.validate_opt_did(submitter_did)?;
+
+        let res = self.ledger_service.build_custom_request(submitter_did, txn_type, operation, ProtocolVersion::get())?;
+
+        debug!("build_custom_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn sign_and_submit_request(&self,
                                pool_handle: i32,
                                wallet_handle: WalletHandle,
@@ -413,7 +610,10 @@ impl LedgerCommandExecutor {
         debug!("sign_and_submit_request >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}",
                pool_handle, wallet_handle, submitter_did, request_json);
 
-        match self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single) {
+        let res = self._prepare_request_for_pool(pool_handle, request_json)
+            .and_then(|request_json| self._sign_request(wallet_handle, submitter_did, &request_json, SignatureType::Single));
+
+        match res {
             Ok(signed_request) => self.submit_request(pool_handle, signed_request.as_str(), cb),
             Err(err) => cb(Err(err))
         }
@@ -448,6 +648,15 @@ impl LedgerCommandExecutor {
                         if !request.contains_key("signatures") {
                             request.insert("signatures".to_string(), Value::Object(serde_json::Map::new()));
                         }
+
+                        // Author could sign the request before it was passed to the endorser
+                        if request.contains_key("endorser") {
+                            if let (Some(Value::String(identifier)), Some(author_signature)) =
+                            (request.get("identifier").cloned(), request.remove("signature")) {
+                                request["signatures"].as_object_mut().unwrap().insert(identifier, author_signature);
+                            }
+                        }
+
                         request["signatures"].as_object_mut().unwrap().insert(submitter_did.to_string(), Value::String(base58::encode(&signature)));
                     });
             }
@@ -467,7 +676,8 @@ impl LedgerCommandExecutor {
                       cb: Box<Fn(IndyResult<String>) + Send>) {
         debug!("submit_request >>> handle: {:?}, request_json: {:?}", handle, request_json);
 
-        let x: IndyResult<i32> = self.pool_service.send_tx(handle, request_json);
+        let x: IndyResult<i32> = self._prepare_request_for_pool(handle, request_json)
+            .and_then(|request_json| self.pool_service.send_tx(handle, &request_json));
         match x {
             Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
             Err(err) => { cb(Err(err)); }
@@ -486,13 +696,76 @@ impl LedgerCommandExecutor {
             return cb(Err(err));
         }
 
-        let x: IndyResult<i32> = self.pool_service.send_action(handle, request_json, nodes, timeout);
+        let x: IndyResult<i32> = self._prepare_request_for_pool(handle, request_json)
+            .and_then(|request_json| self.pool_service.send_action(handle, &request_json, nodes, timeout));
         match x {
             Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
             Err(err) => { cb(Err(err)); }
         };
     }
 
+    fn sync_ledger_mirror(&self,
+                          handle: i32,
+                          ledger_type: &str,
+                          cb: Box<Fn(IndyResult<String>) + Send>) {
+        debug!("sync_ledger_mirror >>> handle: {:?}, ledger_type: {:?}", handle, ledger_type);
+
+        let x: IndyResult<i32> = self._parse_ledger_id(ledger_type)
+            .and_then(|ledger_id| self.pool_service.sync_ledger(handle, ledger_id));
+        match x {
+            Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
+            Err(err) => { cb(Err(err)); }
+        };
+    }
+
+    fn search_ledger_mirror(&self,
+                            handle: i32,
+                            ledger_type: &str,
+                            query_json: &str) -> IndyResult<String> {
+        debug!("search_ledger_mirror >>> handle: {:?}, ledger_type: {:?}, query_json: {:?}", handle, ledger_type, query_json);
+
+        let ledger_id = self._parse_ledger_id(ledger_type)?;
+
+        let query: LedgerMirrorQuery = serde_json::from_str(query_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize LedgerMirrorQuery")?;
+
+        let txns = self.pool_service.search_ledger_mirror(handle, ledger_id, &query)?;
+
+        let res = serde_json::to_string(&txns)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ledger mirror transactions")?;
+
+        debug!("search_ledger_mirror <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _parse_ledger_id(&self, ledger_type: &str) -> IndyResult<usize> {
+        let ledger_id = self.ledger_service.parse_ledger_type(ledger_type)?;
+
+        if ledger_id < 0 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid Ledger type: {}", ledger_type)));
+        }
+
+        Ok(ledger_id as usize)
+    }
+
+    fn prepare_request_for_pool(&self,
+                                pool_handle: i32,
+                                request_json: &str) -> IndyResult<String> {
+        debug!("prepare_request_for_pool >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
+
+        let res = self._prepare_request_for_pool(pool_handle, request_json)?;
+
+        debug!("prepare_request_for_pool <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _prepare_request_for_pool(&self, pool_handle: i32, request_json: &str) -> IndyResult<String> {
+        let protocol_version = self.pool_service.get_protocol_version(pool_handle)?;
+        self.ledger_service.set_request_protocol_version(request_json, protocol_version)
+    }
+
     fn sign_request(&self,
                     wallet_handle: WalletHandle,
                     submitter_did: &str,
@@ -519,12 +792,153 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn sign_request_for_pool(&self,
+                             pool_handle: i32,
+                             wallet_handle: WalletHandle,
+                             submitter_did: &str,
+                             request_json: &str) -> IndyResult<String> {
+        debug!("sign_request_for_pool >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}",
+               pool_handle, wallet_handle, submitter_did, request_json);
+
+        let res = self._prepare_request_for_pool(pool_handle, request_json)
+            .and_then(|request_json| self._sign_request(wallet_handle, submitter_did, &request_json, SignatureType::Single))?;
+
+        debug!("sign_request_for_pool <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn multi_sign_request_for_pool(&self,
+                                   pool_handle: i32,
+                                   wallet_handle: WalletHandle,
+                                   submitter_did: &str,
+                                   request_json: &str) -> IndyResult<String> {
+        debug!("multi_sign_request_for_pool >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}",
+               pool_handle, wallet_handle, submitter_did, request_json);
+
+        let res = self._prepare_request_for_pool(pool_handle, request_json)
+            .and_then(|request_json| self._sign_request(wallet_handle, submitter_did, &request_json, SignatureType::Multi))?;
+
+        debug!("multi_sign_request_for_pool <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_signing_bundle(&self,
+                            request_json: &str,
+                            signers: &HashMap<String, SignerInfo>,
+                            constraint: Option<&Constraint>) -> IndyResult<String> {
+        debug!("build_signing_bundle >>> request_json: {:?}, signers: {:?}, constraint: {:?}", request_json, signers, constraint);
+
+        for (did, signer) in signers {
+            self.crypto_service.validate_did(did)?;
+            self.crypto_service.validate_key(&build_full_verkey(did, Some(&signer.verkey))?)?;
+        }
+
+        let res = self.ledger_service.build_signing_bundle(request_json, signers, constraint)?;
+
+        debug!("build_signing_bundle <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn sign_signing_bundle(&self,
+                           wallet_handle: WalletHandle,
+                           signer_did: &str,
+                           mut bundle: SigningBundle) -> IndyResult<String> {
+        debug!("sign_signing_bundle >>> wallet_handle: {:?}, signer_did: {:?}, bundle: {:?}", wallet_handle, signer_did, bundle);
+
+        self.ledger_service.check_signing_bundle_input(&bundle)?;
+
+        let signer_verkey = bundle.signers.get(signer_did)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("{} isn't a signer of the bundle", signer_did)))
+            .and_then(|signer| build_full_verkey(signer_did, Some(&signer.verkey)))?;
+
+        let my_did: Did = self.wallet_service.get_indy_object(wallet_handle, signer_did, &RecordOptions::id_value())?;
+
+        let my_key: Key = self.wallet_service.get_indy_object(wallet_handle, &my_did.verkey, &RecordOptions::id_value())?;
+
+        if build_full_verkey(signer_did, Some(&my_key.verkey))? != signer_verkey {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Verkey of {} doesn't match the one expected by the bundle", signer_did)));
+        }
+
+        let signature = self.crypto_service.sign(&my_key, bundle.signing_input.as_bytes())?;
+
+        bundle.signatures.insert(signer_did.to_string(), base58::encode(&signature));
+
+        let res = serde_json::to_string(&bundle)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize signing bundle")?;
+
+        debug!("sign_signing_bundle <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn merge_signing_bundles(&self, bundles: Vec<SigningBundle>) -> IndyResult<String> {
+        debug!("merge_signing_bundles >>> bundles: {:?}", bundles);
+
+        let res = self.ledger_service.merge_signing_bundles(bundles)?;
+
+        debug!("merge_signing_bundles <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn check_signing_bundle(&self, bundle: &SigningBundle) -> IndyResult<String> {
+        debug!("check_signing_bundle >>> bundle: {:?}", bundle);
+
+        self.ledger_service.check_signing_bundle_input(bundle)?;
+
+        for (did, signature) in bundle.signatures.iter() {
+            let verkey = build_full_verkey(did, Some(&bundle.signers[did].verkey))?;
+
+            if !self.crypto_service.verify(&verkey, bundle.signing_input.as_bytes(), &base58::decode(signature)?)? {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid signature of {}", did)));
+            }
+        }
+
+        let status = self.ledger_service.get_signing_bundle_status(bundle)?;
+
+        let res = serde_json::to_string(&status)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize signing bundle status")?;
+
+        debug!("check_signing_bundle <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn set_request_req_id(&self,
+                          request_json: &str,
+                          req_id: u64) -> IndyResult<String> {
+        debug!("set_request_req_id >>> request_json: {:?}, req_id: {:?}", request_json, req_id);
+
+        let res = self.ledger_service.set_request_req_id(request_json, req_id)?;
+
+        debug!("set_request_req_id <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn append_request_endorser(&self,
+                               request_json: &str,
+                               endorser_did: &str) -> IndyResult<String> {
+        debug!("append_request_endorser >>> request_json: {:?}, endorser_did: {:?}", request_json, endorser_did);
+
+        self.crypto_service.validate_did(endorser_did)?;
+
+        let res = self.ledger_service.append_request_endorser(request_json, endorser_did)?;
+
+        debug!("append_request_endorser <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_get_ddo_request(&self,
                              submitter_did: Option<&str>,
                              target_did: &str) -> IndyResult<String> {
         debug!("build_get_ddo_request >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
 
-        let res = self.ledger_service.build_get_ddo_request(submitter_did, target_did)?;
+        let res = self.ledger_service.build_get_ddo_request(submitter_did, target_did, ProtocolVersion::get())?;
 
         debug!("build_get_ddo_request <<< res: {:?}", res);
 
@@ -550,7 +964,7 @@ impl LedgerCommandExecutor {
                                                         target_did,
                                                         verkey,
                                                         alias,
-                                                        role)?;
+                                                        role, ProtocolVersion::get())?;
 
         debug!("build_nym_request <<< res: {:?}", res);
 
@@ -573,7 +987,7 @@ impl LedgerCommandExecutor {
                                                            target_did,
                                                            hash,
                                                            raw,
-                                                           enc)?;
+                                                           enc, ProtocolVersion::get())?;
 
         debug!("build_attrib_request <<< res: {:?}", res);
 
@@ -596,7 +1010,7 @@ impl LedgerCommandExecutor {
                                                                target_did,
                                                                raw,
                                                                hash,
-                                                               enc)?;
+                                                               enc, ProtocolVersion::get())?;
 
         debug!("build_get_attrib_request <<< res: {:?}", res);
 
@@ -612,7 +1026,7 @@ impl LedgerCommandExecutor {
         self.crypto_service.validate_did(target_did)?;
 
         let res = self.ledger_service.build_get_nym_request(submitter_did,
-                                                            target_did)?;
+                                                            target_did, ProtocolVersion::get())?;
 
         debug!("build_get_attrib_request <<< res: {:?}", res);
 
@@ -626,7 +1040,7 @@ impl LedgerCommandExecutor {
 
         self.crypto_service.validate_did(submitter_did)?;
 
-        let res = self.ledger_service.build_schema_request(submitter_did, schema)?;
+        let res = self.ledger_service.build_schema_request(submitter_did, schema, ProtocolVersion::get())?;
 
         debug!("build_schema_request <<< res: {:?}", res);
 
@@ -640,7 +1054,7 @@ impl LedgerCommandExecutor {
 
         self.validate_opt_did(submitter_did)?;
 
-        let res = self.ledger_service.build_get_schema_request(submitter_did, id)?;
+        let res = self.ledger_service.build_get_schema_request(submitter_did, id, ProtocolVersion::get())?;
 
         debug!("build_get_schema_request <<< res: {:?}", res);
 
@@ -666,7 +1080,7 @@ impl LedgerCommandExecutor {
 
         self.crypto_service.validate_did(submitter_did)?;
 
-        let res = self.ledger_service.build_cred_def_request(submitter_did, cred_def)?;
+        let res = self.ledger_service.build_cred_def_request(submitter_did, cred_def, ProtocolVersion::get())?;
 
         debug!("build_cred_def_request <<< res: {:?}", res);
 
@@ -680,7 +1094,7 @@ impl LedgerCommandExecutor {
 
         self.validate_opt_did(submitter_did)?;
 
-        let res = self.ledger_service.build_get_cred_def_request(submitter_did, id)?;
+        let res = self.ledger_service.build_get_cred_def_request(submitter_did, id, ProtocolVersion::get())?;
 
         debug!("build_get_cred_def_request <<< res: {:?}", res);
 
@@ -707,20 +1121,47 @@ impl LedgerCommandExecutor {
 
         self.crypto_service.validate_did(submitter_did)?;
 
-        let res = self.ledger_service.build_node_request(submitter_did, target_did, data)?;
+        if let (&Some(ref blskey), &Some(ref blskey_pop)) = (&data.blskey, &data.blskey_pop) {
+            if !self.crypto_service.verify_bls_key_pop(blskey, blskey_pop)? {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Proof of possession doesn't correspond to BLS key"));
+            }
+        }
+
+        let res = self.ledger_service.build_node_request(submitter_did, target_did, data, ProtocolVersion::get())?;
 
         debug!("build_node_request <<< res: {:?}", res);
 
         Ok(res)
     }
 
+    fn build_node_request_with_bls_key(&self,
+                                       wallet_handle: WalletHandle,
+                                       submitter_did: &str,
+                                       target_did: &str,
+                                       mut data: NodeOperationData,
+                                       blskey: &str) -> IndyResult<String> {
+        debug!("build_node_request_with_bls_key >>> wallet_handle: {:?}, submitter_did: {:?}, target_did: {:?}, data: {:?}, blskey: {:?}",
+               wallet_handle, submitter_did, target_did, data, blskey);
+
+        let bls_key: BlsKey = self.wallet_service.get_indy_object(wallet_handle, blskey, &RecordOptions::id_value())?;
+
+        data.blskey_pop = Some(self.crypto_service.create_bls_key_pop(&bls_key)?);
+        data.blskey = Some(bls_key.verkey.clone());
+
+        let res = self.build_node_request(submitter_did, target_did, data)?;
+
+        debug!("build_node_request_with_bls_key <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_get_validator_info_request(&self,
                                         submitter_did: &str) -> IndyResult<String> {
         info!("build_get_validator_info_request >>> submitter_did: {:?}", submitter_did);
 
         self.crypto_service.validate_did(submitter_did)?;
 
-        let res = self.ledger_service.build_get_validator_info_request(submitter_did)?;
+        let res = self.ledger_service.build_get_validator_info_request(submitter_did, ProtocolVersion::get())?;
 
         info!("build_get_validator_info_request <<< res: {:?}", res);
 
@@ -736,7 +1177,7 @@ impl LedgerCommandExecutor {
 
         self.validate_opt_did(submitter_did)?;
 
-        let res = self.ledger_service.build_get_txn_request(submitter_did, ledger_type, seq_no)?;
+        let res = self.ledger_service.build_get_txn_request(submitter_did, ledger_type, seq_no, ProtocolVersion::get())?;
 
         debug!("build_get_txn_request <<< res: {:?}", res);
 
@@ -752,7 +1193,7 @@ impl LedgerCommandExecutor {
 
         self.crypto_service.validate_did(submitter_did)?;
 
-        let res = self.ledger_service.build_pool_config(submitter_did, writes, force)?;
+        let res = self.ledger_service.build_pool_config(submitter_did, writes, force, ProtocolVersion::get())?;
 
         debug!("build_pool_config_request  <<< res: {:?}", res);
 
@@ -765,7 +1206,7 @@ impl LedgerCommandExecutor {
 
         self.crypto_service.validate_did(submitter_did)?;
 
-        let res = self.ledger_service.build_pool_restart(submitter_did, action, datetime)?;
+        let res = self.ledger_service.build_pool_restart(submitter_did, action, datetime, ProtocolVersion::get())?;
 
         debug!("build_pool_config_request  <<< res: {:?}", res);
 
@@ -791,7 +1232,7 @@ impl LedgerCommandExecutor {
         self.crypto_service.validate_did(submitter_did)?;
 
         let res = self.ledger_service.build_pool_upgrade(submitter_did, name, version, action, sha256,
-                                                         timeout, schedule, justification, reinstall, force, package)?;
+                                                         timeout, schedule, justification, reinstall, force, package, ProtocolVersion::get())?;
 
         debug!("build_pool_upgrade_request  <<< res: {:?}", res);
 
@@ -805,7 +1246,7 @@ impl LedgerCommandExecutor {
 
         self.crypto_service.validate_did(submitter_did)?;
 
-        let res = self.ledger_service.build_revoc_reg_def_request(submitter_did, data)?;
+        let res = self.ledger_service.build_revoc_reg_def_request(submitter_did, data, ProtocolVersion::get())?;
 
         debug!("build_revoc_reg_def_request  <<< res: {:?}", res);
 
@@ -819,7 +1260,7 @@ impl LedgerCommandExecutor {
 
         self.validate_opt_did(submitter_did)?;
 
-        let res = self.ledger_service.build_get_revoc_reg_def_request(submitter_did, id)?;
+        let res = self.ledger_service.build_get_revoc_reg_def_request(submitter_did, id, ProtocolVersion::get())?;
 
         debug!("build_get_revoc_reg_def_request  <<< res: {:?}", res);
 
@@ -847,7 +1288,7 @@ impl LedgerCommandExecutor {
 
         self.crypto_service.validate_did(submitter_did)?;
 
-        let res = self.ledger_service.build_revoc_reg_entry_request(submitter_did, revoc_reg_def_id, revoc_def_type, value)?;
+        let res = self.ledger_service.build_revoc_reg_entry_request(submitter_did, revoc_reg_def_id, revoc_def_type, value, ProtocolVersion::get())?;
 
         debug!("build_revoc_reg_request  <<< res: {:?}", res);
 
@@ -862,7 +1303,7 @@ impl LedgerCommandExecutor {
 
         self.validate_opt_did(submitter_did)?;
 
-        let res = self.ledger_service.build_get_revoc_reg_request(submitter_did, revoc_reg_def_id, timestamp)?;
+        let res = self.ledger_service.build_get_revoc_reg_request(submitter_did, revoc_reg_def_id, timestamp, ProtocolVersion::get())?;
 
         debug!("build_get_revoc_reg_request  <<< res: {:?}", res);
 
@@ -889,7 +1330,7 @@ impl LedgerCommandExecutor {
 
         self.validate_opt_did(submitter_did)?;
 
-        let res = self.ledger_service.build_get_revoc_reg_delta_request(submitter_did, revoc_reg_def_id, from, to)?;
+        let res = self.ledger_service.build_get_revoc_reg_delta_request(submitter_did, revoc_reg_def_id, from, to, ProtocolVersion::get())?;
 
         debug!("build_get_revoc_reg_delta_request  <<< res: {:?}", res);
 
@@ -907,6 +1348,77 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn parse_get_nym_response(&self,
+                              get_nym_response: &str) -> IndyResult<String> {
+        debug!("parse_get_nym_response >>> get_nym_response: {:?}", get_nym_response);
+
+        let res = self.ledger_service.parse_get_nym_response(get_nym_response)?;
+
+        debug!("parse_get_nym_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_attrib_response(&self,
+                                 get_attrib_response: &str) -> IndyResult<String> {
+        debug!("parse_get_attrib_response >>> get_attrib_response: {:?}", get_attrib_response);
+
+        let res = self.ledger_service.parse_get_attrib_response(get_attrib_response)?;
+
+        debug!("parse_get_attrib_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_txn_response(&self,
+                              get_txn_response: &str) -> IndyResult<String> {
+        debug!("parse_get_txn_response >>> get_txn_response: {:?}", get_txn_response);
+
+        let res = self.ledger_service.parse_get_txn_response(get_txn_response)?;
+
+        debug!("parse_get_txn_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_validator_info_response(&self,
+                                         get_validator_info_response: &str) -> IndyResult<String> {
+        debug!("parse_get_validator_info_response >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+        let res = self.ledger_service.parse_get_validator_info_response(get_validator_info_response)?;
+
+        debug!("parse_get_validator_info_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn get_pool_status(&self,
+                       pool_handle: i32,
+                       wallet_handle: WalletHandle,
+                       submitter_did: &str,
+                       cb: Box<Fn(IndyResult<String>) + Send>) {
+        debug!("get_pool_status >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}", pool_handle, wallet_handle, submitter_did);
+
+        let res = self.pool_service.get_nodes(pool_handle)
+            .and_then(|nodes| {
+                let protocol_version = self.pool_service.get_protocol_version(pool_handle)?;
+                let request_json = self.ledger_service.build_get_validator_info_request(submitter_did, protocol_version)?;
+                let request_json = self._sign_request(wallet_handle, submitter_did, &request_json, SignatureType::Single)?;
+                let cmd_id = self.pool_service.send_action(pool_handle, &request_json, None, None)?;
+                Ok((nodes, cmd_id))
+            });
+
+        match res {
+            Ok((nodes, cmd_id)) => {
+                let ledger_service = self.ledger_service.clone();
+                self.send_callbacks.borrow_mut().insert(cmd_id, Box::new(move |result| {
+                    cb(result.and_then(|response| ledger_service.build_pool_status(&nodes, &response)))
+                }));
+            }
+            Err(err) => cb(Err(err))
+        };
+    }
+
     fn get_response_metadata(&self,
                              response: &str) -> IndyResult<String> {
         debug!("get_response_metadata >>> response: {:?}", response);
@@ -934,7 +1446,7 @@ impl LedgerCommandExecutor {
 
         self.validate_opt_did(Some(submitter_did))?;
 
-        let res = self.ledger_service.build_auth_rule_request(submitter_did, txn_type, action, field, old_value, new_value, constraint)?;
+        let res = self.ledger_service.build_auth_rule_request(submitter_did, txn_type, action, field, old_value, new_value, constraint, ProtocolVersion::get())?;
 
         debug!("build_auth_rule_request <<< res: {:?}", res);
 
@@ -953,7 +1465,7 @@ impl LedgerCommandExecutor {
 
         self.validate_opt_did(submitter_did)?;
 
-        let res = self.ledger_service.build_get_auth_rule_request(submitter_did, txn_type, action, field, old_value, new_value)?;
+        let res = self.ledger_service.build_get_auth_rule_request(submitter_did, txn_type, action, field, old_value, new_value, ProtocolVersion::get())?;
 
         debug!("build_get_auth_rule_request <<< res: {:?}", res);
 
@@ -20,6 +20,7 @@ use commands::wallet::{WalletCommand, WalletCommandExecutor};
 use domain::IndyConfig;
 use errors::prelude::*;
 use services::anoncreds::AnoncredsService;
+use services::anoncreds::verifier::set_non_revoked_interval_tolerance;
 use services::blob_storage::BlobStorageService;
 use services::crypto::CryptoService;
 use services::ledger::LedgerService;
@@ -70,6 +71,9 @@ pub fn indy_set_runtime_config(config: IndyConfig) {
     if let Some(threshold) = config.freshness_threshold {
         set_freshness_threshold(threshold);
     }
+    if let Some(tolerance) = config.non_revoked_interval_tolerance {
+        set_non_revoked_interval_tolerance(tolerance);
+    }
 }
 
 pub struct CommandExecutor {
@@ -103,11 +107,11 @@ impl CommandExecutor {
                 let pool_service = Rc::new(PoolService::new());
                 let wallet_service = Rc::new(WalletService::new());
 
-                let anoncreds_command_executor = AnoncredsCommandExecutor::new(anoncreds_service.clone(), blob_storage_service.clone(), pool_service.clone(), wallet_service.clone(), crypto_service.clone());
+                let anoncreds_command_executor = AnoncredsCommandExecutor::new(anoncreds_service.clone(), blob_storage_service.clone(), pool_service.clone(), wallet_service.clone(), crypto_service.clone(), ledger_service.clone());
                 let crypto_command_executor = CryptoCommandExecutor::new(wallet_service.clone(), crypto_service.clone());
                 let ledger_command_executor = LedgerCommandExecutor::new(pool_service.clone(), crypto_service.clone(), wallet_service.clone(), ledger_service.clone());
                 let pool_command_executor = PoolCommandExecutor::new(pool_service.clone());
-                let did_command_executor = DidCommandExecutor::new(wallet_service.clone(), crypto_service.clone(), ledger_service.clone());
+                let did_command_executor = DidCommandExecutor::new(wallet_service.clone(), crypto_service.clone(), ledger_service.clone(), pool_service.clone());
                 let wallet_command_executor = WalletCommandExecutor::new(wallet_service.clone(), crypto_service.clone());
                 let pairwise_command_executor = PairwiseCommandExecutor::new(wallet_service.clone());
                 let blob_storage_command_executor = BlobStorageCommandExecutor::new(blob_storage_service.clone());
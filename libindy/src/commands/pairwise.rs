@@ -1,11 +1,15 @@
 use domain::crypto::did::{Did, TheirDid};
-use domain::pairwise::{Pairwise, PairwiseInfo};
+use domain::pairwise::{MY_DID_TAG, Pairwise, PairwiseInfo};
+use domain::wallet::Tags;
 use errors::prelude::*;
-use services::wallet::{RecordOptions, WalletService};
-use std::collections::HashMap;
+use named_type::NamedType;
+use services::wallet::{RecordOptions, SearchOptions, WalletSearch, WalletService};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::str;
 use api::WalletHandle;
+use utils::sequence;
 
 
 pub enum PairwiseCommand {
@@ -30,17 +34,41 @@ pub enum PairwiseCommand {
         WalletHandle,
         String, // their_did
         Option<String>, // metadata
+        Box<Fn(IndyResult<()>) + Send>),
+    SetPairwiseTags(
+        WalletHandle,
+        String, // their_did
+        Tags, // tags
+        Box<Fn(IndyResult<()>) + Send>),
+    DeletePairwise(
+        WalletHandle,
+        String, // their_did
+        Box<Fn(IndyResult<()>) + Send>),
+    SearchPairwise(
+        WalletHandle,
+        Option<String>, // query json
+        Box<Fn(IndyResult<(i32, usize)>) + Send>),
+    FetchPairwiseSearch(
+        i32, // search handle
+        usize, // count
+        Box<Fn(IndyResult<String>) + Send>),
+    ClosePairwiseSearch(
+        i32, // search handle
         Box<Fn(IndyResult<()>) + Send>)
 }
 
 pub struct PairwiseCommandExecutor {
-    wallet_service: Rc<WalletService>
+    wallet_service: Rc<WalletService>,
+    searches: RefCell<HashMap<i32, Box<WalletSearch>>>,
+    tagged_wallets: RefCell<HashSet<WalletHandle>>,
 }
 
 impl PairwiseCommandExecutor {
     pub fn new(wallet_service: Rc<WalletService>) -> PairwiseCommandExecutor {
         PairwiseCommandExecutor {
-            wallet_service
+            wallet_service,
+            searches: RefCell::new(HashMap::new()),
+            tagged_wallets: RefCell::new(HashSet::new()),
         }
     }
 
@@ -66,6 +94,26 @@ impl PairwiseCommandExecutor {
                 info!(target: "pairwise_command_executor", "SetPairwiseMetadata command received");
                 cb(self.set_pairwise_metadata(wallet_handle, &their_did, metadata.as_ref().map(String::as_str)));
             }
+            PairwiseCommand::SetPairwiseTags(wallet_handle, their_did, tags, cb) => {
+                info!(target: "pairwise_command_executor", "SetPairwiseTags command received");
+                cb(self.set_pairwise_tags(wallet_handle, &their_did, tags));
+            }
+            PairwiseCommand::DeletePairwise(wallet_handle, their_did, cb) => {
+                info!(target: "pairwise_command_executor", "DeletePairwise command received");
+                cb(self.delete_pairwise(wallet_handle, &their_did));
+            }
+            PairwiseCommand::SearchPairwise(wallet_handle, query_json, cb) => {
+                info!(target: "pairwise_command_executor", "SearchPairwise command received");
+                cb(self.search_pairwise(wallet_handle, query_json.as_ref().map(String::as_str)));
+            }
+            PairwiseCommand::FetchPairwiseSearch(search_handle, count, cb) => {
+                info!(target: "pairwise_command_executor", "FetchPairwiseSearch command received");
+                cb(self.fetch_pairwise_search(search_handle, count));
+            }
+            PairwiseCommand::ClosePairwiseSearch(search_handle, cb) => {
+                info!(target: "pairwise_command_executor", "ClosePairwiseSearch command received");
+                cb(self.close_pairwise_search(search_handle));
+            }
         };
     }
 
@@ -94,10 +142,11 @@ impl PairwiseCommandExecutor {
         let pairwise = Pairwise {
             my_did: my_did.to_string(),
             their_did: their_did.to_string(),
-            metadata: metadata.map(str::to_string)
+            metadata: metadata.map(str::to_string),
+            tags: None
         };
 
-        self.wallet_service.add_indy_object(wallet_handle, &their_did, &pairwise, &HashMap::new())?;
+        self.wallet_service.add_indy_object(wallet_handle, &their_did, &pairwise, &pairwise.record_tags())?;
 
         debug!("create_pairwise <<<");
 
@@ -165,4 +214,248 @@ impl PairwiseCommandExecutor {
 
         Ok(())
     }
+
+    fn set_pairwise_tags(&self,
+                         wallet_handle: WalletHandle,
+                         their_did: &str,
+                         tags: Tags) -> IndyResult<()> {
+        debug!("set_pairwise_tags >>> wallet_handle: {:?}, their_did: {:?}, tags: {:?}", wallet_handle, their_did, tags);
+
+        if tags.contains_key(MY_DID_TAG) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Tag name \"{}\" is reserved", MY_DID_TAG)));
+        }
+
+        let mut pairwise: Pairwise =
+            self.wallet_service.get_indy_object(wallet_handle, &their_did, &RecordOptions::id_value())?;
+
+        pairwise.tags = if tags.is_empty() { None } else { Some(tags) };
+
+        self.wallet_service.update_indy_object(wallet_handle, &their_did, &pairwise)?;
+        self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix(Pairwise::short_type_name()), &their_did, &pairwise.record_tags())?;
+
+        debug!("set_pairwise_tags <<<");
+
+        Ok(())
+    }
+
+    fn delete_pairwise(&self,
+                       wallet_handle: WalletHandle,
+                       their_did: &str) -> IndyResult<()> {
+        debug!("delete_pairwise >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
+
+        self.wallet_service.delete_indy_record::<Pairwise>(wallet_handle, &their_did)?;
+
+        debug!("delete_pairwise <<<");
+
+        Ok(())
+    }
+
+    fn search_pairwise(&self,
+                       wallet_handle: WalletHandle,
+                       query_json: Option<&str>) -> IndyResult<(i32, usize)> {
+        debug!("search_pairwise >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+        self._backfill_my_did_tags(wallet_handle)?;
+
+        let pairwise_search =
+            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, query_json.unwrap_or("{}"), &SearchOptions::id_value())?;
+
+        let total_count = pairwise_search.get_total_count()?.unwrap_or(0);
+
+        let handle = sequence::get_next_id();
+
+        self.searches.borrow_mut().insert(handle, Box::new(pairwise_search));
+
+        let res = (handle, total_count);
+
+        debug!("search_pairwise <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn fetch_pairwise_search(&self,
+                             search_handle: i32,
+                             count: usize) -> IndyResult<String> {
+        trace!("fetch_pairwise_search >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+        let mut searches = self.searches.borrow_mut();
+        let search = searches.get_mut(&search_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown PairwiseSearch handle: {}", search_handle)))?;
+
+        let mut list_pairwise: Vec<Pairwise> = Vec::new();
+
+        for _ in 0..count {
+            match search.fetch_next_record()? {
+                Some(pairwise_record) => {
+                    let pairwise_id = pairwise_record.get_id();
+
+                    let pairwise: Pairwise = pairwise_record.get_value()
+                        .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Pairwise not found for id: {}", pairwise_id)))
+                        .and_then(|pairwise_json| serde_json::from_str(pairwise_json)
+                            .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize Pairwise: {:?}", pairwise_id)))?;
+
+                    list_pairwise.push(pairwise);
+                }
+                None => break
+            }
+        }
+
+        let res = serde_json::to_string(&list_pairwise)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pairwise list")?;
+
+        trace!("fetch_pairwise_search <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn close_pairwise_search(&self, search_handle: i32) -> IndyResult<()> {
+        trace!("close_pairwise_search >>> search_handle: {:?}", search_handle);
+
+        let res = match self.searches.borrow_mut().remove(&search_handle) {
+            Some(_) => Ok(()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown PairwiseSearch handle: {}", search_handle)))
+        }?;
+
+        trace!("close_pairwise_search <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    // Pairwise records created before tags support have no "my_did" tag and can't be found by my DID.
+    // Set reserved tags of such records once per opened wallet before the first search.
+    fn _backfill_my_did_tags(&self, wallet_handle: WalletHandle) -> IndyResult<()> {
+        trace!("_backfill_my_did_tags >>> wallet_handle: {:?}", wallet_handle);
+
+        if self.tagged_wallets.borrow().contains(&wallet_handle) {
+            return Ok(());
+        }
+
+        // Search is finished before tags are updated as storage can't be modified during iteration
+        let untagged = {
+            let mut pairwise_search =
+                self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, "{}", &json!({"retrieveTags": true}).to_string())?;
+
+            let mut untagged: Vec<(String, Tags)> = Vec::new();
+
+            while let Some(pairwise_record) = pairwise_search.fetch_next_record()? {
+                if pairwise_record.get_tags().map(|tags| tags.contains_key(MY_DID_TAG)).unwrap_or(false) {
+                    continue;
+                }
+
+                let pairwise_id = pairwise_record.get_id();
+
+                let pairwise: Pairwise = pairwise_record.get_value()
+                    .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Pairwise not found for id: {}", pairwise_id)))
+                    .and_then(|pairwise_json| serde_json::from_str(pairwise_json)
+                        .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize Pairwise: {:?}", pairwise_id)))?;
+
+                untagged.push((pairwise_id.to_string(), pairwise.record_tags()));
+            }
+
+            untagged
+        };
+
+        let type_ = self.wallet_service.add_prefix(Pairwise::short_type_name());
+
+        for (their_did, tags) in untagged {
+            self.wallet_service.update_record_tags(wallet_handle, &type_, &their_did, &tags)?;
+        }
+
+        self.tagged_wallets.borrow_mut().insert(wallet_handle);
+
+        trace!("_backfill_my_did_tags <<<");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use domain::wallet::{Config, Credentials, KeyDerivationMethod};
+    use services::wallet::KeyDerivationData;
+
+    const MY_DID: &str = "VsKV7grR1BUE29mG2Fm2kX";
+    const THEIR_DID: &str = "V4SGRU86Z58d6TV7PBUe6f";
+
+    #[test]
+    fn search_pairwise_works_for_record_without_my_did_tag() {
+        let config = _config("pairwise_search_without_my_did_tag");
+        let wallet_service = Rc::new(WalletService::new());
+        let wallet_handle = _create_and_open_wallet(&wallet_service, &config);
+
+        let pairwise = Pairwise {
+            my_did: MY_DID.to_string(),
+            their_did: THEIR_DID.to_string(),
+            metadata: None,
+            tags: None,
+        };
+
+        // record stored before tags support
+        wallet_service.add_record(wallet_handle,
+                                  &wallet_service.add_prefix(Pairwise::short_type_name()),
+                                  THEIR_DID,
+                                  &serde_json::to_string(&pairwise).unwrap(),
+                                  &Tags::new()).unwrap();
+
+        let executor = PairwiseCommandExecutor::new(wallet_service.clone());
+
+        let query = json!({MY_DID_TAG: MY_DID}).to_string();
+        let (search_handle, total_count) = executor.search_pairwise(wallet_handle, Some(&query)).unwrap();
+        assert_eq!(1, total_count);
+
+        let list_pairwise: Vec<Pairwise> = serde_json::from_str(&executor.fetch_pairwise_search(search_handle, 10).unwrap()).unwrap();
+        assert_eq!(1, list_pairwise.len());
+        assert_eq!(THEIR_DID, list_pairwise[0].their_did);
+
+        executor.close_pairwise_search(search_handle).unwrap();
+
+        _close_and_delete_wallet(&wallet_service, wallet_handle, &config);
+    }
+
+    fn _config(id: &str) -> Config {
+        Config {
+            id: id.to_string(),
+            storage_type: None,
+            storage_config: None,
+        }
+    }
+
+    fn _credentials() -> Credentials {
+        Credentials {
+            key: "6nxtSiXFvBd593Y2DCed2dYvRY1PGK9WMtxCBjLzKgbw".to_string(),
+            rekey: None,
+            storage_credentials: None,
+            key_derivation_method: KeyDerivationMethod::RAW,
+            rekey_derivation_method: KeyDerivationMethod::RAW,
+        }
+    }
+
+    fn _create_and_open_wallet(wallet_service: &WalletService, config: &Config) -> WalletHandle {
+        let credentials = _credentials();
+
+        let key_data = KeyDerivationData::from_passphrase_with_new_salt(&credentials.key, &credentials.key_derivation_method);
+        let master_key = key_data.calc_master_key().unwrap();
+
+        let _ = _delete_wallet(wallet_service, config);
+        wallet_service.create_wallet(config, &credentials, (&key_data, &master_key)).unwrap();
+
+        let (wallet_handle, key_data, _) = wallet_service.open_wallet_prepare(config, &credentials).unwrap();
+        let master_key = key_data.calc_master_key().unwrap();
+        wallet_service.open_wallet_continue(wallet_handle, (&master_key, None)).unwrap()
+    }
+
+    fn _close_and_delete_wallet(wallet_service: &WalletService, wallet_handle: WalletHandle, config: &Config) {
+        wallet_service.close_wallet(wallet_handle).unwrap();
+        _delete_wallet(wallet_service, config).unwrap();
+    }
+
+    fn _delete_wallet(wallet_service: &WalletService, config: &Config) -> IndyResult<()> {
+        let credentials = _credentials();
+
+        let (metadata, key_data) = wallet_service.delete_wallet_prepare(config, &credentials)?;
+        let master_key = key_data.calc_master_key()?;
+        wallet_service.delete_wallet_continue(config, &credentials, &metadata, &master_key)
+    }
 }
@@ -157,6 +157,12 @@ impl PoolCommandExecutor {
     fn open(&self, name: &str, config: Option<PoolOpenConfig>, cb: Box<Fn(IndyResult<i32>) + Send>) {
         debug!("open >>> name: {:?}, config: {:?}", name, config);
 
+        if let Some(version) = config.as_ref().and_then(|config| config.protocol_version) {
+            if !ProtocolVersion::is_supported(version) {
+                return cb(Err(err_msg(IndyErrorKind::PoolIncompatibleProtocolVersion, format!("Unsupported Protocol version: {}", version))));
+            }
+        }
+
         let result = self.pool_service.open(name, config)
             .and_then(|handle| {
                 match self.open_callbacks.try_borrow_mut() {
@@ -223,7 +229,7 @@ impl PoolCommandExecutor {
     fn set_protocol_version(&self, version: usize) -> IndyResult<()> {
         debug!("set_protocol_version >>> version: {:?}", version);
 
-        if version != 1 && version != 2 {
+        if !ProtocolVersion::is_supported(version) {
             return Err(err_msg(IndyErrorKind::PoolIncompatibleProtocolVersion, format!("Unsupported Protocol version: {}", version)));
         }
 
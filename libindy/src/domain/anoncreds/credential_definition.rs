@@ -69,8 +69,9 @@ pub enum CredentialDefinition {
 }
 
 impl CredentialDefinition {
-    pub fn cred_def_id(did: &str, schema_id: &str, signature_type: &str, tag: &str) -> String {
-        if ProtocolVersion::is_node_1_3(){
+    /// Ids of protocol version 1 don't contain `tag`.
+    pub fn cred_def_id(did: &str, schema_id: &str, signature_type: &str, tag: &str, protocol_version: usize) -> String {
+        if ProtocolVersion::is_node_1_3(protocol_version) {
             format!("{}{}{}{}{}{}{}", did, DELIMITER, CRED_DEF_MARKER, DELIMITER, signature_type, DELIMITER, schema_id)
         } else {
             format!("{}{}{}{}{}{}{}{}{}", did, DELIMITER, CRED_DEF_MARKER, DELIMITER, signature_type, DELIMITER, schema_id, DELIMITER, tag)
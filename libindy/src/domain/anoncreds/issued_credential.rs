@@ -0,0 +1,59 @@
+use named_type::NamedType;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateCredentialOptions {
+    #[serde(default)]
+    pub track_offer_nonce: bool,
+    #[serde(default)]
+    pub store_issued_credential: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, NamedType)]
+pub struct IssuedCredential {
+    pub schema_id: String,
+    pub cred_def_id: String,
+    pub rev_reg_id: Option<String>,
+    pub cred_rev_id: Option<String>,
+    pub values_hash: String,
+    pub issued_at: u64,
+    pub holder_did: String,
+    #[serde(default)]
+    pub revoked: bool,
+}
+
+pub const ISSUED_CREDENTIAL_VALUES_KEY_ID: &'static str = "issued_credential_values_key";
+
+/// Wallet-held key of `IssuedCredential::values_hash`
+#[derive(Debug, Deserialize, Serialize, NamedType)]
+pub struct IssuedCredentialValuesKey {
+    pub value: String, // base58 encoded
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IssuedCredentialInfo {
+    pub referent: String,
+    pub schema_id: String,
+    pub cred_def_id: String,
+    pub rev_reg_id: Option<String>,
+    pub cred_rev_id: Option<String>,
+    pub values_hash: String,
+    pub issued_at: u64,
+    pub holder_did: String,
+    pub revoked: bool,
+}
+
+impl IssuedCredential {
+    pub fn to_info(self, referent: String) -> IssuedCredentialInfo {
+        IssuedCredentialInfo {
+            referent,
+            schema_id: self.schema_id,
+            cred_def_id: self.cred_def_id,
+            rev_reg_id: self.rev_reg_id,
+            cred_rev_id: self.cred_rev_id,
+            values_hash: self.values_hash,
+            issued_at: self.issued_at,
+            holder_did: self.holder_did,
+            revoked: self.revoked,
+        }
+    }
+}
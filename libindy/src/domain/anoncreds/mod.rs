@@ -3,6 +3,7 @@ pub mod credential_definition;
 pub mod credential_for_proof_request;
 pub mod credential_offer;
 pub mod credential_request;
+pub mod issued_credential;
 pub mod proof;
 pub mod proof_request;
 pub mod requested_credential;
@@ -12,5 +13,7 @@ pub mod revocation_registry;
 pub mod revocation_state;
 pub mod schema;
 pub mod master_secret;
+pub mod tracked_nonce;
+pub mod w3c;
 
 pub const DELIMITER: char = ':';
@@ -0,0 +1,43 @@
+use named_type::NamedType;
+
+use domain::wallet::{Tags, timestamp_tag};
+
+pub const CREDENTIAL_OFFER_NONCE: &'static str = "credential_offer";
+pub const PROOF_REQUEST_NONCE: &'static str = "proof_request";
+
+/// Nonce of a credential offer or proof request created with tracking enabled.
+/// Expiry is bound at creation, so callers can't extend it when the nonce is checked.
+#[derive(Debug, Deserialize, Serialize, NamedType)]
+pub struct TrackedNonce {
+    pub context: String,
+    pub expires_at: u64,
+    pub used: bool,
+}
+
+impl TrackedNonce {
+    pub fn id(context: &str, nonce: &str) -> String {
+        format!("{}:{}", context, nonce)
+    }
+
+    pub fn expires_at_tag(expires_at: u64) -> String {
+        timestamp_tag(expires_at)
+    }
+
+    pub fn tags(&self) -> Tags {
+        let mut tags = Tags::new();
+        tags.insert("context".to_string(), self.context.clone());
+        tags.insert("~expires_at".to_string(), TrackedNonce::expires_at_tag(self.expires_at));
+        tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expires_at_tag_keeps_order() {
+        assert!(TrackedNonce::expires_at_tag(999) < TrackedNonce::expires_at_tag(1000));
+        assert!(TrackedNonce::expires_at_tag(1_500_000_000) < TrackedNonce::expires_at_tag(15_000_000_000));
+    }
+}
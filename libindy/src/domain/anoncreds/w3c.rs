@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use indy_crypto::cl::{CredentialSignature, SignatureCorrectnessProof};
+use serde_json::Value;
+
+use super::proof::RequestedProof;
+
+pub const W3C_CONTEXT: &'static str = "https://www.w3.org/2018/credentials/v1";
+pub const W3C_CREDENTIAL_TYPE: &'static str = "VerifiableCredential";
+pub const W3C_PRESENTATION_TYPE: &'static str = "VerifiablePresentation";
+
+pub const INDY_VOCAB: &'static str = "urn:indy:anoncreds:";
+pub const INDY_CREDENTIAL_TYPE: &'static str = "IndyCredential";
+pub const INDY_PRESENTATION_TYPE: &'static str = "IndyPresentation";
+pub const INDY_SCHEMA_TYPE: &'static str = "IndyCredentialSchema";
+pub const CL_SIGNATURE_TYPE: &'static str = "CLSignature2019";
+pub const CL_SUB_PROOF_TYPE: &'static str = "CLSubProof2019";
+pub const CL_AGGREGATED_PROOF_TYPE: &'static str = "CLAggregatedProof2019";
+
+pub const DID_SOV_PREFIX: &'static str = "did:sov:";
+pub const UUID_URN_PREFIX: &'static str = "urn:uuid:";
+pub const SUBJECT_ID: &'static str = "id";
+
+pub fn w3c_context() -> Vec<Value> {
+    vec![Value::String(W3C_CONTEXT.to_string()), json!({"@vocab": INDY_VOCAB})]
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CCredential<P> {
+    #[serde(rename = "@context")]
+    pub context: Vec<Value>,
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    pub issuer: String,
+    pub issuance_date: String,
+    pub credential_schema: W3CCredentialSchema,
+    pub credential_subject: W3CCredentialSubject,
+    pub proof: P,
+}
+
+/// CL credentials are bound to the holder's master secret rather than to a DID,
+/// so `id` is a random URN generated on every export. A stable id would make presentations linkable.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct W3CCredentialSubject {
+    pub id: String,
+    #[serde(flatten)]
+    pub attributes: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct W3CCredentialSchema {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CLSignatureProof {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub credential_definition: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revocation_registry: Option<String>,
+    pub encoding: HashMap<String, String>,
+    pub signature: CredentialSignature,
+    pub signature_correctness_proof: SignatureCorrectnessProof,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CLSubProof {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub credential_definition: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revocation_registry: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+    pub sub_proof_index: usize,
+    pub proof_value: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CPresentation {
+    #[serde(rename = "@context")]
+    pub context: Vec<Value>,
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    pub verifiable_credential: Vec<W3CCredential<CLSubProof>>,
+    pub proof: CLAggregatedProof,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CLAggregatedProof {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub requested_proof: RequestedProof,
+    pub proof_value: Value,
+}
+
+pub fn qualify_did(did: &str) -> String {
+    if did.starts_with(DID_SOV_PREFIX) { did.to_string() } else { format!("{}{}", DID_SOV_PREFIX, did) }
+}
+
+pub fn subject_id() -> String {
+    format!("{}{}", UUID_URN_PREFIX, uuid::Uuid::new_v4())
+}
+
+/// Current UTC time in RFC 3339 format as required for `issuanceDate`.
+pub fn issuance_date() -> String {
+    time::now_utc().rfc3339().to_string()
+}
+
+pub fn issuer_did_from_cred_def_id(cred_def_id: &str) -> String {
+    cred_def_id.split_terminator(super::DELIMITER).next().map(qualify_did).unwrap_or(String::new())
+}
+
+/// Splits serialized CL proof into sub proofs (one per credential) and aggregated proof.
+pub fn split_cl_proof(proof: Value) -> Option<(Vec<Value>, Value)> {
+    let mut proof = match proof {
+        Value::Object(proof) => proof,
+        _ => return None
+    };
+
+    let sub_proofs = match proof.remove("proofs") {
+        Some(Value::Array(sub_proofs)) => sub_proofs,
+        _ => return None
+    };
+
+    let aggregated_proof = proof.remove("aggregated_proof")?;
+
+    Some((sub_proofs, aggregated_proof))
+}
+
+pub fn join_cl_proof(sub_proofs: Vec<Value>, aggregated_proof: Value) -> Value {
+    json!({
+        "proofs": sub_proofs,
+        "aggregated_proof": aggregated_proof
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qualify_did_works() {
+        assert_eq!("did:sov:NcYxiDXkpYi6ov5FcYDi1e", qualify_did("NcYxiDXkpYi6ov5FcYDi1e"));
+        assert_eq!("did:sov:NcYxiDXkpYi6ov5FcYDi1e", qualify_did("did:sov:NcYxiDXkpYi6ov5FcYDi1e"));
+    }
+
+    #[test]
+    fn issuer_did_from_cred_def_id_works() {
+        assert_eq!("did:sov:NcYxiDXkpYi6ov5FcYDi1e", issuer_did_from_cred_def_id("NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:TAG"));
+    }
+
+    #[test]
+    fn subject_id_works() {
+        let id = subject_id();
+        assert!(id.starts_with(UUID_URN_PREFIX));
+        assert_ne!(id, subject_id());
+    }
+
+    #[test]
+    fn issuance_date_works() {
+        let date = issuance_date();
+        assert!(time::strptime(&date, "%Y-%m-%dT%H:%M:%SZ").is_ok());
+    }
+
+    #[test]
+    fn credential_subject_works() {
+        let mut attributes: HashMap<String, String> = HashMap::new();
+        attributes.insert("name".to_string(), "Alex".to_string());
+
+        let subject = W3CCredentialSubject {
+            id: "urn:uuid:1".to_string(),
+            attributes: attributes.clone(),
+        };
+
+        let subject_json = serde_json::to_value(&subject).unwrap();
+        assert_eq!(json!({"id": "urn:uuid:1", "name": "Alex"}), subject_json);
+
+        let subject: W3CCredentialSubject = serde_json::from_value(subject_json).unwrap();
+        assert_eq!("urn:uuid:1", subject.id);
+        assert_eq!(attributes, subject.attributes);
+    }
+
+    #[test]
+    fn split_cl_proof_works() {
+        let proof = json!({"proofs": [{"primary_proof": 1}, {"primary_proof": 2}], "aggregated_proof": {"c_hash": "1"}});
+
+        let (sub_proofs, aggregated_proof) = split_cl_proof(proof.clone()).unwrap();
+
+        assert_eq!(vec![json!({"primary_proof": 1}), json!({"primary_proof": 2})], sub_proofs);
+        assert_eq!(json!({"c_hash": "1"}), aggregated_proof);
+        assert_eq!(proof, join_cl_proof(sub_proofs, aggregated_proof));
+    }
+
+    #[test]
+    fn split_cl_proof_works_for_invalid_structure() {
+        assert!(split_cl_proof(json!({"proofs": {}, "aggregated_proof": {}})).is_none());
+        assert!(split_cl_proof(json!({"proofs": []})).is_none());
+        assert!(split_cl_proof(json!([])).is_none());
+    }
+}
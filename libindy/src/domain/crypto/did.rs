@@ -2,12 +2,15 @@ extern crate indy_crypto;
 
 use named_type::NamedType;
 
+pub const METADATA_TAG: &'static str = "metadata";
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct MyDidInfo {
     pub did: Option<String>,
     pub seed: Option<String>,
     pub crypto_type: Option<String>,
-    pub cid: Option<bool>
+    pub cid: Option<bool>,
+    pub derivation_path: Option<String>
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -75,3 +78,240 @@ impl From<TemporaryDid> for Did {
     }
 }
 
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidKeyHistoryEntry {
+    pub verkey: String,
+    pub valid_from: Option<u64>,
+    pub valid_to: Option<u64>,
+    pub seq_no: Option<u64>
+}
+
+impl DidKeyHistoryEntry {
+    pub fn is_valid_at(&self, timestamp: u64) -> bool {
+        self.valid_from.map(|from| from <= timestamp).unwrap_or(true) &&
+            self.valid_to.map(|to| timestamp < to).unwrap_or(true)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, NamedType)]
+pub struct DidKeyHistory {
+    pub did: String,
+    pub keys: Vec<DidKeyHistoryEntry>
+}
+
+impl DidKeyHistory {
+    pub fn new(did: String, verkey: String) -> DidKeyHistory {
+        DidKeyHistory {
+            did,
+            keys: vec![DidKeyHistoryEntry { verkey, valid_from: None, valid_to: None, seq_no: None }]
+        }
+    }
+
+    // New verkey stays pending until the time it has been written to the ledger is known (see confirm),
+    // the previous verkey is considered current till then.
+    pub fn rotate(&mut self, verkey: String) {
+        self.keys.push(DidKeyHistoryEntry { verkey, valid_from: None, valid_to: None, seq_no: None });
+    }
+
+    // Record ids of my and their DID histories differ, as my and their DID of the same value are rotated independently
+    pub fn my_id(did: &str) -> String {
+        format!("my:{}", did)
+    }
+
+    pub fn their_id(did: &str) -> String {
+        format!("their:{}", did)
+    }
+
+    pub fn confirm(&mut self, verkey: &str, seq_no: u64, txn_time: u64) -> bool {
+        let idx = match self.keys.iter().rposition(|entry| entry.verkey == verkey) {
+            Some(idx) => idx,
+            None => return false
+        };
+
+        self.keys[idx].seq_no = Some(seq_no);
+
+        // The first verkey has been used since DID creation, so only rotated ones get bounds from the ledger
+        if idx > 0 {
+            self.keys[idx].valid_from = Some(txn_time);
+        }
+
+        self._update_valid_to();
+
+        true
+    }
+
+    pub fn verkey_at(&self, timestamp: u64) -> Option<&str> {
+        self.keys.iter()
+            .enumerate()
+            .filter(|&(idx, entry)| _is_active(idx, entry) && entry.valid_from.unwrap_or(0) <= timestamp)
+            .max_by_key(|&(_, entry)| entry.valid_from.unwrap_or(0))
+            .map(|(_, entry)| entry.verkey.as_str())
+    }
+
+    // Keys can be confirmed out of order and some rotations may never be confirmed,
+    // so every active key is closed by the next one in ledger time.
+    fn _update_valid_to(&mut self) {
+        let mut active: Vec<(u64, usize)> = self.keys.iter()
+            .enumerate()
+            .filter(|&(idx, entry)| _is_active(idx, entry))
+            .map(|(idx, entry)| (entry.valid_from.unwrap_or(0), idx))
+            .collect();
+        active.sort();
+
+        for entry in self.keys.iter_mut() {
+            entry.valid_to = None;
+        }
+
+        for pair in active.windows(2) {
+            self.keys[pair[0].1].valid_to = Some(pair[1].0);
+        }
+    }
+}
+
+// Pending keys are not active until confirmed, the first key is active since DID creation
+fn _is_active(idx: usize, entry: &DidKeyHistoryEntry) -> bool {
+    idx == 0 || entry.valid_from.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _history() -> DidKeyHistory {
+        let mut history = DidKeyHistory::new("did".to_string(), "vk1".to_string());
+        history.rotate("vk2".to_string());
+        history.confirm("vk2", 10, 100);
+        history.rotate("vk3".to_string());
+        history.confirm("vk3", 20, 200);
+        history
+    }
+
+    #[test]
+    fn did_key_history_verkey_at_works() {
+        let history = _history();
+
+        assert_eq!(Some("vk1"), history.verkey_at(0));
+        assert_eq!(Some("vk1"), history.verkey_at(99));
+        assert_eq!(Some("vk2"), history.verkey_at(100));
+        assert_eq!(Some("vk2"), history.verkey_at(199));
+        assert_eq!(Some("vk3"), history.verkey_at(200));
+        assert_eq!(Some("vk3"), history.verkey_at(u64::max_value()));
+    }
+
+    #[test]
+    fn did_key_history_verkey_at_works_for_not_rotated_did() {
+        let history = DidKeyHistory::new("did".to_string(), "vk1".to_string());
+
+        assert_eq!(Some("vk1"), history.verkey_at(0));
+        assert_eq!(Some("vk1"), history.verkey_at(u64::max_value()));
+    }
+
+    #[test]
+    fn did_key_history_verkey_at_ignores_pending_key() {
+        let mut history = DidKeyHistory::new("did".to_string(), "vk1".to_string());
+        history.rotate("vk2".to_string());
+
+        assert_eq!(2, history.keys.len());
+        assert_eq!(None, history.keys[0].valid_to);
+        assert_eq!(None, history.keys[1].valid_from);
+        assert_eq!(Some("vk1"), history.verkey_at(u64::max_value()));
+    }
+
+    #[test]
+    fn did_key_history_confirm_closes_previous_key() {
+        let mut history = DidKeyHistory::new("did".to_string(), "vk1".to_string());
+        history.rotate("vk2".to_string());
+
+        assert!(history.confirm("vk2", 10, 100));
+
+        assert_eq!(Some(100), history.keys[0].valid_to);
+        assert_eq!(Some(100), history.keys[1].valid_from);
+        assert_eq!(Some(10), history.keys[1].seq_no);
+        assert_eq!(None, history.keys[1].valid_to);
+    }
+
+    #[test]
+    fn did_key_history_confirm_works_for_first_key() {
+        let mut history = _history();
+
+        assert!(history.confirm("vk1", 1, 50));
+
+        assert_eq!(Some(1), history.keys[0].seq_no);
+        assert_eq!(None, history.keys[0].valid_from);
+        assert_eq!(Some(100), history.keys[0].valid_to);
+        assert_eq!(Some("vk1"), history.verkey_at(0));
+    }
+
+    #[test]
+    fn did_key_history_confirm_works_for_unknown_key() {
+        let mut history = _history();
+
+        assert!(!history.confirm("vk4", 30, 300));
+        assert_eq!(3, history.keys.len());
+    }
+
+    #[test]
+    fn did_key_history_confirm_works_for_reused_key() {
+        let mut history = _history();
+        history.rotate("vk1".to_string());
+
+        assert!(history.confirm("vk1", 30, 300));
+
+        assert_eq!(None, history.keys[0].seq_no);
+        assert_eq!(Some(300), history.keys[3].valid_from);
+        assert_eq!(Some("vk1"), history.verkey_at(50));
+        assert_eq!(Some("vk3"), history.verkey_at(250));
+        assert_eq!(Some("vk1"), history.verkey_at(300));
+    }
+
+    #[test]
+    fn did_key_history_confirm_works_for_skipped_confirm() {
+        let mut history = DidKeyHistory::new("did".to_string(), "vk1".to_string());
+        history.rotate("vk2".to_string());
+        history.rotate("vk3".to_string());
+
+        assert!(history.confirm("vk3", 20, 200));
+
+        assert_eq!(Some(200), history.keys[0].valid_to);
+        assert_eq!(None, history.keys[1].valid_from);
+        assert_eq!(None, history.keys[1].valid_to);
+        assert_eq!(Some("vk1"), history.verkey_at(199));
+        assert_eq!(Some("vk3"), history.verkey_at(200));
+        assert_eq!(Some("vk3"), history.verkey_at(u64::max_value()));
+    }
+
+    #[test]
+    fn did_key_history_confirm_works_for_out_of_order_confirms() {
+        let mut history = DidKeyHistory::new("did".to_string(), "vk1".to_string());
+        history.rotate("vk2".to_string());
+        history.rotate("vk3".to_string());
+
+        assert!(history.confirm("vk3", 20, 200));
+        assert!(history.confirm("vk2", 10, 100));
+
+        assert_eq!(Some(100), history.keys[0].valid_to);
+        assert_eq!(Some(200), history.keys[1].valid_to);
+        assert_eq!(None, history.keys[2].valid_to);
+        assert_eq!(Some("vk1"), history.verkey_at(99));
+        assert_eq!(Some("vk2"), history.verkey_at(100));
+        assert_eq!(Some("vk2"), history.verkey_at(199));
+        assert_eq!(Some("vk3"), history.verkey_at(200));
+    }
+
+    #[test]
+    fn did_key_history_ids_differ_for_my_and_their_did() {
+        assert_ne!(DidKeyHistory::my_id("did"), DidKeyHistory::their_id("did"));
+    }
+
+    #[test]
+    fn did_key_history_entry_is_valid_at_works() {
+        let entry = DidKeyHistoryEntry { verkey: "vk".to_string(), valid_from: Some(100), valid_to: Some(200), seq_no: None };
+
+        assert!(!entry.is_valid_at(99));
+        assert!(entry.is_valid_at(100));
+        assert!(entry.is_valid_at(199));
+        assert!(!entry.is_valid_at(200));
+    }
+}
@@ -0,0 +1,41 @@
+pub const EDDSA_ALG: &'static str = "EdDSA";
+pub const ES256K_ALG: &'static str = "ES256K";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum JwsSerialization {
+    #[serde(rename = "compact")]
+    Compact,
+    #[serde(rename = "json")]
+    Json,
+}
+
+impl Default for JwsSerialization {
+    fn default() -> Self {
+        JwsSerialization::Compact
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct JwsOptions {
+    pub kid: Option<String>,
+    #[serde(default)]
+    pub detached: bool,
+    #[serde(default)]
+    pub serialization: JwsSerialization,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct JwsHeader {
+    pub alg: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+/// Flattened JWS JSON Serialization (RFC 7515 section 7.2.2)
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct FlattenedJws {
+    pub protected: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+    pub signature: String,
+}
@@ -5,6 +5,8 @@ use self::zeroize::Zeroize;
 
 use named_type::NamedType;
 
+use std::str::FromStr;
+
 
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -32,10 +34,100 @@ memzeroize!(Key, signkey);
 #[derive(Serialize, Deserialize, Debug)]
 pub struct KeyInfo {
     pub seed: Option<String>,
-    pub crypto_type: Option<String>
+    pub crypto_type: Option<String>,
+    pub derivation_path: Option<String>
+}
+
+/// BLS key pair used by validator nodes to sign ledger state (multi-signature).
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[derive(Serialize, Deserialize, Clone, NamedType)]
+pub struct BlsKey {
+    pub verkey: String,
+    #[derivative(Debug="ignore")]
+    pub signkey: String
+}
+
+impl BlsKey {
+    pub fn new(verkey: String, signkey: String) -> BlsKey {
+        BlsKey {
+            verkey,
+            signkey
+        }
+    }
+}
+
+memzeroize!(BlsKey, signkey);
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct BlsKeyInfo {
+    pub seed: Option<String>
+}
+
+pub const MASTER_SEED_ID: &'static str = "master_seed";
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[derive(Serialize, Deserialize, NamedType)]
+pub struct MasterSeed {
+    #[derivative(Debug="ignore")]
+    pub seed: String // hex encoded
+}
+
+memzeroize!(MasterSeed, seed);
+
+/// Derivation path of a key (and DID it is used by) derived from the wallet master seed.
+/// Lets derived identities be enumerated and re-derived from the backed up master seed.
+#[derive(Serialize, Deserialize, Debug, NamedType)]
+pub struct DerivedKey {
+    pub verkey: String,
+    pub derivation_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub did: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, NamedType)]
 pub struct KeyMetadata {
     pub value: String
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum KeyExportFormat {
+    #[serde(rename = "jwk")]
+    Jwk,
+    #[serde(rename = "pkcs8")]
+    Pkcs8,
+}
+
+impl FromStr for KeyExportFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, String> {
+        match format {
+            "jwk" => Ok(KeyExportFormat::Jwk),
+            "pkcs8" => Ok(KeyExportFormat::Pkcs8),
+            _ => Err(format!("Unsupported key export format: {}", format))
+        }
+    }
+}
+
+/// JSON Web Key for OKP (RFC 8037) curves.
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    pub crv: String,
+    pub x: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[derivative(Debug = "ignore")]
+    pub d: Option<String>,
+}
+
+impl Drop for Jwk {
+    fn drop(&mut self) {
+        if let Some(ref mut d) = self.d {
+            d.zeroize();
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use serde_json::Value;
+
+pub const FORWARD_MESSAGE_TYPE: &'static str = "https://didcomm.org/routing/1.0/forward";
+pub const LEGACY_FORWARD_MESSAGE_TYPE: &'static str = "did:sov:BzCbsNYhMrjHiqZDTUASHg;spec/routing/1.0/forward";
+
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct JWE {
     pub protected: String,
@@ -38,4 +43,85 @@ pub struct UnpackMessage {
     pub recipient_verkey: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sender_verkey: Option<String>
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct ForwardMessage {
+    #[serde(rename = "@type")]
+    pub type_: String,
+    #[serde(rename = "@id")]
+    pub id: String,
+    pub to: String,
+    pub msg: Value,
+}
+
+impl ForwardMessage {
+    pub fn new(to: String, msg: Value) -> ForwardMessage {
+        ForwardMessage {
+            type_: FORWARD_MESSAGE_TYPE.to_string(),
+            id: uuid::Uuid::new_v4().to_string(),
+            to,
+            msg,
+        }
+    }
+
+    pub fn is_forward(&self) -> bool {
+        self.type_ == FORWARD_MESSAGE_TYPE || self.type_ == LEGACY_FORWARD_MESSAGE_TYPE
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct UnpackedLayer {
+    pub recipient_verkey: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_verkey: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_to: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct UnpackRoutedMessage {
+    pub message: String,
+    pub recipient_verkey: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_verkey: Option<String>,
+    pub layers: Vec<UnpackedLayer>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forward_message_new_works() {
+        let forward = ForwardMessage::new("vk".to_string(), json!({"protected": "p"}));
+
+        assert_eq!(FORWARD_MESSAGE_TYPE, forward.type_);
+        assert_eq!("vk", forward.to);
+        assert!(forward.is_forward());
+    }
+
+    #[test]
+    fn forward_message_is_forward_works_for_legacy_type() {
+        let forward: ForwardMessage = serde_json::from_value(json!({
+            "@type": LEGACY_FORWARD_MESSAGE_TYPE,
+            "@id": "1",
+            "to": "vk",
+            "msg": {}
+        })).unwrap();
+
+        assert!(forward.is_forward());
+    }
+
+    #[test]
+    fn forward_message_is_forward_works_for_other_type() {
+        let forward: ForwardMessage = serde_json::from_value(json!({
+            "@type": "https://didcomm.org/basicmessage/1.0/message",
+            "@id": "1",
+            "to": "vk",
+            "msg": {}
+        })).unwrap();
+
+        assert!(!forward.is_forward());
+    }
+}
@@ -0,0 +1,22 @@
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct StreamHeader {
+    pub enc: String,
+    pub alg: String,
+    pub recipient: String,
+    pub encrypted_key: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iv: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>,
+    pub nonce: String,
+    pub chunk_size: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct StreamDecryptInfo {
+    pub recipient_verkey: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_verkey: Option<String>,
+}
@@ -1,5 +1,5 @@
 use super::constants::{ATTRIB, GET_ATTR};
-use super::response::GetReplyResultV1;
+use super::response::{GetReplyResultV1, ReplyType};
 
 use named_type::NamedType;
 
@@ -61,13 +61,23 @@ pub enum GetAttrReplyResult {
     GetAttrReplyResultV1(GetReplyResultV1<GetAttResultDataV1>)
 }
 
+impl ReplyType for GetAttrReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_ATTR
+    }
+}
+
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAttResultV0 {
     pub  identifier: String,
-    pub  data: String,
+    pub  data: Option<String>,
     pub  dest: String,
-    pub  raw: String
+    pub  raw: Option<String>,
+    pub  hash: Option<String>,
+    pub  enc: Option<String>,
+    pub  seq_no: Option<u64>,
+    pub  txn_time: Option<u64>
 }
 
 #[derive(Deserialize, Eq, PartialEq, Debug)]
@@ -75,12 +85,37 @@ pub struct GetAttResultDataV1 {
     pub ver: String,
     pub id: String,
     pub did: String,
-    pub raw: String,
+    pub raw: Option<String>,
+    pub hash: Option<String>,
+    pub enc: Option<String>,
+}
+
+#[derive(Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AttribInfo {
+    pub did: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enc: Option<String>,
+    pub data: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txn_time: Option<u64>
 }
 
+pub const ENDPOINT_ATTRIB: &'static str = "endpoint";
+pub const SERVICE_ATTRIB: &'static str = "service";
+// Service type used to represent legacy endpoint attrib as a service
+pub const LEGACY_ENDPOINT_SERVICE_TYPE: &'static str = "IndyAgent";
+
 #[derive(Deserialize, Debug)]
 pub struct AttribData {
-    pub endpoint: Endpoint
+    pub endpoint: Option<Endpoint>,
+    pub service: Option<Vec<Service>>
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, NamedType)]
@@ -97,3 +132,100 @@ impl Endpoint {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Service {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub priority: u32,
+    #[serde(default)]
+    pub recipient_keys: Vec<String>,
+    #[serde(default)]
+    pub routing_keys: Vec<String>,
+    pub service_endpoint: String
+}
+
+impl From<Endpoint> for Service {
+    fn from(endpoint: Endpoint) -> Self {
+        Service {
+            id: None,
+            type_: LEGACY_ENDPOINT_SERVICE_TYPE.to_string(),
+            priority: 0,
+            recipient_keys: endpoint.verkey.into_iter().collect(),
+            routing_keys: Vec::new(),
+            service_endpoint: endpoint.ha
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, NamedType)]
+pub struct DidServices {
+    pub services: Vec<Service>
+}
+
+impl DidServices {
+    pub fn new(mut services: Vec<Service>) -> DidServices {
+        services.sort_by_key(|service| service.priority);
+        DidServices { services }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _service(priority: u32, endpoint: &str) -> Service {
+        Service {
+            id: None,
+            type_: LEGACY_ENDPOINT_SERVICE_TYPE.to_string(),
+            priority,
+            recipient_keys: Vec::new(),
+            routing_keys: Vec::new(),
+            service_endpoint: endpoint.to_string()
+        }
+    }
+
+    #[test]
+    fn service_from_endpoint_works() {
+        let service = Service::from(Endpoint::new("127.0.0.1:9700".to_string(), Some("verkey".to_string())));
+
+        assert_eq!(LEGACY_ENDPOINT_SERVICE_TYPE, service.type_);
+        assert_eq!("127.0.0.1:9700", service.service_endpoint);
+        assert_eq!(vec!["verkey".to_string()], service.recipient_keys);
+    }
+
+    #[test]
+    fn service_from_endpoint_works_for_address_only() {
+        let service = Service::from(Endpoint::new("127.0.0.1:9700".to_string(), None));
+
+        assert!(service.recipient_keys.is_empty());
+    }
+
+    #[test]
+    fn did_services_new_works_for_sorting_by_priority() {
+        let did_services = DidServices::new(vec![_service(2, "c"), _service(0, "a"), _service(1, "b")]);
+
+        let endpoints: Vec<&str> = did_services.services.iter().map(|service| service.service_endpoint.as_str()).collect();
+        assert_eq!(vec!["a", "b", "c"], endpoints);
+    }
+
+    #[test]
+    fn attrib_data_deserialize_works_for_service() {
+        let attrib_data: AttribData = serde_json::from_str(r#"{"service":[{"type":"IndyAgent","serviceEndpoint":"https://agent.example.com"}]}"#).unwrap();
+
+        assert!(attrib_data.endpoint.is_none());
+        assert_eq!(vec![_service(0, "https://agent.example.com")], attrib_data.service.unwrap());
+    }
+
+    #[test]
+    fn attrib_data_deserialize_works_for_legacy_endpoint() {
+        let attrib_data: AttribData = serde_json::from_str(r#"{"endpoint":{"ha":"127.0.0.1:9700"}}"#).unwrap();
+
+        assert!(attrib_data.service.is_none());
+        assert_eq!("127.0.0.1:9700", attrib_data.endpoint.unwrap().ha);
+    }
+}
@@ -9,7 +9,7 @@ pub enum AuthAction {
     EDIT
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(tag = "constraint_id")]
 pub enum Constraint {
     #[serde(rename = "OR")]
@@ -20,7 +20,7 @@ pub enum Constraint {
     RoleConstraint(RoleConstraint),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct RoleConstraint {
     pub sig_count: u32,
     pub role: String,
@@ -30,11 +30,31 @@ pub struct RoleConstraint {
     pub need_to_be_owner: Option<bool>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct CombinationConstraint {
     pub auth_constraints: Vec<Constraint>
 }
 
+pub const ANY_ROLE: &str = "*";
+
+impl Constraint {
+    /// Checks whether signatures of DIDs with given roles (`None` for identity owner) satisfy the constraint.
+    /// Ownership (`need_to_be_owner`) can't be checked without the ledger state and is ignored,
+    /// so the result is optimistic for constraints that require the owner's signature.
+    pub fn is_satisfied_by(&self, roles: &[Option<&str>]) -> bool {
+        match self {
+            Constraint::OrConstraint(constraint) => constraint.auth_constraints.iter().any(|c| c.is_satisfied_by(roles)),
+            Constraint::AndConstraint(constraint) => constraint.auth_constraints.iter().all(|c| c.is_satisfied_by(roles)),
+            Constraint::RoleConstraint(constraint) => {
+                let count = roles.iter()
+                    .filter(|role| constraint.role == ANY_ROLE || role.unwrap_or("") == constraint.role)
+                    .count();
+                count >= constraint.sig_count as usize
+            }
+        }
+    }
+}
+
 #[derive(Serialize, PartialEq, Debug)]
 pub struct AuthRuleOperation {
     #[serde(rename = "type")]
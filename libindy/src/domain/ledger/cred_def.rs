@@ -16,12 +16,13 @@ pub struct CredDefOperation {
 }
 
 impl CredDefOperation {
-    pub fn new(data: CredentialDefinitionV1) -> CredDefOperation {
+    /// `tag` is only sent to pools of protocol version 2.
+    pub fn new(data: CredentialDefinitionV1, protocol_version: usize) -> CredDefOperation {
         CredDefOperation {
             _ref: data.schema_id.parse::<i32>().unwrap_or(0),
             signature_type: data.signature_type.to_str().to_string(),
             data: data.value,
-            tag: if ProtocolVersion::is_node_1_3() { None } else { Some(data.tag.clone()) },
+            tag: if ProtocolVersion::is_node_1_3(protocol_version) { None } else { Some(data.tag.clone()) },
             _type: CRED_DEF.to_string()
         }
     }
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomTxnKind {
+    Read,
+    Write,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldType {
+    String,
+    Number,
+    Boolean,
+    Object,
+    Array,
+    Any,
+}
+
+impl FieldType {
+    pub fn matches(&self, value: &Value) -> bool {
+        match *self {
+            FieldType::String => value.is_string(),
+            FieldType::Number => value.is_number(),
+            FieldType::Boolean => value.is_boolean(),
+            FieldType::Object => value.is_object(),
+            FieldType::Array => value.is_array(),
+            FieldType::Any => true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FieldSchema {
+    #[serde(rename = "type")]
+    pub type_: FieldType,
+    #[serde(default)]
+    pub optional: bool,
+}
+
+/// Transaction type declared by a ledger plugin.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CustomTxnType {
+    pub name: String,
+    pub code: String,
+    pub kind: CustomTxnKind,
+    /// schema of `operation` fields by field name (`operation` isn't validated if not set)
+    pub schema: Option<HashMap<String, FieldSchema>>,
+}
+
+impl CustomTxnType {
+    pub fn validate_operation(&self, operation: &Map<String, Value>) -> Result<(), String> {
+        let schema = match self.schema {
+            Some(ref schema) => schema,
+            None => return Ok(())
+        };
+
+        for (field, field_schema) in schema {
+            match operation.get(field) {
+                Some(value) if !(field_schema.optional && value.is_null()) && !field_schema.type_.matches(value) =>
+                    return Err(format!("Field `{}` of {} operation must be {:?}", field, self.name, field_schema.type_)),
+                None if !field_schema.optional =>
+                    return Err(format!("Field `{}` of {} operation is required", field, self.name)),
+                _ => {}
+            }
+        }
+
+        if let Some(field) = operation.keys().find(|field| *field != "type" && !schema.contains_key(*field)) {
+            return Err(format!("Unknown field `{}` of {} operation", field, self.name));
+        }
+
+        Ok(())
+    }
+}
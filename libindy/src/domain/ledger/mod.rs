@@ -13,3 +13,5 @@ pub mod response;
 pub mod validator_info;
 pub mod constants;
 pub mod auth_rule;
+pub mod signing_bundle;
+pub mod custom_txn;
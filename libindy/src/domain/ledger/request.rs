@@ -1,11 +1,19 @@
 use serde;
 use serde_json;
-use time;
 
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use utils::sequence;
+
 pub const DEFAULT_LIBIDY_DID: &'static str = "LibindyDid111111111111";
 
+/// Process-wide default PROTOCOL_VERSION.
+///
+/// Each opened pool keeps its own version: the one set in `PoolOpenConfig::protocol_version` or,
+/// if it is not set, the one matching the format of the pool genesis transactions. Requests built
+/// for a pool use its version and unsigned requests are rewritten to it on submission. The global
+/// value is only used where no pool is known: by the public request builders, for the format of
+/// credential definition ids and as the version of pools whose genesis transactions are empty.
 pub struct ProtocolVersion {}
 
 lazy_static! {
@@ -21,8 +29,12 @@ impl ProtocolVersion {
         PROTOCOL_VERSION.load(Ordering::Relaxed)
     }
 
-    pub fn is_node_1_3() -> bool {
-        ProtocolVersion::get() == 1
+    pub fn is_supported(version: usize) -> bool {
+        version == 1 || version == 2
+    }
+
+    pub fn is_node_1_3(version: usize) -> bool {
+        version == 1
     }
 }
 
@@ -35,7 +47,9 @@ pub struct Request<T: serde::Serialize> {
     pub operation: T,
     pub protocol_version: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub signature: Option<String>
+    pub signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endorser: Option<String>
 }
 
 impl<T: serde::Serialize> Request<T> {
@@ -45,13 +59,16 @@ impl<T: serde::Serialize> Request<T> {
             identifier: identifier.to_string(),
             operation,
             protocol_version,
-            signature: None
+            signature: None,
+            endorser: None
         }
     }
 
-    pub fn build_request(identifier: Option<&str>, operation: T) -> Result<String, serde_json::Error> {
-        let req_id = time::get_time().sec as u64 * (1e9 as u64) + time::get_time().nsec as u64;
+    /// The protocol version is replaced by the version of the target pool on submission unless
+    /// the request has already been signed.
+    pub fn build_request(identifier: Option<&str>, operation: T, protocol_version: usize) -> Result<String, serde_json::Error> {
+        let req_id = sequence::get_next_req_id();
         let identifier = identifier.unwrap_or(DEFAULT_LIBIDY_DID);
-        serde_json::to_string(&Request::new(req_id, identifier, operation, ProtocolVersion::get()))
+        serde_json::to_string(&Request::new(req_id, identifier, operation, protocol_version))
     }
 }
\ No newline at end of file
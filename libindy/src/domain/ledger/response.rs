@@ -36,11 +36,6 @@ pub struct ReplyDataV1<T> {
     pub  result: Vec<ReplyV0<T>>
 }
 
-#[derive(Debug, Deserialize)]
-pub struct GetReplyResultV0<T> {
-    pub  data: Option<T>
-}
-
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetReplyResultV1<T> {
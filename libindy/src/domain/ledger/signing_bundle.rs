@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use super::auth_rule::Constraint;
+
+pub const SIGNING_BUNDLE_VERSION: &str = "1";
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct SignerInfo {
+    pub verkey: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
+
+/// Portable container for collecting signatures of a request on several (possibly offline) machines.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SigningBundle {
+    pub ver: String,
+    pub request: Value,
+    pub signing_input: String,
+    pub signers: BTreeMap<String, SignerInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<Constraint>,
+    #[serde(default)]
+    pub signatures: BTreeMap<String, String>,
+}
+
+impl SigningBundle {
+    pub fn new(request: Value, signing_input: String, signers: BTreeMap<String, SignerInfo>, constraint: Option<Constraint>) -> SigningBundle {
+        SigningBundle {
+            ver: SIGNING_BUNDLE_VERSION.to_string(),
+            request,
+            signing_input,
+            signers,
+            constraint,
+            signatures: BTreeMap::new(),
+        }
+    }
+
+    pub fn is_same_request(&self, other: &SigningBundle) -> bool {
+        self.request == other.request &&
+            self.signing_input == other.signing_input &&
+            self.signers == other.signers &&
+            self.constraint == other.constraint
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SigningBundleStatus {
+    pub complete: bool,
+    pub signed: Vec<String>,
+    pub unsigned: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<Value>,
+}
@@ -2,7 +2,10 @@ extern crate serde;
 extern crate serde_json;
 extern crate indy_crypto;
 
+use self::serde_json::Value;
+
 use super::constants::GET_TXN;
+use super::response::ReplyType;
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct GetTxnOperation {
@@ -38,4 +41,30 @@ impl LedgerType {
             LedgerType::CONFIG => LedgerType::CONFIG as i32,
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTxnReplyResult {
+    pub seq_no: Option<u64>,
+    pub data: Option<Value>
+}
+
+impl ReplyType for GetTxnReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TxnInfo {
+    pub seq_no: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txn_time: Option<u64>,
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    pub data: Value
+}
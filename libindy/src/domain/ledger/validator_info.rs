@@ -1,4 +1,9 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
 use super::constants::GET_VALIDATOR_INFO;
+use super::response::ReplyType;
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct GetValidatorInfoOperation {
@@ -14,3 +19,21 @@ impl GetValidatorInfoOperation {
     }
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetValidatorInfoReplyResult {
+    pub data: Value
+}
+
+impl ReplyType for GetValidatorInfoReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_VALIDATOR_INFO
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct ValidatorInfo {
+    /// validator info data by node alias
+    pub nodes: HashMap<String, Value>,
+    /// error reason by alias of node that hasn't returned validator info
+    pub errors: HashMap<String, String>
+}
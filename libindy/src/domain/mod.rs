@@ -9,5 +9,6 @@ pub mod wallet;
 pub struct IndyConfig {
     pub crypto_thread_pool_size : Option<usize>,
     pub collect_backtrace: Option<bool>,
-    pub freshness_threshold: Option<u64>
+    pub freshness_threshold: Option<u64>,
+    pub non_revoked_interval_tolerance: Option<u64>
 }
\ No newline at end of file
@@ -1,11 +1,26 @@
 use named_type::NamedType;
 
+use domain::wallet::Tags;
+
+pub const MY_DID_TAG: &'static str = "my_did";
+
 #[derive(Serialize, Deserialize, NamedType)]
 pub struct Pairwise {
     pub my_did: String,
     pub their_did: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Tags>,
+}
+
+impl Pairwise {
+    // Record tags are user tags extended with reserved ones to make pairwise searchable by my DID
+    pub fn record_tags(&self) -> Tags {
+        let mut tags = self.tags.clone().unwrap_or_default();
+        tags.insert(MY_DID_TAG.to_string(), self.my_did.clone());
+        tags
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -13,13 +28,66 @@ pub struct PairwiseInfo {
     pub my_did: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Tags>,
 }
 
 impl From<Pairwise> for PairwiseInfo {
     fn from(pairwise: Pairwise) -> Self {
         PairwiseInfo {
             my_did: pairwise.my_did,
-            metadata: pairwise.metadata
+            metadata: pairwise.metadata,
+            tags: pairwise.tags
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _pairwise(tags: Option<Tags>) -> Pairwise {
+        Pairwise {
+            my_did: "VsKV7grR1BUE29mG2Fm2kX".to_string(),
+            their_did: "V4SGRU86Z58d6TV7PBUe6f".to_string(),
+            metadata: None,
+            tags,
+        }
+    }
+
+    #[test]
+    fn record_tags_works_for_no_tags() {
+        let tags = _pairwise(None).record_tags();
+
+        assert_eq!(1, tags.len());
+        assert_eq!("VsKV7grR1BUE29mG2Fm2kX", tags[MY_DID_TAG]);
+    }
+
+    #[test]
+    fn record_tags_works_for_user_tags() {
+        let mut user_tags = Tags::new();
+        user_tags.insert("~label".to_string(), "Alice".to_string());
+
+        let tags = _pairwise(Some(user_tags)).record_tags();
+
+        assert_eq!(2, tags.len());
+        assert_eq!("Alice", tags["~label"]);
+        assert_eq!("VsKV7grR1BUE29mG2Fm2kX", tags[MY_DID_TAG]);
+    }
+
+    #[test]
+    fn record_tags_works_for_overridden_my_did_tag() {
+        let mut user_tags = Tags::new();
+        user_tags.insert(MY_DID_TAG.to_string(), "other".to_string());
+
+        let tags = _pairwise(Some(user_tags)).record_tags();
+
+        assert_eq!("VsKV7grR1BUE29mG2Fm2kX", tags[MY_DID_TAG]);
+    }
+
+    #[test]
+    fn pairwise_deserialize_works_for_record_without_tags() {
+        let pairwise: Pairwise = serde_json::from_str(r#"{"my_did":"VsKV7grR1BUE29mG2Fm2kX","their_did":"V4SGRU86Z58d6TV7PBUe6f"}"#).unwrap();
+
+        assert!(pairwise.tags.is_none());
+    }
+}
@@ -1,3 +1,7 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
 pub const POOL_CON_ACTIVE_TO: i64 = 5;
 pub const POOL_ACK_TIMEOUT: i64 = 20;
 pub const POOL_REPLY_TIMEOUT: i64 = 60;
@@ -28,6 +32,7 @@ pub struct PoolOpenConfig {
     pub conn_active_timeout: i64,
     #[serde(default="PoolOpenConfig::default_preordered_nodes")]
     pub preordered_nodes: Vec<String>,
+    pub protocol_version: Option<usize>,
 }
 
 impl Default for PoolOpenConfig {
@@ -37,7 +42,8 @@ impl Default for PoolOpenConfig {
             extended_timeout: PoolOpenConfig::default_extended_timeout(),
             conn_limit: PoolOpenConfig::default_conn_limit(),
             conn_active_timeout: PoolOpenConfig::default_conn_active_timeout(),
-            preordered_nodes: PoolOpenConfig::default_preordered_nodes()
+            preordered_nodes: PoolOpenConfig::default_preordered_nodes(),
+            protocol_version: None
         }
     }
 }
@@ -63,3 +69,70 @@ impl PoolOpenConfig {
         Vec::new()
     }
 }
+
+#[derive(Debug, Default, Deserialize)]
+pub struct LedgerMirrorQuery {
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    pub author: Option<String>,
+    pub dest: Option<String>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerMirrorStatus {
+    pub ledger_id: usize,
+    pub txn_count: usize,
+    pub root_hash: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolStatus {
+    /// status by node alias
+    pub nodes: BTreeMap<String, NodeStatus>,
+    pub consensus: ConsensusStatus,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeStatus {
+    /// client address from the pool ledger (None if the node isn't a validator in the pool ledger)
+    pub address: Option<String>,
+    /// whether the node has replied to GET_VALIDATOR_INFO request in time
+    pub reachable: bool,
+    /// reason why the node hasn't returned validator info
+    pub error: Option<String>,
+    pub mode: Option<String>,
+    pub software_version: Option<String>,
+    /// [view_no, pp_seq_no] of the last ordered batch of the master replica
+    pub last_ordered_3pc: Option<Value>,
+    /// catchup status by ledger id
+    pub catchup_status: Option<Value>,
+    pub view_no: Option<u64>,
+    pub view_change_in_progress: Option<bool>,
+    pub primary: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsensusStatus {
+    pub total_nodes: usize,
+    /// number of faulty nodes the pool can tolerate
+    pub f: usize,
+    /// number of nodes required to reach consensus (n - f)
+    pub required_nodes: usize,
+    /// number of pool ledger nodes that have replied
+    pub reachable_nodes: usize,
+    /// whether enough nodes are reachable to reach consensus
+    pub f_tolerated: bool,
+    /// view number reported by the most of reachable nodes
+    pub view_no: Option<u64>,
+    /// primary reported by the most of reachable nodes
+    pub primary: Option<String>,
+    /// whether all reachable nodes agree on the view and the primary and no view change is in progress
+    pub primary_agreed: bool,
+}
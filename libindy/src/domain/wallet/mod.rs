@@ -74,3 +74,8 @@ pub struct KeyConfig {
 }
 
 pub type Tags = HashMap<String, String>;
+
+// Unencrypted tags are compared as strings, so timestamp is padded to keep $gt/$lt queries correct
+pub fn timestamp_tag(timestamp: u64) -> String {
+    format!("{:020}", timestamp)
+}
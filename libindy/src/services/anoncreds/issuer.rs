@@ -1,4 +1,6 @@
-use std::collections::HashMap;
+extern crate hex;
+
+use std::collections::{BTreeMap, HashMap};
 use std::collections::HashSet;
 
 use indy_crypto::cl::{
@@ -19,9 +21,14 @@ use indy_crypto::cl::issuer::Issuer as CryptoIssuer;
 use domain::anoncreds::credential::AttributeValues;
 use domain::anoncreds::credential_definition::{CredentialDefinitionData, CredentialDefinitionV1 as CredentialDefinition};
 use domain::anoncreds::credential_request::CredentialRequest;
+use domain::anoncreds::issued_credential::IssuedCredential;
 use domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinitionV1, RevocationRegistryDefinitionValuePublicKeys};
+use domain::wallet::{Tags, timestamp_tag};
 use errors::prelude::*;
 use services::anoncreds::helpers::*;
+use utils::crypto::hmacsha256;
+
+use self::hex::ToHex;
 
 pub struct Issuer {}
 
@@ -178,4 +185,100 @@ impl Issuer {
 
         Ok(rev_reg_delta)
     }
+
+    /// Keyed hash of raw credential values. Unkeyed hash of values with low entropy (names, dates of birth)
+    /// can be easily reverted, so key is held in the issuer wallet and never leaves it.
+    pub fn hash_credential_values(&self, cred_values: &HashMap<String, AttributeValues>, key: &hmacsha256::Key) -> IndyResult<String> {
+        trace!("hash_credential_values >>> cred_values: {:?}", secret!(cred_values));
+
+        // Sorted map makes hash independent from attributes order
+        let raw_values: BTreeMap<&String, &String> = cred_values
+            .iter()
+            .map(|(attr, values)| (attr, &values.raw))
+            .collect();
+
+        let raw_values_json = serde_json::to_string(&raw_values)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize credential values")?;
+
+        let res = hmacsha256::authenticate(raw_values_json.as_bytes(), key)[..].to_hex();
+
+        trace!("hash_credential_values <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn build_issued_credential_tags(&self, issued_credential: &IssuedCredential) -> Tags {
+        trace!("build_issued_credential_tags >>> issued_credential: {:?}", issued_credential);
+
+        let mut res = Tags::new();
+        res.insert("schema_id".to_string(), issued_credential.schema_id.clone());
+        res.insert("cred_def_id".to_string(), issued_credential.cred_def_id.clone());
+        res.insert("rev_reg_id".to_string(), issued_credential.rev_reg_id.clone().unwrap_or("None".to_string()));
+        res.insert("cred_rev_id".to_string(), issued_credential.cred_rev_id.clone().unwrap_or("None".to_string()));
+        res.insert("holder_did".to_string(), issued_credential.holder_did.clone());
+        res.insert("values_hash".to_string(), issued_credential.values_hash.clone());
+        res.insert("revoked".to_string(), issued_credential.revoked.to_string());
+        res.insert("~issued_at".to_string(), timestamp_tag(issued_credential.issued_at));
+
+        trace!("build_issued_credential_tags <<< res: {:?}", res);
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _cred_values(values: &[(&str, &str)]) -> HashMap<String, AttributeValues> {
+        values.iter()
+            .map(|&(attr, raw)| (attr.to_string(), AttributeValues { raw: raw.to_string(), encoded: String::new() }))
+            .collect()
+    }
+
+    #[test]
+    fn hash_credential_values_works() {
+        let issuer = Issuer::new();
+        let key = hmacsha256::gen_key();
+
+        let hash = issuer.hash_credential_values(&_cred_values(&[("name", "Alex"), ("age", "28")]), &key).unwrap();
+        assert_eq!(64, hash.len());
+
+        let same_hash = issuer.hash_credential_values(&_cred_values(&[("age", "28"), ("name", "Alex")]), &key).unwrap();
+        assert_eq!(hash, same_hash);
+
+        let other_hash = issuer.hash_credential_values(&_cred_values(&[("name", "Alex"), ("age", "29")]), &key).unwrap();
+        assert_ne!(hash, other_hash);
+    }
+
+    #[test]
+    fn hash_credential_values_works_for_different_keys() {
+        let issuer = Issuer::new();
+        let cred_values = _cred_values(&[("name", "Alex"), ("age", "28")]);
+
+        let hash = issuer.hash_credential_values(&cred_values, &hmacsha256::gen_key()).unwrap();
+        let other_hash = issuer.hash_credential_values(&cred_values, &hmacsha256::gen_key()).unwrap();
+        assert_ne!(hash, other_hash);
+    }
+
+    #[test]
+    fn build_issued_credential_tags_works() {
+        let issued_credential = IssuedCredential {
+            schema_id: "NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string(),
+            cred_def_id: "NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:TAG".to_string(),
+            rev_reg_id: None,
+            cred_rev_id: None,
+            values_hash: "hash".to_string(),
+            issued_at: 1_500_000_000,
+            holder_did: "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW".to_string(),
+            revoked: false,
+        };
+
+        let tags = Issuer::new().build_issued_credential_tags(&issued_credential);
+
+        assert_eq!("None", tags["rev_reg_id"]);
+        assert_eq!("None", tags["cred_rev_id"]);
+        assert_eq!("false", tags["revoked"]);
+        assert_eq!("00000000001500000000", tags["~issued_at"]);
+    }
 }
@@ -24,6 +24,7 @@ use domain::anoncreds::requested_credential::RequestedCredentials;
 use domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinitionV1;
 use domain::anoncreds::revocation_state::RevocationState;
 use domain::anoncreds::schema::SchemaV1;
+use domain::anoncreds::w3c::*;
 use errors::prelude::*;
 use services::anoncreds::helpers::*;
 
@@ -31,6 +32,13 @@ const ATTRIBUTE_EXISTENCE_MARKER: &'static str = "1";
 
 pub struct Prover {}
 
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RevocationStateSource {
+    Cached(u64),
+    Update(u64),
+    Create,
+}
+
 macro_rules! serde_map {
     ($( $key: expr => $val: expr ),*) => {
         {
@@ -214,6 +222,23 @@ impl Prover {
         interval
     }
 
+    pub fn get_revocation_state_source(&self, cached_timestamps: &[u64], interval: &NonRevocedInterval, to: u64) -> RevocationStateSource {
+        trace!("get_revocation_state_source >>> cached_timestamps: {:?}, interval: {:?}, to: {:?}", cached_timestamps, interval, to);
+
+        // Without lower bound only the ledger knows if cached accumulator is still the latest one
+        let cached = interval.from
+            .and_then(|from| cached_timestamps.iter().filter(|ts| from <= **ts && **ts <= to).max())
+            .map(|ts| RevocationStateSource::Cached(*ts));
+
+        let res = cached
+            .or(cached_timestamps.iter().filter(|ts| **ts <= to).max().map(|ts| RevocationStateSource::Update(*ts)))
+            .unwrap_or(RevocationStateSource::Create);
+
+        trace!("get_revocation_state_source <<< res: {:?}", res);
+
+        res
+    }
+
     pub fn _prepare_credentials_for_proving(requested_credentials: &RequestedCredentials,
                                             proof_req: &ProofRequest) -> IndyResult<HashMap<ProvingCredentialKey, (Vec<RequestedAttributeInfo>, Vec<RequestedPredicateInfo>)>> {
         trace!("_prepare_credentials_for_proving >>> requested_credentials: {:?}, proof_req: {:?}", requested_credentials, proof_req);
@@ -305,6 +330,137 @@ impl Prover {
         res
     }
 
+    pub fn credential_to_w3c(&self, credential: Credential) -> IndyResult<W3CCredential<CLSignatureProof>> {
+        trace!("credential_to_w3c >>> credential: {:?}", credential);
+
+        let mut attributes: HashMap<String, String> = HashMap::new();
+        let mut encoding: HashMap<String, String> = HashMap::new();
+
+        for (attr, values) in credential.values.into_iter() {
+            Prover::_check_subject_attribute(&attr)?;
+            attributes.insert(attr.clone(), values.raw);
+            encoding.insert(attr, values.encoded);
+        }
+
+        let res = W3CCredential {
+            context: w3c_context(),
+            type_: vec![W3C_CREDENTIAL_TYPE.to_string(), INDY_CREDENTIAL_TYPE.to_string()],
+            issuer: issuer_did_from_cred_def_id(&credential.cred_def_id),
+            issuance_date: issuance_date(),
+            credential_schema: W3CCredentialSchema {
+                id: credential.schema_id,
+                type_: INDY_SCHEMA_TYPE.to_string(),
+            },
+            credential_subject: W3CCredentialSubject {
+                id: subject_id(),
+                attributes,
+            },
+            proof: CLSignatureProof {
+                type_: CL_SIGNATURE_TYPE.to_string(),
+                credential_definition: credential.cred_def_id,
+                revocation_registry: credential.rev_reg_id,
+                encoding,
+                signature: credential.signature,
+                signature_correctness_proof: credential.signature_correctness_proof,
+            },
+        };
+
+        trace!("credential_to_w3c <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _check_subject_attribute(attr: &str) -> IndyResult<()> {
+        if attr == SUBJECT_ID {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Attribute \"{}\" clashes with W3C credential subject id", attr)));
+        }
+        Ok(())
+    }
+
+    pub fn proof_to_w3c(&self, proof: Proof, proof_req: &ProofRequest) -> IndyResult<W3CPresentation> {
+        trace!("proof_to_w3c >>> proof: {:?}, proof_req: {:?}", proof, proof_req);
+
+        let cl_proof = serde_json::to_value(&proof.proof)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Proof")?;
+
+        let (sub_proofs, aggregated_proof) = split_cl_proof(cl_proof)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Invalid CL Proof structure"))?;
+
+        if sub_proofs.len() != proof.identifiers.len() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Number of sub proofs {} does not correspond to number of identifiers {}", sub_proofs.len(), proof.identifiers.len())));
+        }
+
+        let mut verifiable_credential: Vec<W3CCredential<CLSubProof>> = Vec::new();
+        let issuance_date = issuance_date();
+
+        for (sub_proof_index, (identifier, sub_proof)) in proof.identifiers.into_iter().zip(sub_proofs.into_iter()).enumerate() {
+            let attributes: HashMap<String, String> = proof.requested_proof.revealed_attrs
+                .iter()
+                .filter(|&(_, revealed_attr)| revealed_attr.sub_proof_index as usize == sub_proof_index)
+                .filter_map(|(attr_referent, revealed_attr)|
+                    proof_req.requested_attributes.get(attr_referent)
+                        .map(|attr_info| (attr_info.name.clone(), revealed_attr.raw.clone())))
+                .collect();
+
+            for attr in attributes.keys() {
+                Prover::_check_subject_attribute(attr)?;
+            }
+
+            verifiable_credential.push(W3CCredential {
+                context: w3c_context(),
+                type_: vec![W3C_CREDENTIAL_TYPE.to_string(), INDY_CREDENTIAL_TYPE.to_string()],
+                issuer: issuer_did_from_cred_def_id(&identifier.cred_def_id),
+                issuance_date: issuance_date.clone(),
+                credential_schema: W3CCredentialSchema {
+                    id: identifier.schema_id,
+                    type_: INDY_SCHEMA_TYPE.to_string(),
+                },
+                credential_subject: W3CCredentialSubject {
+                    id: subject_id(),
+                    attributes,
+                },
+                proof: CLSubProof {
+                    type_: CL_SUB_PROOF_TYPE.to_string(),
+                    credential_definition: identifier.cred_def_id,
+                    revocation_registry: identifier.rev_reg_id,
+                    timestamp: identifier.timestamp,
+                    sub_proof_index,
+                    proof_value: sub_proof,
+                },
+            });
+        }
+
+        let res = W3CPresentation {
+            context: w3c_context(),
+            type_: vec![W3C_PRESENTATION_TYPE.to_string(), INDY_PRESENTATION_TYPE.to_string()],
+            verifiable_credential,
+            proof: CLAggregatedProof {
+                type_: CL_AGGREGATED_PROOF_TYPE.to_string(),
+                requested_proof: proof.requested_proof,
+                proof_value: aggregated_proof,
+            },
+        };
+
+        trace!("proof_to_w3c <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn build_revocation_state_tags(&self, rev_reg_id: &str, cred_rev_id: &str, timestamp: u64) -> HashMap<String, String> {
+        trace!("build_revocation_state_tags >>> rev_reg_id: {:?}, cred_rev_id: {:?}, timestamp: {:?}", rev_reg_id, cred_rev_id, timestamp);
+
+        let mut res: HashMap<String, String> = HashMap::new();
+        res.insert("rev_reg_id".to_string(), rev_reg_id.to_string());
+        res.insert("cred_rev_id".to_string(), cred_rev_id.to_string());
+        res.insert("timestamp".to_string(), timestamp.to_string());
+
+        trace!("build_revocation_state_tags <<< res: {:?}", res);
+
+        res
+    }
+
     pub fn build_query(&self,
                        name: &str,
                        referent: &str,
@@ -471,7 +627,7 @@ mod tests {
     mod build_credential_tags {
         use super::*;
 
-        fn _credential() -> Credential {
+        pub fn _credential() -> Credential {
             // note that encoding is not standardized by Indy except that 32-bit integers are encoded as themselves. IS-786
             // so Alex -> 12345 is an application choice while 25 -> 25 is not
             let mut attr_values: HashMap<String, AttributeValues> = HashMap::new();
@@ -538,6 +694,38 @@ mod tests {
         }
     }
 
+    mod credential_to_w3c {
+        use super::*;
+        use super::build_credential_tags::_credential;
+
+        #[test]
+        fn credential_to_w3c_works() {
+            let ps = Prover::new();
+            let w3c_credential = ps.credential_to_w3c(_credential()).unwrap();
+
+            assert_eq!(qualify_did(ISSUER_DID), w3c_credential.issuer);
+            assert!(w3c_credential.credential_subject.id.starts_with(UUID_URN_PREFIX));
+            assert_eq!("Alex", w3c_credential.credential_subject.attributes["name"]);
+            assert_eq!("12345", w3c_credential.proof.encoding["name"]);
+            assert!(time::strptime(&w3c_credential.issuance_date, "%Y-%m-%dT%H:%M:%SZ").is_ok());
+
+            let w3c_credential_json = serde_json::to_value(&w3c_credential).unwrap();
+            assert_eq!(json!(w3c_credential.issuance_date), w3c_credential_json["issuanceDate"]);
+            assert_eq!(json!(w3c_credential.credential_subject.id), w3c_credential_json["credentialSubject"]["id"]);
+            assert_eq!(json!("25"), w3c_credential_json["credentialSubject"]["age"]);
+        }
+
+        #[test]
+        fn credential_to_w3c_works_for_id_attribute() {
+            let ps = Prover::new();
+            let mut credential = _credential();
+            credential.values.insert(SUBJECT_ID.to_string(), AttributeValues { raw: "1".to_string(), encoded: "1".to_string() });
+
+            let res = ps.credential_to_w3c(credential);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
     mod build_query {
         use super::*;
 
@@ -1018,4 +1206,47 @@ mod tests {
             assert_eq!(_attr_values(), res);
         }
     }
+
+    mod get_revocation_state_source {
+        use super::*;
+
+        fn _interval(from: Option<u64>, to: Option<u64>) -> NonRevocedInterval {
+            NonRevocedInterval { from, to }
+        }
+
+        #[test]
+        fn get_revocation_state_source_works_for_cached_in_interval() {
+            let ps = Prover::new();
+            let res = ps.get_revocation_state_source(&[10, 50, 70], &_interval(Some(40), Some(60)), 60);
+            assert_eq!(RevocationStateSource::Cached(50), res);
+        }
+
+        #[test]
+        fn get_revocation_state_source_works_for_update_of_older_state() {
+            let ps = Prover::new();
+            let res = ps.get_revocation_state_source(&[10, 30, 70], &_interval(Some(40), Some(60)), 60);
+            assert_eq!(RevocationStateSource::Update(30), res);
+        }
+
+        #[test]
+        fn get_revocation_state_source_works_for_no_lower_bound() {
+            let ps = Prover::new();
+            let res = ps.get_revocation_state_source(&[10, 60], &_interval(None, Some(60)), 60);
+            assert_eq!(RevocationStateSource::Update(60), res);
+        }
+
+        #[test]
+        fn get_revocation_state_source_works_for_no_suitable_state() {
+            let ps = Prover::new();
+            let res = ps.get_revocation_state_source(&[70], &_interval(Some(40), Some(60)), 60);
+            assert_eq!(RevocationStateSource::Create, res);
+        }
+
+        #[test]
+        fn get_revocation_state_source_works_for_empty_cache() {
+            let ps = Prover::new();
+            let res = ps.get_revocation_state_source(&[], &_interval(Some(40), Some(60)), 60);
+            assert_eq!(RevocationStateSource::Create, res);
+        }
+    }
 }
@@ -1,19 +1,34 @@
 extern crate indy_crypto;
 
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 use domain::anoncreds::credential_definition::CredentialDefinitionV1 as CredentialDefinition;
-use domain::anoncreds::proof::{Proof, RequestedProof};
-use domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, ProofRequest};
+use domain::anoncreds::proof::{Identifier, Proof, RequestedProof};
+use domain::anoncreds::proof_request::{AttributeInfo, NonRevocedInterval, PredicateInfo, ProofRequest};
 use domain::anoncreds::revocation_registry::RevocationRegistryV1;
 use domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinitionV1;
 use domain::anoncreds::schema::SchemaV1;
+use domain::anoncreds::w3c::{join_cl_proof, W3C_PRESENTATION_TYPE, W3CPresentation};
 use errors::prelude::*;
 use services::anoncreds::helpers::*;
 
 use self::indy_crypto::cl::CredentialPublicKey;
 use self::indy_crypto::cl::verifier::Verifier as CryptoVerifier;
 
+lazy_static! {
+    static ref NON_REVOKED_INTERVAL_TOLERANCE: Mutex<u64> = Mutex::new(0);
+}
+
+pub fn set_non_revoked_interval_tolerance(tolerance: u64) {
+    let mut tol = NON_REVOKED_INTERVAL_TOLERANCE.lock().unwrap();
+    *tol = tolerance;
+}
+
+fn _get_non_revoked_interval_tolerance() -> u64 {
+    NON_REVOKED_INTERVAL_TOLERANCE.lock().unwrap().clone()
+}
+
 pub struct Verifier {}
 
 impl Verifier {
@@ -64,6 +79,10 @@ impl Verifier {
                     .get(&timestamp)
                     .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("RevocationRegistry not found for timestamp: {:?}", timestamp)))?);
 
+                if !Verifier::_validate_timestamp(sub_proof_index, timestamp, &full_proof.requested_proof, proof_req, rev_regs_for_cred)? {
+                    return Ok(false);
+                }
+
                 (rev_reg_def, rev_reg)
             } else { (None, None) };
 
@@ -90,6 +109,139 @@ impl Verifier {
         Ok(valid)
     }
 
+    pub fn proof_from_w3c(&self, presentation: W3CPresentation) -> IndyResult<Proof> {
+        trace!("proof_from_w3c >>> presentation: {:?}", presentation);
+
+        if !presentation.type_.iter().any(|type_| type_ == W3C_PRESENTATION_TYPE) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Presentation type must contain {}", W3C_PRESENTATION_TYPE)));
+        }
+
+        let mut credentials = presentation.verifiable_credential;
+        credentials.sort_by_key(|credential| credential.proof.sub_proof_index);
+
+        let mut identifiers: Vec<Identifier> = Vec::new();
+        let mut sub_proofs: Vec<serde_json::Value> = Vec::new();
+
+        for (sub_proof_index, credential) in credentials.into_iter().enumerate() {
+            if credential.proof.sub_proof_index != sub_proof_index {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Sub proof not found for index: {}", sub_proof_index)));
+            }
+
+            identifiers.push(Identifier {
+                schema_id: credential.credential_schema.id,
+                cred_def_id: credential.proof.credential_definition,
+                rev_reg_id: credential.proof.revocation_registry,
+                timestamp: credential.proof.timestamp,
+            });
+
+            sub_proofs.push(credential.proof.proof_value);
+        }
+
+        let proof = serde_json::from_value(join_cl_proof(sub_proofs, presentation.proof.proof_value))
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid CL Proof structure")?;
+
+        let res = Proof {
+            proof,
+            requested_proof: presentation.proof.requested_proof,
+            identifiers,
+        };
+
+        trace!("proof_from_w3c <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _validate_timestamp(sub_proof_index: usize,
+                           timestamp: u64,
+                           requested_proof: &RequestedProof,
+                           proof_req: &ProofRequest,
+                           rev_regs_for_cred: &HashMap<u64, RevocationRegistryV1>) -> IndyResult<bool> {
+        trace!("_validate_timestamp >>> sub_proof_index: {:?}, timestamp: {:?}, requested_proof: {:?}, proof_req: {:?}",
+               sub_proof_index, timestamp, requested_proof, proof_req);
+
+        let tolerance = _get_non_revoked_interval_tolerance();
+
+        let intervals = Verifier::_get_non_revoked_intervals_for_credential(sub_proof_index, requested_proof, proof_req);
+
+        for interval in intervals.iter() {
+            if !Verifier::_check_timestamp_in_interval(timestamp, interval, tolerance) {
+                return Ok(false);
+            }
+
+            // Without lower bound verifier asks for the state as of `to`,
+            // so accumulator must not be replaced by another one before that moment
+            if let (None, Some(to)) = (interval.from, interval.to) {
+                let newer_timestamps = rev_regs_for_cred.keys()
+                    .filter(|ts| timestamp < **ts && **ts <= to.saturating_add(tolerance))
+                    .cloned()
+                    .collect::<Vec<u64>>();
+
+                let accum = serde_json::to_value(&rev_regs_for_cred[&timestamp].value)
+                    .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistry")?;
+
+                for newer_timestamp in newer_timestamps {
+                    let newer_accum = serde_json::to_value(&rev_regs_for_cred[&newer_timestamp].value)
+                        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistry")?;
+
+                    if accum != newer_accum {
+                        debug!("_validate_timestamp: proof uses stale accumulator for timestamp: {}, newer one exists for timestamp: {}", timestamp, newer_timestamp);
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+
+        trace!("_validate_timestamp <<< valid: true");
+
+        Ok(true)
+    }
+
+    fn _check_timestamp_in_interval(timestamp: u64, interval: &NonRevocedInterval, tolerance: u64) -> bool {
+        if let Some(from) = interval.from {
+            if timestamp < from.saturating_sub(tolerance) {
+                debug!("_check_timestamp_in_interval: timestamp {} is before non_revoked interval {:?} (tolerance: {})", timestamp, interval, tolerance);
+                return false;
+            }
+        }
+
+        if let Some(to) = interval.to {
+            if timestamp > to.saturating_add(tolerance) {
+                debug!("_check_timestamp_in_interval: timestamp {} is after non_revoked interval {:?} (tolerance: {})", timestamp, interval, tolerance);
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn _get_non_revoked_intervals_for_credential(sub_proof_index: usize,
+                                                 requested_proof: &RequestedProof,
+                                                 proof_req: &ProofRequest) -> Vec<NonRevocedInterval> {
+        let attr_referents = requested_proof.revealed_attrs
+            .iter()
+            .filter(|&(_, ref revealed_attr_info)| sub_proof_index == revealed_attr_info.sub_proof_index as usize)
+            .map(|(attr_referent, _)| attr_referent)
+            .chain(requested_proof.unrevealed_attrs
+                .iter()
+                .filter(|&(_, ref sub_proof_referent)| sub_proof_index == sub_proof_referent.sub_proof_index as usize)
+                .map(|(attr_referent, _)| attr_referent));
+
+        let attr_intervals = attr_referents
+            .filter_map(|attr_referent| proof_req.requested_attributes.get(attr_referent))
+            .map(|attr_info| attr_info.non_revoked.clone());
+
+        let predicate_intervals = requested_proof.predicates
+            .iter()
+            .filter(|&(_, ref sub_proof_referent)| sub_proof_index == sub_proof_referent.sub_proof_index as usize)
+            .filter_map(|(predicate_referent, _)| proof_req.requested_predicates.get(predicate_referent))
+            .map(|predicate_info| predicate_info.non_revoked.clone());
+
+        attr_intervals
+            .chain(predicate_intervals)
+            .filter_map(|local_interval| local_interval.or(proof_req.non_revoked.clone()))
+            .collect()
+    }
+
     fn _get_revealed_attributes_for_credential(sub_proof_index: usize,
                                                requested_proof: &RequestedProof,
                                                proof_req: &ProofRequest) -> IndyResult<Vec<AttributeInfo>> {
@@ -128,3 +280,102 @@ impl Verifier {
         Ok(predicates_for_credential)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use self::indy_crypto::cl::issuer::Issuer as CryptoIssuer;
+    use std::collections::HashSet;
+
+    fn _interval(from: Option<u64>, to: Option<u64>) -> NonRevocedInterval {
+        NonRevocedInterval { from, to }
+    }
+
+    #[test]
+    fn check_timestamp_in_interval_works() {
+        assert!(Verifier::_check_timestamp_in_interval(150, &_interval(Some(100), Some(200)), 0));
+        assert!(Verifier::_check_timestamp_in_interval(100, &_interval(Some(100), Some(200)), 0));
+        assert!(Verifier::_check_timestamp_in_interval(200, &_interval(Some(100), Some(200)), 0));
+        assert!(Verifier::_check_timestamp_in_interval(50, &_interval(None, Some(200)), 0));
+        assert!(Verifier::_check_timestamp_in_interval(500, &_interval(Some(100), None), 0));
+    }
+
+    #[test]
+    fn check_timestamp_in_interval_works_for_timestamp_out_of_interval() {
+        assert!(!Verifier::_check_timestamp_in_interval(99, &_interval(Some(100), Some(200)), 0));
+        assert!(!Verifier::_check_timestamp_in_interval(201, &_interval(Some(100), Some(200)), 0));
+        assert!(!Verifier::_check_timestamp_in_interval(201, &_interval(None, Some(200)), 0));
+    }
+
+    #[test]
+    fn check_timestamp_in_interval_works_for_tolerance() {
+        assert!(Verifier::_check_timestamp_in_interval(90, &_interval(Some(100), Some(200)), 10));
+        assert!(Verifier::_check_timestamp_in_interval(210, &_interval(Some(100), Some(200)), 10));
+        assert!(!Verifier::_check_timestamp_in_interval(89, &_interval(Some(100), Some(200)), 10));
+    }
+
+    fn _requested_proof() -> RequestedProof {
+        serde_json::from_value(json!({
+            "revealed_attrs": {"attr1_referent": {"sub_proof_index": 0, "raw": "Alex", "encoded": "1139481716457488690172217916278103335"}},
+            "unrevealed_attrs": {},
+            "self_attested_attrs": {},
+            "predicates": {}
+        })).unwrap()
+    }
+
+    fn _proof_req(non_revoked: serde_json::Value) -> ProofRequest {
+        serde_json::from_value(json!({
+            "nonce": "123432421212",
+            "name": "proof_req_1",
+            "version": "0.1",
+            "requested_attributes": {"attr1_referent": {"name": "name"}},
+            "requested_predicates": {},
+            "non_revoked": non_revoked
+        })).unwrap()
+    }
+
+    // Every registry issued by default gets own accumulator
+    fn _rev_regs(count: usize) -> Vec<RevocationRegistryV1> {
+        let attr_names: HashSet<String> = vec!["name".to_string()].into_iter().collect();
+        let credential_schema = build_credential_schema(&attr_names).unwrap();
+        let non_credential_schema = build_non_credential_schema().unwrap();
+
+        let (credential_pub_key, _, _) = CryptoIssuer::new_credential_def(&credential_schema, &non_credential_schema, true).unwrap();
+
+        (0..count)
+            .map(|_| {
+                let (_, _, rev_reg, _) = CryptoIssuer::new_revocation_registry_def(&credential_pub_key, 5, true).unwrap();
+                RevocationRegistryV1 { value: rev_reg }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn validate_timestamp_works() {
+        let mut rev_regs = _rev_regs(1);
+
+        let mut rev_regs_for_cred = HashMap::new();
+        rev_regs_for_cred.insert(150, rev_regs.remove(0));
+
+        assert!(Verifier::_validate_timestamp(0, 150, &_requested_proof(), &_proof_req(json!({"from": 100, "to": 200})), &rev_regs_for_cred).unwrap());
+        assert!(Verifier::_validate_timestamp(0, 150, &_requested_proof(), &_proof_req(json!({"to": 200})), &rev_regs_for_cred).unwrap());
+        assert!(!Verifier::_validate_timestamp(0, 150, &_requested_proof(), &_proof_req(json!({"to": 100})), &rev_regs_for_cred).unwrap());
+    }
+
+    #[test]
+    fn validate_timestamp_works_for_stale_accumulator() {
+        let mut rev_regs = _rev_regs(2);
+
+        let mut rev_regs_for_cred = HashMap::new();
+        rev_regs_for_cred.insert(100, rev_regs.remove(0));
+        rev_regs_for_cred.insert(150, rev_regs.remove(0));
+
+        // accumulator has been changed at 150, so state as of 200 isn't proven by accumulator of 100
+        assert!(!Verifier::_validate_timestamp(0, 100, &_requested_proof(), &_proof_req(json!({"to": 200})), &rev_regs_for_cred).unwrap());
+        assert!(Verifier::_validate_timestamp(0, 150, &_requested_proof(), &_proof_req(json!({"to": 200})), &rev_regs_for_cred).unwrap());
+
+        // newer accumulator is out of requested interval
+        assert!(Verifier::_validate_timestamp(0, 100, &_requested_proof(), &_proof_req(json!({"to": 120})), &rev_regs_for_cred).unwrap());
+    }
+}
@@ -1,11 +1,14 @@
 extern crate hex;
+extern crate indy_crypto;
+extern crate zeroize;
 
 use std::collections::HashMap;
 use std::str;
 
 use domain::crypto::combo_box::ComboBox;
 use domain::crypto::did::{Did, MyDidInfo, TheirDid, TheirDidInfo};
-use domain::crypto::key::{Key, KeyInfo};
+use domain::crypto::jws::{EDDSA_ALG, ES256K_ALG, FlattenedJws, JwsHeader, JwsOptions, JwsSerialization};
+use domain::crypto::key::{BlsKey, BlsKeyInfo, Jwk, Key, KeyExportFormat, KeyInfo, MasterSeed};
 use errors::prelude::*;
 use utils::crypto::base58;
 use utils::crypto::base64;
@@ -13,27 +16,48 @@ use utils::crypto::ed25519_box;
 use utils::crypto::chacha20poly1305_ietf;
 use utils::crypto::chacha20poly1305_ietf::{ gen_nonce_and_encrypt_detached};
 use utils::crypto::ed25519_sign;
+use utils::crypto::pkcs8;
+use utils::crypto::randombytes::randombytes;
+use utils::crypto::slip10;
 use utils::crypto::verkey_builder::build_full_verkey;
 
 use self::ed25519::ED25519CryptoType;
-use self::hex::FromHex;
+use self::hex::{FromHex, ToHex};
+use self::indy_crypto::bls::{Bls, Generator, ProofOfPossession, SignKey as BlsSignKey, VerKey as BlsVerKey};
+use self::secp256k1::{Secp256k1CryptoType, SECP256K1_CRYPTO_TYPE};
+use self::x25519::{X25519CryptoType, X25519_CRYPTO_TYPE};
+use self::zeroize::Zeroize;
 
 mod ed25519;
+mod secp256k1;
+mod x25519;
+pub mod stream;
 
 pub const DEFAULT_CRYPTO_TYPE: &'static str = "ed25519";
 
-//TODO fix this crypto trait so it matches the functions below
+const OKP_KEY_TYPE: &'static str = "OKP";
+
+// Base58 encoded BLS generator used by Indy Node for pool multi-signatures
+const BLS_GENERATOR: &'static str = "3LHpUjiyFC2q2hD7MnwwNmVXiuaFbQx2XkAFJWzswCjgN1utjsCeLzHsKk1nJvFEaS4fcrUmVAkdhtPCYbrVyATZcmzwJReTcJqwqBCPTmTQ9uWPwz6rEncKb2pYYYFcdHa8N17HzVyTqKfgPi4X9pMetfT3A5xCHq54R2pDNYWVLDX";
+
+// crypto type, JWK curve, PKCS#8 algorithm oid
+const EXPORTABLE_CRYPTO_TYPES: [(&'static str, &'static str, &'static [u8]); 2] = [
+    (DEFAULT_CRYPTO_TYPE, "Ed25519", pkcs8::ED25519_OID),
+    (X25519_CRYPTO_TYPE, "X25519", pkcs8::X25519_OID),
+];
+
 //TODO create a second crypto trait for additional functions
+// Keys and signatures are passed as raw bytes, each crypto type validates and parses them itself.
 trait CryptoType {
-    fn crypto_box(&self, sk: &ed25519_sign::SecretKey, vk: &ed25519_sign::PublicKey, doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>>;
-    fn crypto_box_open(&self, sk: &ed25519_sign::SecretKey, vk: &ed25519_sign::PublicKey, doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>>;
+    fn crypto_box(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>>;
+    fn crypto_box_open(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>>;
     fn gen_nonce(&self) -> ed25519_box::Nonce;
-    fn create_key(&self, seed: Option<&ed25519_sign::Seed>) -> IndyResult<(ed25519_sign::PublicKey, ed25519_sign::SecretKey)>;
-    fn validate_key(&self, _vk: &ed25519_sign::PublicKey) -> IndyResult<()>;
-    fn sign(&self, sk: &ed25519_sign::SecretKey, doc: &[u8]) -> IndyResult<ed25519_sign::Signature>;
-    fn verify(&self, vk: &ed25519_sign::PublicKey, doc: &[u8], signature: &ed25519_sign::Signature) -> IndyResult<bool>;
-    fn crypto_box_seal(&self, vk: &ed25519_sign::PublicKey, doc: &[u8]) -> IndyResult<Vec<u8>>;
-    fn crypto_box_seal_open(&self, vk: &ed25519_sign::PublicKey, sk: &ed25519_sign::SecretKey, doc: &[u8]) -> IndyResult<Vec<u8>>;
+    fn create_key(&self, seed: Option<&ed25519_sign::Seed>) -> IndyResult<(Vec<u8>, Vec<u8>)>;
+    fn validate_key(&self, vk: &[u8]) -> IndyResult<()>;
+    fn sign(&self, sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>>;
+    fn verify(&self, vk: &[u8], doc: &[u8], signature: &[u8]) -> IndyResult<bool>;
+    fn crypto_box_seal(&self, vk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>>;
+    fn crypto_box_seal_open(&self, vk: &[u8], sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>>;
 }
 
 pub struct CryptoService {
@@ -44,6 +68,8 @@ impl CryptoService {
     pub fn new() -> CryptoService {
         let mut crypto_types: HashMap<&str, Box<CryptoType>> = HashMap::new();
         crypto_types.insert(DEFAULT_CRYPTO_TYPE, Box::new(ED25519CryptoType::new()));
+        crypto_types.insert(SECP256K1_CRYPTO_TYPE, Box::new(Secp256k1CryptoType::new()));
+        crypto_types.insert(X25519_CRYPTO_TYPE, Box::new(X25519CryptoType::new()));
 
         CryptoService {
             crypto_types
@@ -155,8 +181,8 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let my_sk = ed25519_sign::SecretKey::from_slice(&base58::decode(my_key.signkey.as_str())?)?;
-        let signature = crypto_type.sign(&my_sk, doc)?[..].to_vec();
+        let my_sk = base58::decode(my_key.signkey.as_str())?;
+        let signature = crypto_type.sign(&my_sk, doc)?;
 
         trace!("sign <<< signature: {:?}", signature);
 
@@ -179,10 +205,9 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let their_vk = ed25519_sign::PublicKey::from_slice(&base58::decode(&their_vk)?)?;
-        let signature = ed25519_sign::Signature::from_slice(&signature)?;
+        let their_vk = base58::decode(&their_vk)?;
 
-        let valid = crypto_type.verify(&their_vk, msg, &signature)?;
+        let valid = crypto_type.verify(&their_vk, msg, signature)?;
 
         trace!("verify <<< valid: {:?}", valid);
 
@@ -236,8 +261,8 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(&crypto_type_name).unwrap();
 
-        let my_sk = ed25519_sign::SecretKey::from_slice(&base58::decode(my_key.signkey.as_str())?)?;
-        let their_vk = ed25519_sign::PublicKey::from_slice(&base58::decode(their_vk)?)?;
+        let my_sk = base58::decode(my_key.signkey.as_str())?;
+        let their_vk = base58::decode(their_vk)?;
         let nonce = crypto_type.gen_nonce();
 
         let encrypted_doc = crypto_type.crypto_box(&my_sk, &their_vk, doc, &nonce)?;
@@ -280,8 +305,8 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let my_sk = ed25519_sign::SecretKey::from_slice(&base58::decode(&my_key.signkey)?)?;
-        let their_vk = ed25519_sign::PublicKey::from_slice(&base58::decode(their_vk)?)?;
+        let my_sk = base58::decode(&my_key.signkey)?;
+        let their_vk = base58::decode(their_vk)?;
         let nonce = ed25519_box::Nonce::from_slice(&nonce)?;
 
         let decrypted_doc = crypto_type.crypto_box_open(&my_sk, &their_vk, &doc, &nonce)?;
@@ -307,7 +332,7 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let their_vk = ed25519_sign::PublicKey::from_slice(&base58::decode(their_vk)?)?;
+        let their_vk = base58::decode(their_vk)?;
 
         let encrypted_doc = crypto_type.crypto_box_seal(&their_vk, doc)?;
 
@@ -333,8 +358,8 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let my_vk = ed25519_sign::PublicKey::from_slice(&base58::decode(my_vk)?)?;
-        let my_sk = ed25519_sign::SecretKey::from_slice(&base58::decode(my_key.signkey.as_str())?)?;
+        let my_vk = base58::decode(my_vk)?;
+        let my_sk = base58::decode(my_key.signkey.as_str())?;
 
         let decrypted_doc = crypto_type.crypto_box_seal_open(&my_vk, &my_sk, doc)?;
 
@@ -343,6 +368,44 @@ impl CryptoService {
         Ok(decrypted_doc)
     }
 
+    pub fn create_master_seed(&self, seed: Option<&str>) -> IndyResult<MasterSeed> {
+        trace!("create_master_seed >>> seed: {:?}", secret!(seed));
+
+        let seed = match seed {
+            Some(seed) => Vec::from_hex(seed)
+                .to_indy(IndyErrorKind::InvalidStructure, "Master seed is invalid hex")?,
+            None => randombytes(ed25519_sign::SEEDBYTES)
+        };
+
+        if seed.len() < slip10::MIN_SEEDBYTES || seed.len() > slip10::MAX_SEEDBYTES {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid master seed length"));
+        }
+
+        let res = MasterSeed { seed: seed.to_hex() };
+
+        trace!("create_master_seed <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    // Returns hex encoded seed that can be used as KeyInfo or MyDidInfo seed
+    pub fn derive_seed(&self, master_seed: &MasterSeed, crypto_type: Option<&str>, derivation_path: &str) -> IndyResult<String> {
+        trace!("derive_seed >>> master_seed: {:?}, crypto_type: {:?}, derivation_path: {:?}", master_seed, crypto_type, derivation_path);
+
+        if crypto_type.unwrap_or(DEFAULT_CRYPTO_TYPE) != DEFAULT_CRYPTO_TYPE {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Derivation path is supported only for {} keys", DEFAULT_CRYPTO_TYPE)));
+        }
+
+        let master_seed = Vec::from_hex(&master_seed.seed)
+            .to_indy(IndyErrorKind::InvalidState, "Master seed is invalid hex")?;
+
+        let res = slip10::derive_ed25519_key(&master_seed, derivation_path)?.to_hex();
+
+        trace!("derive_seed <<< res: {:?}", secret!(&res));
+
+        Ok(res)
+    }
+
     pub fn convert_seed(&self, seed: Option<&str>) -> IndyResult<Option<ed25519_sign::Seed>> {
         trace!("convert_seed >>> seed: {:?}", secret!(seed));
 
@@ -375,6 +438,47 @@ impl CryptoService {
         Ok(Some(res))
     }
 
+    pub fn create_bls_key(&self, key_info: &BlsKeyInfo) -> IndyResult<BlsKey> {
+        trace!("create_bls_key >>> key_info: {:?}", secret!(key_info));
+
+        let seed = self.convert_seed(key_info.seed.as_ref().map(String::as_ref))?;
+
+        let sign_key = BlsSignKey::new(seed.as_ref().map(|seed| &seed[0..ed25519_sign::SEEDBYTES]))?;
+        let ver_key = BlsVerKey::new(&_bls_generator()?, &sign_key)?;
+
+        let res = BlsKey::new(base58::encode(ver_key.as_bytes()), base58::encode(sign_key.as_bytes()));
+
+        trace!("create_bls_key <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn create_bls_key_pop(&self, bls_key: &BlsKey) -> IndyResult<String> {
+        trace!("create_bls_key_pop >>> bls_key: {:?}", bls_key);
+
+        let sign_key = BlsSignKey::from_bytes(&base58::decode(&bls_key.signkey)?)?;
+        let ver_key = BlsVerKey::from_bytes(&base58::decode(&bls_key.verkey)?)?;
+
+        let res = base58::encode(ProofOfPossession::new(&ver_key, &sign_key)?.as_bytes());
+
+        trace!("create_bls_key_pop <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn verify_bls_key_pop(&self, blskey: &str, blskey_pop: &str) -> IndyResult<bool> {
+        trace!("verify_bls_key_pop >>> blskey: {:?}, blskey_pop: {:?}", blskey, blskey_pop);
+
+        let ver_key = BlsVerKey::from_bytes(&base58::decode(blskey)?)?;
+        let pop = ProofOfPossession::from_bytes(&base58::decode(blskey_pop)?)?;
+
+        let res = Bls::verify_proof_of_posession(&pop, &ver_key, &_bls_generator()?)?;
+
+        trace!("verify_bls_key_pop <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     pub fn validate_key(&self, vk: &str) -> IndyResult<()> {
         trace!("validate_key >>> vk: {:?}", vk);
 
@@ -394,7 +498,7 @@ impl CryptoService {
         if vk.starts_with('~') {
             base58::decode(&vk[1..])?; // TODO: proper validate abbreviated verkey
         } else {
-            let vk = ed25519_sign::PublicKey::from_slice(&base58::decode(vk)?)?;
+            let vk = base58::decode(vk)?;
             crypto_type.validate_key(&vk)?;
         };
 
@@ -419,6 +523,249 @@ impl CryptoService {
         Ok(res)
     }
 
+    pub fn export_key(&self, key: &Key, format: KeyExportFormat, protection_key: Option<&str>) -> IndyResult<String> {
+        trace!("export_key >>> key: {:?}, format: {:?}", key, format);
+
+        let (vk, crypto_type_name) = if key.verkey.contains(':') {
+            let splits: Vec<&str> = key.verkey.split(':').collect();
+            (splits[0], splits[1])
+        } else {
+            (key.verkey.as_str(), DEFAULT_CRYPTO_TYPE)
+        };
+
+        let &(_, curve, oid) = EXPORTABLE_CRYPTO_TYPES.iter()
+            .find(|&&(name, _, _)| name == crypto_type_name)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownCrypto, format!("Trying to export key with unsupported crypto: {}", crypto_type_name)))?;
+
+        let vk = base58::decode(vk)?;
+        let mut sk = base58::decode(&key.signkey)?;
+
+        // ed25519 secret key is stored as seed || public key, export seed only
+        let mut private_key = if crypto_type_name == DEFAULT_CRYPTO_TYPE {
+            if sk.len() != ed25519_sign::SIG_SECRETKEYBYTES {
+                sk.zeroize();
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid bytes for ed25519 SecretKey"));
+            }
+            let seed = sk[..ed25519_sign::SEEDBYTES].to_vec();
+            sk.zeroize();
+            seed
+        } else {
+            sk
+        };
+
+        let res = self._export_private_key(&vk, &private_key, curve, oid, format, protection_key);
+
+        private_key.zeroize();
+
+        trace!("export_key <<<");
+
+        res
+    }
+
+    fn _export_private_key(&self,
+                           vk: &[u8],
+                           private_key: &[u8],
+                           curve: &str,
+                           oid: &[u8],
+                           format: KeyExportFormat,
+                           protection_key: Option<&str>) -> IndyResult<String> {
+        let res = match format {
+            KeyExportFormat::Jwk => {
+                if protection_key.is_some() {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, "Protection key is not supported for jwk format"));
+                }
+
+                let jwk = Jwk {
+                    kty: OKP_KEY_TYPE.to_string(),
+                    crv: curve.to_string(),
+                    x: base64::encode_urlsafe_nopad(&vk),
+                    d: Some(base64::encode_urlsafe_nopad(&private_key)),
+                };
+
+                serde_json::to_string(&jwk)
+                    .to_indy(IndyErrorKind::InvalidState, "Can't serialize JWK")?
+            }
+            KeyExportFormat::Pkcs8 => {
+                let protection_key = protection_key
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Protection key is required for pkcs8 format"))?;
+
+                pkcs8::encrypt_private_key(oid, &private_key, protection_key)?
+            }
+        };
+
+        Ok(res)
+    }
+
+    pub fn import_key(&self, key_data: &str, format: KeyExportFormat, protection_key: Option<&str>) -> IndyResult<Key> {
+        trace!("import_key >>> format: {:?}", format);
+
+        let (crypto_type_name, private_key, public_key) = match format {
+            KeyExportFormat::Jwk => {
+                if protection_key.is_some() {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, "Protection key is not supported for jwk format"));
+                }
+
+                let jwk: Jwk = serde_json::from_str(key_data)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Invalid JWK")?;
+
+                if jwk.kty != OKP_KEY_TYPE {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported JWK key type: {}", jwk.kty)));
+                }
+
+                let &(crypto_type_name, _, _) = EXPORTABLE_CRYPTO_TYPES.iter()
+                    .find(|&&(_, curve, _)| curve == jwk.crv)
+                    .ok_or_else(|| err_msg(IndyErrorKind::UnknownCrypto, format!("Unsupported JWK curve: {}", jwk.crv)))?;
+
+                let private_key = jwk.d.as_ref()
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "JWK doesn't contain private key"))?;
+
+                (crypto_type_name,
+                 base64::decode_urlsafe_nopad(private_key)?,
+                 Some(base64::decode_urlsafe_nopad(&jwk.x)?))
+            }
+            KeyExportFormat::Pkcs8 => {
+                let protection_key = protection_key
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Protection key is required for pkcs8 format"))?;
+
+                let (algorithm_oid, private_key) = pkcs8::decrypt_private_key(key_data, protection_key)?;
+
+                let &(crypto_type_name, _, _) = EXPORTABLE_CRYPTO_TYPES.iter()
+                    .find(|&&(_, _, oid)| oid == algorithm_oid.as_slice())
+                    .ok_or_else(|| err_msg(IndyErrorKind::UnknownCrypto, "Unsupported PKCS#8 private key algorithm"))?;
+
+                (crypto_type_name, private_key, None)
+            }
+        };
+
+        let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
+
+        let mut private_key = private_key;
+        let seed = ed25519_sign::Seed::from_slice(&private_key);
+        private_key.zeroize();
+
+        let (vk, sk) = crypto_type.create_key(Some(&seed?))?;
+
+        if let Some(public_key) = public_key {
+            if public_key != vk {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "JWK public key doesn't match private key"));
+            }
+        }
+
+        let mut vk = base58::encode(&vk[..]);
+        let sk = base58::encode(&sk[..]);
+
+        if !crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
+            // Use suffix with crypto type name to store crypto type inside of vk
+            vk = format!("{}:{}", vk, crypto_type_name);
+        }
+
+        let key = Key::new(vk, sk);
+
+        trace!("import_key <<< key: {:?}", key);
+
+        Ok(key)
+    }
+
+    pub fn sign_jws(&self, my_key: &Key, payload: &[u8], options: &JwsOptions) -> IndyResult<String> {
+        trace!("sign_jws >>> my_key: {:?}, payload: {:?}, options: {:?}", my_key, payload, options);
+
+        let header = JwsHeader {
+            alg: self._jws_alg(&my_key.verkey)?.to_string(),
+            kid: Some(options.kid.clone().unwrap_or_else(|| my_key.verkey.clone())),
+        };
+
+        let header = serde_json::to_string(&header)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize JWS header")?;
+
+        let protected = base64::encode_urlsafe_nopad(header.as_bytes());
+        let payload = base64::encode_urlsafe_nopad(payload);
+
+        let signature = self.sign(my_key, format!("{}.{}", protected, payload).as_bytes())?;
+        let signature = base64::encode_urlsafe_nopad(&signature);
+
+        let res = match options.serialization {
+            JwsSerialization::Compact => {
+                let payload = if options.detached { "" } else { payload.as_str() };
+                format!("{}.{}.{}", protected, payload, signature)
+            }
+            JwsSerialization::Json => {
+                let jws = FlattenedJws {
+                    protected,
+                    payload: if options.detached { None } else { Some(payload) },
+                    signature,
+                };
+
+                serde_json::to_string(&jws)
+                    .to_indy(IndyErrorKind::InvalidState, "Can't serialize JWS")?
+            }
+        };
+
+        trace!("sign_jws <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn verify_jws(&self, jws: &str, detached_payload: Option<&[u8]>, their_vk: &str) -> IndyResult<bool> {
+        trace!("verify_jws >>> jws: {:?}, detached_payload: {:?}, their_vk: {:?}", jws, detached_payload, their_vk);
+
+        let jws = jws.trim();
+
+        let (protected, payload, signature) = if jws.starts_with('{') {
+            let jws: FlattenedJws = serde_json::from_str(jws)
+                .to_indy(IndyErrorKind::InvalidStructure, "Invalid JWS JSON serialization")?;
+            (jws.protected, jws.payload, jws.signature)
+        } else {
+            let parts: Vec<&str> = jws.split('.').collect();
+
+            if parts.len() != 3 {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid JWS compact serialization"));
+            }
+
+            let payload = if parts[1].is_empty() { None } else { Some(parts[1].to_string()) };
+            (parts[0].to_string(), payload, parts[2].to_string())
+        };
+
+        let payload = match (payload, detached_payload) {
+            (Some(payload), None) => payload,
+            (None, Some(detached_payload)) => base64::encode_urlsafe_nopad(detached_payload),
+            (Some(_), Some(_)) => return Err(err_msg(IndyErrorKind::InvalidStructure, "JWS contains payload, detached payload must not be passed")),
+            (None, None) => return Err(err_msg(IndyErrorKind::InvalidStructure, "JWS payload is detached but not passed")),
+        };
+
+        let header: JwsHeader = serde_json::from_slice(&base64::decode_urlsafe_nopad(&protected)?)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid JWS protected header")?;
+
+        // kid header is controlled by signer, so it is never used to choose the verification key
+        self.validate_key(their_vk)?;
+
+        if header.alg != self._jws_alg(their_vk)? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("JWS alg {} doesn't match key crypto type", header.alg)));
+        }
+
+        let signature = base64::decode_urlsafe_nopad(&signature)?;
+
+        let valid = self.verify(their_vk, format!("{}.{}", protected, payload).as_bytes(), &signature)?;
+
+        trace!("verify_jws <<< valid: {:?}", valid);
+
+        Ok(valid)
+    }
+
+    fn _jws_alg(&self, verkey: &str) -> IndyResult<&'static str> {
+        let crypto_type_name = if verkey.contains(':') {
+            let splits: Vec<&str> = verkey.split(':').collect();
+            splits[1]
+        } else {
+            DEFAULT_CRYPTO_TYPE
+        };
+
+        match crypto_type_name {
+            DEFAULT_CRYPTO_TYPE => Ok(EDDSA_ALG),
+            SECP256K1_CRYPTO_TYPE => Ok(ES256K_ALG),
+            _ => Err(err_msg(IndyErrorKind::UnknownCrypto, format!("JWS is not supported for crypto: {}", crypto_type_name)))
+        }
+    }
+
     pub fn encrypt_plaintext(&self,
                              plaintext: Vec<u8>,
                              aad: &str,
@@ -490,6 +837,10 @@ impl CryptoService {
 }
 
 
+fn _bls_generator() -> IndyResult<Generator> {
+    Ok(Generator::from_bytes(&base58::decode(BLS_GENERATOR)?)?)
+}
+
 #[cfg(test)]
 mod tests {
     use domain::crypto::did::MyDidInfo;
@@ -500,7 +851,7 @@ mod tests {
     #[test]
     fn create_my_did_with_works_for_empty_info() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
         let my_did = service.create_my_did(&did_info);
         assert!(my_did.is_ok());
     }
@@ -510,7 +861,7 @@ mod tests {
         let service = CryptoService::new();
 
         let did = "NcYxiDXkpYi6ov5FcYDi1e";
-        let did_info = MyDidInfo { did: Some(did.to_string()), cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: Some(did.to_string()), cid: None, seed: None, crypto_type: None, derivation_path: None };
 
         let (my_did, _) = service.create_my_did(&did_info).unwrap();
         assert_eq!(did, my_did.did);
@@ -523,7 +874,7 @@ mod tests {
         let did = Some("NcYxiDXkpYi6ov5FcYDi1e".to_string());
         let crypto_type = Some("type".to_string());
 
-        let did_info = MyDidInfo { did: did.clone(), cid: None, seed: None, crypto_type };
+        let did_info = MyDidInfo { did: did.clone(), cid: None, seed: None, crypto_type, derivation_path: None };
 
         assert!(service.create_my_did(&did_info).is_err());
     }
@@ -535,8 +886,8 @@ mod tests {
         let did = Some("NcYxiDXkpYi6ov5FcYDi1e".to_string());
         let seed = Some("00000000000000000000000000000My1".to_string());
 
-        let did_info_with_seed = MyDidInfo { did: did.clone(), cid: None, seed, crypto_type: None };
-        let did_info_without_seed = MyDidInfo { did: did.clone(), cid: None, seed: None, crypto_type: None };
+        let did_info_with_seed = MyDidInfo { did: did.clone(), cid: None, seed, crypto_type: None, derivation_path: None };
+        let did_info_without_seed = MyDidInfo { did: did.clone(), cid: None, seed: None, crypto_type: None, derivation_path: None };
 
         let (did_with_seed, _) = service.create_my_did(&did_info_with_seed).unwrap();
         let (did_without_seed, _) = service.create_my_did(&did_info_without_seed).unwrap();
@@ -583,7 +934,7 @@ mod tests {
     #[test]
     fn sign_works() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
 
         let message = r#"message"#;
         let (_, my_key) = service.create_my_did(&did_info).unwrap();
@@ -602,7 +953,7 @@ mod tests {
     #[test]
     fn sign_verify_works() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
         let message = r#"message"#;
         let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
         let signature = service.sign(&my_key, message.as_bytes()).unwrap();
@@ -613,7 +964,7 @@ mod tests {
     #[test]
     fn sign_verify_works_for_verkey_contained_crypto_type() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
         let message = r#"message"#;
         let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
         let signature = service.sign(&my_key, message.as_bytes()).unwrap();
@@ -626,7 +977,7 @@ mod tests {
     #[test]
     fn sign_verify_works_for_verkey_contained_invalid_crypto_type() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
         let message = r#"message"#;
         let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
         let signature = service.sign(&my_key, message.as_bytes()).unwrap();
@@ -637,7 +988,7 @@ mod tests {
     #[test]
     fn verify_not_works_for_invalid_verkey() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
         let message = r#"message"#;
         let (_, my_key) = service.create_my_did(&did_info).unwrap();
         let signature = service.sign(&my_key, message.as_bytes()).unwrap();
@@ -650,7 +1001,7 @@ mod tests {
     fn crypto_box_works() {
         let service = CryptoService::new();
         let msg = "some message";
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
         let (_, my_key) = service.create_my_did(&did_info).unwrap();
         let (their_did, _) = service.create_my_did(&did_info.clone()).unwrap();
         let their_did = Did::new(their_did.did, their_did.verkey);
@@ -664,7 +1015,7 @@ mod tests {
 
         let msg = "some message";
 
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
 
         let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
 
@@ -692,7 +1043,7 @@ mod tests {
 
         let msg = "some message";
 
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
 
         let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
 
@@ -718,7 +1069,7 @@ mod tests {
     fn crypto_box_seal_works() {
         let service = CryptoService::new();
         let msg = "some message";
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
         let (did, _) = service.create_my_did(&did_info.clone()).unwrap();
         let did = Did::new(did.did, did.verkey);
         let encrypted_message = service.crypto_box_seal(&did.verkey, msg.as_bytes());
@@ -729,7 +1080,7 @@ mod tests {
     fn crypto_box_seal_and_crypto_box_seal_open_works() {
         let service = CryptoService::new();
         let msg = "some message".as_bytes();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, derivation_path: None };
         let (did, key) = service.create_my_did(&did_info.clone()).unwrap();
         let encrypt_did = Did::new(did.did.clone(), did.verkey.clone());
         let encrypted_message = service.crypto_box_seal(&encrypt_did.verkey, msg).unwrap();
@@ -737,6 +1088,223 @@ mod tests {
         assert_eq!(msg, decrypted_message.as_slice());
     }
 
+    #[test]
+    fn sign_verify_works_for_secp256k1() {
+        let service = CryptoService::new();
+        let key_info = KeyInfo { seed: None, crypto_type: Some("secp256k1".to_string()), derivation_path: None };
+        let message = r#"message"#;
+        let key = service.create_key(&key_info).unwrap();
+        assert!(key.verkey.ends_with(":secp256k1"));
+
+        let signature = service.sign(&key, message.as_bytes()).unwrap();
+        assert!(service.verify(&key.verkey, message.as_bytes(), &signature).unwrap());
+        assert!(!service.verify(&key.verkey, "other message".as_bytes(), &signature).unwrap());
+    }
+
+    #[test]
+    fn create_key_works_for_secp256k1_seed() {
+        let service = CryptoService::new();
+        let key_info = KeyInfo { seed: Some("00000000000000000000000000000My1".to_string()), crypto_type: Some("secp256k1".to_string()), derivation_path: None };
+
+        let key1 = service.create_key(&key_info).unwrap();
+        let key2 = service.create_key(&key_info).unwrap();
+
+        assert_eq!(key1.verkey, key2.verkey);
+        assert!(service.validate_key(&key1.verkey).is_ok());
+    }
+
+    #[test]
+    fn create_bls_key_works_for_seed() {
+        let service = CryptoService::new();
+        let key_info = BlsKeyInfo { seed: Some("00000000000000000000000000000My1".to_string()) };
+
+        let key1 = service.create_bls_key(&key_info).unwrap();
+        let key2 = service.create_bls_key(&key_info).unwrap();
+
+        assert_eq!(key1.verkey, key2.verkey);
+        assert_ne!(key1.verkey, service.create_bls_key(&BlsKeyInfo::default()).unwrap().verkey);
+    }
+
+    #[test]
+    fn create_bls_key_pop_works() {
+        let service = CryptoService::new();
+        let key = service.create_bls_key(&BlsKeyInfo::default()).unwrap();
+
+        let pop = service.create_bls_key_pop(&key).unwrap();
+        assert!(service.verify_bls_key_pop(&key.verkey, &pop).unwrap());
+    }
+
+    #[test]
+    fn verify_bls_key_pop_works_for_other_key() {
+        let service = CryptoService::new();
+        let key = service.create_bls_key(&BlsKeyInfo::default()).unwrap();
+        let other_key = service.create_bls_key(&BlsKeyInfo::default()).unwrap();
+
+        let pop = service.create_bls_key_pop(&other_key).unwrap();
+        assert!(!service.verify_bls_key_pop(&key.verkey, &pop).unwrap());
+    }
+
+    #[test]
+    fn verify_bls_key_pop_works_for_invalid_key() {
+        let service = CryptoService::new();
+        assert_kind!(IndyErrorKind::InvalidStructure, service.verify_bls_key_pop("blskey", "pop"));
+    }
+
+    #[test]
+    fn crypto_box_not_works_for_secp256k1() {
+        let service = CryptoService::new();
+        let key_info = KeyInfo { seed: None, crypto_type: Some("secp256k1".to_string()), derivation_path: None };
+        let my_key = service.create_key(&key_info).unwrap();
+        let their_key = service.create_key(&key_info).unwrap();
+        assert!(service.crypto_box(&my_key, &their_key.verkey, "some message".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn crypto_box_and_crypto_box_open_works_for_x25519() {
+        let service = CryptoService::new();
+        let msg = "some message";
+        let key_info = KeyInfo { seed: None, crypto_type: Some("x25519".to_string()), derivation_path: None };
+
+        let my_key = service.create_key(&key_info).unwrap();
+        let their_key = service.create_key(&key_info).unwrap();
+        assert!(my_key.verkey.ends_with(":x25519"));
+
+        let (encrypted_message, nonce) = service.crypto_box(&my_key, &their_key.verkey, msg.as_bytes()).unwrap();
+        let decrypted_message = service.crypto_box_open(&their_key, &my_key.verkey, &encrypted_message, &nonce).unwrap();
+
+        assert_eq!(msg.as_bytes().to_vec(), decrypted_message);
+    }
+
+    #[test]
+    fn crypto_box_seal_and_crypto_box_seal_open_works_for_x25519() {
+        let service = CryptoService::new();
+        let msg = "some message".as_bytes();
+        let key_info = KeyInfo { seed: None, crypto_type: Some("x25519".to_string()), derivation_path: None };
+        let key = service.create_key(&key_info).unwrap();
+        let encrypted_message = service.crypto_box_seal(&key.verkey, msg).unwrap();
+        let decrypted_message = service.crypto_box_seal_open(&key, &encrypted_message).unwrap();
+        assert_eq!(msg, decrypted_message.as_slice());
+    }
+
+    #[test]
+    fn crypto_box_not_works_for_x25519_and_ed25519_keys() {
+        let service = CryptoService::new();
+        let my_key = service.create_key(&KeyInfo { seed: None, crypto_type: Some("x25519".to_string()), derivation_path: None }).unwrap();
+        let their_key = service.create_key(&KeyInfo { seed: None, crypto_type: None, derivation_path: None }).unwrap();
+        assert!(service.crypto_box(&my_key, &their_key.verkey, "some message".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn sign_not_works_for_x25519() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: Some("x25519".to_string()), derivation_path: None }).unwrap();
+        assert!(service.sign(&key, "message".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn export_import_key_works_for_jwk() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: None, derivation_path: None }).unwrap();
+
+        let jwk = service.export_key(&key, KeyExportFormat::Jwk, None).unwrap();
+        let jwk_value: serde_json::Value = serde_json::from_str(&jwk).unwrap();
+        assert_eq!("OKP", jwk_value["kty"].as_str().unwrap());
+        assert_eq!("Ed25519", jwk_value["crv"].as_str().unwrap());
+
+        let imported_key = service.import_key(&jwk, KeyExportFormat::Jwk, None).unwrap();
+        assert_eq!(key.verkey, imported_key.verkey);
+        assert_eq!(key.signkey, imported_key.signkey);
+    }
+
+    #[test]
+    fn import_key_not_works_for_jwk_with_mismatched_public_key() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: None, derivation_path: None }).unwrap();
+        let other_key = service.create_key(&KeyInfo { seed: None, crypto_type: None, derivation_path: None }).unwrap();
+
+        let mut jwk: Jwk = serde_json::from_str(&service.export_key(&key, KeyExportFormat::Jwk, None).unwrap()).unwrap();
+        jwk.x = base64::encode_urlsafe_nopad(&base58::decode(&other_key.verkey).unwrap());
+
+        let res = service.import_key(&serde_json::to_string(&jwk).unwrap(), KeyExportFormat::Jwk, None);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn export_import_key_works_for_pkcs8_and_x25519() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: Some("x25519".to_string()), derivation_path: None }).unwrap();
+
+        let pem = service.export_key(&key, KeyExportFormat::Pkcs8, Some("passphrase")).unwrap();
+
+        let imported_key = service.import_key(&pem, KeyExportFormat::Pkcs8, Some("passphrase")).unwrap();
+        assert_eq!(key.verkey, imported_key.verkey);
+
+        assert!(service.import_key(&pem, KeyExportFormat::Pkcs8, Some("other")).is_err());
+    }
+
+    #[test]
+    fn export_key_not_works_for_pkcs8_without_protection_key() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: None, derivation_path: None }).unwrap();
+        assert_kind!(IndyErrorKind::InvalidStructure, service.export_key(&key, KeyExportFormat::Pkcs8, None));
+    }
+
+    #[test]
+    fn export_key_not_works_for_secp256k1() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: Some("secp256k1".to_string()), derivation_path: None }).unwrap();
+        assert_kind!(IndyErrorKind::UnknownCrypto, service.export_key(&key, KeyExportFormat::Jwk, None));
+    }
+
+    #[test]
+    fn sign_verify_jws_works_for_compact_serialization() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: None, derivation_path: None }).unwrap();
+
+        let jws = service.sign_jws(&key, "payload".as_bytes(), &JwsOptions::default()).unwrap();
+        assert_eq!(3, jws.split('.').count());
+
+        assert!(service.verify_jws(&jws, None, &key.verkey).unwrap());
+    }
+
+    #[test]
+    fn verify_jws_works_for_kid_of_other_signer() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: None, derivation_path: None }).unwrap();
+        let other_key = service.create_key(&KeyInfo { seed: None, crypto_type: None, derivation_path: None }).unwrap();
+        let options = JwsOptions { kid: Some(key.verkey.clone()), detached: false, serialization: JwsSerialization::Compact };
+
+        let jws = service.sign_jws(&other_key, "payload".as_bytes(), &options).unwrap();
+
+        assert!(!service.verify_jws(&jws, None, &key.verkey).unwrap());
+    }
+
+    #[test]
+    fn sign_verify_jws_works_for_detached_json_serialization() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: Some("secp256k1".to_string()), derivation_path: None }).unwrap();
+        let options = JwsOptions { kid: Some("did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1".to_string()), detached: true, serialization: JwsSerialization::Json };
+
+        let jws = service.sign_jws(&key, "payload".as_bytes(), &options).unwrap();
+        let flattened: FlattenedJws = serde_json::from_str(&jws).unwrap();
+        assert!(flattened.payload.is_none());
+
+        assert!(service.verify_jws(&jws, Some("payload".as_bytes()), &key.verkey).unwrap());
+        assert!(!service.verify_jws(&jws, Some("other".as_bytes()), &key.verkey).unwrap());
+        assert_kind!(IndyErrorKind::InvalidStructure, service.verify_jws(&jws, None, &key.verkey));
+    }
+
+    #[test]
+    fn verify_jws_not_works_for_alg_mismatch() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: None, derivation_path: None }).unwrap();
+        let other_key = service.create_key(&KeyInfo { seed: None, crypto_type: Some("secp256k1".to_string()), derivation_path: None }).unwrap();
+
+        let jws = service.sign_jws(&key, "payload".as_bytes(), &JwsOptions::default()).unwrap();
+
+        assert_kind!(IndyErrorKind::InvalidStructure, service.verify_jws(&jws, None, &other_key.verkey));
+    }
+
     #[test]
     pub fn test_encrypt_plaintext_and_decrypt_ciphertext_works() {
         let service: CryptoService = CryptoService::new();
@@ -857,4 +1425,50 @@ mod tests {
             .decrypt_ciphertext(&expected_ciphertext, &iv_encoded, &tag, bad_aad, &cek);
         assert!(expected_error.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn create_master_seed_works() {
+        let service = CryptoService::new();
+
+        let master_seed = service.create_master_seed(None).unwrap();
+        assert_eq!(ed25519_sign::SEEDBYTES * 2, master_seed.seed.len());
+
+        let master_seed = service.create_master_seed(Some("000102030405060708090a0b0c0d0e0f")).unwrap();
+        assert_eq!("000102030405060708090a0b0c0d0e0f", master_seed.seed);
+    }
+
+    #[test]
+    fn create_master_seed_works_for_invalid_seed() {
+        let service = CryptoService::new();
+
+        assert_kind!(IndyErrorKind::InvalidStructure, service.create_master_seed(Some("0001")));
+        assert_kind!(IndyErrorKind::InvalidStructure, service.create_master_seed(Some("not a hex")));
+    }
+
+    #[test]
+    fn derive_seed_works() {
+        let service = CryptoService::new();
+        let master_seed = service.create_master_seed(None).unwrap();
+
+        let seed = service.derive_seed(&master_seed, None, "m/0'/1'").unwrap();
+        let same_seed = service.derive_seed(&master_seed, Some(DEFAULT_CRYPTO_TYPE), "m/0'/1'").unwrap();
+        let other_seed = service.derive_seed(&master_seed, None, "m/0'/2'").unwrap();
+
+        assert_eq!(seed, same_seed);
+        assert_ne!(seed, other_seed);
+
+        let key_info = KeyInfo { seed: Some(seed), crypto_type: None, derivation_path: None };
+        let key = service.create_key(&key_info).unwrap();
+        let same_key = service.create_key(&key_info).unwrap();
+        assert_eq!(key.verkey, same_key.verkey);
+    }
+
+    #[test]
+    fn derive_seed_works_for_unsupported_crypto_type() {
+        let service = CryptoService::new();
+        let master_seed = service.create_master_seed(None).unwrap();
+
+        let res = service.derive_seed(&master_seed, Some(SECP256K1_CRYPTO_TYPE), "m/0'");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+}
@@ -0,0 +1,59 @@
+use errors::prelude::*;
+use super::CryptoType;
+use utils::crypto::ed25519_box;
+use utils::crypto::ed25519_sign;
+use utils::crypto::secp256k1;
+
+
+pub const SECP256K1_CRYPTO_TYPE: &'static str = "secp256k1";
+
+pub struct Secp256k1CryptoType {}
+
+impl Secp256k1CryptoType {
+    pub fn new() -> Secp256k1CryptoType {
+        Secp256k1CryptoType {}
+    }
+
+    fn _unsupported<T>(operation: &str) -> IndyResult<T> {
+        Err(err_msg(IndyErrorKind::UnknownCrypto,
+                    format!("Operation {} is not supported for {} keys", operation, SECP256K1_CRYPTO_TYPE)))
+    }
+}
+
+impl CryptoType for Secp256k1CryptoType {
+    fn crypto_box(&self, _sk: &[u8], _vk: &[u8], _doc: &[u8], _nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>> {
+        Secp256k1CryptoType::_unsupported("crypto_box")
+    }
+
+    fn crypto_box_open(&self, _sk: &[u8], _vk: &[u8], _doc: &[u8], _nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>> {
+        Secp256k1CryptoType::_unsupported("crypto_box_open")
+    }
+
+    fn gen_nonce(&self) -> ed25519_box::Nonce {
+        ed25519_box::gen_nonce()
+    }
+
+    fn create_key(&self, seed: Option<&ed25519_sign::Seed>) -> IndyResult<(Vec<u8>, Vec<u8>)> {
+        secp256k1::create_key_pair(seed.map(|seed| &seed[..]))
+    }
+
+    fn sign(&self, sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>> {
+        secp256k1::sign(sk, doc)
+    }
+
+    fn verify(&self, vk: &[u8], doc: &[u8], signature: &[u8]) -> IndyResult<bool> {
+        secp256k1::verify(vk, doc, signature)
+    }
+
+    fn crypto_box_seal(&self, _vk: &[u8], _doc: &[u8]) -> IndyResult<Vec<u8>> {
+        Secp256k1CryptoType::_unsupported("crypto_box_seal")
+    }
+
+    fn crypto_box_seal_open(&self, _vk: &[u8], _sk: &[u8], _doc: &[u8]) -> IndyResult<Vec<u8>> {
+        Secp256k1CryptoType::_unsupported("crypto_box_seal_open")
+    }
+
+    fn validate_key(&self, vk: &[u8]) -> IndyResult<()> {
+        secp256k1::validate_public_key(vk)
+    }
+}
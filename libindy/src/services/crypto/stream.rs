@@ -0,0 +1,207 @@
+use errors::prelude::*;
+use utils::crypto::chacha20poly1305_ietf;
+
+pub const DEFAULT_STREAM_CHUNK_SIZE: usize = 64 * 1024;
+pub const MAX_STREAM_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
+// STREAM construction: every chunk uses the next nonce and the last one is marked in associated data,
+// so reordering, truncation at chunk boundary and appending after the last chunk are detected
+const CHUNK_FLAG: [u8; 1] = [0];
+const LAST_CHUNK_FLAG: [u8; 1] = [1];
+
+/// Encrypts stream of data by chunks of fixed size, the last chunk can be shorter (or empty).
+pub struct StreamEncryptor {
+    buffer: Vec<u8>,
+    chunk_size: usize,
+    key: chacha20poly1305_ietf::Key,
+    nonce: chacha20poly1305_ietf::Nonce,
+}
+
+impl StreamEncryptor {
+    pub fn new(key: chacha20poly1305_ietf::Key, nonce: chacha20poly1305_ietf::Nonce, chunk_size: usize) -> StreamEncryptor {
+        StreamEncryptor {
+            buffer: Vec::new(),
+            chunk_size,
+            key,
+            nonce,
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) -> IndyResult<Vec<u8>> {
+        self.buffer.extend_from_slice(data);
+
+        let mut res = Vec::new();
+
+        // Keep the last full chunk in buffer as it can be the final one
+        while self.buffer.len() > self.chunk_size {
+            let chunk: Vec<u8> = self.buffer.drain(..self.chunk_size).collect();
+            res.extend(chacha20poly1305_ietf::encrypt_with_ad(&chunk, &CHUNK_FLAG, &self.key, &self.nonce));
+            self.nonce.increment();
+        }
+
+        Ok(res)
+    }
+
+    pub fn finalize(self) -> IndyResult<Vec<u8>> {
+        Ok(chacha20poly1305_ietf::encrypt_with_ad(&self.buffer, &LAST_CHUNK_FLAG, &self.key, &self.nonce))
+    }
+}
+
+/// Decrypts stream of data produced by StreamEncryptor.
+pub struct StreamDecryptor {
+    buffer: Vec<u8>,
+    chunk_size: usize,
+    key: chacha20poly1305_ietf::Key,
+    nonce: chacha20poly1305_ietf::Nonce,
+}
+
+impl StreamDecryptor {
+    pub fn new(key: chacha20poly1305_ietf::Key, nonce: chacha20poly1305_ietf::Nonce, chunk_size: usize) -> StreamDecryptor {
+        StreamDecryptor {
+            buffer: Vec::new(),
+            chunk_size,
+            key,
+            nonce,
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) -> IndyResult<Vec<u8>> {
+        self.buffer.extend_from_slice(data);
+
+        let encrypted_chunk_size = self.chunk_size + chacha20poly1305_ietf::TAGBYTES;
+
+        let mut res = Vec::new();
+
+        // Keep the last full chunk in buffer as it can be the final one
+        while self.buffer.len() > encrypted_chunk_size {
+            let chunk: Vec<u8> = self.buffer.drain(..encrypted_chunk_size).collect();
+            res.extend(chacha20poly1305_ietf::decrypt_with_ad(&chunk, &CHUNK_FLAG, &self.key, &self.nonce)?);
+            self.nonce.increment();
+        }
+
+        Ok(res)
+    }
+
+    pub fn finalize(self) -> IndyResult<Vec<u8>> {
+        if self.buffer.len() < chacha20poly1305_ietf::TAGBYTES {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Encrypted stream is truncated"));
+        }
+
+        chacha20poly1305_ietf::decrypt_with_ad(&self.buffer, &LAST_CHUNK_FLAG, &self.key, &self.nonce)
+            .map_err(|err| err.extend("Encrypted stream is truncated or modified"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::crypto::randombytes::randombytes;
+
+    fn _encrypt(data: &[u8], chunk_size: usize, update_size: usize) -> (Vec<u8>, chacha20poly1305_ietf::Key, chacha20poly1305_ietf::Nonce) {
+        let key = chacha20poly1305_ietf::gen_key();
+        let nonce = chacha20poly1305_ietf::gen_nonce();
+
+        let mut encryptor = StreamEncryptor::new(key.clone(), nonce.clone(), chunk_size);
+
+        let mut encrypted = Vec::new();
+        for part in data.chunks(update_size) {
+            encrypted.extend(encryptor.update(part).unwrap());
+        }
+        encrypted.extend(encryptor.finalize().unwrap());
+
+        (encrypted, key, nonce)
+    }
+
+    fn _decrypt(encrypted: &[u8], key: chacha20poly1305_ietf::Key, nonce: chacha20poly1305_ietf::Nonce, chunk_size: usize, update_size: usize) -> IndyResult<Vec<u8>> {
+        let mut decryptor = StreamDecryptor::new(key, nonce, chunk_size);
+
+        let mut decrypted = Vec::new();
+        for part in encrypted.chunks(update_size) {
+            decrypted.extend(decryptor.update(part)?);
+        }
+        decrypted.extend(decryptor.finalize()?);
+
+        Ok(decrypted)
+    }
+
+    #[test]
+    fn stream_encrypt_decrypt_works() {
+        let data = randombytes(1000);
+
+        let (encrypted, key, nonce) = _encrypt(&data, 100, 33);
+        let decrypted = _decrypt(&encrypted, key, nonce, 100, 77).unwrap();
+
+        assert_eq!(data, decrypted);
+    }
+
+    #[test]
+    fn stream_encrypt_decrypt_works_for_exact_chunks() {
+        let data = randombytes(300);
+
+        let (encrypted, key, nonce) = _encrypt(&data, 100, 100);
+        assert_eq!(3 * (100 + chacha20poly1305_ietf::TAGBYTES), encrypted.len());
+
+        let decrypted = _decrypt(&encrypted, key, nonce, 100, 116).unwrap();
+
+        assert_eq!(data, decrypted);
+    }
+
+    #[test]
+    fn stream_encrypt_decrypt_works_for_empty_data() {
+        let (encrypted, key, nonce) = _encrypt(&[], 100, 1);
+        assert_eq!(chacha20poly1305_ietf::TAGBYTES, encrypted.len());
+
+        let decrypted = _decrypt(&encrypted, key, nonce, 100, 100).unwrap();
+
+        assert!(decrypted.is_empty());
+    }
+
+    #[test]
+    fn stream_decrypt_fails_for_truncated_stream() {
+        let data = randombytes(1000);
+
+        let (encrypted, key, nonce) = _encrypt(&data, 100, 1000);
+        let truncated = &encrypted[..5 * (100 + chacha20poly1305_ietf::TAGBYTES)];
+
+        let res = _decrypt(truncated, key, nonce, 100, 100);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn stream_decrypt_fails_for_appended_chunk() {
+        let data = randombytes(1000);
+
+        let (mut encrypted, key, nonce) = _encrypt(&data, 100, 1000);
+        let first_chunk = encrypted[..100 + chacha20poly1305_ietf::TAGBYTES].to_vec();
+        encrypted.extend(first_chunk);
+
+        let res = _decrypt(&encrypted, key, nonce, 100, 100);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn stream_decrypt_fails_for_reordered_chunks() {
+        let data = randombytes(1000);
+
+        let (encrypted, key, nonce) = _encrypt(&data, 100, 1000);
+        let encrypted_chunk_size = 100 + chacha20poly1305_ietf::TAGBYTES;
+
+        let mut reordered = encrypted[encrypted_chunk_size..2 * encrypted_chunk_size].to_vec();
+        reordered.extend(&encrypted[..encrypted_chunk_size]);
+        reordered.extend(&encrypted[2 * encrypted_chunk_size..]);
+
+        let res = _decrypt(&reordered, key, nonce, 100, 100);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn stream_decrypt_fails_for_modified_chunk() {
+        let data = randombytes(300);
+
+        let (mut encrypted, key, nonce) = _encrypt(&data, 100, 300);
+        encrypted[10] ^= 1;
+
+        let res = _decrypt(&encrypted, key, nonce, 100, 100);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+}
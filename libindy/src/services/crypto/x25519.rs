@@ -0,0 +1,64 @@
+use errors::prelude::*;
+use super::CryptoType;
+use utils::crypto::ed25519_box;
+use utils::crypto::ed25519_sign;
+use utils::crypto::sealedbox;
+
+
+pub const X25519_CRYPTO_TYPE: &'static str = "x25519";
+
+pub struct X25519CryptoType {}
+
+impl X25519CryptoType {
+    pub fn new() -> X25519CryptoType {
+        X25519CryptoType {}
+    }
+
+    fn _unsupported<T>(operation: &str) -> IndyResult<T> {
+        Err(err_msg(IndyErrorKind::UnknownCrypto,
+                    format!("Operation {} is not supported for {} keys", operation, X25519_CRYPTO_TYPE)))
+    }
+}
+
+impl CryptoType for X25519CryptoType {
+    fn crypto_box(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>> {
+        ed25519_box::encrypt(&ed25519_box::SecretKey::from_slice(sk)?,
+                             &ed25519_box::PublicKey::from_slice(vk)?, doc, nonce)
+    }
+
+    fn crypto_box_open(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>> {
+        ed25519_box::decrypt(&ed25519_box::SecretKey::from_slice(sk)?,
+                             &ed25519_box::PublicKey::from_slice(vk)?, doc, nonce)
+    }
+
+    fn gen_nonce(&self) -> ed25519_box::Nonce {
+        ed25519_box::gen_nonce()
+    }
+
+    fn create_key(&self, seed: Option<&ed25519_sign::Seed>) -> IndyResult<(Vec<u8>, Vec<u8>)> {
+        let (pk, sk) = ed25519_box::create_key_pair(seed.map(|seed| &seed[..]))?;
+        Ok((pk[..].to_vec(), sk[..].to_vec()))
+    }
+
+    fn sign(&self, _sk: &[u8], _doc: &[u8]) -> IndyResult<Vec<u8>> {
+        X25519CryptoType::_unsupported("sign")
+    }
+
+    fn verify(&self, _vk: &[u8], _doc: &[u8], _signature: &[u8]) -> IndyResult<bool> {
+        X25519CryptoType::_unsupported("verify")
+    }
+
+    fn crypto_box_seal(&self, vk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>> {
+        sealedbox::encrypt(&ed25519_box::PublicKey::from_slice(vk)?, doc)
+    }
+
+    fn crypto_box_seal_open(&self, vk: &[u8], sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>> {
+        sealedbox::decrypt(&ed25519_box::PublicKey::from_slice(vk)?,
+                           &ed25519_box::SecretKey::from_slice(sk)?, doc)
+    }
+
+    fn validate_key(&self, vk: &[u8]) -> IndyResult<()> {
+        ed25519_box::PublicKey::from_slice(vk)?;
+        Ok(())
+    }
+}
@@ -47,7 +47,7 @@ impl MerkleTree {
                     }
                 }
             }
-            &Tree::Leaf { ref hash, .. } => {
+            &Tree::Leaf { ref hash, .. } | &Tree::Pruned { ref hash, .. } => {
                 if hash == required_hash {
                     return Some(from);
                 } else {
@@ -126,72 +126,114 @@ impl MerkleTree {
         return Ok(true);
     }
 
+    pub fn check_inclusion_proof(root_hash: &Vec<u8>, leaf_value: &TreeLeafData,
+                                 seq_no: usize, tree_size: usize,
+                                 audit_path: &Vec<Vec<u8>>) -> IndyResult<bool> {
+        if seq_no == 0 || seq_no > tree_size {
+            // leaf is out of the tree
+            return Ok(false);
+        }
+
+        let mut node = seq_no - 1;
+        let mut last_node = tree_size - 1;
+        let mut hash = Hash::hash_leaf(leaf_value)?.to_vec();
+
+        for sibling in audit_path {
+            if last_node == 0 {
+                // audit path is longer than the tree height
+                return Ok(false);
+            }
+
+            if node % 2 != 0 || node == last_node {
+                hash = Hash::hash_nodes(sibling, &hash)?.to_vec();
+                while node % 2 == 0 && node != 0 {
+                    node = node / 2;
+                    last_node = last_node / 2;
+                }
+            } else {
+                hash = Hash::hash_nodes(&hash, sibling)?.to_vec();
+            }
+
+            node = node / 2;
+            last_node = last_node / 2;
+        }
+
+        Ok(last_node == 0 && hash == *root_hash)
+    }
+
     pub fn append(&mut self, node: TreeLeafData) -> IndyResult<()> {
+        let leaf = Tree::new_leaf(node)?;
+
         if self.count == 0 {
             // empty tree
-            self.root = Tree::new_leaf(node)?;
-            self.count += 1;
-        } else if Self::count_bits(self.count) != 1 {
-            // add to right subtree
-            match self.root.clone() {
-                Tree::Node { ref left, ref right, .. } => {
-                    let mut iter = right.iter().map(|x| (*x).clone()).collect::<Vec<TreeLeafData>>();
-                    iter.push(node);
-                    let new_right = MerkleTree::from_vec(iter)?;
-                    let combined_hash = Hash::hash_nodes(
-                        left.hash(),
-                        new_right.root_hash() as &Vec<u8>
-                    )?;
-                    self.root = Tree::Node {
-                        left: (*left).clone(),
-                        right: Box::new(new_right.root),
-                        hash: combined_hash.to_vec()
-                    };
-                    self.count += 1;
-                    self.nodes_count += 1;
-                }
-                _ => {
-                    assert!(false);
-                }
-            }
+            self.root = leaf;
         } else {
-            // add tree layer
-            let new_right = MerkleTree::from_vec(vec![node])?;
-            match self.root.clone() {
-                Tree::Node { ref hash, .. } => {
-                    let combined_hash = Hash::hash_nodes(
-                        hash,
-                        new_right.root_hash()
-                    )?;
-                    self.root = Tree::Node {
-                        left: Box::new(self.root.clone()),
-                        right: Box::new(new_right.root),
-                        hash: combined_hash.to_vec()
-                    };
-                    self.count += 1;
-                    self.nodes_count += 1;
-                }
-                Tree::Leaf { ref hash, ref value } => {
-                    let combined_hash = Hash::hash_nodes(
-                        hash,
-                        new_right.root_hash()
-                    )?;
-                    self.root = Tree::Node {
-                        left: Box::new(Tree::new_leaf((*value).clone())?),
-                        right: Box::new(new_right.root),
-                        hash: combined_hash.to_vec()
-                    };
-                    self.count += 1;
-                    self.nodes_count += 1;
-                }
-                _ => {
-                    assert!(false);
-                }
+            if Self::count_bits(self.count) == 1 {
+                // add tree layer
+                self.height += 1;
             }
-            self.height += 1;
+
+            let root = ::std::mem::replace(&mut self.root, Tree::empty(Hash::hash_empty()?));
+            self.root = Self::_append_leaf(root, self.count, leaf)?;
+            self.nodes_count += 1;
         }
+
+        self.count += 1;
         Ok(())
     }
+
+    /// Drops values and inner nodes of complete subtrees keeping only their hashes.
+    /// The tree can be appended and checked for consistency after that, but it can't be iterated or used for proofs.
+    pub fn prune(&mut self) -> IndyResult<()> {
+        let root = ::std::mem::replace(&mut self.root, Tree::empty(Hash::hash_empty()?));
+        self.root = Self::_prune(root, self.count);
+        Ok(())
+    }
+
+    // Only the right-most path of the tree is rebuilt, so complete subtrees are kept as is
+    fn _append_leaf(tree: Tree, count: usize, leaf: Tree) -> IndyResult<Tree> {
+        if Self::count_bits(count) == 1 {
+            return Self::_join(tree, leaf);
+        }
+
+        match tree {
+            Tree::Node { left, right, .. } => {
+                let left_count = count.next_power_of_two() / 2;
+                let right = Self::_append_leaf(*right, count - left_count, leaf)?;
+                Self::_join(*left, right)
+            }
+            _ => Err(err_msg(IndyErrorKind::InvalidState, "Merkle tree structure doesn't correspond to its count"))
+        }
+    }
+
+    fn _join(left: Tree, right: Tree) -> IndyResult<Tree> {
+        let combined_hash = Hash::hash_nodes(left.hash(), right.hash())?;
+
+        Ok(Tree::Node {
+            hash: combined_hash.to_vec(),
+            left: Box::new(left),
+            right: Box::new(right)
+        })
+    }
+
+    fn _prune(tree: Tree, count: usize) -> Tree {
+        if Self::count_bits(count) == 1 {
+            return Tree::Pruned { hash: tree.hash().clone(), count };
+        }
+
+        match tree {
+            Tree::Node { hash, left, right } => {
+                let left_count = count.next_power_of_two() / 2;
+
+                Tree::Node {
+                    hash,
+                    left: Box::new(Self::_prune(*left, left_count)),
+                    right: Box::new(Self::_prune(*right, count - left_count))
+                }
+            }
+            tree => tree
+        }
+    }
 }
 
 
@@ -200,6 +242,7 @@ mod tests {
     extern crate rust_base58;
 
     use super::*;
+    use super::proof::Positioned;
     use self::rust_base58::FromBase58;
 
     #[test]
@@ -217,6 +260,38 @@ mod tests {
         assert_eq!(mt.root_hash_hex(), "1285070cf01debc1155cef8dfd5ba54c05abb919a4c08c8632b079fb1e1e5e7c");
     }
 
+    #[test]
+    fn append_works_same_as_from_vec() {
+        let values: Vec<Vec<u8>> = (1..20).map(|i| i.to_string().into_bytes()).collect();
+        let mut mt = MerkleTree::from_vec(vec![]).unwrap();
+
+        for (i, value) in values.iter().enumerate() {
+            mt.append(value.clone()).unwrap();
+
+            let expected = MerkleTree::from_vec(values[..i + 1].to_vec()).unwrap();
+            assert_eq!(expected.root_hash(), mt.root_hash());
+            assert_eq!(expected.height(), mt.height());
+            assert_eq!(expected.nodes_count, mt.nodes_count);
+        }
+    }
+
+    #[test]
+    fn append_works_for_pruned_tree() {
+        let values: Vec<Vec<u8>> = (1..20).map(|i| i.to_string().into_bytes()).collect();
+        let mut mt = MerkleTree::from_vec(values[..11].to_vec()).unwrap();
+        mt.prune().unwrap();
+
+        assert_eq!(MerkleTree::from_vec(values[..11].to_vec()).unwrap().root_hash(), mt.root_hash());
+        assert_eq!(0, mt.iter().count());
+
+        for value in values[11..].iter() {
+            mt.append(value.clone()).unwrap();
+        }
+
+        assert_eq!(MerkleTree::from_vec(values).unwrap().root_hash(), mt.root_hash());
+        assert_eq!(19, mt.count());
+    }
+
     #[test]
     fn find_hash_works() {
         let values = vec!["1", "2", "3", "4", "5", "6", "7", "8", "9"];
@@ -317,6 +392,67 @@ mod tests {
         }
     }
 
+    fn _audit_path(tree: &MerkleTree, value: TreeLeafData) -> Vec<Vec<u8>> {
+        let proof = tree.gen_proof(value).unwrap().unwrap();
+        let mut path = Vec::new();
+        let mut lemma = Some(&proof.lemma);
+        while let Some(l) = lemma {
+            match l.sibling_hash {
+                Some(Positioned::Left(ref hash)) | Some(Positioned::Right(ref hash)) => path.push(hash.clone()),
+                None => {}
+            }
+            lemma = l.sub_lemma.as_ref().map(|sub| sub.as_ref());
+        }
+        path.reverse();
+        path
+    }
+
+    #[test]
+    fn check_inclusion_proof_works() {
+        for size in 1..12 {
+            let values = (1..size + 1).map(|x| x.to_string().as_bytes().to_vec()).collect::<Vec<_>>();
+            let tree = MerkleTree::from_vec(values.clone()).unwrap();
+
+            for (i, value) in values.into_iter().enumerate() {
+                let path = _audit_path(&tree, value.clone());
+                assert!(MerkleTree::check_inclusion_proof(tree.root_hash(), &value, i + 1, size, &path).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn check_inclusion_proof_works_for_wrong_seq_no() {
+        let values = (1..8).map(|x| x.to_string().as_bytes().to_vec()).collect::<Vec<_>>();
+        let tree = MerkleTree::from_vec(values.clone()).unwrap();
+        let path = _audit_path(&tree, values[2].clone());
+
+        assert!(!MerkleTree::check_inclusion_proof(tree.root_hash(), &values[2], 2, 7, &path).unwrap());
+        assert!(!MerkleTree::check_inclusion_proof(tree.root_hash(), &values[2], 0, 7, &path).unwrap());
+        assert!(!MerkleTree::check_inclusion_proof(tree.root_hash(), &values[2], 8, 7, &path).unwrap());
+    }
+
+    #[test]
+    fn check_inclusion_proof_works_for_tampered_value() {
+        let values = (1..8).map(|x| x.to_string().as_bytes().to_vec()).collect::<Vec<_>>();
+        let tree = MerkleTree::from_vec(values.clone()).unwrap();
+        let path = _audit_path(&tree, values[4].clone());
+
+        assert!(!MerkleTree::check_inclusion_proof(tree.root_hash(), &"42".as_bytes().to_vec(), 5, 7, &path).unwrap());
+    }
+
+    #[test]
+    fn check_inclusion_proof_works_for_short_and_long_path() {
+        let values = (1..8).map(|x| x.to_string().as_bytes().to_vec()).collect::<Vec<_>>();
+        let tree = MerkleTree::from_vec(values.clone()).unwrap();
+        let mut path = _audit_path(&tree, values[0].clone());
+
+        path.push(path[0].clone());
+        assert!(!MerkleTree::check_inclusion_proof(tree.root_hash(), &values[0], 1, 7, &path).unwrap());
+
+        path.truncate(1);
+        assert!(!MerkleTree::check_inclusion_proof(tree.root_hash(), &values[0], 1, 7, &path).unwrap());
+    }
+
     #[test]
     fn serialize_works() {
         let strvals = vec!["1", "2", "3", "4", "5", "6", "7", "8", "9", "10"];
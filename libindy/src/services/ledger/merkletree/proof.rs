@@ -82,7 +82,7 @@ impl Lemma {
     /// Attempts to generate a proof that the a value with hash `needle` is a member of the given `tree`.
     pub fn new(tree: &Tree, needle: &[u8]) -> Option<Lemma> {
         match *tree {
-            Tree::Empty {.. } =>
+            Tree::Empty {.. } | Tree::Pruned { .. } =>
                 None,
 
             Tree::Leaf { ref hash, .. } =>
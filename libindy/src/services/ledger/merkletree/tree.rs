@@ -26,6 +26,12 @@ pub enum Tree {
         hash: Vec<u8>,
         left: Box<Tree>,
         right: Box<Tree>
+    },
+
+    /// Complete subtree with `count` leaves of which only the hash is kept.
+    Pruned {
+        hash: Vec<u8>,
+        count: usize
     }
 }
 
@@ -57,7 +63,8 @@ impl Tree {
         match *self {
             Tree::Empty { ref hash }    => hash,
             Tree::Leaf { ref hash, .. } => hash,
-            Tree::Node { ref hash, .. } => hash
+            Tree::Node { ref hash, .. } => hash,
+            Tree::Pruned { ref hash, .. } => hash
         }
     }
 
@@ -72,7 +79,8 @@ impl Tree {
             Tree::Node { ref left, ref right, .. } => {
                 1 + cmp::max(left.get_height(),right.get_height())
             },
-            Tree::Leaf { .. } => { 0 }
+            Tree::Leaf { .. } => { 0 },
+            Tree::Pruned { count, .. } => { count.trailing_zeros() as usize }
         }
     }
 
@@ -82,7 +90,8 @@ impl Tree {
             Tree::Node { ref left, ref right, .. } => {
                 left.get_count() + right.get_count()
             },
-            Tree::Leaf { .. } => { 1 }
+            Tree::Leaf { .. } => { 1 },
+            Tree::Pruned { count, .. } => { count }
         }
     }
 }
@@ -125,6 +134,16 @@ impl <'a> LeavesIterator<'a> {
                 Tree::Leaf { ref value, .. } => {
                     self.current_value = Some(value);
                     break;
+                },
+
+                Tree::Pruned { .. } => {
+                    match self.right_nodes.pop() {
+                        Some(right) => tree = right,
+                        None => {
+                            self.current_value = None;
+                            break;
+                        }
+                    }
                 }
             }
         }
@@ -184,6 +203,16 @@ impl LeavesIntoIterator {
                 Tree::Leaf { value, .. } => {
                     self.current_value = Some(value);
                     break;
+                },
+
+                Tree::Pruned { .. } => {
+                    match self.right_nodes.pop() {
+                        Some(right) => tree = right,
+                        None => {
+                            self.current_value = None;
+                            break;
+                        }
+                    }
                 }
             }
         }
@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
 
 use indy_crypto::cl::RevocationRegistryDelta as CryproRevocationRegistryDelta;
 use serde::de::DeserializeOwned;
@@ -11,25 +12,33 @@ use domain::anoncreds::revocation_registry::RevocationRegistry;
 use domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
 use domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use domain::anoncreds::schema::{Schema, SchemaV1, MAX_ATTRIBUTES_COUNT};
-use domain::ledger::attrib::{AttribOperation, GetAttribOperation};
-use domain::ledger::constants::{GET_VALIDATOR_INFO, NYM, POOL_RESTART, ROLE_REMOVE, STEWARD, TRUST_ANCHOR, TRUSTEE, NETWORK_MONITOR, txn_name_to_code};
+use domain::ledger::attrib::{AttribInfo, AttribOperation, GetAttribOperation, GetAttrReplyResult};
+use domain::ledger::constants::{CRED_DEF, ENDORSER, GET_CRED_DEF, GET_VALIDATOR_INFO, NYM, POOL_RESTART, ROLE_REMOVE, STEWARD, TRUST_ANCHOR, TRUSTEE, NETWORK_MONITOR, txn_name_to_code};
+use domain::ledger::custom_txn::{CustomTxnKind, CustomTxnType};
 use domain::ledger::cred_def::{CredDefOperation, GetCredDefOperation, GetCredDefReplyResult};
 use domain::ledger::ddo::GetDdoOperation;
 use domain::ledger::node::{NodeOperation, NodeOperationData};
-use domain::ledger::nym::GetNymOperation;
+use domain::ledger::nym::{GetNymOperation, GetNymReplyResult, GetNymResultDataV0, NymInfo};
 use domain::ledger::pool::{PoolConfigOperation, PoolRestartOperation, PoolUpgradeOperation};
 use domain::ledger::request::Request;
 use domain::ledger::response::{Message, Reply, ReplyType};
 use domain::ledger::rev_reg::{GetRevocRegDeltaReplyResult, GetRevocRegReplyResult, GetRevRegDeltaOperation, GetRevRegOperation, RevRegEntryOperation};
 use domain::ledger::rev_reg_def::{GetRevocRegDefReplyResult, GetRevRegDefOperation, RevRegDefOperation};
 use domain::ledger::schema::{GetSchemaOperation, GetSchemaOperationData, GetSchemaReplyResult, SchemaOperation, SchemaOperationData};
-use domain::ledger::txn::{GetTxnOperation, LedgerType};
-use domain::ledger::validator_info::GetValidatorInfoOperation;
+use domain::ledger::txn::{GetTxnOperation, GetTxnReplyResult, LedgerType, TxnInfo};
+use domain::ledger::validator_info::{GetValidatorInfoOperation, GetValidatorInfoReplyResult, ValidatorInfo};
 use domain::ledger::auth_rule::*;
+use domain::ledger::signing_bundle::{SignerInfo, SigningBundle, SigningBundleStatus};
+use domain::pool::{ConsensusStatus, NodeStatus, PoolStatus};
 use errors::prelude::*;
+use utils::crypto::signature_serializer::serialize_signature;
 
 pub mod merkletree;
 
+lazy_static! {
+    static ref CUSTOM_TXN_TYPES: Mutex<HashMap<String, CustomTxnType>> = Mutex::new(HashMap::new());
+}
+
 trait LedgerSerializer {
     fn serialize(&self) -> String;
 }
@@ -42,7 +51,7 @@ impl LedgerService {
     }
 
     pub fn build_nym_request(&self, identifier: &str, dest: &str, verkey: Option<&str>,
-                             alias: Option<&str>, role: Option<&str>) -> IndyResult<String> {
+                             alias: Option<&str>, role: Option<&str>, protocol_version: usize) -> IndyResult<String> {
         info!("build_nym_request >>> identifier: {:?}, dest: {:?}, verkey: {:?}, alias: {:?}, role: {:?}", identifier, dest, verkey, alias, role);
 
         let mut operation: Value = Value::Object(serde_json::map::Map::new());
@@ -61,17 +70,11 @@ impl LedgerService {
             if r == ROLE_REMOVE {
                 operation["role"] = Value::Null
             } else {
-                operation["role"] = Value::String(match r {
-                    "STEWARD" => STEWARD,
-                    "TRUSTEE" => TRUSTEE,
-                    "TRUST_ANCHOR" => TRUST_ANCHOR,
-                    "NETWORK_MONITOR" => NETWORK_MONITOR,
-                    role @ _ => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid role: {}", role)))
-                }.to_string())
+                operation["role"] = Value::String(_role_name_to_code(r)?.to_string())
             }
         }
 
-        let request = Request::build_request(Some(identifier), operation)
+        let request = Request::build_request(Some(identifier), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "NYM request json is invalid")?;
 
         info!("build_nym_request <<< request: {:?}", request);
@@ -79,12 +82,12 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_get_nym_request(&self, identifier: Option<&str>, dest: &str) -> IndyResult<String> {
+    pub fn build_get_nym_request(&self, identifier: Option<&str>, dest: &str, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_nym_request >>> identifier: {:?}, dest: {:?}", identifier, dest);
 
         let operation = GetNymOperation::new(dest.to_string());
 
-        let request = Request::build_request(identifier, operation)
+        let request = Request::build_request(identifier, operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_NYM request json is invalid")?;
 
         info!("build_get_nym_request <<< request: {:?}", request);
@@ -92,12 +95,12 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_get_ddo_request(&self, identifier: Option<&str>, dest: &str) -> IndyResult<String> {
+    pub fn build_get_ddo_request(&self, identifier: Option<&str>, dest: &str, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_ddo_request >>> identifier: {:?}, dest: {:?}", identifier, dest);
 
         let operation = GetDdoOperation::new(dest.to_string());
 
-        let request = Request::build_request(identifier, operation)
+        let request = Request::build_request(identifier, operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_DDO request json is invalid")?;
 
         info!("build_get_nym_request <<< request: {:?}", request);
@@ -106,7 +109,7 @@ impl LedgerService {
     }
 
     pub fn build_attrib_request(&self, identifier: &str, dest: &str, hash: Option<&str>,
-                                raw: Option<&str>, enc: Option<&str>) -> IndyResult<String> {
+                                raw: Option<&str>, enc: Option<&str>, protocol_version: usize) -> IndyResult<String> {
         info!("build_attrib_request >>> identifier: {:?}, dest: {:?}, hash: {:?}, raw: {:?}, enc: {:?}", identifier, dest, hash, raw, enc);
 
         if raw.is_none() && hash.is_none() && enc.is_none() {
@@ -123,7 +126,7 @@ impl LedgerService {
                                              raw.map(String::from),
                                              enc.map(String::from));
 
-        let request = Request::build_request(Some(identifier), operation)
+        let request = Request::build_request(Some(identifier), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "ATTRIB request json is invalid")?;
 
         info!("build_attrib_request <<< request: {:?}", request);
@@ -132,7 +135,7 @@ impl LedgerService {
     }
 
     pub fn build_get_attrib_request(&self, identifier: Option<&str>, dest: &str, raw: Option<&str>, hash: Option<&str>,
-                                    enc: Option<&str>) -> IndyResult<String> {
+                                    enc: Option<&str>, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_attrib_request >>> identifier: {:?}, dest: {:?}, hash: {:?}, raw: {:?}, enc: {:?}", identifier, dest, hash, raw, enc);
 
         if raw.is_none() && hash.is_none() && enc.is_none() {
@@ -141,7 +144,7 @@ impl LedgerService {
 
         let operation = GetAttribOperation::new(dest.to_string(), raw, hash, enc);
 
-        let request = Request::build_request(identifier, operation)
+        let request = Request::build_request(identifier, operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_ATTRIB request json is invalid")?;
 
         info!("build_get_attrib_request <<< request: {:?}", request);
@@ -149,7 +152,7 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_schema_request(&self, identifier: &str, schema: SchemaV1) -> IndyResult<String> {
+    pub fn build_schema_request(&self, identifier: &str, schema: SchemaV1, protocol_version: usize) -> IndyResult<String> {
         info!("build_schema_request >>> identifier: {:?}, schema: {:?}", identifier, schema);
 
         if schema.attr_names.len() > MAX_ATTRIBUTES_COUNT {
@@ -161,7 +164,7 @@ impl LedgerService {
 
         let operation = SchemaOperation::new(schema_data);
 
-        let request = Request::build_request(Some(identifier), operation)
+        let request = Request::build_request(Some(identifier), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "SCHEMA request json is invalid")?;
 
         info!("build_schema_request <<< request: {:?}", request);
@@ -169,7 +172,7 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_get_schema_request(&self, identifier: Option<&str>, id: &str) -> IndyResult<String> {
+    pub fn build_get_schema_request(&self, identifier: Option<&str>, id: &str, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_schema_request >>> identifier: {:?}, id: {:?}", identifier, id);
 
         let parts: Vec<&str> = id.split_terminator(DELIMITER).collect::<Vec<&str>>();
@@ -186,7 +189,7 @@ impl LedgerService {
         let data = GetSchemaOperationData::new(name, version);
         let operation = GetSchemaOperation::new(dest, data);
 
-        let request = Request::build_request(identifier, operation)
+        let request = Request::build_request(identifier, operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_SCHEMA request json is invalid")?;
 
         info!("build_get_schema_request <<< request: {:?}", request);
@@ -194,12 +197,12 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_cred_def_request(&self, identifier: &str, cred_def: CredentialDefinitionV1) -> IndyResult<String> {
+    pub fn build_cred_def_request(&self, identifier: &str, cred_def: CredentialDefinitionV1, protocol_version: usize) -> IndyResult<String> {
         info!("build_cred_def_request >>> identifier: {:?}, cred_def: {:?}", identifier, cred_def);
 
-        let operation = CredDefOperation::new(cred_def);
+        let operation = CredDefOperation::new(cred_def, protocol_version);
 
-        let request = Request::build_request(Some(identifier), operation)
+        let request = Request::build_request(Some(identifier), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "CRED_DEF request json is invalid")?;
 
         info!("build_cred_def_request <<< request: {:?}", request);
@@ -207,7 +210,7 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_get_cred_def_request(&self, identifier: Option<&str>, id: &str) -> IndyResult<String> {
+    pub fn build_get_cred_def_request(&self, identifier: Option<&str>, id: &str, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_cred_def_request >>> identifier: {:?}, id {:?}", identifier, id);
 
         let parts: Vec<&str> = id.split_terminator(DELIMITER).collect::<Vec<&str>>();
@@ -227,7 +230,7 @@ impl LedgerService {
 
         let operation = GetCredDefOperation::new(ref_, signature_type, origin, tag);
 
-        let request = Request::build_request(identifier, operation)
+        let request = Request::build_request(identifier, operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_CRED_DEF request json is invalid")?;
 
         info!("build_get_cred_def_request <<< request: {:?}", request);
@@ -235,7 +238,7 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_node_request(&self, identifier: &str, dest: &str, data: NodeOperationData) -> IndyResult<String> {
+    pub fn build_node_request(&self, identifier: &str, dest: &str, data: NodeOperationData, protocol_version: usize) -> IndyResult<String> {
         info!("build_node_request >>> identifier: {:?}, dest {:?}, data {:?}", identifier, dest, data);
 
         if data.node_ip.is_none() && data.node_port.is_none()
@@ -252,7 +255,7 @@ impl LedgerService {
 
         let operation = NodeOperation::new(dest.to_string(), data);
 
-        let request = Request::build_request(Some(identifier), operation)
+        let request = Request::build_request(Some(identifier), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "NODE request json is invalid")?;
 
         info!("build_node_request <<< request: {:?}", request);
@@ -260,12 +263,12 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_get_validator_info_request(&self, identifier: &str) -> IndyResult<String> {
+    pub fn build_get_validator_info_request(&self, identifier: &str, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_validator_info_request >>> identifier: {:?}", identifier);
 
         let operation = GetValidatorInfoOperation::new();
 
-        let request = Request::build_request(Some(identifier), operation)
+        let request = Request::build_request(Some(identifier), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_TXN request json is invalid")?;
 
         info!("build_get_validator_info_request <<< request: {:?}", request);
@@ -273,21 +276,17 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_get_txn_request(&self, identifier: Option<&str>, ledger_type: Option<&str>, seq_no: i32) -> IndyResult<String> {
+    pub fn build_get_txn_request(&self, identifier: Option<&str>, ledger_type: Option<&str>, seq_no: i32, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_txn_request >>> identifier: {:?}, seq_no {:?}, ledger_type {:?}", identifier, ledger_type, seq_no);
 
         let ledger_id = match ledger_type {
-            Some(type_) =>
-                serde_json::from_str::<LedgerType>(&format!(r#""{}""#, type_))
-                    .map(|type_| type_.to_id())
-                    .or_else(|_| type_.parse::<i32>())
-                    .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid Ledger type: {}", type_))?,
+            Some(type_) => self.parse_ledger_type(type_)?,
             None => LedgerType::DOMAIN.to_id()
         };
 
         let operation = GetTxnOperation::new(seq_no, ledger_id);
 
-        let request = Request::build_request(identifier, operation)
+        let request = Request::build_request(identifier, operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_TXN request json is invalid")?;
 
         info!("build_get_txn_request <<< request: {:?}", request);
@@ -295,12 +294,19 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_pool_config(&self, identifier: &str, writes: bool, force: bool) -> IndyResult<String> {
+    pub fn parse_ledger_type(&self, ledger_type: &str) -> IndyResult<i32> {
+        serde_json::from_str::<LedgerType>(&format!(r#""{}""#, ledger_type))
+            .map(|type_| type_.to_id())
+            .or_else(|_| ledger_type.parse::<i32>())
+            .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid Ledger type: {}", ledger_type))
+    }
+
+    pub fn build_pool_config(&self, identifier: &str, writes: bool, force: bool, protocol_version: usize) -> IndyResult<String> {
         info!("build_pool_config >>> identifier: {:?}, writes {:?}, force {:?}", identifier, writes, force);
 
         let operation = PoolConfigOperation::new(writes, force);
 
-        let request = Request::build_request(Some(identifier), operation)
+        let request = Request::build_request(Some(identifier), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "POOL_CONFIG request json is invalid")?;
 
         info!("build_pool_config <<< request: {:?}", request);
@@ -308,7 +314,7 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_pool_restart(&self, identifier: &str, action: &str, datetime: Option<&str>) -> IndyResult<String> {
+    pub fn build_pool_restart(&self, identifier: &str, action: &str, datetime: Option<&str>, protocol_version: usize) -> IndyResult<String> {
         info!("build_pool_restart >>> identifier: {:?}, action {:?}, datetime {:?}", identifier, action, datetime);
 
         if action != "start" && action != "cancel" {
@@ -317,7 +323,7 @@ impl LedgerService {
 
         let operation = PoolRestartOperation::new(action, datetime.map(String::from));
 
-        let request = Request::build_request(Some(identifier), operation)
+        let request = Request::build_request(Some(identifier), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "POOL_RESTART request json is invalid")?;
 
         info!("build_pool_restart <<< request: {:?}", request);
@@ -327,7 +333,7 @@ impl LedgerService {
 
     pub fn build_pool_upgrade(&self, identifier: &str, name: &str, version: &str, action: &str,
                               sha256: &str, timeout: Option<u32>, schedule: Option<&str>,
-                              justification: Option<&str>, reinstall: bool, force: bool, package: Option<&str>) -> IndyResult<String> {
+                              justification: Option<&str>, reinstall: bool, force: bool, package: Option<&str>, protocol_version: usize) -> IndyResult<String> {
         info!("build_pool_upgrade >>> identifier: {:?}, name {:?}, version {:?}, action {:?}, sha256 {:?}, timeout {:?}, schedule {:?}, justification {:?}, \
         reinstall {:?}, reinstall {:?}, package {:?}", identifier, name, version, action, sha256, timeout, schedule, justification, reinstall, reinstall, package);
 
@@ -347,7 +353,7 @@ impl LedgerService {
 
         let operation = PoolUpgradeOperation::new(name, version, action, sha256, timeout, schedule, justification, reinstall, force, package);
 
-        let request = Request::build_request(Some(identifier), operation)
+        let request = Request::build_request(Some(identifier), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "POOL_UPGRADE request json is invalid")?;
 
         info!("build_pool_upgrade <<< request: {:?}", request);
@@ -355,12 +361,12 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_revoc_reg_def_request(&self, identifier: &str, rev_reg_def: RevocationRegistryDefinitionV1) -> IndyResult<String> {
+    pub fn build_revoc_reg_def_request(&self, identifier: &str, rev_reg_def: RevocationRegistryDefinitionV1, protocol_version: usize) -> IndyResult<String> {
         info!("build_revoc_reg_def_request >>> identifier: {:?}, rev_reg_def {:?}", identifier, rev_reg_def);
 
         let rev_reg_def_operation = RevRegDefOperation::new(rev_reg_def);
 
-        let request = Request::build_request(Some(identifier), rev_reg_def_operation)
+        let request = Request::build_request(Some(identifier), rev_reg_def_operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "REVOC_REG_DEF request json is invalid")?;
 
         info!("build_revoc_reg_def_request <<< request: {:?}", request);
@@ -368,12 +374,12 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_get_revoc_reg_def_request(&self, identifier: Option<&str>, id: &str) -> IndyResult<String> {
+    pub fn build_get_revoc_reg_def_request(&self, identifier: Option<&str>, id: &str, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_revoc_reg_def_request >>> identifier: {:?}, id {:?}", identifier, id);
 
         let operation = GetRevRegDefOperation::new(id);
 
-        let request = Request::build_request(identifier, operation)
+        let request = Request::build_request(identifier, operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_REVOC_REG_DEF request json is invalid")?;
 
         info!("build_get_revoc_reg_def_request <<< request: {:?}", request);
@@ -382,13 +388,13 @@ impl LedgerService {
     }
 
     pub fn build_revoc_reg_entry_request(&self, identifier: &str, revoc_reg_def_id: &str,
-                                         revoc_def_type: &str, rev_reg_entry: RevocationRegistryDeltaV1) -> IndyResult<String> {
+                                         revoc_def_type: &str, rev_reg_entry: RevocationRegistryDeltaV1, protocol_version: usize) -> IndyResult<String> {
         info!("build_revoc_reg_entry_request >>> identifier: {:?}, revoc_reg_def_id {:?}, revoc_def_type {:?}, rev_reg_entry {:?}",
               identifier, revoc_reg_def_id, revoc_def_type, rev_reg_entry);
 
         let operation = RevRegEntryOperation::new(revoc_def_type, revoc_reg_def_id, rev_reg_entry);
 
-        let request = Request::build_request(Some(identifier), operation)
+        let request = Request::build_request(Some(identifier), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "REVOC_REG_ENTRY request json is invalid")?;
 
         info!("build_revoc_reg_entry_request <<< request: {:?}", request);
@@ -396,12 +402,12 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_get_revoc_reg_request(&self, identifier: Option<&str>, revoc_reg_def_id: &str, timestamp: i64) -> IndyResult<String> {
+    pub fn build_get_revoc_reg_request(&self, identifier: Option<&str>, revoc_reg_def_id: &str, timestamp: i64, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_revoc_reg_request >>> identifier: {:?}, revoc_reg_def_id {:?}, timestamp {:?}", identifier, revoc_reg_def_id, timestamp);
 
         let operation = GetRevRegOperation::new(revoc_reg_def_id, timestamp);
 
-        let request = Request::build_request(identifier, operation)
+        let request = Request::build_request(identifier, operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_REVOC_REG request json is invalid")?;
 
         info!("build_get_revoc_reg_request <<< request: {:?}", request);
@@ -409,12 +415,12 @@ impl LedgerService {
         Ok(request)
     }
 
-    pub fn build_get_revoc_reg_delta_request(&self, identifier: Option<&str>, revoc_reg_def_id: &str, from: Option<i64>, to: i64) -> IndyResult<String> {
+    pub fn build_get_revoc_reg_delta_request(&self, identifier: Option<&str>, revoc_reg_def_id: &str, from: Option<i64>, to: i64, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_revoc_reg_delta_request >>> identifier: {:?}, revoc_reg_def_id {:?}, from {:?}, to: {:?}", identifier, revoc_reg_def_id, from, to);
 
         let operation = GetRevRegDeltaOperation::new(revoc_reg_def_id, from, to);
 
-        let request = Request::build_request(identifier, operation)
+        let request = Request::build_request(identifier, operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_REVOC_REG_DELTA request json is invalid")?;
 
         info!("build_get_revoc_reg_delta_request <<< request: {:?}", request);
@@ -460,7 +466,8 @@ impl LedgerService {
 
         let cred_def = match reply.result() {
             GetCredDefReplyResult::GetCredDefReplyResultV0(res) => CredentialDefinitionV1 {
-                id: CredentialDefinition::cred_def_id(&res.origin, &res.ref_.to_string(), &res.signature_type.to_str(), &res.tag.clone().unwrap_or(String::new())),
+                id: CredentialDefinition::cred_def_id(&res.origin, &res.ref_.to_string(), &res.signature_type.to_str(), &res.tag.clone().unwrap_or(String::new()),
+                                                      if res.tag.is_some() { 2 } else { 1 }),
                 schema_id: res.ref_.to_string(),
                 signature_type: res.signature_type,
                 tag: res.tag.unwrap_or(String::new()),
@@ -549,6 +556,286 @@ impl LedgerService {
         Ok(res)
     }
 
+    pub fn parse_get_nym_response(&self, get_nym_response: &str) -> IndyResult<String> {
+        info!("parse_get_nym_response >>> get_nym_response: {:?}", get_nym_response);
+
+        let reply: Reply<GetNymReplyResult> = LedgerService::parse_response(get_nym_response)?;
+
+        let nym = match reply.result() {
+            GetNymReplyResult::GetNymReplyResultV0(res) => {
+                let data = res.data
+                    .ok_or(err_msg(IndyErrorKind::LedgerItemNotFound, "NYM isn't found on the ledger"))?;
+
+                let data: GetNymResultDataV0 = serde_json::from_str(&data)
+                    .to_indy(IndyErrorKind::InvalidTransaction, "Invalid GET_NYM response data json")?;
+
+                NymInfo {
+                    did: data.dest,
+                    verkey: data.verkey,
+                    role: data.role,
+                    seq_no: res.seq_no,
+                    txn_time: res.txn_time,
+                }
+            }
+            GetNymReplyResult::GetNymReplyResultV1(res) => NymInfo {
+                did: res.txn.data.did,
+                verkey: res.txn.data.verkey,
+                role: res.txn.data.role,
+                seq_no: Some(res.txn_metadata.seq_no as u64),
+                txn_time: Some(res.txn_metadata.creation_time),
+            }
+        };
+
+        let res = serde_json::to_string(&nym)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize NYM")?;
+
+        info!("parse_get_nym_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn parse_get_attrib_response(&self, get_attrib_response: &str) -> IndyResult<String> {
+        info!("parse_get_attrib_response >>> get_attrib_response: {:?}", get_attrib_response);
+
+        let reply: Reply<GetAttrReplyResult> = LedgerService::parse_response(get_attrib_response)?;
+
+        let attrib = match reply.result() {
+            GetAttrReplyResult::GetAttrReplyResultV0(res) => AttribInfo {
+                did: res.dest,
+                raw: res.raw,
+                hash: res.hash,
+                enc: res.enc,
+                data: res.data
+                    .ok_or(err_msg(IndyErrorKind::LedgerItemNotFound, "Attribute isn't found on the ledger"))?,
+                seq_no: res.seq_no,
+                txn_time: res.txn_time,
+            },
+            GetAttrReplyResult::GetAttrReplyResultV1(res) => {
+                let data = res.txn.data;
+
+                // V1 reply contains the attribute value only, so the name of raw attribute is taken from its json
+                let (raw, hash, enc, value) = match (data.raw, data.hash, data.enc) {
+                    (Some(raw), _, _) => (Some(LedgerService::_get_raw_attrib_name(&raw)?), None, None, raw),
+                    (None, Some(hash), _) => (None, Some(hash.clone()), None, hash),
+                    (None, None, Some(enc)) => (None, None, Some(enc.clone()), enc),
+                    (None, None, None) => return Err(err_msg(IndyErrorKind::LedgerItemNotFound, "Attribute isn't found on the ledger"))
+                };
+
+                AttribInfo {
+                    did: data.did,
+                    raw,
+                    hash,
+                    enc,
+                    data: value,
+                    seq_no: Some(res.txn_metadata.seq_no as u64),
+                    txn_time: Some(res.txn_metadata.creation_time),
+                }
+            }
+        };
+
+        let res = serde_json::to_string(&attrib)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Attribute")?;
+
+        info!("parse_get_attrib_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _get_raw_attrib_name(raw: &str) -> IndyResult<String> {
+        let raw: serde_json::Value = serde_json::from_str(raw)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Raw attribute is invalid json")?;
+
+        raw.as_object()
+            .and_then(|raw| raw.keys().next().cloned())
+            .ok_or(err_msg(IndyErrorKind::InvalidTransaction, "Raw attribute isn't json object"))
+    }
+
+    pub fn parse_get_txn_response(&self, get_txn_response: &str) -> IndyResult<String> {
+        info!("parse_get_txn_response >>> get_txn_response: {:?}", get_txn_response);
+
+        let reply: Reply<GetTxnReplyResult> = LedgerService::parse_response(get_txn_response)?;
+        let reply = reply.result();
+
+        let txn: Value = match reply.data {
+            Some(Value::String(txn)) => serde_json::from_str(&txn)
+                .to_indy(IndyErrorKind::InvalidTransaction, "Invalid GET_TXN response data json")?,
+            Some(txn @ Value::Object(_)) => txn,
+            _ => return Err(err_msg(IndyErrorKind::LedgerItemNotFound, "Transaction isn't found on the ledger"))
+        };
+
+        let txn = match txn["ver"].as_str() {
+            None => self._parse_txn_v0(&txn, reply.seq_no)?,
+            Some("1") => self._parse_txn_v1(&txn, reply.seq_no)?,
+            ver @ _ => return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Unsupported transaction version: {:?}", ver)))
+        };
+
+        let res = serde_json::to_string(&txn)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Transaction")?;
+
+        info!("parse_get_txn_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _parse_txn_v0(&self, txn: &Value, seq_no: Option<u64>) -> IndyResult<TxnInfo> {
+        let mut data = txn.as_object()
+            .ok_or(err_msg(IndyErrorKind::InvalidTransaction, "Transaction isn't json object"))?
+            .clone();
+
+        // Envelope and ledger proof fields aren't part of transaction data
+        for field in &["type", "seqNo", "txnTime", "identifier", "reqId", "signature", "signatures",
+            "protocolVersion", "auditPath", "ledgerSize", "rootHash"] {
+            data.remove(*field);
+        }
+
+        Ok(TxnInfo {
+            seq_no: txn["seqNo"].as_u64().or(seq_no)
+                .ok_or(err_msg(IndyErrorKind::InvalidTransaction, "Transaction doesn't contain seqNo"))?,
+            txn_time: txn["txnTime"].as_u64(),
+            type_: txn["type"].as_str()
+                .ok_or(err_msg(IndyErrorKind::InvalidTransaction, "Transaction doesn't contain type"))?
+                .to_string(),
+            from: txn["identifier"].as_str().map(String::from),
+            data: Value::Object(data),
+        })
+    }
+
+    fn _parse_txn_v1(&self, txn: &Value, seq_no: Option<u64>) -> IndyResult<TxnInfo> {
+        Ok(TxnInfo {
+            seq_no: txn["txnMetadata"]["seqNo"].as_u64().or(seq_no)
+                .ok_or(err_msg(IndyErrorKind::InvalidTransaction, "Transaction doesn't contain seqNo"))?,
+            txn_time: txn["txnMetadata"]["txnTime"].as_u64(),
+            type_: txn["txn"]["type"].as_str()
+                .ok_or(err_msg(IndyErrorKind::InvalidTransaction, "Transaction doesn't contain type"))?
+                .to_string(),
+            from: txn["txn"]["metadata"]["from"].as_str().map(String::from),
+            data: txn["txn"]["data"].clone(),
+        })
+    }
+
+    pub fn parse_get_validator_info_response(&self, get_validator_info_response: &str) -> IndyResult<String> {
+        info!("parse_get_validator_info_response >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+        let replies: HashMap<String, String> = serde_json::from_str(get_validator_info_response)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid GET_VALIDATOR_INFO response json")?;
+
+        let mut validator_info = ValidatorInfo {
+            nodes: HashMap::new(),
+            errors: HashMap::new(),
+        };
+
+        for (node, reply) in replies {
+            match serde_json::from_str::<Message<GetValidatorInfoReplyResult>>(&reply) {
+                Ok(Message::Reply(reply)) => {
+                    validator_info.nodes.insert(node, reply.result().data);
+                }
+                Ok(Message::Reject(response)) | Ok(Message::ReqNACK(response)) => {
+                    validator_info.errors.insert(node, response.reason);
+                }
+                // Node hasn't replied in time or replied with unexpected message
+                Err(_) => {
+                    validator_info.errors.insert(node, reply);
+                }
+            }
+        }
+
+        let res = serde_json::to_string(&validator_info)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ValidatorInfo")?;
+
+        info!("parse_get_validator_info_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn build_pool_status(&self, nodes: &BTreeMap<String, String>, get_validator_info_response: &str) -> IndyResult<String> {
+        trace!("build_pool_status >>> nodes: {:?}, get_validator_info_response: {:?}", nodes, get_validator_info_response);
+
+        let replies: HashMap<String, String> = serde_json::from_str(get_validator_info_response)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid GET_VALIDATOR_INFO response json")?;
+
+        let mut statuses: BTreeMap<String, NodeStatus> = nodes.iter()
+            .map(|(alias, address)| (alias.clone(), NodeStatus {
+                address: Some(address.clone()),
+                error: Some("No reply".to_string()),
+                ..NodeStatus::default()
+            }))
+            .collect();
+
+        for (alias, reply) in replies {
+            let status = statuses.entry(alias.clone()).or_insert_with(NodeStatus::default);
+
+            match serde_json::from_str::<Message<GetValidatorInfoReplyResult>>(&reply) {
+                Ok(Message::Reply(reply)) => {
+                    status.reachable = true;
+                    status.error = None;
+                    self._fill_node_status(status, &alias, &reply.result().data);
+                }
+                Ok(Message::Reject(response)) | Ok(Message::ReqNACK(response)) => {
+                    status.reachable = true;
+                    status.error = Some(response.reason);
+                }
+                // Node hasn't replied in time or replied with unexpected message
+                Err(_) => {
+                    status.error = Some(reply);
+                }
+            }
+        }
+
+        let total_nodes = nodes.len();
+        let f = if total_nodes < 4 { 0 } else { (total_nodes - 1) / 3 };
+        let required_nodes = total_nodes - f;
+        // nodes missed in the pool ledger are reported, but don't count towards the consensus
+        let reachable_nodes = statuses.iter()
+            .filter(|&(alias, status)| status.reachable && nodes.contains_key(alias))
+            .count();
+
+        let view_no = _most_common(statuses.values().filter_map(|status| status.view_no));
+        let primary = _most_common(statuses.values().filter_map(|status| status.primary.clone()));
+
+        let primary_agreed = view_no.is_some() && primary.is_some() &&
+            statuses.values()
+                .filter(|status| status.view_no.is_some())
+                .all(|status| status.view_no == view_no && status.primary == primary && status.view_change_in_progress != Some(true));
+
+        let pool_status = PoolStatus {
+            nodes: statuses,
+            consensus: ConsensusStatus {
+                total_nodes,
+                f,
+                required_nodes,
+                reachable_nodes,
+                f_tolerated: total_nodes > 0 && reachable_nodes >= required_nodes,
+                view_no,
+                primary,
+                primary_agreed,
+            },
+        };
+
+        let res = serde_json::to_string(&pool_status)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize PoolStatus")?;
+
+        trace!("build_pool_status <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _fill_node_status(&self, status: &mut NodeStatus, alias: &str, validator_info: &Value) {
+        let node_info = &validator_info["Node_info"];
+        let master_replica = &node_info["Replicas_status"][format!("{}:0", alias).as_str()];
+        let view_change_status = &node_info["View_change_status"];
+
+        status.mode = node_info["Mode"].as_str().map(String::from);
+        status.software_version = validator_info["Software"]["indy-node"].as_str().map(String::from);
+        status.last_ordered_3pc = _non_null(&master_replica["Last_ordered_3PC"]);
+        status.catchup_status = _non_null(&node_info["Catchup_status"]["Ledger_statuses"]);
+        status.view_no = view_change_status["View_No"].as_u64();
+        status.view_change_in_progress = view_change_status.as_object()
+            .map(|view_change_status| view_change_status.get("VC_in_progress_for").map(|view_no| !view_no.is_null()).unwrap_or(false));
+        // Replica name has format <node alias>:<instance id>
+        status.primary = master_replica["Primary"].as_str()
+            .map(|primary| primary.split(':').next().unwrap_or(primary).to_string());
+    }
+
     fn _parse_auth_action(&self, action: &str, old_value: Option<&str>) -> IndyResult<AuthAction> {
         let action = serde_json::from_str::<AuthAction>(&format!("\"{}\"", action))
             .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Cannot parse auth action: {}", err)))?;
@@ -561,11 +848,11 @@ impl LedgerService {
     }
 
     pub fn build_auth_rule_request(&self, submitter_did: &str, txn_type: &str, action: &str, field: &str,
-                                   old_value: Option<&str>, new_value: &str, constraint: &str) -> IndyResult<String> {
+                                   old_value: Option<&str>, new_value: &str, constraint: &str, protocol_version: usize) -> IndyResult<String> {
         info!("build_auth_rule_request >>> submitter_did: {:?}, txn_type: {:?}, action: {:?}, field: {:?}, \
             old_value: {:?}, new_value: {:?}, constraint: {:?}", submitter_did, txn_type, action, field, old_value, new_value, constraint);
 
-        let txn_type = txn_name_to_code(&txn_type)
+        let txn_type = _txn_name_to_code(&txn_type)
             .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported `txn_type`: {}", txn_type)))?;
 
         let action = self._parse_auth_action(action, old_value)?;
@@ -573,10 +860,10 @@ impl LedgerService {
         let constraint = serde_json::from_str::<Constraint>(constraint)
             .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Can not deserialize Constraint: {}", err)))?;
 
-        let operation = AuthRuleOperation::new(txn_type.to_string(), field.to_string(), action,
+        let operation = AuthRuleOperation::new(txn_type, field.to_string(), action,
                                                old_value.map(String::from), new_value.to_string(), constraint);
 
-        let request = Request::build_request(Some(submitter_did), operation)
+        let request = Request::build_request(Some(submitter_did), operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "AUTH_RULE request json is invalid")?;
 
         info!("build_auth_rule_request <<< request: {:?}", request);
@@ -585,7 +872,7 @@ impl LedgerService {
     }
 
     pub fn build_get_auth_rule_request(&self, submitter_did: Option<&str>, auth_type: Option<&str>, auth_action: Option<&str>,
-                                       field: Option<&str>, old_value: Option<&str>, new_value: Option<&str>) -> IndyResult<String> {
+                                       field: Option<&str>, old_value: Option<&str>, new_value: Option<&str>, protocol_version: usize) -> IndyResult<String> {
         info!("build_get_auth_rule_request >>> submitter_did: {:?}, auth_type: {:?}, auth_action: {:?}, field: {:?}, \
             old_value: {:?}, new_value: {:?}", submitter_did, auth_type, auth_action, field, old_value, new_value);
 
@@ -596,7 +883,7 @@ impl LedgerService {
 
         let auth_type = match auth_type {
             Some(type_) => Some(
-                txn_name_to_code(&type_)
+                _txn_name_to_code(&type_)
                     .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported `auth_type`: {}", type_)))?),
             None => None
         };
@@ -606,13 +893,13 @@ impl LedgerService {
             None => None
         };
 
-        let operation = GetAuthRuleOperation::new(auth_type.map(String::from),
+        let operation = GetAuthRuleOperation::new(auth_type,
                                                   field.map(String::from),
                                                   auth_action,
                                                   old_value.map(String::from),
                                                   new_value.map(String::from));
 
-        let request = Request::build_request(submitter_did, operation)
+        let request = Request::build_request(submitter_did, operation, protocol_version)
             .to_indy(IndyErrorKind::InvalidState, "GET_AUTH_RULE request json is invalid")?;
 
         info!("build_get_auth_rule_request <<< request: {:?}", request);
@@ -656,6 +943,337 @@ impl LedgerService {
         trace!("validate_action <<< res {:?}", res);
         res
     }
+
+    pub fn set_request_protocol_version(&self, request_json: &str, protocol_version: usize) -> IndyResult<String> {
+        trace!("set_request_protocol_version >>> request_json {:?}, protocol_version: {:?}", request_json, protocol_version);
+
+        let mut request: serde_json::Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        if !request.is_object() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Request isn't json object"));
+        }
+
+        let res = match request["protocolVersion"].as_u64() {
+            Some(version) if version as usize != protocol_version => {
+                if request.get("signature").is_some() || request.get("signatures").is_some() {
+                    return Err(err_msg(IndyErrorKind::PoolIncompatibleProtocolVersion,
+                                       format!("Request is signed for PROTOCOL_VERSION {} but Pool PROTOCOL_VERSION is {}", version, protocol_version)));
+                }
+
+                request["protocolVersion"] = json!(protocol_version);
+
+                if protocol_version == 1 {
+                    let is_cred_def = match request["operation"]["type"].as_str() {
+                        Some(CRED_DEF) | Some(GET_CRED_DEF) => true,
+                        _ => false
+                    };
+
+                    if is_cred_def {
+                        request["operation"].as_object_mut().map(|operation| operation.remove("tag"));
+                    }
+                }
+
+                serde_json::to_string(&request)
+                    .to_indy(IndyErrorKind::InvalidState, "Can't serialize request with protocol version")?
+            }
+            _ => request_json.to_string()
+        };
+
+        trace!("set_request_protocol_version <<< res {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn append_request_endorser(&self, request_json: &str, endorser_did: &str) -> IndyResult<String> {
+        trace!("append_request_endorser >>> request_json {:?}, endorser_did: {:?}", request_json, endorser_did);
+
+        let mut request: serde_json::Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        {
+            let request = request.as_object_mut()
+                .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Request isn't json object"))?;
+
+            if request.contains_key("signature") || request.contains_key("signatures") {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Endorser must be appended before the request is signed"));
+            }
+
+            request.insert("endorser".to_string(), Value::String(endorser_did.to_string()));
+        }
+
+        let res = serde_json::to_string(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize request with endorser")?;
+
+        trace!("append_request_endorser <<< res {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn register_custom_txn_type(txn_type: CustomTxnType) -> IndyResult<()> {
+        if txn_type.name.is_empty() || txn_type.code.is_empty() || !txn_type.code.chars().all(|c| c.is_digit(10)) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Custom transaction type must have not empty name and numeric code"));
+        }
+
+        if txn_name_to_code(&txn_type.name).is_some() || txn_name_to_code(&txn_type.code).is_some() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Try to override default transaction type {} ({})", txn_type.name, txn_type.code)));
+        }
+
+        let mut txn_types = CUSTOM_TXN_TYPES.lock()
+            .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Custom transaction types registry is poisoned"))?;
+
+        if let Some(registered) = txn_types.values().find(|registered| registered.code == txn_type.code && registered.name != txn_type.name) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Transaction type code {} is already registered for {}", txn_type.code, registered.name)));
+        }
+
+        if let Some(registered) = txn_types.get(&txn_type.name).filter(|registered| registered.code != txn_type.code) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Transaction type {} is already registered with code {}", txn_type.name, registered.code)));
+        }
+
+        txn_types.insert(txn_type.name.clone(), txn_type);
+
+        Ok(())
+    }
+
+    pub fn get_custom_txn_type(txn_type: &str) -> Option<CustomTxnType> {
+        CUSTOM_TXN_TYPES.lock().ok()
+            .and_then(|txn_types| txn_types.values()
+                .find(|registered| registered.name == txn_type || registered.code == txn_type)
+                .cloned())
+    }
+
+    pub fn build_custom_request(&self, submitter_did: Option<&str>, txn_type: &str, operation: &str, protocol_version: usize) -> IndyResult<String> {
+        info!("build_custom_request >>> submitter_did: {:?}, txn_type: {:?}, operation: {:?}", submitter_did, txn_type, operation);
+
+        let txn_type = LedgerService::get_custom_txn_type(txn_type)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown custom transaction type: {}", txn_type)))?;
+
+        if txn_type.kind == CustomTxnKind::Write && submitter_did.is_none() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Submitter DID is required for {} transaction", txn_type.name)));
+        }
+
+        let mut operation: serde_json::Map<String, Value> = serde_json::from_str(operation)
+            .to_indy(IndyErrorKind::InvalidStructure, "Operation is invalid json object")?;
+
+        if let Some(type_) = operation.get("type") {
+            if type_.as_str() != Some(txn_type.code.as_str()) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Operation type {} doesn't correspond to {} transaction", type_, txn_type.name)));
+            }
+        }
+
+        txn_type.validate_operation(&operation)
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        operation.insert("type".to_string(), Value::String(txn_type.code.clone()));
+
+        let request = Request::build_request(submitter_did, operation, protocol_version)
+            .to_indy(IndyErrorKind::InvalidState, "Custom request json is invalid")?;
+
+        info!("build_custom_request <<< request: {:?}", request);
+
+        Ok(request)
+    }
+
+    pub fn set_request_req_id(&self, request_json: &str, req_id: u64) -> IndyResult<String> {
+        trace!("set_request_req_id >>> request_json {:?}, req_id: {:?}", request_json, req_id);
+
+        let mut request: serde_json::Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        {
+            let request = request.as_object_mut()
+                .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Request isn't json object"))?;
+
+            if request.contains_key("signature") || request.contains_key("signatures") {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Request id must be set before the request is signed"));
+            }
+
+            request.insert("reqId".to_string(), json!(req_id));
+        }
+
+        let res = serde_json::to_string(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize request with request id")?;
+
+        trace!("set_request_req_id <<< res {:?}", res);
+
+        Ok(res)
+    }
+
+    /// Signer roles are self-declared and aren't verified against the ledger, so the constraint check
+    /// is only a hint for the caller (see `Constraint::is_satisfied_by`), the ledger checks actual roles on submission.
+    pub fn build_signing_bundle(&self, request_json: &str, signers: &HashMap<String, SignerInfo>, constraint: Option<&Constraint>) -> IndyResult<String> {
+        trace!("build_signing_bundle >>> request_json {:?}, signers: {:?}, constraint: {:?}", request_json, signers, constraint);
+
+        let request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        {
+            let request = request.as_object()
+                .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Request isn't json object"))?;
+
+            if request.contains_key("signature") || request.contains_key("signatures") {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Signing bundle must be built for not signed request"));
+            }
+        }
+
+        if signers.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Signing bundle requires at least one signer"));
+        }
+
+        let signers = signers.iter()
+            .map(|(did, signer)| -> IndyResult<(String, SignerInfo)> {
+                let role = match signer.role.as_ref().map(String::as_str) {
+                    None | Some(ROLE_REMOVE) => None,
+                    Some(role) => Some(_role_to_code(role)?.to_string())
+                };
+                Ok((did.to_string(), SignerInfo { verkey: signer.verkey.clone(), role }))
+            })
+            .collect::<IndyResult<BTreeMap<String, SignerInfo>>>()?;
+
+        if let Some(constraint) = constraint {
+            let roles = signers.values().map(|signer| signer.role.as_ref().map(String::as_str)).collect::<Vec<_>>();
+
+            if !constraint.is_satisfied_by(&roles) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Signers can't satisfy the constraint"));
+            }
+        }
+
+        let signing_input = serialize_signature(request.clone())?;
+
+        let bundle = SigningBundle::new(request, signing_input, signers, constraint.cloned());
+
+        let res = serde_json::to_string(&bundle)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize signing bundle")?;
+
+        trace!("build_signing_bundle <<< res {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn merge_signing_bundles(&self, bundles: Vec<SigningBundle>) -> IndyResult<String> {
+        trace!("merge_signing_bundles >>> bundles {:?}", bundles);
+
+        let mut bundles = bundles.into_iter();
+
+        let mut res = bundles.next()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "No signing bundles to merge"))?;
+
+        self.check_signing_bundle_input(&res)?;
+
+        for bundle in bundles {
+            if !res.is_same_request(&bundle) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Signing bundles are built for different requests"));
+            }
+
+            for (did, signature) in bundle.signatures {
+                if res.signatures.get(&did).map(|existing| existing != &signature).unwrap_or(false) {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Signing bundles contain different signatures of {}", did)));
+                }
+                res.signatures.insert(did, signature);
+            }
+        }
+
+        let res = serde_json::to_string(&res)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize signing bundle")?;
+
+        trace!("merge_signing_bundles <<< res {:?}", res);
+
+        Ok(res)
+    }
+
+    // Signing input is carried to let offline signers see exactly what they sign, so it must match the request
+    pub fn check_signing_bundle_input(&self, bundle: &SigningBundle) -> IndyResult<()> {
+        trace!("check_signing_bundle_input >>> bundle {:?}", bundle);
+
+        if bundle.signing_input != serialize_signature(bundle.request.clone())? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Signing input doesn't correspond to the request"));
+        }
+
+        if let Some(did) = bundle.signatures.keys().find(|did| !bundle.signers.contains_key(*did)) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Signing bundle contains signature of unknown signer {}", did)));
+        }
+
+        trace!("check_signing_bundle_input <<<");
+
+        Ok(())
+    }
+
+    // Expects that signatures are already verified
+    pub fn get_signing_bundle_status(&self, bundle: &SigningBundle) -> IndyResult<SigningBundleStatus> {
+        trace!("get_signing_bundle_status >>> bundle {:?}", bundle);
+
+        let (signed, unsigned): (Vec<String>, Vec<String>) = bundle.signers.keys()
+            .cloned()
+            .partition(|did| bundle.signatures.contains_key(did));
+
+        let complete = match bundle.constraint {
+            Some(ref constraint) => {
+                let roles = signed.iter()
+                    .map(|did| bundle.signers[did].role.as_ref().map(String::as_str))
+                    .collect::<Vec<_>>();
+                constraint.is_satisfied_by(&roles)
+            }
+            None => unsigned.is_empty()
+        };
+
+        let request = if complete {
+            let mut request = bundle.request.clone();
+            request["signatures"] = json!(bundle.signatures);
+            Some(request)
+        } else {
+            None
+        };
+
+        let res = SigningBundleStatus { complete, signed, unsigned, request };
+
+        trace!("get_signing_bundle_status <<< res {:?}", res);
+
+        Ok(res)
+    }
+}
+
+fn _role_name_to_code(role: &str) -> IndyResult<&'static str> {
+    match role {
+        "STEWARD" => Ok(STEWARD),
+        "TRUSTEE" => Ok(TRUSTEE),
+        "TRUST_ANCHOR" => Ok(TRUST_ANCHOR),
+        "ENDORSER" => Ok(ENDORSER),
+        "NETWORK_MONITOR" => Ok(NETWORK_MONITOR),
+        role @ _ => Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid role: {}", role)))
+    }
+}
+
+// Accepts both role names and codes as they are used in auth rules
+fn _role_to_code(role: &str) -> IndyResult<&str> {
+    match role {
+        TRUSTEE | STEWARD | ENDORSER | NETWORK_MONITOR => Ok(role),
+        _ => _role_name_to_code(role)
+    }
+}
+
+// Accepts both default and registered custom transaction types
+fn _txn_name_to_code(txn: &str) -> Option<String> {
+    txn_name_to_code(txn)
+        .map(String::from)
+        .or_else(|| LedgerService::get_custom_txn_type(txn).map(|txn_type| txn_type.code))
+}
+
+fn _non_null(value: &Value) -> Option<Value> {
+    if value.is_null() { None } else { Some(value.clone()) }
+}
+
+fn _most_common<T: Ord, I: Iterator<Item=T>>(values: I) -> Option<T> {
+    let mut counts: BTreeMap<T, usize> = BTreeMap::new();
+
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+
+    counts.into_iter().max_by_key(|&(_, count)| count).map(|(value, _)| value)
 }
 
 #[cfg(test)]
@@ -663,10 +1281,10 @@ mod tests {
     use domain::anoncreds::schema::AttributeNames;
     use domain::ledger::constants::*;
     use domain::ledger::node::Services;
-    use domain::ledger::request::ProtocolVersion;
 
     use super::*;
 
+    const TEST_PROTOCOL_VERSION: usize = 2;
     const IDENTIFIER: &'static str = "NcYxiDXkpYi6ov5FcYDi1e";
     const DEST: &'static str = "VsKV7grR1BUE29mG2Fm2kX";
     const VERKEY: &'static str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
@@ -680,7 +1298,7 @@ mod tests {
             "dest": DEST
         });
 
-        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None).unwrap();
+        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
@@ -694,7 +1312,7 @@ mod tests {
             "role": serde_json::Value::Null,
         });
 
-        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, Some("")).unwrap();
+        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, Some(""), TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
@@ -710,97 +1328,223 @@ mod tests {
             "verkey": VERKEY,
         });
 
-        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, Some(VERKEY), Some("some_alias"), Some("")).unwrap();
+        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, Some(VERKEY), Some("some_alias"), Some(""), TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
     #[test]
-    fn build_get_nym_request_works() {
+    fn build_nym_request_works_for_endorser_role() {
         let ledger_service = LedgerService::new();
 
         let expected_result = json!({
-            "type": GET_NYM,
-            "dest": DEST
+            "type": NYM,
+            "dest": DEST,
+            "role": ENDORSER,
         });
 
-        let request = ledger_service.build_get_nym_request(Some(IDENTIFIER), DEST).unwrap();
+        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, Some("ENDORSER"), TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
     #[test]
-    fn build_get_ddo_request_works() {
+    fn set_request_protocol_version_works() {
         let ledger_service = LedgerService::new();
 
-        let expected_result = json!({
-            "type": GET_DDO,
-            "dest": DEST
-        });
+        let request = json!({"reqId": 1, "identifier": IDENTIFIER, "operation": {"type": NYM}, "protocolVersion": 1});
 
-        let request = ledger_service.build_get_ddo_request(Some(IDENTIFIER), DEST).unwrap();
-        check_request(&request, expected_result);
+        let request = ledger_service.set_request_protocol_version(&request.to_string(), 2).unwrap();
+
+        let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert_eq!(request["protocolVersion"], 2);
     }
 
     #[test]
-    fn build_attrib_request_works_for_miss_attrib_field() {
+    fn set_request_protocol_version_works_for_same_version() {
         let ledger_service = LedgerService::new();
 
-        let res = ledger_service.build_attrib_request(IDENTIFIER, DEST, None, None, None);
-        assert_kind!(IndyErrorKind::InvalidStructure, res);
+        let request = json!({"reqId": 1, "identifier": IDENTIFIER, "operation": {"type": NYM}, "protocolVersion": 2, "signature": "signature"}).to_string();
+
+        assert_eq!(request, ledger_service.set_request_protocol_version(&request, 2).unwrap());
     }
 
     #[test]
-    fn build_attrib_request_works_for_hash_field() {
+    fn set_request_protocol_version_works_for_signed_request() {
         let ledger_service = LedgerService::new();
 
-        let expected_result = json!({
-            "type": ATTRIB,
-            "dest": DEST,
-            "hash": "hash"
-        });
+        let request = json!({"reqId": 1, "identifier": IDENTIFIER, "operation": {"type": NYM}, "protocolVersion": 1, "signature": "signature"});
 
-        let request = ledger_service.build_attrib_request(IDENTIFIER, DEST, Some("hash"), None, None).unwrap();
-        check_request(&request, expected_result);
+        let res = ledger_service.set_request_protocol_version(&request.to_string(), 2);
+        assert_kind!(IndyErrorKind::PoolIncompatibleProtocolVersion, res);
     }
 
     #[test]
-    fn build_get_attrib_request_works_for_raw_value() {
+    fn set_request_protocol_version_works_for_cred_def_tag() {
         let ledger_service = LedgerService::new();
 
-        let expected_result = json!({
-            "type": GET_ATTR,
-            "dest": DEST,
-            "raw": "raw"
-        });
+        let request = json!({"reqId": 1, "identifier": IDENTIFIER, "operation": {"type": CRED_DEF, "tag": "tag"}, "protocolVersion": 2});
 
-        let request = ledger_service.build_get_attrib_request(Some(IDENTIFIER), DEST, Some("raw"), None, None).unwrap();
-        check_request(&request, expected_result);
+        let request = ledger_service.set_request_protocol_version(&request.to_string(), 1).unwrap();
+
+        let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert_eq!(request["protocolVersion"], 1);
+        assert!(request["operation"].get("tag").is_none());
     }
 
     #[test]
-    fn build_get_attrib_request_works_for_hash_value() {
+    fn append_request_endorser_works() {
         let ledger_service = LedgerService::new();
 
-        let expected_result = json!({
-            "type": GET_ATTR,
-            "dest": DEST,
-            "hash": "hash"
-        });
+        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION).unwrap();
+        let request = ledger_service.append_request_endorser(&request, DEST).unwrap();
 
-        let request = ledger_service.build_get_attrib_request(Some(IDENTIFIER), DEST, None, Some("hash"), None).unwrap();
-        check_request(&request, expected_result);
+        let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert_eq!(request["endorser"], DEST);
+        assert_eq!(request["identifier"], IDENTIFIER);
     }
 
     #[test]
-    fn build_get_attrib_request_works_for_enc_value() {
+    fn append_request_endorser_works_for_signed_request() {
         let ledger_service = LedgerService::new();
 
-        let expected_result = json!({
+        let mut request: serde_json::Value = serde_json::from_str(&ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION).unwrap()).unwrap();
+        request["signature"] = json!("signature");
+
+        let res = ledger_service.append_request_endorser(&request.to_string(), DEST);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_requests_works_for_unique_req_ids() {
+        let ledger_service = LedgerService::new();
+
+        let req_id = |request: String| serde_json::from_str::<serde_json::Value>(&request).unwrap()["reqId"].as_u64().unwrap();
+
+        let req_id_1 = req_id(ledger_service.build_get_nym_request(Some(IDENTIFIER), DEST, TEST_PROTOCOL_VERSION).unwrap());
+        let req_id_2 = req_id(ledger_service.build_get_nym_request(Some(IDENTIFIER), DEST, TEST_PROTOCOL_VERSION).unwrap());
+        assert!(req_id_2 > req_id_1);
+    }
+
+    #[test]
+    fn set_request_req_id_works() {
+        let ledger_service = LedgerService::new();
+
+        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION).unwrap();
+        let request = ledger_service.set_request_req_id(&request, 42).unwrap();
+
+        let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert_eq!(request["reqId"], 42);
+    }
+
+    #[test]
+    fn set_request_req_id_works_for_max_req_id() {
+        let ledger_service = LedgerService::new();
+
+        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION).unwrap();
+        ledger_service.set_request_req_id(&request, u64::max_value()).unwrap();
+
+        let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION).unwrap();
+        let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert!(request["reqId"].as_u64().unwrap() < u64::max_value());
+    }
+
+    #[test]
+    fn set_request_req_id_works_for_signed_request() {
+        let ledger_service = LedgerService::new();
+
+        let mut request: serde_json::Value = serde_json::from_str(&ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION).unwrap()).unwrap();
+        request["signature"] = json!("signature");
+
+        let res = ledger_service.set_request_req_id(&request.to_string(), 42);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_get_nym_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_NYM,
+            "dest": DEST
+        });
+
+        let request = ledger_service.build_get_nym_request(Some(IDENTIFIER), DEST, TEST_PROTOCOL_VERSION).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_ddo_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_DDO,
+            "dest": DEST
+        });
+
+        let request = ledger_service.build_get_ddo_request(Some(IDENTIFIER), DEST, TEST_PROTOCOL_VERSION).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_attrib_request_works_for_miss_attrib_field() {
+        let ledger_service = LedgerService::new();
+
+        let res = ledger_service.build_attrib_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_attrib_request_works_for_hash_field() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": ATTRIB,
+            "dest": DEST,
+            "hash": "hash"
+        });
+
+        let request = ledger_service.build_attrib_request(IDENTIFIER, DEST, Some("hash"), None, None, TEST_PROTOCOL_VERSION).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_attrib_request_works_for_raw_value() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_ATTR,
+            "dest": DEST,
+            "raw": "raw"
+        });
+
+        let request = ledger_service.build_get_attrib_request(Some(IDENTIFIER), DEST, Some("raw"), None, None, TEST_PROTOCOL_VERSION).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_attrib_request_works_for_hash_value() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_ATTR,
+            "dest": DEST,
+            "hash": "hash"
+        });
+
+        let request = ledger_service.build_get_attrib_request(Some(IDENTIFIER), DEST, None, Some("hash"), None, TEST_PROTOCOL_VERSION).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_attrib_request_works_for_enc_value() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
             "type": GET_ATTR,
             "dest": DEST,
             "enc": "enc"
         });
 
-        let request = ledger_service.build_get_attrib_request(Some(IDENTIFIER), DEST, None, None, Some("enc")).unwrap();
+        let request = ledger_service.build_get_attrib_request(Some(IDENTIFIER), DEST, None, None, Some("enc"), TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
@@ -828,7 +1572,7 @@ mod tests {
             }
         });
 
-        let request = ledger_service.build_schema_request(IDENTIFIER, data).unwrap();
+        let request = ledger_service.build_schema_request(IDENTIFIER, data, TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
@@ -846,7 +1590,7 @@ mod tests {
             seq_no: None,
         };
 
-        let res = ledger_service.build_schema_request(IDENTIFIER, data);
+        let res = ledger_service.build_schema_request(IDENTIFIER, data, TEST_PROTOCOL_VERSION);
         assert_kind!(IndyErrorKind::InvalidStructure, res);
     }
 
@@ -854,7 +1598,7 @@ mod tests {
     fn build_get_schema_request_works_for_invalid_id() {
         let ledger_service = LedgerService::new();
 
-        let res = ledger_service.build_get_schema_request(Some(IDENTIFIER), "wrong_schema_id");
+        let res = ledger_service.build_get_schema_request(Some(IDENTIFIER), "wrong_schema_id", TEST_PROTOCOL_VERSION);
         assert_kind!(IndyErrorKind::InvalidStructure, res);
     }
 
@@ -873,17 +1617,15 @@ mod tests {
             }
         });
 
-        let request = ledger_service.build_get_schema_request(Some(IDENTIFIER), &id).unwrap();
+        let request = ledger_service.build_get_schema_request(Some(IDENTIFIER), &id, TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
     #[test]
     fn build_get_cred_def_request_works() {
-        ProtocolVersion::set(2);
-
         let ledger_service = LedgerService::new();
 
-        let id = CredentialDefinition::cred_def_id(IDENTIFIER, "1", "signature_type", "tag");
+        let id = CredentialDefinition::cred_def_id(IDENTIFIER, "1", "signature_type", "tag", TEST_PROTOCOL_VERSION);
 
         let expected_result = json!({
             "type": GET_CRED_DEF,
@@ -893,10 +1635,53 @@ mod tests {
             "tag":"tag"
         });
 
-        let request = ledger_service.build_get_cred_def_request(Some(IDENTIFIER), &id).unwrap();
+        let request = ledger_service.build_get_cred_def_request(Some(IDENTIFIER), &id, TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
+    #[test]
+    fn build_get_cred_def_request_works_for_protocol_version_1_id() {
+        let ledger_service = LedgerService::new();
+
+        let id = CredentialDefinition::cred_def_id(IDENTIFIER, "1", "signature_type", "tag", 1);
+
+        let expected_result = json!({
+            "type": GET_CRED_DEF,
+            "ref": 1,
+            "signature_type": "signature_type",
+            "origin": IDENTIFIER
+        });
+
+        let request = ledger_service.build_get_cred_def_request(Some(IDENTIFIER), &id, 1).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_requests_works_for_different_protocol_versions() {
+        let ledger_service = LedgerService::new();
+
+        let protocol_version = |request: String| serde_json::from_str::<serde_json::Value>(&request).unwrap()["protocolVersion"].as_u64().unwrap();
+
+        assert_eq!(1, protocol_version(ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, 1).unwrap()));
+        assert_eq!(2, protocol_version(ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, 2).unwrap()));
+        assert_eq!(1, protocol_version(ledger_service.build_get_nym_request(Some(IDENTIFIER), DEST, 1).unwrap()));
+        assert_eq!(2, protocol_version(ledger_service.build_get_nym_request(Some(IDENTIFIER), DEST, 2).unwrap()));
+    }
+
+    #[test]
+    fn set_request_protocol_version_works_for_request_built_for_pool() {
+        let ledger_service = LedgerService::new();
+
+        let mut request: serde_json::Value = serde_json::from_str(&ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, 1).unwrap()).unwrap();
+        request["signature"] = json!("signature");
+        let request = request.to_string();
+
+        assert_eq!(request, ledger_service.set_request_protocol_version(&request, 1).unwrap());
+
+        let res = ledger_service.set_request_protocol_version(&request, 2);
+        assert_kind!(IndyErrorKind::PoolIncompatibleProtocolVersion, res);
+    }
+
     #[test]
     fn build_node_request_works() {
         let ledger_service = LedgerService::new();
@@ -927,7 +1712,7 @@ mod tests {
             }
         });
 
-        let request = ledger_service.build_node_request(IDENTIFIER, DEST, data).unwrap();
+        let request = ledger_service.build_node_request(IDENTIFIER, DEST, data, TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
@@ -941,7 +1726,7 @@ mod tests {
             "ledgerId": 1
         });
 
-        let request = ledger_service.build_get_txn_request(Some(IDENTIFIER), None, 1).unwrap();
+        let request = ledger_service.build_get_txn_request(Some(IDENTIFIER), None, 1, TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
@@ -955,7 +1740,7 @@ mod tests {
             "ledgerId": 0
         });
 
-        let request = ledger_service.build_get_txn_request(Some(IDENTIFIER), Some("POOL"), 1).unwrap();
+        let request = ledger_service.build_get_txn_request(Some(IDENTIFIER), Some("POOL"), 1, TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
@@ -970,7 +1755,7 @@ mod tests {
             "ledgerId": 10
         });
 
-        let request = ledger_service.build_get_txn_request(Some(identifier), Some("10"), 1).unwrap();
+        let request = ledger_service.build_get_txn_request(Some(identifier), Some("10"), 1, TEST_PROTOCOL_VERSION).unwrap();
         check_request(&request, expected_result);
     }
 
@@ -978,24 +1763,365 @@ mod tests {
     fn build_get_txn_request_works_for_invalid_type() {
         let ledger_service = LedgerService::new();
 
-        let res = ledger_service.build_get_txn_request(Some(IDENTIFIER), Some("type"), 1);
+        let res = ledger_service.build_get_txn_request(Some(IDENTIFIER), Some("type"), 1, TEST_PROTOCOL_VERSION);
         assert_kind!(IndyErrorKind::InvalidStructure, res);
     }
 
     #[test]
     fn validate_action_works_for_pool_restart() {
         let ledger_service = LedgerService::new();
-        let request = ledger_service.build_pool_restart(IDENTIFIER, "start", None).unwrap();
+        let request = ledger_service.build_pool_restart(IDENTIFIER, "start", None, TEST_PROTOCOL_VERSION).unwrap();
         ledger_service.validate_action(&request).unwrap();
     }
 
     #[test]
     fn validate_action_works_for_get_validator_info() {
         let ledger_service = LedgerService::new();
-        let request = ledger_service.build_get_validator_info_request(IDENTIFIER).unwrap();
+        let request = ledger_service.build_get_validator_info_request(IDENTIFIER, TEST_PROTOCOL_VERSION).unwrap();
         ledger_service.validate_action(&request).unwrap();
     }
 
+    mod parse_response {
+        use super::*;
+
+        fn _reply(result: serde_json::Value) -> String {
+            json!({"op": "REPLY", "result": result}).to_string()
+        }
+
+        #[test]
+        fn parse_get_nym_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let data = json!({"dest": DEST, "identifier": IDENTIFIER, "role": "101", "verkey": VERKEY}).to_string();
+            let response = _reply(json!({"type": GET_NYM, "dest": DEST, "data": data, "seqNo": 10, "txnTime": 1551780000}));
+
+            let nym = ledger_service.parse_get_nym_response(&response).unwrap();
+            let nym: serde_json::Value = serde_json::from_str(&nym).unwrap();
+            assert_eq!(json!({"did": DEST, "verkey": VERKEY, "role": "101", "seqNo": 10, "txnTime": 1551780000}), nym);
+        }
+
+        #[test]
+        fn parse_get_nym_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({"type": GET_NYM, "dest": DEST, "data": null}));
+
+            let res = ledger_service.parse_get_nym_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn parse_get_nym_response_works_for_reject() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({"op": "REJECT", "reqId": 1, "reason": "some reason"}).to_string();
+
+            let res = ledger_service.parse_get_nym_response(&response);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_get_nym_response_works_for_other_type() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({"type": GET_ATTR, "dest": DEST, "data": null}));
+
+            let res = ledger_service.parse_get_nym_response(&response);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_raw_value() {
+            let ledger_service = LedgerService::new();
+
+            let data = json!({"endpoint": {"ha": "127.0.0.1:5555"}}).to_string();
+            let response = _reply(json!({"type": GET_ATTR, "identifier": IDENTIFIER, "dest": DEST, "raw": "endpoint", "data": data, "seqNo": 11, "txnTime": 1551780000}));
+
+            let attrib = ledger_service.parse_get_attrib_response(&response).unwrap();
+            let attrib: serde_json::Value = serde_json::from_str(&attrib).unwrap();
+            assert_eq!(json!({"did": DEST, "raw": "endpoint", "data": data, "seqNo": 11, "txnTime": 1551780000}), attrib);
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_hash_value() {
+            let ledger_service = LedgerService::new();
+
+            let hash = "83d907821df1c87db829e96569a11f6fc2e7880acba5e43d07ab786959e13bd3";
+            let response = _reply(json!({"type": GET_ATTR, "identifier": IDENTIFIER, "dest": DEST, "hash": hash, "data": hash}));
+
+            let attrib = ledger_service.parse_get_attrib_response(&response).unwrap();
+            let attrib: serde_json::Value = serde_json::from_str(&attrib).unwrap();
+            assert_eq!(json!({"did": DEST, "hash": hash, "data": hash}), attrib);
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({"type": GET_ATTR, "identifier": IDENTIFIER, "dest": DEST, "raw": "endpoint", "data": null}));
+
+            let res = ledger_service.parse_get_attrib_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_not_found_data() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({"type": GET_ATTR, "identifier": IDENTIFIER, "dest": DEST, "raw": "endpoint"}));
+
+            let res = ledger_service.parse_get_attrib_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_raw_value_v1() {
+            let ledger_service = LedgerService::new();
+
+            let data = json!({"endpoint": {"ha": "127.0.0.1:5555"}}).to_string();
+            let response = _reply(json!({"type": GET_ATTR,
+                "txn": {"data": {"ver": "1", "id": "id", "did": DEST, "raw": data}},
+                "txnMetadata": {"seqNo": 11, "creationTime": 1551780000}
+            }));
+
+            let attrib = ledger_service.parse_get_attrib_response(&response).unwrap();
+            let attrib: serde_json::Value = serde_json::from_str(&attrib).unwrap();
+            assert_eq!(json!({"did": DEST, "raw": "endpoint", "data": data, "seqNo": 11, "txnTime": 1551780000}), attrib);
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_hash_value_v1() {
+            let ledger_service = LedgerService::new();
+
+            let hash = "83d907821df1c87db829e96569a11f6fc2e7880acba5e43d07ab786959e13bd3";
+            let response = _reply(json!({"type": GET_ATTR,
+                "txn": {"data": {"ver": "1", "id": "id", "did": DEST, "hash": hash}},
+                "txnMetadata": {"seqNo": 11, "creationTime": 1551780000}
+            }));
+
+            let attrib = ledger_service.parse_get_attrib_response(&response).unwrap();
+            let attrib: serde_json::Value = serde_json::from_str(&attrib).unwrap();
+            assert_eq!(json!({"did": DEST, "hash": hash, "data": hash, "seqNo": 11, "txnTime": 1551780000}), attrib);
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_not_found_v1() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({"type": GET_ATTR,
+                "txn": {"data": {"ver": "1", "id": "id", "did": DEST}},
+                "txnMetadata": {"seqNo": 11, "creationTime": 1551780000}
+            }));
+
+            let res = ledger_service.parse_get_attrib_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_txn_v0() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({"type": GET_TXN, "seqNo": 5, "data": {
+                "type": NYM, "dest": DEST, "verkey": VERKEY, "identifier": IDENTIFIER,
+                "reqId": 1, "signature": "sig", "seqNo": 5, "txnTime": 1551780000
+            }}));
+
+            let txn = ledger_service.parse_get_txn_response(&response).unwrap();
+            let txn: serde_json::Value = serde_json::from_str(&txn).unwrap();
+            assert_eq!(json!({
+                "seqNo": 5,
+                "txnTime": 1551780000,
+                "type": NYM,
+                "from": IDENTIFIER,
+                "data": {"dest": DEST, "verkey": VERKEY}
+            }), txn);
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_txn_v1() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({"type": GET_TXN, "seqNo": 5, "data": {
+                "txn": {
+                    "type": NYM,
+                    "data": {"dest": DEST, "verkey": VERKEY},
+                    "metadata": {"from": IDENTIFIER, "reqId": 1}
+                },
+                "txnMetadata": {"seqNo": 5, "txnTime": 1551780000},
+                "reqSignature": {},
+                "ver": "1",
+                "auditPath": ["path"],
+                "ledgerSize": 5,
+                "rootHash": "hash"
+            }}));
+
+            let txn = ledger_service.parse_get_txn_response(&response).unwrap();
+            let txn: serde_json::Value = serde_json::from_str(&txn).unwrap();
+            assert_eq!(json!({
+                "seqNo": 5,
+                "txnTime": 1551780000,
+                "type": NYM,
+                "from": IDENTIFIER,
+                "data": {"dest": DEST, "verkey": VERKEY}
+            }), txn);
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({"type": GET_TXN, "seqNo": null, "data": null}));
+
+            let res = ledger_service.parse_get_txn_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_unsupported_version() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({"type": GET_TXN, "seqNo": 5, "data": {"ver": "2"}}));
+
+            let res = ledger_service.parse_get_txn_response(&response);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_get_validator_info_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "Node1": _reply(json!({"type": GET_VALIDATOR_INFO, "data": {"Node_info": {"Name": "Node1"}}})),
+                "Node2": json!({"op": "REQNACK", "reqId": 1, "reason": "not allowed"}).to_string(),
+                "Node3": "timeout",
+            }).to_string();
+
+            let validator_info = ledger_service.parse_get_validator_info_response(&response).unwrap();
+            let validator_info: serde_json::Value = serde_json::from_str(&validator_info).unwrap();
+            assert_eq!(json!({
+                "nodes": {"Node1": {"Node_info": {"Name": "Node1"}}},
+                "errors": {"Node2": "not allowed", "Node3": "timeout"}
+            }), validator_info);
+        }
+
+        #[test]
+        fn parse_get_validator_info_response_works_for_invalid_json() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.parse_get_validator_info_response("[]");
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        fn _validator_info(alias: &str, primary: &str, view_no: u64) -> String {
+            let mut replicas_status = serde_json::Map::new();
+            replicas_status.insert(format!("{}:0", alias), json!({"Primary": format!("{}:0", primary), "Last_ordered_3PC": [view_no, 12]}));
+
+            _reply(json!({"type": GET_VALIDATOR_INFO, "data": {
+                "Node_info": {
+                    "Name": alias,
+                    "Mode": "participating",
+                    "Catchup_status": {"Ledger_statuses": {"0": "synced", "1": "synced"}},
+                    "View_change_status": {"View_No": view_no, "VC_in_progress_for": null},
+                    "Replicas_status": replicas_status
+                },
+                "Software": {"indy-node": "1.9.0"}
+            }}))
+        }
+
+        fn _nodes() -> BTreeMap<String, String> {
+            (1..5).map(|i| (format!("Node{}", i), format!("tcp://127.0.0.1:970{}", i * 2))).collect()
+        }
+
+        #[test]
+        fn build_pool_status_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "Node1": _validator_info("Node1", "Node1", 0),
+                "Node2": _validator_info("Node2", "Node1", 0),
+                "Node3": json!({"op": "REQNACK", "reqId": 1, "reason": "not allowed"}).to_string(),
+                "Node4": "timeout",
+            }).to_string();
+
+            let pool_status = ledger_service.build_pool_status(&_nodes(), &response).unwrap();
+            let pool_status: serde_json::Value = serde_json::from_str(&pool_status).unwrap();
+
+            assert_eq!(json!({
+                "address": "tcp://127.0.0.1:9702",
+                "reachable": true,
+                "error": null,
+                "mode": "participating",
+                "softwareVersion": "1.9.0",
+                "lastOrdered3pc": [0, 12],
+                "catchupStatus": {"0": "synced", "1": "synced"},
+                "viewNo": 0,
+                "viewChangeInProgress": false,
+                "primary": "Node1"
+            }), pool_status["nodes"]["Node1"]);
+            assert_eq!(json!(true), pool_status["nodes"]["Node3"]["reachable"]);
+            assert_eq!(json!("not allowed"), pool_status["nodes"]["Node3"]["error"]);
+            assert_eq!(json!(false), pool_status["nodes"]["Node4"]["reachable"]);
+            assert_eq!(json!("timeout"), pool_status["nodes"]["Node4"]["error"]);
+
+            assert_eq!(json!({
+                "totalNodes": 4,
+                "f": 1,
+                "requiredNodes": 3,
+                "reachableNodes": 3,
+                "fTolerated": true,
+                "viewNo": 0,
+                "primary": "Node1",
+                "primaryAgreed": true
+            }), pool_status["consensus"]);
+        }
+
+        #[test]
+        fn build_pool_status_works_for_not_tolerated_f() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "Node1": _validator_info("Node1", "Node1", 0),
+                "Node2": _validator_info("Node2", "Node2", 1),
+            }).to_string();
+
+            let pool_status = ledger_service.build_pool_status(&_nodes(), &response).unwrap();
+            let pool_status: serde_json::Value = serde_json::from_str(&pool_status).unwrap();
+
+            assert_eq!(json!("No reply"), pool_status["nodes"]["Node3"]["error"]);
+            assert_eq!(json!(2), pool_status["consensus"]["reachableNodes"]);
+            assert_eq!(json!(false), pool_status["consensus"]["fTolerated"]);
+            assert_eq!(json!(false), pool_status["consensus"]["primaryAgreed"]);
+        }
+
+        #[test]
+        fn build_pool_status_works_for_node_not_in_pool_ledger() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "Node1": _validator_info("Node1", "Node1", 0),
+                "Node2": _validator_info("Node2", "Node1", 0),
+                "Node5": _validator_info("Node5", "Node1", 0),
+            }).to_string();
+
+            let pool_status = ledger_service.build_pool_status(&_nodes(), &response).unwrap();
+            let pool_status: serde_json::Value = serde_json::from_str(&pool_status).unwrap();
+
+            assert_eq!(json!(null), pool_status["nodes"]["Node5"]["address"]);
+            assert_eq!(json!(true), pool_status["nodes"]["Node5"]["reachable"]);
+            assert_eq!(json!(4), pool_status["consensus"]["totalNodes"]);
+            assert_eq!(json!(2), pool_status["consensus"]["reachableNodes"]);
+            assert_eq!(json!(false), pool_status["consensus"]["fTolerated"]);
+        }
+
+        #[test]
+        fn build_pool_status_works_for_invalid_json() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_pool_status(&_nodes(), "[]");
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
     mod auth_rule {
         use super::*;
 
@@ -1033,7 +2159,7 @@ mod tests {
 
             let request = ledger_service.build_auth_rule_request(IDENTIFIER, NYM, ADD_AUTH_ACTION, FIELD,
                                                                  None, NEW_VALUE,
-                                                                 &_role_constraint_json()).unwrap();
+                                                                 &_role_constraint_json(), TEST_PROTOCOL_VERSION).unwrap();
             check_request(&request, expected_result);
         }
 
@@ -1066,7 +2192,7 @@ mod tests {
 
             let request = ledger_service.build_auth_rule_request(IDENTIFIER, NYM, ADD_AUTH_ACTION, FIELD,
                                                                  None, NEW_VALUE,
-                                                                 &constraint_json).unwrap();
+                                                                 &constraint_json, TEST_PROTOCOL_VERSION).unwrap();
 
             check_request(&request, expected_result);
         }
@@ -1087,7 +2213,7 @@ mod tests {
 
             let request = ledger_service.build_auth_rule_request(IDENTIFIER, NYM, EDIT_AUTH_ACTION, FIELD,
                                                                  Some(OLD_VALUE), NEW_VALUE,
-                                                                 &_role_constraint_json()).unwrap();
+                                                                 &_role_constraint_json(), TEST_PROTOCOL_VERSION).unwrap();
             check_request(&request, expected_result);
         }
 
@@ -1097,7 +2223,7 @@ mod tests {
 
             let res = ledger_service.build_auth_rule_request(IDENTIFIER, NYM, EDIT_AUTH_ACTION, FIELD,
                                                              None, NEW_VALUE,
-                                                             &_role_constraint_json());
+                                                             &_role_constraint_json(), TEST_PROTOCOL_VERSION);
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
 
@@ -1107,7 +2233,7 @@ mod tests {
 
             let res = ledger_service.build_auth_rule_request(IDENTIFIER, "WRONG", ADD_AUTH_ACTION, FIELD,
                                                              None, NEW_VALUE,
-                                                             &_role_constraint_json());
+                                                             &_role_constraint_json(), TEST_PROTOCOL_VERSION);
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
 
@@ -1115,7 +2241,7 @@ mod tests {
         fn build_auth_rule_request_works_for_invalid_auth_action() {
             let ledger_service = LedgerService::new();
 
-            let res = ledger_service.build_auth_rule_request(IDENTIFIER, NYM, "WRONG", FIELD, None, NEW_VALUE, &_role_constraint_json());
+            let res = ledger_service.build_auth_rule_request(IDENTIFIER, NYM, "WRONG", FIELD, None, NEW_VALUE, &_role_constraint_json(), TEST_PROTOCOL_VERSION);
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
 
@@ -1134,7 +2260,7 @@ mod tests {
 
             let request = ledger_service.build_get_auth_rule_request(Some(IDENTIFIER), Some(NYM),
                                                                      Some(ADD_AUTH_ACTION), Some(FIELD),
-                                                                     Some(OLD_VALUE), Some(NEW_VALUE)).unwrap();
+                                                                     Some(OLD_VALUE), Some(NEW_VALUE), TEST_PROTOCOL_VERSION).unwrap();
             check_request(&request, expected_result);
         }
 
@@ -1148,7 +2274,7 @@ mod tests {
 
             let request = ledger_service.build_get_auth_rule_request(Some(IDENTIFIER), None,
                                                                      None, None,
-                                                                     None, None).unwrap();
+                                                                     None, None, TEST_PROTOCOL_VERSION).unwrap();
             check_request(&request, expected_result);
         }
 
@@ -1158,7 +2284,7 @@ mod tests {
 
             let res = ledger_service.build_get_auth_rule_request(Some(IDENTIFIER), Some(NYM),
                                                                  None, Some(FIELD),
-                                                                 None, None);
+                                                                 None, None, TEST_PROTOCOL_VERSION);
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
 
@@ -1166,7 +2292,7 @@ mod tests {
         fn build_get_auth_rule_request_works_for_invalid_auth_action() {
             let ledger_service = LedgerService::new();
 
-            let res = ledger_service.build_get_auth_rule_request(Some(IDENTIFIER), None, Some("WRONG"), None, None, None);
+            let res = ledger_service.build_get_auth_rule_request(Some(IDENTIFIER), None, Some("WRONG"), None, None, None, TEST_PROTOCOL_VERSION);
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
 
@@ -1174,9 +2300,272 @@ mod tests {
         fn build_get_auth_rule_request_works_for_invalid_auth_type() {
             let ledger_service = LedgerService::new();
 
-            let res = ledger_service.build_get_auth_rule_request(Some(IDENTIFIER), Some("WRONG"), None, None, None, None);
+            let res = ledger_service.build_get_auth_rule_request(Some(IDENTIFIER), Some("WRONG"), None, None, None, None, TEST_PROTOCOL_VERSION);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
+    mod signing_bundle {
+        use super::*;
+
+        fn _signers() -> HashMap<String, SignerInfo> {
+            let mut signers = HashMap::new();
+            signers.insert(IDENTIFIER.to_string(), SignerInfo { verkey: VERKEY.to_string(), role: Some("TRUSTEE".to_string()) });
+            signers.insert(DEST.to_string(), SignerInfo { verkey: VERKEY.to_string(), role: Some(TRUSTEE.to_string()) });
+            signers
+        }
+
+        fn _trustees_constraint(sig_count: u32) -> Constraint {
+            Constraint::RoleConstraint(RoleConstraint {
+                sig_count,
+                metadata: None,
+                role: TRUSTEE.to_string(),
+                need_to_be_owner: None,
+            })
+        }
+
+        fn _bundle(constraint: Option<&Constraint>) -> SigningBundle {
+            let ledger_service = LedgerService::new();
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION).unwrap();
+            let bundle = ledger_service.build_signing_bundle(&request, &_signers(), constraint).unwrap();
+            serde_json::from_str(&bundle).unwrap()
+        }
+
+        #[test]
+        fn build_signing_bundle_works() {
+            let bundle = _bundle(None);
+
+            assert_eq!(serialize_signature(bundle.request.clone()).unwrap(), bundle.signing_input);
+            assert_eq!(Some(TRUSTEE.to_string()), bundle.signers[IDENTIFIER].role);
+            assert!(bundle.signatures.is_empty());
+        }
+
+        #[test]
+        fn build_signing_bundle_works_for_signed_request() {
+            let ledger_service = LedgerService::new();
+
+            let mut request: serde_json::Value = serde_json::from_str(&ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION).unwrap()).unwrap();
+            request["signature"] = json!("signature");
+
+            let res = ledger_service.build_signing_bundle(&request.to_string(), &_signers(), None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_signing_bundle_works_for_unsatisfiable_constraint() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None, TEST_PROTOCOL_VERSION).unwrap();
+            let res = ledger_service.build_signing_bundle(&request, &_signers(), Some(&_trustees_constraint(3)));
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn merge_signing_bundles_works() {
+            let ledger_service = LedgerService::new();
+
+            let bundle = _bundle(None);
+            let mut bundle_1 = bundle.clone();
+            bundle_1.signatures.insert(IDENTIFIER.to_string(), "signature_1".to_string());
+            let mut bundle_2 = bundle.clone();
+            bundle_2.signatures.insert(DEST.to_string(), "signature_2".to_string());
+
+            let bundle = ledger_service.merge_signing_bundles(vec![bundle_1, bundle_2]).unwrap();
+            let bundle: SigningBundle = serde_json::from_str(&bundle).unwrap();
+            assert_eq!(2, bundle.signatures.len());
+        }
+
+        #[test]
+        fn merge_signing_bundles_works_for_different_requests() {
+            let ledger_service = LedgerService::new();
+
+            let bundle_1 = _bundle(None);
+            let mut bundle_2 = bundle_1.clone();
+            bundle_2.request["operation"]["dest"] = json!(IDENTIFIER);
+            bundle_2.signing_input = serialize_signature(bundle_2.request.clone()).unwrap();
+
+            let res = ledger_service.merge_signing_bundles(vec![bundle_1, bundle_2]);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn merge_signing_bundles_works_for_conflicting_signatures() {
+            let ledger_service = LedgerService::new();
+
+            let bundle = _bundle(None);
+            let mut bundle_1 = bundle.clone();
+            bundle_1.signatures.insert(IDENTIFIER.to_string(), "signature_1".to_string());
+            let mut bundle_2 = bundle.clone();
+            bundle_2.signatures.insert(IDENTIFIER.to_string(), "signature_2".to_string());
+
+            let res = ledger_service.merge_signing_bundles(vec![bundle_1, bundle_2]);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn check_signing_bundle_input_works_for_changed_request() {
+            let ledger_service = LedgerService::new();
+
+            let mut bundle = _bundle(None);
+            bundle.request["operation"]["dest"] = json!(IDENTIFIER);
+
+            assert_kind!(IndyErrorKind::InvalidStructure, ledger_service.check_signing_bundle_input(&bundle));
+        }
+
+        #[test]
+        fn get_signing_bundle_status_works() {
+            let ledger_service = LedgerService::new();
+
+            let mut bundle = _bundle(None);
+            bundle.signatures.insert(IDENTIFIER.to_string(), "signature_1".to_string());
+
+            let status = ledger_service.get_signing_bundle_status(&bundle).unwrap();
+            assert!(!status.complete);
+            assert_eq!(vec![DEST.to_string()], status.unsigned);
+            assert!(status.request.is_none());
+
+            bundle.signatures.insert(DEST.to_string(), "signature_2".to_string());
+
+            let status = ledger_service.get_signing_bundle_status(&bundle).unwrap();
+            assert!(status.complete);
+            assert_eq!(json!("signature_2"), status.request.unwrap()["signatures"][DEST]);
+        }
+
+        #[test]
+        fn get_signing_bundle_status_works_for_constraint() {
+            let ledger_service = LedgerService::new();
+
+            let mut bundle = _bundle(Some(&_trustees_constraint(1)));
+            bundle.signatures.insert(DEST.to_string(), "signature_2".to_string());
+
+            let status = ledger_service.get_signing_bundle_status(&bundle).unwrap();
+            assert!(status.complete);
+            assert_eq!(vec![IDENTIFIER.to_string()], status.unsigned);
+        }
+    }
+
+    mod custom_txn {
+        use super::*;
+
+        // Registry is global so every test uses its own transaction type
+        fn _register(name: &str, code: &str, kind: CustomTxnKind) {
+            let txn_type = serde_json::from_value(json!({
+                "name": name,
+                "code": code,
+                "kind": kind,
+                "schema": {
+                    "data": {"type": "string"},
+                    "amount": {"type": "number", "optional": true}
+                }
+            })).unwrap();
+
+            LedgerService::register_custom_txn_type(txn_type).unwrap();
+        }
+
+        #[test]
+        fn register_custom_txn_type_works() {
+            _register("TEST_REGISTER", "20001", CustomTxnKind::Write);
+
+            assert_eq!("20001", LedgerService::get_custom_txn_type("TEST_REGISTER").unwrap().code);
+            assert_eq!("TEST_REGISTER", LedgerService::get_custom_txn_type("20001").unwrap().name);
+        }
+
+        #[test]
+        fn register_custom_txn_type_works_for_default_type() {
+            let txn_type = serde_json::from_value(json!({"name": "NYM", "code": "20002", "kind": "write", "schema": null})).unwrap();
+
+            let res = LedgerService::register_custom_txn_type(txn_type);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn register_custom_txn_type_works_for_used_code() {
+            _register("TEST_USED_CODE", "20003", CustomTxnKind::Write);
+
+            let txn_type = serde_json::from_value(json!({"name": "TEST_USED_CODE_2", "code": "20003", "kind": "read", "schema": null})).unwrap();
+
+            let res = LedgerService::register_custom_txn_type(txn_type);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn register_custom_txn_type_works_for_used_name() {
+            _register("TEST_USED_NAME", "20008", CustomTxnKind::Write);
+
+            let txn_type = serde_json::from_value(json!({"name": "TEST_USED_NAME", "code": "20009", "kind": "write", "schema": null})).unwrap();
+
+            let res = LedgerService::register_custom_txn_type(txn_type);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+            assert_eq!("20008", LedgerService::get_custom_txn_type("TEST_USED_NAME").unwrap().code);
+            assert!(LedgerService::get_custom_txn_type("20009").is_none());
+        }
+
+        #[test]
+        fn register_custom_txn_type_works_for_same_name_and_code() {
+            _register("TEST_SAME_TYPE", "20010", CustomTxnKind::Write);
+            _register("TEST_SAME_TYPE", "20010", CustomTxnKind::Read);
+
+            assert_eq!(CustomTxnKind::Read, LedgerService::get_custom_txn_type("TEST_SAME_TYPE").unwrap().kind);
+        }
+
+        #[test]
+        fn build_custom_request_works() {
+            let ledger_service = LedgerService::new();
+
+            _register("TEST_BUILD", "20004", CustomTxnKind::Write);
+
+            let expected_result = json!({
+                "type": "20004",
+                "data": "some data",
+                "amount": 10
+            });
+
+            let request = ledger_service.build_custom_request(Some(IDENTIFIER), "TEST_BUILD", r#"{"data": "some data", "amount": 10}"#, TEST_PROTOCOL_VERSION).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_custom_request_works_for_unknown_type() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_custom_request(Some(IDENTIFIER), "TEST_UNKNOWN", r#"{"data": "some data"}"#, TEST_PROTOCOL_VERSION);
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
+
+        #[test]
+        fn build_custom_request_works_for_invalid_operation() {
+            let ledger_service = LedgerService::new();
+
+            _register("TEST_INVALID_OPERATION", "20005", CustomTxnKind::Read);
+
+            for operation in &[r#"{"amount": 10}"#, r#"{"data": 1}"#, r#"{"data": "some data", "other": 1}"#, r#"{"type": "1", "data": "some data"}"#] {
+                let res = ledger_service.build_custom_request(None, "TEST_INVALID_OPERATION", operation, TEST_PROTOCOL_VERSION);
+                assert_kind!(IndyErrorKind::InvalidStructure, res);
+            }
+        }
+
+        #[test]
+        fn build_custom_request_works_for_write_without_submitter() {
+            let ledger_service = LedgerService::new();
+
+            _register("TEST_NO_SUBMITTER", "20006", CustomTxnKind::Write);
+
+            let res = ledger_service.build_custom_request(None, "TEST_NO_SUBMITTER", r#"{"data": "some data"}"#, TEST_PROTOCOL_VERSION);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_auth_rule_request_works_for_custom_txn_type() {
+            let ledger_service = LedgerService::new();
+
+            _register("TEST_AUTH_RULE", "20007", CustomTxnKind::Write);
+
+            let constraint = json!({"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false, "metadata": {}});
+
+            let request = ledger_service.build_auth_rule_request(IDENTIFIER, "TEST_AUTH_RULE", "ADD", "*", None, "*", &constraint.to_string(), TEST_PROTOCOL_VERSION).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            assert_eq!(json!("20007"), request["operation"]["auth_type"]);
+        }
     }
 
     fn check_request(request: &str, expected_result: serde_json::Value) {
@@ -10,6 +10,12 @@ use services::pool::merkle_tree_factory;
 use services::pool::rust_base58::{FromBase58, ToBase58};
 use services::pool::types::{CatchupReq, Message};
 
+pub const POOL_LEDGER_ID: usize = 0;
+
+/// Max number of transactions requested from a node by single CatchupReq.
+/// Bigger ledgers are caught up by several consecutive requests.
+pub const CATCHUP_BATCH_SIZE: usize = 1000;
+
 pub enum CatchupProgress {
     ShouldBeStarted(
         Vec<u8>, //target_mt_root
@@ -21,18 +27,16 @@ pub enum CatchupProgress {
     InProgress,
 }
 
-pub fn build_catchup_req(merkle: &MerkleTree, target_mt_size: usize) -> IndyResult<Option<(String, String)>> {
-    let txns_cnt = target_mt_size - merkle.count();
-
-    if txns_cnt <= 0 {
+pub fn build_catchup_req(merkle: &MerkleTree, target_mt_size: usize, ledger_id: usize) -> IndyResult<Option<(String, String)>> {
+    if target_mt_size <= merkle.count() {
         warn!("No transactions to catch up!");
         return Ok(None);
     }
     let seq_no_start = merkle.count() + 1;
-    let seq_no_end = target_mt_size;
+    let seq_no_end = ::std::cmp::min(target_mt_size, merkle.count() + CATCHUP_BATCH_SIZE);
 
     let cr = CatchupReq {
-        ledgerId: 0,
+        ledgerId: ledger_id,
         seqNoStart: seq_no_start.clone(),
         seqNoEnd: seq_no_end.clone(),
         catchupTill: target_mt_size,
@@ -51,7 +55,8 @@ pub fn check_nodes_responses_on_status(nodes_votes: &HashMap<(String, usize, Opt
                                        merkle_tree: &MerkleTree,
                                        node_cnt: usize,
                                        f: usize,
-                                       pool_name: &str) -> IndyResult<CatchupProgress> {
+                                       pool_name: &str,
+                                       ledger_id: usize) -> IndyResult<CatchupProgress> {
     let (votes, timeout_votes): (HashMap<&(String, usize, Option<Vec<String>>), usize>, HashMap<&(String, usize, Option<Vec<String>>), usize>) =
         nodes_votes
             .iter()
@@ -68,7 +73,7 @@ pub fn check_nodes_responses_on_status(nodes_votes: &HashMap<(String, usize, Opt
     if let Some((most_popular_not_timeout_vote, votes_cnt)) = most_popular_not_timeout {
         if *votes_cnt == f + 1 {
             return _try_to_catch_up(most_popular_not_timeout_vote, merkle_tree).or_else(|err| {
-                if merkle_tree_factory::drop_cache(pool_name).is_ok() {
+                if ledger_id == POOL_LEDGER_ID && merkle_tree_factory::drop_cache(pool_name).is_ok() {
                     let merkle_tree = merkle_tree_factory::create(pool_name)?;
                     _try_to_catch_up(most_popular_not_timeout_vote, &merkle_tree)
                 } else {
@@ -76,13 +81,13 @@ pub fn check_nodes_responses_on_status(nodes_votes: &HashMap<(String, usize, Opt
                 }
             });
         } else {
-            return _if_consensus_reachable(nodes_votes, node_cnt, *votes_cnt, f, pool_name);
+            return _if_consensus_reachable(nodes_votes, node_cnt, *votes_cnt, f, pool_name, ledger_id);
         }
     } else if let Some((_, votes_cnt)) = timeout_votes {
         if *votes_cnt == node_cnt - f {
-            return _try_to_restart_catch_up(pool_name, err_msg(IndyErrorKind::PoolTimeout, "Pool timeout"));
+            return _try_to_restart_catch_up(pool_name, ledger_id, err_msg(IndyErrorKind::PoolTimeout, "Pool timeout"));
         } else {
-            return _if_consensus_reachable(nodes_votes, node_cnt, *votes_cnt, f, pool_name);
+            return _if_consensus_reachable(nodes_votes, node_cnt, *votes_cnt, f, pool_name, ledger_id);
         }
     }
     Ok(CatchupProgress::InProgress)
@@ -92,21 +97,23 @@ fn _if_consensus_reachable(nodes_votes: &HashMap<(String, usize, Option<Vec<Stri
                            node_cnt: usize,
                            votes_cnt: usize,
                            f: usize,
-                           pool_name: &str) -> IndyResult<CatchupProgress> {
+                           pool_name: &str,
+                           ledger_id: usize) -> IndyResult<CatchupProgress> {
     let reps_cnt: usize = nodes_votes.values().map(HashSet::len).sum();
     let positive_votes_cnt = votes_cnt + (node_cnt - reps_cnt);
     let is_consensus_not_reachable = positive_votes_cnt < node_cnt - f;
     if is_consensus_not_reachable {
         //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
-        _try_to_restart_catch_up(pool_name, err_msg(IndyErrorKind::PoolTimeout, "No consensus possible"))
+        _try_to_restart_catch_up(pool_name, ledger_id, err_msg(IndyErrorKind::PoolTimeout, "No consensus possible"))
     } else {
         Ok(CatchupProgress::InProgress)
     }
 }
 
 
-fn _try_to_restart_catch_up(pool_name: &str, err: IndyError) -> IndyResult<CatchupProgress> {
-    if merkle_tree_factory::drop_cache(pool_name).is_ok() {
+fn _try_to_restart_catch_up(pool_name: &str, ledger_id: usize, err: IndyError) -> IndyResult<CatchupProgress> {
+    // only pool ledger can be restarted from genesis, other ledgers are caught up into the mirror
+    if ledger_id == POOL_LEDGER_ID && merkle_tree_factory::drop_cache(pool_name).is_ok() {
         let merkle_tree = merkle_tree_factory::create(pool_name)?;
         return Ok(CatchupProgress::Restart(merkle_tree));
     } else {
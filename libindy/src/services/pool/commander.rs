@@ -39,6 +39,9 @@ impl Commander {
             Some(PoolEvent::Refresh(id))
         } else if "connect".eq(cmd_s.as_str()) {
             Some(PoolEvent::CheckCache(id))
+        } else if "sync_ledger".eq(cmd_s.as_str()) {
+            let ledger_id = cmd_parts.get(2).map(|ledger_id: &Vec<u8>| LittleEndian::read_u32(ledger_id.as_slice()))?;
+            Some(PoolEvent::SyncLedger(id, ledger_id as usize))
         } else {
             let timeout = LittleEndian::read_i32(cmd_parts[2].as_slice());
             let timeout = if timeout == -1 { None } else { Some(timeout) };
@@ -144,6 +147,22 @@ mod commander_tests {
         assert_match!(Some(PoolEvent::CheckCache(cmd_id_)), cmd.fetch_events(), cmd_id_, cmd_id);
     }
 
+    #[test]
+    pub fn commander_fetch_sync_ledger_event_works() {
+        let (send_cmd_sock, recv_cmd_sock) = _create_pair_of_sockets("sync_ledger");
+
+        let cmd = Commander::new(recv_cmd_sock);
+
+        let cmd_id: i32 = sequence::get_next_id();
+        let mut buf = [0u8; 4];
+        LittleEndian::write_i32(&mut buf, cmd_id);
+        let mut buf_ledger_id = [0u8; 4];
+        LittleEndian::write_u32(&mut buf_ledger_id, 1);
+        let msg = "sync_ledger";
+        send_cmd_sock.send_multipart(&[msg.as_bytes(), &buf, &buf_ledger_id], zmq::DONTWAIT).expect("FIXME");
+        assert_match!(Some(PoolEvent::SyncLedger(cmd_id_, 1)), cmd.fetch_events(), cmd_id_, cmd_id);
+    }
+
     #[test]
     pub fn commander_fetch_send_request_event_works() {
         let (send_cmd_sock, recv_cmd_sock) = _create_pair_of_sockets("send_request");
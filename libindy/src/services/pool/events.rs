@@ -2,12 +2,15 @@ use serde_json;
 use serde_json::Value as SJsonValue;
 
 use domain::ledger::constants;
+use domain::ledger::custom_txn::CustomTxnKind;
 use errors::prelude::*;
+use services::ledger::LedgerService;
 use services::ledger::merkletree::merkletree::MerkleTree;
 use services::pool::{PoolService, types::*};
 
-pub const REQUESTS_FOR_STATE_PROOFS: [&'static str; 7] = [
+pub const REQUESTS_FOR_STATE_PROOFS: [&'static str; 8] = [
     constants::GET_NYM,
+    constants::GET_TXN,
     constants::GET_SCHEMA,
     constants::GET_CRED_DEF,
     constants::GET_ATTR,
@@ -64,6 +67,10 @@ pub enum PoolEvent {
     Refresh(
         i32, //cmd_id
     ),
+    SyncLedger(
+        i32, //cmd_id
+        usize, //ledger_id
+    ),
     CatchupTargetFound(
         Vec<u8>, //target_mt_root
         usize, //target_mt_size
@@ -103,6 +110,7 @@ pub enum RequestEvent {
         MerkleTree,
         usize, // target mt size
         Vec<u8>, // target mt root
+        usize, // ledger id
     ),
     Timeout(
         String, //req_id
@@ -173,6 +181,15 @@ impl RequestEvent {
             _ => "".to_string()
         }
     }
+
+    pub fn get_ledger_id(&self) -> Option<usize> {
+        match self {
+            &RequestEvent::LedgerStatus(ref ls, _, _) => Some(ls.ledgerId),
+            &RequestEvent::ConsistencyProof(ref cp, _) => Some(cp.ledgerId),
+            &RequestEvent::CatchupRep(ref cr, _) => Some(cr.ledgerId),
+            _ => None
+        }
+    }
 }
 
 impl Into<Option<RequestEvent>> for PoolEvent {
@@ -183,7 +200,7 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                     match parsed {
                         //TODO change mapping for CatchupReq. May be return None
                         //TODO: REMOVE UNWRAP!!!!!
-                        Message::CatchupReq(_) => RequestEvent::CatchupReq(MerkleTree::from_vec(Vec::new()).unwrap(), 0, vec![]),
+                        Message::CatchupReq(_) => RequestEvent::CatchupReq(MerkleTree::from_vec(Vec::new()).unwrap(), 0, vec![], 0),
                         Message::CatchupRep(rep) => RequestEvent::CatchupRep(rep, node_alias),
                         Message::LedgerStatus(ls) => RequestEvent::LedgerStatus(ls, Some(node_alias), None),
                         Message::ConsistencyProof(cp) => RequestEvent::ConsistencyProof(cp, node_alias),
@@ -217,8 +234,7 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                         error!("Timeout {:?} or nodes {:?} is specified for non-supported request operation type {}",
                                timeout, nodes, op);
                         None
-                    } else if REQUESTS_FOR_STATE_PROOFS.contains(&op.as_str())
-                        || PoolService::get_sp_parser(&op.as_str()).is_some() {
+                    } else if REQUESTS_FOR_STATE_PROOFS.contains(&op.as_str()) || _is_plugged_read(&op) {
                         Some(RequestEvent::CustomSingleRequest(msg, req_id.clone()))
                     } else {
                         Some(RequestEvent::CustomConsensusRequest(msg, req_id.clone()))
@@ -234,6 +250,13 @@ impl Into<Option<RequestEvent>> for PoolEvent {
     }
 }
 
+// Reply to a request with plugged state proof parser can be verified by a single node
+// unless the plugin has declared the transaction type as write one
+fn _is_plugged_read(op: &str) -> bool {
+    PoolService::get_sp_parser(op).is_some() &&
+        LedgerService::get_custom_txn_type(op).map(|txn_type| txn_type.kind == CustomTxnKind::Read).unwrap_or(true)
+}
+
 fn _parse_msg(msg: &str) -> Option<Message> {
     Message::from_raw_str(msg).map_err(map_err_trace!()).ok()
 }
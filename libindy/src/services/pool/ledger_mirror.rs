@@ -0,0 +1,287 @@
+extern crate rmp_serde;
+
+use rusqlite;
+use rusqlite::types::ToSql;
+use serde_json::Value as SJsonValue;
+
+use domain::pool::LedgerMirrorQuery;
+use errors::prelude::*;
+use services::ledger::merkletree::merkletree::MerkleTree;
+use utils::environment;
+
+const _MIRROR_DB: &str = "mirror.db";
+const _CREATE_SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS txns(
+        ledger_id INTEGER NOT NULL,
+        seq_no INTEGER NOT NULL,
+        type TEXT,
+        author TEXT,
+        dest TEXT,
+        txn_time INTEGER,
+        data BLOB NOT NULL,
+        PRIMARY KEY(ledger_id, seq_no)
+    );
+
+    CREATE INDEX IF NOT EXISTS ix_txns_type ON txns(ledger_id, type);
+    CREATE INDEX IF NOT EXISTS ix_txns_author ON txns(ledger_id, author);
+    CREATE INDEX IF NOT EXISTS ix_txns_dest ON txns(ledger_id, dest);
+";
+
+/// Local copy of a ledger, stored in the pool directory next to the pool ledger cache.
+///
+/// Transactions are kept in the same msgpack form that is hashed into the ledger merkle tree,
+/// so the tree can be restored from the mirror to continue catchup from the last stored txn.
+pub struct LedgerMirror {
+    conn: rusqlite::Connection,
+    ledger_id: i64,
+}
+
+impl LedgerMirror {
+    pub fn open(pool_name: &str, ledger_id: usize) -> IndyResult<LedgerMirror> {
+        let mut path = environment::pool_path(pool_name);
+
+        if !path.exists() {
+            return Err(err_msg(IndyErrorKind::PoolNotCreated, format!("Pool is not created for name: {:?}", pool_name)));
+        }
+
+        path.push(_MIRROR_DB);
+
+        let conn = rusqlite::Connection::open(path.as_path())?;
+        conn.execute_batch(_CREATE_SCHEMA)?;
+
+        Ok(LedgerMirror { conn, ledger_id: ledger_id as i64 })
+    }
+
+    pub fn count(&self) -> IndyResult<usize> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM txns WHERE ledger_id = ?1",
+            &[&self.ledger_id],
+            |row| row.get(0))?;
+
+        Ok(count as usize)
+    }
+
+    /// Restores the ledger merkle tree from stored txns. The tree is pruned as txns are appended,
+    /// so only hashes of its complete subtrees are kept in memory.
+    pub fn merkle_tree(&self) -> IndyResult<MerkleTree> {
+        let mut mt = MerkleTree::from_vec(Vec::new())?;
+
+        let mut stmt = self.conn.prepare("SELECT seq_no, data FROM txns WHERE ledger_id = ?1 ORDER BY seq_no")?;
+        let rows = stmt.query_map(&[&self.ledger_id], |row| -> (i64, Vec<u8>) { (row.get(0), row.get(1)) })?;
+
+        for row in rows {
+            let (seq_no, data) = row?;
+
+            if seq_no as usize != mt.count() + 1 {
+                return Err(err_msg(IndyErrorKind::InvalidState, format!("Ledger mirror is broken: txn {} is missed", mt.count() + 1)));
+            }
+
+            mt.append(data)?;
+            mt.prune()?;
+        }
+
+        Ok(mt)
+    }
+
+    pub fn add_txns(&mut self, first_seq_no: usize, txns: &Vec<Vec<u8>>) -> IndyResult<()> {
+        let tx = self.conn.transaction()?;
+
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT OR REPLACE INTO txns (ledger_id, seq_no, type, author, dest, txn_time, data) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)")?;
+
+            for (i, txn) in txns.iter().enumerate() {
+                let txn_json: SJsonValue = rmp_serde::from_slice(txn)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Invalid transaction -- can not transform from bytes")?;
+
+                let seq_no = (first_seq_no + i) as i64;
+                let (type_, author, dest, txn_time) = _index_fields(&txn_json);
+
+                stmt.execute(&[&self.ledger_id, &seq_no, &type_, &author, &dest, &txn_time, txn])?;
+            }
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub fn search(&self, query: &LedgerMirrorQuery) -> IndyResult<Vec<SJsonValue>> {
+        let from = query.from.map(|seq_no| seq_no as i64);
+        let to = query.to.map(|seq_no| seq_no as i64);
+        let limit = query.limit.map(|limit| limit as i64).unwrap_or(-1);
+
+        let mut sql = "SELECT data FROM txns WHERE ledger_id = ?".to_string();
+        let mut args: Vec<&ToSql> = vec![&self.ledger_id];
+
+        if let Some(ref type_) = query.type_ {
+            sql.push_str(" AND type = ?");
+            args.push(type_);
+        }
+        if let Some(ref author) = query.author {
+            sql.push_str(" AND author = ?");
+            args.push(author);
+        }
+        if let Some(ref dest) = query.dest {
+            sql.push_str(" AND dest = ?");
+            args.push(dest);
+        }
+        if let Some(ref from) = from {
+            sql.push_str(" AND seq_no >= ?");
+            args.push(from);
+        }
+        if let Some(ref to) = to {
+            sql.push_str(" AND seq_no <= ?");
+            args.push(to);
+        }
+        sql.push_str(" ORDER BY seq_no LIMIT ?");
+        args.push(&limit);
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(&args, |row| -> Vec<u8> { row.get(0) })?;
+
+        let mut txns = Vec::new();
+        for row in rows {
+            let txn = rmp_serde::from_slice(&row?)
+                .to_indy(IndyErrorKind::InvalidState, "Ledger mirror contains malformed transaction")?;
+            txns.push(txn);
+        }
+
+        Ok(txns)
+    }
+}
+
+// Supports both txn formats: the old plain one and the new one with `txn` and `txnMetadata` parts.
+fn _index_fields(txn: &SJsonValue) -> (Option<String>, Option<String>, Option<String>, Option<i64>) {
+    if txn["txn"].is_object() {
+        (_as_string(&txn["txn"]["type"]),
+         _as_string(&txn["txn"]["metadata"]["from"]),
+         _as_string(&txn["txn"]["data"]["dest"]),
+         txn["txnMetadata"]["txnTime"].as_i64())
+    } else {
+        (_as_string(&txn["type"]),
+         _as_string(&txn["identifier"]),
+         _as_string(&txn["dest"]),
+         txn["txnTime"].as_i64())
+    }
+}
+
+fn _as_string(value: &SJsonValue) -> Option<String> {
+    match value {
+        &SJsonValue::String(ref s) => Some(s.clone()),
+        &SJsonValue::Number(ref n) => Some(n.to_string()),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use utils::test;
+
+    use super::*;
+
+    const POOL: &'static str = "ledger_mirror_pool";
+
+    fn _mirror() -> LedgerMirror {
+        test::cleanup_indy_home();
+        fs::create_dir_all(environment::pool_path(POOL)).unwrap();
+        LedgerMirror::open(POOL, 1).unwrap()
+    }
+
+    fn _txn(seq_no: u64, type_: &str, from: &str, dest: &str) -> Vec<u8> {
+        let txn = json!({
+            "txn": {
+                "type": type_,
+                "metadata": {"from": from},
+                "data": {"dest": dest}
+            },
+            "txnMetadata": {"seqNo": seq_no, "txnTime": 1550000000 + seq_no},
+            "ver": "1"
+        });
+        rmp_serde::to_vec_named(&txn).unwrap()
+    }
+
+    fn _txns() -> Vec<Vec<u8>> {
+        vec![
+            _txn(1, "1", "V4SGRU86Z58d6TV7PBUe6f", "7qf2nMnqh7wPaJVRDazaEB"),
+            _txn(2, "1", "V4SGRU86Z58d6TV7PBUe6f", "GJ1SzoWzavQYfNL9XkaJdr"),
+            _txn(3, "101", "7qf2nMnqh7wPaJVRDazaEB", "7qf2nMnqh7wPaJVRDazaEB"),
+        ]
+    }
+
+    #[test]
+    fn ledger_mirror_open_works_for_not_created_pool() {
+        test::cleanup_indy_home();
+        assert_kind!(IndyErrorKind::PoolNotCreated, LedgerMirror::open(POOL, 1));
+    }
+
+    #[test]
+    fn ledger_mirror_add_txns_works() {
+        let mut mirror = _mirror();
+        mirror.add_txns(1, &_txns()).unwrap();
+        assert_eq!(3, mirror.count().unwrap());
+        assert_eq!(0, LedgerMirror::open(POOL, 2).unwrap().count().unwrap());
+        test::cleanup_indy_home();
+    }
+
+    #[test]
+    fn ledger_mirror_merkle_tree_works() {
+        let mut mirror = _mirror();
+        mirror.add_txns(1, &_txns()).unwrap();
+
+        let expected = MerkleTree::from_vec(_txns()).unwrap();
+        let mt = mirror.merkle_tree().unwrap();
+        assert_eq!(expected.count(), mt.count());
+        assert_eq!(expected.root_hash(), mt.root_hash());
+        assert_eq!(0, mt.iter().count());
+        test::cleanup_indy_home();
+    }
+
+    #[test]
+    fn ledger_mirror_merkle_tree_works_for_gap() {
+        let mut mirror = _mirror();
+        mirror.add_txns(2, &_txns()).unwrap();
+        assert_kind!(IndyErrorKind::InvalidState, mirror.merkle_tree());
+        test::cleanup_indy_home();
+    }
+
+    #[test]
+    fn ledger_mirror_search_works() {
+        let mut mirror = _mirror();
+        mirror.add_txns(1, &_txns()).unwrap();
+
+        let txns = mirror.search(&LedgerMirrorQuery::default()).unwrap();
+        assert_eq!(3, txns.len());
+        assert_eq!(1, txns[0]["txnMetadata"]["seqNo"].as_u64().unwrap());
+
+        let query = LedgerMirrorQuery { type_: Some("1".to_string()), ..LedgerMirrorQuery::default() };
+        assert_eq!(2, mirror.search(&query).unwrap().len());
+
+        let query = LedgerMirrorQuery { author: Some("7qf2nMnqh7wPaJVRDazaEB".to_string()), ..LedgerMirrorQuery::default() };
+        assert_eq!(1, mirror.search(&query).unwrap().len());
+
+        let query = LedgerMirrorQuery { dest: Some("7qf2nMnqh7wPaJVRDazaEB".to_string()), ..LedgerMirrorQuery::default() };
+        assert_eq!(2, mirror.search(&query).unwrap().len());
+
+        let query = LedgerMirrorQuery { from: Some(2), limit: Some(1), ..LedgerMirrorQuery::default() };
+        let txns = mirror.search(&query).unwrap();
+        assert_eq!(1, txns.len());
+        assert_eq!(2, txns[0]["txnMetadata"]["seqNo"].as_u64().unwrap());
+
+        let query = LedgerMirrorQuery { to: Some(1), ..LedgerMirrorQuery::default() };
+        assert_eq!(1, mirror.search(&query).unwrap().len());
+        test::cleanup_indy_home();
+    }
+
+    #[test]
+    fn ledger_mirror_search_works_for_old_txn_format() {
+        let mut mirror = _mirror();
+        let txn = json!({"type": "1", "identifier": "V4SGRU86Z58d6TV7PBUe6f", "dest": "7qf2nMnqh7wPaJVRDazaEB", "seqNo": 1});
+        mirror.add_txns(1, &vec![rmp_serde::to_vec_named(&txn).unwrap()]).unwrap();
+
+        let query = LedgerMirrorQuery { author: Some("V4SGRU86Z58d6TV7PBUe6f".to_string()), ..LedgerMirrorQuery::default() };
+        assert_eq!(vec![txn], mirror.search(&query).unwrap());
+        test::cleanup_indy_home();
+    }
+}
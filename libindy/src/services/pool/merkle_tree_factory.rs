@@ -9,7 +9,6 @@ use std::path::PathBuf;
 use serde_json;
 use serde_json::Value as SJsonValue;
 
-use domain::ledger::request::ProtocolVersion;
 use errors::prelude::*;
 use services::ledger::merkletree::merkletree::MerkleTree;
 use services::pool::types::{NodeTransaction, NodeTransactionV0, NodeTransactionV1};
@@ -184,21 +183,37 @@ fn _parse_txn_from_json(txn: &str) -> IndyResult<Vec<u8>> {
         .to_indy(IndyErrorKind::InvalidState, "Can't encode genesis txn as message pack")
 }
 
-pub fn build_node_state(merkle_tree: &MerkleTree) -> IndyResult<HashMap<String, NodeTransactionV1>> {
+/// Pools of PROTOCOL_VERSION 1 use the old transaction format.
+pub fn get_protocol_version(merkle_tree: &MerkleTree) -> IndyResult<Option<usize>> {
+    let txn = match merkle_tree.into_iter().next() {
+        Some(txn) => txn,
+        None => return Ok(None)
+    };
+
+    let txn: NodeTransaction = rmp_serde::decode::from_slice(txn.as_slice())
+        .to_indy(IndyErrorKind::InvalidState, "MerkleTree contains invalid item")?;
+
+    let protocol_version = match txn {
+        NodeTransaction::NodeTransactionV0(_) => 1,
+        NodeTransaction::NodeTransactionV1(_) => 2
+    };
+
+    Ok(Some(protocol_version))
+}
+
+pub fn build_node_state(merkle_tree: &MerkleTree, protocol_version: usize) -> IndyResult<HashMap<String, NodeTransactionV1>> {
     let mut gen_tnxs: HashMap<String, NodeTransactionV1> = HashMap::new();
 
     for gen_txn in merkle_tree {
         let gen_txn: NodeTransaction = rmp_serde::decode::from_slice(gen_txn.as_slice())
             .to_indy(IndyErrorKind::InvalidState, "MerkleTree contains invalid item")?;
 
-        let protocol_version = ProtocolVersion::get();
-
         let mut gen_txn = match gen_txn {
             NodeTransaction::NodeTransactionV0(txn) => {
                 if protocol_version != 1 {
                     Err(err_msg(IndyErrorKind::PoolIncompatibleProtocolVersion,
-                                format!("Libindy PROTOCOL_VERSION is {} but Pool Genesis Transactions are of version {}.\
-                                         Call indy_set_protocol_version(1) to set correct PROTOCOL_VERSION",
+                                format!("Pool PROTOCOL_VERSION is {} but Pool Genesis Transactions are of version {}.\
+                                         Set protocol_version 1 in pool open config or omit it to use the version of Genesis Transactions",
                                         protocol_version, NodeTransactionV0::VERSION)))?;
                 }
                 NodeTransactionV1::from(txn)
@@ -206,8 +221,8 @@ pub fn build_node_state(merkle_tree: &MerkleTree) -> IndyResult<HashMap<String,
             NodeTransaction::NodeTransactionV1(txn) => {
                 if protocol_version != 2 {
                     return Err(err_msg(IndyErrorKind::PoolIncompatibleProtocolVersion,
-                                       format!("Libindy PROTOCOL_VERSION is {} but Pool Genesis Transactions are of version {}.\
-                                                Call indy_set_protocol_version(2) to set correct PROTOCOL_VERSION",
+                                       format!("Pool PROTOCOL_VERSION is {} but Pool Genesis Transactions are of version {}.\
+                                                Set protocol_version 2 in pool open config or omit it to use the version of Genesis Transactions",
                                                protocol_version, NodeTransactionV1::VERSION)));
                 }
                 txn
@@ -234,15 +249,10 @@ mod tests {
 
     use byteorder::LittleEndian;
 
-    use domain::ledger::request::ProtocolVersion;
     use utils::test;
 
     use super::*;
 
-    fn _set_protocol_version(version: usize) {
-        ProtocolVersion::set(version);
-    }
-
     const TEST_PROTOCOL_VERSION: usize = 2;
     pub const NODE1_OLD: &'static str = r#"{"data":{"alias":"Node1","client_ip":"192.168.1.35","client_port":9702,"node_ip":"192.168.1.35","node_port":9701,"services":["VALIDATOR"]},"dest":"Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv","identifier":"FYmoFw55GeQH7SRFa37dkx1d2dZ3zUF8ckg7wmL7ofN4","txnId":"fea82e10e894419fe2bea7d96296a6d46f50f93f9eeda954ec461b2ed2950b62","type":"0"}"#;
     pub const NODE2_OLD: &'static str = r#"{"data":{"alias":"Node2","client_ip":"192.168.1.35","client_port":9704,"node_ip":"192.168.1.35","node_port":9703,"services":["VALIDATOR"]},"dest":"8ECVSk179mjsjKRLWiQtssMLgp6EPhWXtaYyStWPSGAb","identifier":"8QhFxKxyaFsJy4CyxeYX34dFH8oWqyBv1P4HLQCsoeLy","txnId":"1ac8aece2a18ced660fef8694b61aac3af08ba875ce3026a160acbc3a3af35fc","type":"0"}"#;
@@ -263,15 +273,13 @@ mod tests {
     fn pool_worker_build_node_state_works_for_new_txns_format_and_1_protocol_version() {
         test::cleanup_storage();
 
-        _set_protocol_version(1);
-
         let node_txns = test::gen_txns();
         let txns_src = node_txns[0..(2 as usize)].join("\n");
 
         _write_genesis_txns(&txns_src);
 
         let merkle_tree = super::create("test").unwrap();
-        let res = super::build_node_state(&merkle_tree);
+        let res = super::build_node_state(&merkle_tree, 1);
         assert_kind!(IndyErrorKind::PoolIncompatibleProtocolVersion, res);
     }
 
@@ -279,8 +287,6 @@ mod tests {
     pub fn pool_worker_works_for_deserialize_cache() {
         test::cleanup_storage();
 
-        _set_protocol_version(TEST_PROTOCOL_VERSION);
-
         let node_txns = test::gen_txns();
 
         let txn1_json: serde_json::Value = serde_json::from_str(&node_txns[0]).unwrap();
@@ -305,7 +311,7 @@ mod tests {
         });
 
         let merkle_tree = super::create("test").unwrap();
-        let _node_state = super::build_node_state(&merkle_tree).unwrap();
+        let _node_state = super::build_node_state(&merkle_tree, TEST_PROTOCOL_VERSION).unwrap();
     }
 
     #[test]
@@ -328,8 +334,6 @@ mod tests {
     fn pool_worker_build_node_state_works_for_old_format() {
         test::cleanup_storage();
 
-        _set_protocol_version(1);
-
         let node1: NodeTransactionV1 = NodeTransactionV1::from(serde_json::from_str::<NodeTransactionV0>(NODE1_OLD).unwrap());
         let node2: NodeTransactionV1 = NodeTransactionV1::from(serde_json::from_str::<NodeTransactionV0>(NODE2_OLD).unwrap());
 
@@ -338,9 +342,7 @@ mod tests {
         _write_genesis_txns(&txns_src);
 
         let merkle_tree = super::create("test").unwrap();
-        let node_state = super::build_node_state(&merkle_tree).unwrap();
-
-        assert_eq!(1, ProtocolVersion::get());
+        let node_state = super::build_node_state(&merkle_tree, 1).unwrap();
 
         assert_eq!(2, node_state.len());
         assert!(node_state.contains_key("Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv"));
@@ -354,8 +356,6 @@ mod tests {
     fn pool_worker_build_node_state_works_for_new_format() {
         test::cleanup_storage();
 
-        _set_protocol_version(TEST_PROTOCOL_VERSION);
-
         let node_txns = test::gen_txns();
 
         let node1: NodeTransactionV1 = serde_json::from_str(&node_txns[0]).unwrap();
@@ -366,9 +366,7 @@ mod tests {
         _write_genesis_txns(&txns_src);
 
         let merkle_tree = super::create("test").unwrap();
-        let node_state = super::build_node_state(&merkle_tree).unwrap();
-
-        assert_eq!(2, ProtocolVersion::get());
+        let node_state = super::build_node_state(&merkle_tree, TEST_PROTOCOL_VERSION).unwrap();
 
         assert_eq!(4, node_state.len());
         assert!(node_state.contains_key("Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv"));
@@ -382,14 +380,42 @@ mod tests {
     fn pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version() {
         test::cleanup_storage();
 
-        _set_protocol_version(TEST_PROTOCOL_VERSION);
-
         let txns_src = format!("{}\n{}\n", NODE1_OLD, NODE2_OLD);
 
         _write_genesis_txns(&txns_src);
 
         let merkle_tree = super::create("test").unwrap();
-        let res = super::build_node_state(&merkle_tree);
+        let res = super::build_node_state(&merkle_tree, TEST_PROTOCOL_VERSION);
         assert_kind!(IndyErrorKind::PoolIncompatibleProtocolVersion, res);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn get_protocol_version_works_for_old_format() {
+        test::cleanup_storage();
+
+        _write_genesis_txns(&format!("{}\n{}\n", NODE1_OLD, NODE2_OLD));
+
+        let merkle_tree = super::create("test").unwrap();
+        assert_eq!(Some(1), super::get_protocol_version(&merkle_tree).unwrap());
+    }
+
+    #[test]
+    fn get_protocol_version_works_for_new_format() {
+        test::cleanup_storage();
+
+        _write_genesis_txns(&test::gen_txns().join("\n"));
+
+        let merkle_tree = super::create("test").unwrap();
+        assert_eq!(Some(2), super::get_protocol_version(&merkle_tree).unwrap());
+    }
+
+    #[test]
+    fn get_protocol_version_works_for_empty_txns() {
+        test::cleanup_storage();
+
+        _write_genesis_txns("");
+
+        let merkle_tree = super::create("test").unwrap();
+        assert_eq!(None, super::get_protocol_version(&merkle_tree).unwrap());
+    }
+}
@@ -14,7 +14,7 @@ use self::zmq::Socket;
 
 use std::{fs, io};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Write;
 use std::sync::Mutex;
 
@@ -23,7 +23,7 @@ use serde::de::DeserializeOwned;
 
 use api::ledger::{CustomFree, CustomTransactionParser};
 use domain::{
-    pool::{PoolConfig, PoolOpenConfig},
+    pool::{LedgerMirrorQuery, PoolConfig, PoolOpenConfig},
     ledger::response::{
         Message,
         Reply,
@@ -39,6 +39,7 @@ use std::u64;
 mod catchup;
 mod commander;
 mod events;
+mod ledger_mirror;
 mod merkle_tree_factory;
 mod networker;
 mod pool;
@@ -180,6 +181,13 @@ impl PoolService {
     }
 
 
+    pub fn get_protocol_version(&self, handle: i32) -> IndyResult<usize> {
+        self.open_pools.try_borrow()?
+            .get(&handle)
+            .map(|pool| pool.pool.get_protocol_version())
+            .ok_or(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))
+    }
+
     pub fn send_tx(&self, handle: i32, msg: &str) -> IndyResult<i32> {
         self.send_action(handle, msg, None, None)
     }
@@ -234,6 +242,40 @@ impl PoolService {
         self.send_action(handle, "refresh", None, None)
     }
 
+    pub fn sync_ledger(&self, handle: i32, ledger_id: usize) -> IndyResult<i32> {
+        let pools = self.open_pools.try_borrow()?;
+
+        if let Some(ref pool) = pools.get(&handle) {
+            let cmd_id: i32 = sequence::get_next_id();
+            let mut buf = [0u8; 4];
+            let mut buf_ledger_id = [0u8; 4];
+            LittleEndian::write_i32(&mut buf, cmd_id);
+            LittleEndian::write_u32(&mut buf_ledger_id, ledger_id as u32);
+            pool.cmd_socket.send_multipart(&["sync_ledger".as_bytes(), &buf, &buf_ledger_id], zmq::DONTWAIT)?;
+            Ok(cmd_id)
+        } else {
+            Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))
+        }
+    }
+
+    pub fn search_ledger_mirror(&self, handle: i32, ledger_id: usize, query: &LedgerMirrorQuery) -> IndyResult<Vec<serde_json::Value>> {
+        let pools = self.open_pools.try_borrow()?;
+
+        let pool = pools.get(&handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))?;
+
+        ledger_mirror::LedgerMirror::open(pool.pool.get_name(), ledger_id)?.search(query)
+    }
+
+    pub fn get_nodes(&self, handle: i32) -> IndyResult<BTreeMap<String, String>> {
+        let pools = self.open_pools.try_borrow()?;
+
+        let pool = pools.get(&handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))?;
+
+        pool::get_nodes(pool.pool.get_name(), pool.pool.get_protocol_version())
+    }
+
     fn _send_msg(&self, cmd_id: i32, msg: &str, socket: &Socket, nodes: Option<&str>, timeout: Option<i32>) -> IndyResult<()> {
         let mut buf = [0u8; 4];
         let mut buf_to = [0u8; 4];
@@ -407,6 +449,26 @@ mod tests {
             assert_eq!(cmd_id, LittleEndian::read_i32(recv[1].as_slice()));
         }
 
+        #[test]
+        fn pool_service_sync_ledger_works() {
+            test::cleanup_storage();
+
+            let ps = PoolService::new();
+            let pool_id = sequence::get_next_id();
+            let ctx = zmq::Context::new();
+            let send_soc = ctx.socket(zmq::SocketType::PAIR).unwrap();
+            let recv_soc = ctx.socket(zmq::SocketType::PAIR).unwrap();
+            recv_soc.bind("inproc://test_sync_ledger").unwrap();
+            send_soc.connect("inproc://test_sync_ledger").unwrap();
+            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(Pool::new("", pool_id, PoolOpenConfig::default()), send_soc));
+            let cmd_id = ps.sync_ledger(pool_id, 1).unwrap();
+            let recv = recv_soc.recv_multipart(zmq::DONTWAIT).unwrap();
+            assert_eq!(recv.len(), 3);
+            assert_eq!("sync_ledger", String::from_utf8(recv[0].clone()).unwrap());
+            assert_eq!(cmd_id, LittleEndian::read_i32(recv[1].as_slice()));
+            assert_eq!(1, LittleEndian::read_u32(recv[2].as_slice()));
+        }
+
         #[test]
         fn pool_service_delete_works() {
             test::cleanup_storage();
@@ -523,6 +585,22 @@ mod tests {
             assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
         }
 
+        #[test]
+        fn pool_sync_ledger_works_for_invalid_handle() {
+            test::cleanup_storage();
+            let ps = PoolService::new();
+            let res = ps.sync_ledger(-1, 1);
+            assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn pool_search_ledger_mirror_works_for_invalid_handle() {
+            test::cleanup_storage();
+            let ps = PoolService::new();
+            let res = ps.search_ledger_mirror(-1, 1, &LedgerMirrorQuery::default());
+            assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
+        }
+
         #[test]
         fn pool_register_sp_parser_works() {
             test::cleanup_storage();
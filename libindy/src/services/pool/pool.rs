@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::collections::VecDeque;
 use std::marker::PhantomData;
 use std::rc::Rc;
@@ -7,17 +7,20 @@ use std::thread;
 use std::thread::JoinHandle;
 
 use failure::Context;
+use serde_json;
 
 use commands::Command;
 use commands::CommandExecutor;
 use commands::ledger::LedgerCommand;
 use commands::pool::PoolCommand;
 use domain::ledger::request::ProtocolVersion;
-use domain::pool::PoolOpenConfig;
+use domain::pool::{LedgerMirrorStatus, PoolOpenConfig};
 use errors::prelude::*;
 use services::ledger::merkletree::merkletree::MerkleTree;
+use services::pool::catchup::POOL_LEDGER_ID;
 use services::pool::commander::Commander;
 use services::pool::events::*;
+use services::pool::ledger_mirror::LedgerMirror;
 use services::pool::merkle_tree_factory;
 use services::pool::networker::{Networker, ZMQNetworker};
 use services::pool::request_handler::{RequestHandler, RequestHandlerImpl};
@@ -33,6 +36,7 @@ struct PoolSM<T: Networker, R: RequestHandler<T>> {
     id: i32,
     timeout: i64,
     extended_timeout: i64,
+    protocol_version: usize,
     state: PoolState<T, R>,
 }
 
@@ -67,6 +71,15 @@ struct ActiveState<T: Networker, R: RequestHandler<T>> {
     networker: Rc<RefCell<T>>,
     request_handlers: HashMap<String, R>,
     nodes: HashMap<String, Option<VerKey>>,
+    ledger_sync: Option<LedgerSync<R>>,
+}
+
+/// Catchup of a non-pool ledger into the local ledger mirror.
+/// Catchup messages have no req_id so only one ledger can be synced at a time.
+struct LedgerSync<R> {
+    cmd_id: i32,
+    ledger_id: usize,
+    request_handler: R,
 }
 
 struct SyncCatchupState<T: Networker, R: RequestHandler<T>> {
@@ -83,20 +96,21 @@ struct TerminatedState<T: Networker> {
 struct ClosedState {}
 
 impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
-    pub fn new(networker: Rc<RefCell<T>>, pname: &str, id: i32, timeout: i64, extended_timeout: i64) -> PoolSM<T, R> {
+    pub fn new(networker: Rc<RefCell<T>>, pname: &str, id: i32, timeout: i64, extended_timeout: i64, protocol_version: usize) -> PoolSM<T, R> {
         PoolSM {
             pool_name: pname.to_string(),
             id,
             timeout,
             extended_timeout,
+            protocol_version,
             state: PoolState::Initialization(InitializationState {
                 networker
             }),
         }
     }
 
-    pub fn step(pool_name: String, id: i32, timeout: i64, extended_timeout: i64, state: PoolState<T, R>) -> Self {
-        PoolSM { pool_name, id, timeout, extended_timeout, state }
+    pub fn step(pool_name: String, id: i32, timeout: i64, extended_timeout: i64, protocol_version: usize, state: PoolState<T, R>) -> Self {
+        PoolSM { pool_name, id, timeout, extended_timeout, protocol_version, state }
     }
 }
 
@@ -136,6 +150,7 @@ impl<T: Networker, R: RequestHandler<T>> From<(InitializationState<T>, HashMap<S
             networker: state.networker,
             request_handlers: HashMap::new(),
             nodes,
+            ledger_sync: None,
         }
     }
 }
@@ -160,6 +175,7 @@ impl<T: Networker, R: RequestHandler<T>> From<(GettingCatchupTargetState<T, R>,
             networker: state.networker,
             request_handlers: HashMap::new(),
             nodes,
+            ledger_sync: None,
         }
     }
 }
@@ -184,9 +200,10 @@ impl<T: Networker, R: RequestHandler<T>> From<GettingCatchupTargetState<T, R>> f
 // transitions from Active
 
 impl<T: Networker, R: RequestHandler<T>> From<(ActiveState<T, R>, R, i32)> for GettingCatchupTargetState<T, R> {
-    fn from((state, request_handler, cmd_id): (ActiveState<T, R>, R, i32)) -> Self {
+    fn from((mut state, request_handler, cmd_id): (ActiveState<T, R>, R, i32)) -> Self {
         trace!("PoolSM: from active to getting catchup target");
         //TODO: close connections!
+        state.terminate_ledger_sync();
         GettingCatchupTargetState {
             networker: state.networker,
             cmd_id,
@@ -197,8 +214,9 @@ impl<T: Networker, R: RequestHandler<T>> From<(ActiveState<T, R>, R, i32)> for G
 }
 
 impl<T: Networker, R: RequestHandler<T>> From<ActiveState<T, R>> for TerminatedState<T> {
-    fn from(state: ActiveState<T, R>) -> Self {
+    fn from(mut state: ActiveState<T, R>) -> Self {
         trace!("PoolSM: from active to terminated");
+        state.terminate_ledger_sync();
         TerminatedState { networker: state.networker }
     }
 }
@@ -209,11 +227,21 @@ impl<T: Networker, R: RequestHandler<T>> From<ActiveState<T, R>> for ClosedState
             trace!("Termintating ongoing request");
             p.process_event(Some(RequestEvent::Terminate));
         });
+        state.terminate_ledger_sync();
         trace!("PoolSM: from active to closed");
         ClosedState {}
     }
 }
 
+impl<T: Networker, R: RequestHandler<T>> ActiveState<T, R> {
+    fn terminate_ledger_sync(&mut self) {
+        if let Some(mut ledger_sync) = self.ledger_sync.take() {
+            trace!("Terminating ongoing ledger sync");
+            ledger_sync.request_handler.process_event(Some(RequestEvent::Terminate));
+        }
+    }
+}
+
 // transitions from SyncCatchup
 
 impl<T: Networker, R: RequestHandler<T>> From<(SyncCatchupState<T, R>, HashMap<String, Option<VerKey>>)> for ActiveState<T, R> {
@@ -223,6 +251,7 @@ impl<T: Networker, R: RequestHandler<T>> From<(SyncCatchupState<T, R>, HashMap<S
             networker: state.networker,
             request_handlers: HashMap::new(),
             nodes,
+            ledger_sync: None,
         }
     }
 }
@@ -265,7 +294,7 @@ impl<T: Networker> From<TerminatedState<T>> for ClosedState {
 
 impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
     pub fn handle_event(self, pe: PoolEvent) -> Self {
-        let PoolSM { pool_name, id, state, timeout, extended_timeout } = self;
+        let PoolSM { pool_name, id, state, timeout, extended_timeout, protocol_version } = self;
         let state = match state {
             PoolState::Initialization(state) => match pe {
                 PoolEvent::CheckCache(cmd_id) => {
@@ -275,7 +304,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         //                        PoolWrapper::Active(pool.into())
                         unimplemented!()
                     } else {
-                        match _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout) {
+                        match _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout, protocol_version) {
                             Ok(request_handler) => PoolState::GettingCatchupTarget((request_handler, cmd_id, state).into()),
                             Err(err) => {
                                 CommandExecutor::instance().send(
@@ -306,10 +335,10 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         PoolState::Terminated(state.into())
                     }
                     PoolEvent::CatchupRestart(merkle_tree) => {
-                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
+                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree, protocol_version) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-                            state.request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &vec![], &nodes, None, &pool_name, timeout, extended_timeout);
-                            let ls = _ledger_status(&merkle_tree);
+                            state.request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &vec![], &nodes, None, &pool_name, timeout, extended_timeout, protocol_version);
+                            let ls = _ledger_status(&merkle_tree, POOL_LEDGER_ID, protocol_version);
                             state.request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle_tree))));
                             PoolState::GettingCatchupTarget(state)
                         } else {
@@ -317,17 +346,17 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         }
                     }
                     PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree) => {
-                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
+                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree, protocol_version) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-                            let mut request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &vec![], &nodes, None, &pool_name, timeout, extended_timeout);
-                            request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root)));
+                            let mut request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &vec![], &nodes, None, &pool_name, timeout, extended_timeout, protocol_version);
+                            request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root, POOL_LEDGER_ID)));
                             PoolState::SyncCatchup((request_handler, state).into())
                         } else {
                             PoolState::Terminated(state.into())
                         }
                     }
                     PoolEvent::Synced(merkle) => {
-                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle) {
+                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle, protocol_version) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh);
                             PoolState::Active((state, nodes).into())
@@ -345,7 +374,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         PoolState::Closed(state.into())
                     }
                     PoolEvent::Refresh(cmd_id) => {
-                        if let Ok(request_handler) = _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout) {
+                        if let Ok(request_handler) = _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout, protocol_version) {
                             PoolState::GettingCatchupTarget((state, request_handler, cmd_id).into())
                         } else {
                             PoolState::Terminated(state)
@@ -371,7 +400,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         PoolState::Closed(state.into())
                     }
                     PoolEvent::Refresh(cmd_id) => {
-                        if let Ok(request_handler) = _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout) {
+                        if let Ok(request_handler) = _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout, protocol_version) {
                             PoolState::GettingCatchupTarget((state, request_handler, cmd_id).into())
                         } else {
                             PoolState::Terminated(state.into())
@@ -382,7 +411,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         let re: Option<RequestEvent> = pe.into();
                         match re.as_ref().map(|r| r.get_req_id()) {
                             Some(req_id) => {
-                                let mut request_handler = R::new(state.networker.clone(), _get_f(state.nodes.len()), &vec![cmd_id], &state.nodes, None, &pool_name, timeout, extended_timeout);
+                                let mut request_handler = R::new(state.networker.clone(), _get_f(state.nodes.len()), &vec![cmd_id], &state.nodes, None, &pool_name, timeout, extended_timeout, protocol_version);
                                 request_handler.process_event(re);
                                 state.request_handlers.insert(req_id.to_string(), request_handler); //FIXME check already exists
                             }
@@ -393,11 +422,28 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         };
                         PoolState::Active(state)
                     }
+                    PoolEvent::SyncLedger(cmd_id, ledger_id) => {
+                        if state.ledger_sync.is_some() {
+                            _send_submit_ack(cmd_id, Err(err_msg(IndyErrorKind::InvalidState, "Ledger sync is already in progress")));
+                        } else if ledger_id == POOL_LEDGER_ID {
+                            _send_submit_ack(cmd_id, _mirror_pool_ledger(&pool_name));
+                        } else {
+                            match _get_ledger_sync_with_ledger_status_sent(state.networker.clone(), &state.nodes, cmd_id, ledger_id, &pool_name, timeout, extended_timeout, protocol_version) {
+                                Ok(ledger_sync) => state.ledger_sync = Some(ledger_sync),
+                                Err(err) => _send_submit_ack(cmd_id, Err(err))
+                            }
+                        }
+                        PoolState::Active(state)
+                    }
                     PoolEvent::NodeReply(reply, node) => {
                         trace!("received reply from node {:?}: {:?}", node, reply);
                         let re: Option<RequestEvent> = pe.into();
-                        match re.as_ref().map(|r| r.get_req_id()) {
-                            Some(req_id) => {
+                        let ledger_sync_id = state.ledger_sync.as_ref().map(|ledger_sync| ledger_sync.ledger_id);
+                        match re.as_ref().map(|r| (r.get_req_id(), r.get_ledger_id())) {
+                            Some((_, Some(ledger_id))) if Some(ledger_id) == ledger_sync_id => {
+                                _process_ledger_sync_event(&mut state, re, &pool_name, timeout, extended_timeout, protocol_version);
+                            }
+                            Some((req_id, _)) => {
                                 let remove = if let Some(rh) = state.request_handlers.get_mut(&req_id) {
                                     rh.process_event(re);
                                     rh.is_terminal()
@@ -418,6 +464,8 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                             rh.process_event(pe.into());
                         } else if "".eq(&req_id) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::Timeout));
+                        } else if state.ledger_sync.is_some() {
+                            _process_ledger_sync_event(&mut state, pe.into(), &pool_name, timeout, extended_timeout, protocol_version);
                         } else {
                             warn!("Unexpected timeout: req_id {}, node_alias {}", req_id, node_alias)
                         }
@@ -435,7 +483,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     }
                     PoolEvent::NodesBlacklisted => PoolState::Terminated(state.into()),
                     PoolEvent::Synced(merkle) => {
-                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle).map_err(map_err_err!()) {
+                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle, protocol_version).map_err(map_err_err!()) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh);
                             PoolState::Active((state, nodes).into())
@@ -447,7 +495,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                 }
             }
         };
-        PoolSM::step(pool_name, id, timeout, extended_timeout, state)
+        PoolSM::step(pool_name, id, timeout, extended_timeout, protocol_version, state)
     }
 
     pub fn is_terminal(&self) -> bool {
@@ -472,6 +520,7 @@ pub struct Pool<S: Networker, R: RequestHandler<S>> {
     active_timeout: i64,
     conn_limit: usize,
     preordered_nodes: Vec<String>,
+    protocol_version: usize,
 }
 
 impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
@@ -487,6 +536,9 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
             active_timeout: config.conn_active_timeout,
             conn_limit: config.conn_limit,
             preordered_nodes: config.preordered_nodes,
+            protocol_version: config.protocol_version
+                .or_else(|| _discover_protocol_version(name))
+                .unwrap_or(ProtocolVersion::get()),
         }
     }
 
@@ -498,11 +550,12 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
         let active_timeout = self.active_timeout;
         let conn_limit = self.conn_limit;
         let preordered_nodes = self.preordered_nodes.clone();
+        let protocol_version = self.protocol_version;
         self.worker = Some(thread::spawn(move || {
             let mut pool_thread: PoolThread<S, R> = PoolThread::new(cmd_socket, name, id,
                                                                     timeout, extended_timeout,
                                                                     active_timeout, conn_limit,
-                                                                    preordered_nodes);
+                                                                    preordered_nodes, protocol_version);
             pool_thread.work();
         }));
     }
@@ -514,6 +567,10 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
     pub fn get_id(&self) -> i32 {
         self.id
     }
+
+    pub fn get_protocol_version(&self) -> usize {
+        self.protocol_version
+    }
 }
 
 struct PoolThread<S: Networker, R: RequestHandler<S>> {
@@ -524,10 +581,10 @@ struct PoolThread<S: Networker, R: RequestHandler<S>> {
 }
 
 impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
-    pub fn new(cmd_socket: zmq::Socket, name: String, id: i32, timeout: i64, extended_timeout: i64, active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>) -> Self {
+    pub fn new(cmd_socket: zmq::Socket, name: String, id: i32, timeout: i64, extended_timeout: i64, active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, protocol_version: usize) -> Self {
         let networker = Rc::new(RefCell::new(S::new(active_timeout, conn_limit, preordered_nodes)));
         PoolThread {
-            pool_sm: Some(PoolSM::new(networker.clone(), &name, id, timeout, extended_timeout)),
+            pool_sm: Some(PoolSM::new(networker.clone(), &name, id, timeout, extended_timeout, protocol_version)),
             events: VecDeque::new(),
             commander: Commander::new(cmd_socket),
             networker,
@@ -592,6 +649,12 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
     }
 }
 
+fn _discover_protocol_version(pool_name: &str) -> Option<usize> {
+    merkle_tree_factory::create(pool_name)
+        .and_then(|merkle| merkle_tree_factory::get_protocol_version(&merkle))
+        .unwrap_or(None)
+}
+
 fn _get_f(cnt: usize) -> usize {
     if cnt < 4 {
         return 0;
@@ -599,43 +662,117 @@ fn _get_f(cnt: usize) -> usize {
     (cnt - 1) / 3
 }
 
-fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<T>>(networker: Rc<RefCell<T>>, pool_name: &str, timeout: i64, extended_timeout: i64) -> IndyResult<R> {
+fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<T>>(networker: Rc<RefCell<T>>, pool_name: &str, timeout: i64, extended_timeout: i64, protocol_version: usize) -> IndyResult<R> {
     let mut merkle = merkle_tree_factory::create(pool_name)?;
 
-    let (nodes, remotes) = match _get_nodes_and_remotes(&merkle) {
+    let (nodes, remotes) = match _get_nodes_and_remotes(&merkle, protocol_version) {
         Ok(n) => n,
         Err(err) => {
             match merkle_tree_factory::drop_cache(pool_name) {
                 Ok(_) => {
                     merkle = merkle_tree_factory::create(pool_name)?;
-                    _get_nodes_and_remotes(&merkle)?
+                    _get_nodes_and_remotes(&merkle, protocol_version)?
                 }
                 Err(_) => { return Err(err); }
             }
         }
     };
     networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-    let mut request_handler = R::new(networker.clone(), _get_f(nodes.len()), &vec![], &nodes, None, pool_name, timeout, extended_timeout);
-    let ls = _ledger_status(&merkle);
+    let mut request_handler = R::new(networker.clone(), _get_f(nodes.len()), &vec![], &nodes, None, pool_name, timeout, extended_timeout, protocol_version);
+    let ls = _ledger_status(&merkle, POOL_LEDGER_ID, protocol_version);
     request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle))));
     Ok(request_handler)
 }
 
-fn _ledger_status(merkle: &MerkleTree) -> LedgerStatus{
-    let protocol_version = ProtocolVersion::get();
+fn _get_ledger_sync_with_ledger_status_sent<T: Networker, R: RequestHandler<T>>(networker: Rc<RefCell<T>>, nodes: &HashMap<String, Option<VerKey>>, cmd_id: i32, ledger_id: usize, pool_name: &str, timeout: i64, extended_timeout: i64, protocol_version: usize) -> IndyResult<LedgerSync<R>> {
+    let merkle = LedgerMirror::open(pool_name, ledger_id)?.merkle_tree()?;
+
+    let mut request_handler = R::new(networker, _get_f(nodes.len()), &vec![cmd_id], nodes, None, pool_name, timeout, extended_timeout, protocol_version);
+    let ls = _ledger_status(&merkle, ledger_id, protocol_version);
+    request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle))));
+
+    Ok(LedgerSync { cmd_id, ledger_id, request_handler })
+}
 
+fn _process_ledger_sync_event<T: Networker, R: RequestHandler<T>>(state: &mut ActiveState<T, R>, re: Option<RequestEvent>, pool_name: &str, timeout: i64, extended_timeout: i64, protocol_version: usize) {
+    let mut ledger_sync = match state.ledger_sync.take() {
+        Some(ledger_sync) => ledger_sync,
+        None => return
+    };
+
+    let mut pe = ledger_sync.request_handler.process_event(re);
+
+    loop {
+        pe = match pe {
+            Some(PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree)) => {
+                ledger_sync.request_handler = R::new(state.networker.clone(), _get_f(state.nodes.len()), &vec![ledger_sync.cmd_id], &state.nodes, None, pool_name, timeout, extended_timeout, protocol_version);
+                ledger_sync.request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root, ledger_sync.ledger_id)))
+            }
+            Some(PoolEvent::Synced(merkle_tree)) => {
+                _send_submit_ack(ledger_sync.cmd_id, _ledger_mirror_status(ledger_sync.ledger_id, &merkle_tree));
+                return;
+            }
+            Some(PoolEvent::CatchupTargetNotFound(err)) => {
+                _send_submit_ack(ledger_sync.cmd_id, Err(err));
+                return;
+            }
+            _ => {
+                if !ledger_sync.request_handler.is_terminal() {
+                    state.ledger_sync = Some(ledger_sync);
+                }
+                return;
+            }
+        }
+    }
+}
+
+fn _mirror_pool_ledger(pool_name: &str) -> IndyResult<String> {
+    // pool ledger is already caught up and verified on pool open/refresh, so just copy new txns from the cache
+    let merkle = merkle_tree_factory::create(pool_name)?;
+    let mut mirror = LedgerMirror::open(pool_name, POOL_LEDGER_ID)?;
+
+    let mirrored_cnt = mirror.count()?;
+    if mirrored_cnt > merkle.count() {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Ledger mirror contains more txns than pool ledger"));
+    }
+
+    let txns: Vec<Vec<u8>> = merkle.iter().skip(mirrored_cnt).cloned().collect();
+    mirror.add_txns(mirrored_cnt + 1, &txns)?;
+
+    _ledger_mirror_status(POOL_LEDGER_ID, &merkle)
+}
+
+fn _ledger_mirror_status(ledger_id: usize, merkle: &MerkleTree) -> IndyResult<String> {
+    let status = LedgerMirrorStatus {
+        ledger_id,
+        txn_count: merkle.count(),
+        root_hash: merkle.root_hash().as_slice().to_base58(),
+    };
+
+    serde_json::to_string(&status)
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ledger mirror status")
+}
+
+fn _ledger_status(merkle: &MerkleTree, ledger_id: usize, protocol_version: usize) -> LedgerStatus {
     LedgerStatus {
         txnSeqNo: merkle.count(),
         merkleRoot: merkle.root_hash().as_slice().to_base58(),
-        ledgerId: 0,
+        ledgerId: ledger_id,
         ppSeqNo: None,
         viewNo: None,
         protocolVersion: if protocol_version > 1 { Some(protocol_version) } else { None },
     }
 }
 
-fn _get_nodes_and_remotes(merkle: &MerkleTree) -> IndyResult<(HashMap<String, Option<VerKey>>, Vec<RemoteNode>)> {
-    let nodes = merkle_tree_factory::build_node_state(merkle)?;
+pub fn get_nodes(pool_name: &str, protocol_version: usize) -> IndyResult<BTreeMap<String, String>> {
+    let merkle = merkle_tree_factory::create(pool_name)?;
+    let (_, remotes) = _get_nodes_and_remotes(&merkle, protocol_version)?;
+
+    Ok(remotes.into_iter().map(|remote| (remote.name, remote.zaddr)).collect())
+}
+
+fn _get_nodes_and_remotes(merkle: &MerkleTree, protocol_version: usize) -> IndyResult<(HashMap<String, Option<VerKey>>, Vec<RemoteNode>)> {
+    let nodes = merkle_tree_factory::build_node_state(merkle, protocol_version)?;
 
     Ok(nodes.iter().map(|(_, txn)| {
         let node_alias = txn.txn.data.data.alias.clone();
@@ -780,6 +917,35 @@ mod tests {
             let p: Pool<MockNetworker, MockRequestHandler> = Pool::new(name, id, PoolOpenConfig::default());
             assert_eq!(id, p.get_id());
         }
+
+        #[test]
+        pub fn pool_get_protocol_version_works_for_config() {
+            let config = PoolOpenConfig { protocol_version: Some(1), ..PoolOpenConfig::default() };
+            let p: Pool<MockNetworker, MockRequestHandler> = Pool::new("name", 1, config);
+            assert_eq!(1, p.get_protocol_version());
+        }
+
+        #[test]
+        pub fn pool_get_protocol_version_works_for_genesis_txns() {
+            use std::fs;
+            use std::io::Write;
+
+            use utils::environment;
+
+            test::cleanup_storage();
+
+            let name = "pool_protocol_version";
+            let mut path = environment::pool_path(name);
+            fs::create_dir_all(path.as_path()).unwrap();
+            path.push(name);
+            path.set_extension("txn");
+            fs::File::create(path.as_path()).unwrap().write_all(test::gen_txns().join("\n").as_bytes()).unwrap();
+
+            let p: Pool<MockNetworker, MockRequestHandler> = Pool::new(name, 1, PoolOpenConfig::default());
+            assert_eq!(2, p.get_protocol_version());
+
+            test::cleanup_storage();
+        }
     }
 
     mod pool_sm {
@@ -796,17 +962,16 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_new_initialization_works() {
-            let _p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "name", 1, 0, 0);
+            let _p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "name", 1, 0, 0, 2);
         }
 
         #[test]
         pub fn pool_wrapper_check_cache_works() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             assert_match!(PoolState::GettingCatchupTarget(_), p.state);
 
@@ -815,14 +980,14 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_check_cache_works_for_no_pool_created() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             assert_match!(PoolState::Terminated(_), p.state);
         }
 
         #[test]
         pub fn pool_wrapper_terminated_close_works() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::Close(2));
             assert_match!(PoolState::Closed(_), p.state);
@@ -830,10 +995,9 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_terminated_refresh_works() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let p = p.handle_event(PoolEvent::Refresh(2));
@@ -850,6 +1014,7 @@ mod tests {
                 }),
                 timeout: 0,
                 extended_timeout: 0,
+                protocol_version: 2,
             };
 
             let p = p.handle_event(PoolEvent::Timeout("".to_string(), "".to_string()));
@@ -866,7 +1031,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_cloe_works_from_initialization() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::Close(1));
             assert_match!(PoolState::Closed(_), p.state);
         }
@@ -875,10 +1040,9 @@ mod tests {
         pub fn pool_wrapper_close_works_from_getting_catchup_target() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::Close(2));
             assert_match!(PoolState::Closed(_), p.state);
@@ -890,10 +1054,9 @@ mod tests {
         pub fn pool_wrapper_catchup_target_not_found_works() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::CatchupTargetNotFound(err_msg(IndyErrorKind::PoolTimeout, "Pool timeout")));
             assert_match!(PoolState::Terminated(_), p.state);
@@ -905,10 +1068,9 @@ mod tests {
         pub fn pool_wrapper_getting_catchup_target_synced_works() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             assert_match!(PoolState::Active(_), p.state);
@@ -920,12 +1082,11 @@ mod tests {
         pub fn pool_wrapper_getting_catchup_target_synced_works_for_node_state_error() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
-            let p = p.handle_event(PoolEvent::CheckCache(1));
-            ProtocolVersion::set(1);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
+            let mut p = p.handle_event(PoolEvent::CheckCache(1));
+            p.protocol_version = 1;
             let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create(POOL).unwrap()));
             assert_match!(PoolState::Terminated(_), p.state);
 
@@ -936,12 +1097,11 @@ mod tests {
         pub fn pool_wrapper_getting_catchup_target_catchup_target_found_works() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let mt = merkle_tree_factory::create(POOL).unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
             assert_match!(PoolState::SyncCatchup(_), p.state);
@@ -953,14 +1113,13 @@ mod tests {
         pub fn pool_wrapper_getting_catchup_target_catchup_target_found_works_for_node_state_error() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let mt = merkle_tree_factory::create(POOL).unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
-            let p = p.handle_event(PoolEvent::CheckCache(1));
-            ProtocolVersion::set(1);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
+            let mut p = p.handle_event(PoolEvent::CheckCache(1));
+            p.protocol_version = 1;
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
             assert_match!(PoolState::Terminated(_), p.state);
 
@@ -971,12 +1130,11 @@ mod tests {
         pub fn pool_wrapper_sync_catchup_close_works() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let mt = merkle_tree_factory::create(POOL).unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
             let p = p.handle_event(PoolEvent::Close(2));
@@ -989,12 +1147,11 @@ mod tests {
         pub fn pool_wrapper_sync_catchup_synced_works() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let mt = merkle_tree_factory::create(POOL).unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
             let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create(POOL).unwrap()));
@@ -1007,15 +1164,14 @@ mod tests {
         pub fn pool_wrapper_sync_catchup_synced_works_for_node_state_error() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let mt = merkle_tree_factory::create(POOL).unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
-            ProtocolVersion::set(1);
+            let mut p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
+            p.protocol_version = 1;
             let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create(POOL).unwrap()));
             assert_match!(PoolState::Terminated(_), p.state);
 
@@ -1026,7 +1182,6 @@ mod tests {
         pub fn pool_wrapper_active_send_request_works() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let req = json!({
@@ -1036,7 +1191,7 @@ mod tests {
                 }
             }).to_string();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
@@ -1056,7 +1211,6 @@ mod tests {
         pub fn pool_wrapper_active_send_request_works_for_no_req_id() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let req = json!({
@@ -1065,7 +1219,7 @@ mod tests {
                 }
             }).to_string();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
@@ -1084,7 +1238,6 @@ mod tests {
         pub fn pool_wrapper_active_node_reply_works() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let req = json!({
@@ -1108,7 +1261,7 @@ mod tests {
 
             let rep = serde_json::to_string(&rep).unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
@@ -1128,7 +1281,6 @@ mod tests {
         pub fn pool_wrapper_active_node_reply_works_for_no_request() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let req = json!({
@@ -1152,7 +1304,7 @@ mod tests {
 
             let rep = serde_json::to_string(&rep).unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
@@ -1173,7 +1325,6 @@ mod tests {
         pub fn pool_wrapper_active_node_reply_works_for_invalid_reply() {
             test::cleanup_storage();
 
-            ProtocolVersion::set(2);
             _write_genesis_txns();
 
             let req = json!({
@@ -1185,7 +1336,7 @@ mod tests {
 
             let rep = r#"{}"#;
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
@@ -1201,6 +1352,48 @@ mod tests {
             test::cleanup_storage();
         }
 
+        #[test]
+        pub fn pool_wrapper_active_sync_ledger_works() {
+            test::cleanup_storage();
+
+            _write_genesis_txns();
+
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
+            let p = p.handle_event(PoolEvent::CheckCache(1));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let p = p.handle_event(PoolEvent::SyncLedger(3, 1));
+            assert_match!(PoolState::Active(_), p.state);
+            match p.state {
+                PoolState::Active(state) => {
+                    assert_eq!(state.ledger_sync.map(|ledger_sync| ledger_sync.ledger_id), Some(1));
+                }
+                _ => assert!(false)
+            };
+
+            test::cleanup_storage();
+        }
+
+        #[test]
+        pub fn pool_wrapper_active_sync_ledger_works_for_pool_ledger() {
+            test::cleanup_storage();
+
+            _write_genesis_txns();
+
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), POOL, 1, 0, 0, 2);
+            let p = p.handle_event(PoolEvent::CheckCache(1));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let p = p.handle_event(PoolEvent::SyncLedger(3, POOL_LEDGER_ID));
+            match p.state {
+                PoolState::Active(state) => assert!(state.ledger_sync.is_none()),
+                _ => assert!(false)
+            };
+
+            let mirror = LedgerMirror::open(POOL, POOL_LEDGER_ID).unwrap();
+            assert_eq!(test::gen_txns().len(), mirror.count().unwrap());
+
+            test::cleanup_storage();
+        }
+
         fn _write_genesis_txns() {
             let txns = test::gen_txns().join("\n");
 
@@ -18,11 +18,12 @@ use commands::CommandExecutor;
 use commands::ledger::LedgerCommand;
 use errors::prelude::*;
 use services::ledger::merkletree::merkletree::MerkleTree;
-use services::pool::catchup::{build_catchup_req, CatchupProgress, check_cons_proofs, check_nodes_responses_on_status};
+use services::pool::catchup::{build_catchup_req, CatchupProgress, check_cons_proofs, check_nodes_responses_on_status, POOL_LEDGER_ID};
 use services::pool::events::NetworkerEvent;
 use services::pool::events::PoolEvent;
 use services::pool::events::RequestEvent;
 use services::pool::get_last_signed_time;
+use services::pool::ledger_mirror::LedgerMirror;
 use services::pool::merkle_tree_factory;
 use services::pool::networker::Networker;
 use services::pool::state_proof;
@@ -43,6 +44,7 @@ struct RequestSM<T: Networker> {
     pool_name: String,
     timeout: i64,
     extended_timeout: i64,
+    protocol_version: usize,
     state: RequestState<T>,
 }
 
@@ -70,7 +72,7 @@ impl<T: Networker> RequestSM<T> {
                cmd_ids: &Vec<i32>,
                nodes: &HashMap<String, Option<VerKey>>,
                generator: Option<Generator>,
-               pool_name: &str, timeout: i64, extended_timeout: i64) -> Self {
+               pool_name: &str, timeout: i64, extended_timeout: i64, protocol_version: usize) -> Self {
         RequestSM {
             f,
             cmd_ids: cmd_ids.clone(),
@@ -79,6 +81,7 @@ impl<T: Networker> RequestSM<T> {
             generator: generator.unwrap_or(Generator::from_bytes(&"3LHpUjiyFC2q2hD7MnwwNmVXiuaFbQx2XkAFJWzswCjgN1utjsCeLzHsKk1nJvFEaS4fcrUmVAkdhtPCYbrVyATZcmzwJReTcJqwqBCPTmTQ9uWPwz6rEncKb2pYYYFcdHa8N17HzVyTqKfgPi4X9pMetfT3A5xCHq54R2pDNYWVLDX".from_base58().unwrap()).unwrap()),
             timeout,
             extended_timeout,
+            protocol_version,
             state: RequestState::Start(StartState {
                 networker
             }),
@@ -92,6 +95,7 @@ impl<T: Networker> RequestSM<T> {
                 pool_name: String,
                 timeout: i64,
                 extended_timeout: i64,
+                protocol_version: usize,
                 state: RequestState<T>) -> Self {
         RequestSM {
             f,
@@ -101,6 +105,7 @@ impl<T: Networker> RequestSM<T> {
             generator,
             timeout,
             extended_timeout,
+            protocol_version,
             state,
         }
     }
@@ -121,6 +126,7 @@ struct CatchupConsensusState<T: Networker> {
     replies: HashMap<(String, usize, Option<Vec<String>>), HashSet<String>>,
     networker: Rc<RefCell<T>>,
     merkle_tree: MerkleTree,
+    ledger_id: usize,
 }
 
 struct CatchupSingleState<T: Networker> {
@@ -129,6 +135,7 @@ struct CatchupSingleState<T: Networker> {
     merkle_tree: MerkleTree,
     networker: Rc<RefCell<T>>,
     req_id: String,
+    ledger_id: usize,
 }
 
 struct SingleState<T: Networker> {
@@ -136,6 +143,7 @@ struct SingleState<T: Networker> {
     replies: HashMap<HashableValue, HashSet<NodeResponse>>,
     timeout_nodes: HashSet<String>,
     networker: Rc<RefCell<T>>,
+    request: SJsonValue,
 }
 
 struct FullState<T: Networker> {
@@ -146,13 +154,14 @@ struct FullState<T: Networker> {
 
 struct FinishState {}
 
-impl<T: Networker> From<StartState<T>> for SingleState<T> {
-    fn from(state: StartState<T>) -> Self {
+impl<T: Networker> From<(SJsonValue, StartState<T>)> for SingleState<T> {
+    fn from((request, state): (SJsonValue, StartState<T>)) -> Self {
         SingleState {
             denied_nodes: HashSet::new(),
             replies: HashMap::new(),
             timeout_nodes: HashSet::new(),
             networker: state.networker.clone(),
+            request,
         }
     }
 }
@@ -168,24 +177,26 @@ impl<T: Networker> From<StartState<T>> for ConsensusState<T> {
     }
 }
 
-impl<T: Networker> From<(MerkleTree, StartState<T>)> for CatchupConsensusState<T> {
-    fn from((merkle_tree, state): (MerkleTree, StartState<T>)) -> Self {
+impl<T: Networker> From<(MerkleTree, StartState<T>, usize)> for CatchupConsensusState<T> {
+    fn from((merkle_tree, state, ledger_id): (MerkleTree, StartState<T>, usize)) -> Self {
         CatchupConsensusState {
             replies: HashMap::new(),
             networker: state.networker.clone(),
             merkle_tree,
+            ledger_id,
         }
     }
 }
 
-impl<T: Networker> From<(MerkleTree, StartState<T>, Vec<u8>, usize, String)> for CatchupSingleState<T> {
-    fn from((merkle_tree, state, target_mt_root, target_mt_size, req_id): (MerkleTree, StartState<T>, Vec<u8>, usize, String)) -> Self {
+impl<T: Networker> From<(MerkleTree, StartState<T>, Vec<u8>, usize, String, usize)> for CatchupSingleState<T> {
+    fn from((merkle_tree, state, target_mt_root, target_mt_size, req_id, ledger_id): (MerkleTree, StartState<T>, Vec<u8>, usize, String, usize)) -> Self {
         CatchupSingleState {
             target_mt_root,
             target_mt_size,
             networker: state.networker.clone(),
             merkle_tree,
             req_id,
+            ledger_id,
         }
     }
 }
@@ -238,23 +249,24 @@ impl Hash for NodeResponse {
 
 impl<T: Networker> RequestSM<T> {
     fn handle_event(self, re: RequestEvent) -> (Self, Option<PoolEvent>) {
-        let RequestSM { state, f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout } = self;
+        let RequestSM { state, f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, protocol_version } = self;
         let (state, event) = match state {
             RequestState::Start(state) => {
                 match re {
                     RequestEvent::LedgerStatus(ls, _, Some(merkle)) => {
                         let req_id = ls.merkleRoot.clone();
+                        let ledger_id = ls.ledgerId;
                         let ne = Some(NetworkerEvent::SendAllRequest(serde_json::to_string(&super::types::Message::LedgerStatus(ls)).expect("FIXME"),
                                                                      req_id, extended_timeout, None));
                         trace!("start catchup, ne: {:?}", ne);
                         state.networker.borrow_mut().process_event(ne);
-                        (RequestState::CatchupConsensus((merkle, state).into()), None)
+                        (RequestState::CatchupConsensus((merkle, state, ledger_id).into()), None)
                     }
-                    RequestEvent::CatchupReq(merkle, target_mt_size, target_mt_root) => {
-                        match build_catchup_req(&merkle, target_mt_size) {
+                    RequestEvent::CatchupReq(merkle, target_mt_size, target_mt_root, ledger_id) => {
+                        match build_catchup_req(&merkle, target_mt_size, ledger_id) {
                             Ok(Some((req_id, req_json))) => {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(req_json, req_id.clone(), timeout)));
-                                (RequestState::CatchupSingle((merkle, state, target_mt_root, target_mt_size, req_id).into()), None)
+                                (RequestState::CatchupSingle((merkle, state, target_mt_root, target_mt_size, req_id, ledger_id).into()), None)
                             }
                             Ok(None) => {
                                 warn!("No transactions to catch up!");
@@ -267,8 +279,9 @@ impl<T: Networker> RequestSM<T> {
                         }
                     }
                     RequestEvent::CustomSingleRequest(msg, req_id) => {
+                        let request = serde_json::from_str(&msg).unwrap_or(SJsonValue::Null);
                         state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(msg, req_id, timeout)));
-                        (RequestState::Single(state.into()), None)
+                        (RequestState::Single((request, state).into()), None)
                     }
                     RequestEvent::CustomFullRequest(msg, req_id, local_timeout, nodes_to_send) => {
                         let timeout = local_timeout.map(|to| to as i64).unwrap_or(extended_timeout);
@@ -390,7 +403,7 @@ impl<T: Networker> RequestSM<T> {
 
                             trace!("Last signed time: {}", last_write_time);
                             if cnt > f
-                                || _check_state_proof(&result, f, &generator, &nodes, &raw_msg)
+                                || _check_state_proof(&result, &state.request, f, &generator, &nodes, &raw_msg, protocol_version)
                                 && (_get_freshness_threshold() == u64::MAX || _get_cur_time() as u64 <= _get_freshness_threshold() + last_write_time) {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
                                 _send_ok_replies(&cmd_ids, if cnt > f { &soonest } else { &raw_msg });
@@ -449,10 +462,23 @@ impl<T: Networker> RequestSM<T> {
             RequestState::CatchupSingle(mut state) => {
                 match re {
                     RequestEvent::CatchupRep(mut cr, node_alias) => {
-                        match _process_catchup_reply(&mut cr, &mut state.merkle_tree, &state.target_mt_root, state.target_mt_size, &pool_name) {
+                        match _process_catchup_reply(&mut cr, &mut state.merkle_tree, &state.target_mt_root, state.target_mt_size, &pool_name, state.ledger_id) {
                             Ok(merkle) => {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(state.req_id.clone(), None)));
-                                (RequestState::finish(), Some(PoolEvent::Synced(merkle)))
+                                match build_catchup_req(&merkle, state.target_mt_size, state.ledger_id) {
+                                    Ok(Some((req_id, req_json))) => {
+                                        trace!("catchup batch is applied, continue with {}", req_id);
+                                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(req_json, req_id.clone(), timeout)));
+                                        state.merkle_tree = merkle;
+                                        state.req_id = req_id;
+                                        (RequestState::CatchupSingle(state), None)
+                                    }
+                                    Ok(None) => (RequestState::finish(), Some(PoolEvent::Synced(merkle))),
+                                    Err(e) => {
+                                        _send_replies(&cmd_ids, Err(e));
+                                        (RequestState::finish(), None)
+                                    }
+                                }
                             }
                             Err(_) => {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(state.req_id.clone(), timeout)));
@@ -493,7 +519,7 @@ impl<T: Networker> RequestSM<T> {
             }
             RequestState::Finish(state) => (RequestState::Finish(state), None)
         };
-        (RequestSM::step(f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, state), event)
+        (RequestSM::step(f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, protocol_version, state), event)
     }
 
     fn is_terminal(&self) -> bool {
@@ -584,7 +610,8 @@ impl<T: Networker> RequestSM<T> {
                                               &state.merkle_tree,
                                               nodes.len(),
                                               f,
-                                              &pool_name) {
+                                              &pool_name,
+                                              state.ledger_id) {
             Ok(CatchupProgress::InProgress) => (false, None),
             Ok(CatchupProgress::NotNeeded(merkle_tree)) => (true, Some(PoolEvent::Synced(merkle_tree))),
             Ok(CatchupProgress::Restart(merkle_tree)) => (false, Some(PoolEvent::CatchupRestart(merkle_tree))),
@@ -596,7 +623,7 @@ impl<T: Networker> RequestSM<T> {
 }
 
 pub trait RequestHandler<T: Networker> {
-    fn new(networker: Rc<RefCell<T>>, f: usize, cmd_ids: &Vec<i32>, nodes: &HashMap<String, Option<VerKey>>, generator: Option<Generator>, pool_name: &str, timeout: i64, extended_timeout: i64) -> Self;
+    fn new(networker: Rc<RefCell<T>>, f: usize, cmd_ids: &Vec<i32>, nodes: &HashMap<String, Option<VerKey>>, generator: Option<Generator>, pool_name: &str, timeout: i64, extended_timeout: i64, protocol_version: usize) -> Self;
     fn process_event(&mut self, ore: Option<RequestEvent>) -> Option<PoolEvent>;
     fn is_terminal(&self) -> bool;
 }
@@ -606,9 +633,9 @@ pub struct RequestHandlerImpl<T: Networker> {
 }
 
 impl<T: Networker> RequestHandler<T> for RequestHandlerImpl<T> {
-    fn new(networker: Rc<RefCell<T>>, f: usize, cmd_ids: &Vec<i32>, nodes: &HashMap<String, Option<VerKey>>, generator: Option<Generator>, pool_name: &str, timeout: i64, extended_timeout: i64) -> Self {
+    fn new(networker: Rc<RefCell<T>>, f: usize, cmd_ids: &Vec<i32>, nodes: &HashMap<String, Option<VerKey>>, generator: Option<Generator>, pool_name: &str, timeout: i64, extended_timeout: i64, protocol_version: usize) -> Self {
         RequestHandlerImpl {
-            request_wrapper: Some(RequestSM::new(networker, f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout)),
+            request_wrapper: Some(RequestSM::new(networker, f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, protocol_version)),
         }
     }
 
@@ -671,9 +698,14 @@ fn _parse_nack(denied_nodes: &mut HashSet<String>, f: usize, raw_msg: &str, cmd_
     }
 }
 
-fn _process_catchup_reply(rep: &mut CatchupRep, merkle: &MerkleTree, target_mt_root: &Vec<u8>, target_mt_size: usize, pool_name: &str) -> IndyResult<MerkleTree> {
+fn _process_catchup_reply(rep: &mut CatchupRep, merkle: &MerkleTree, target_mt_root: &Vec<u8>, target_mt_size: usize, pool_name: &str, ledger_id: usize) -> IndyResult<MerkleTree> {
+    if rep.ledgerId != ledger_id {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Catchup reply for unexpected ledger {}", rep.ledgerId)));
+    }
+
     let mut txns_to_drop = vec![];
     let mut merkle = merkle.clone();
+    let first_seq_no = merkle.count() + 1;
 
     while !rep.txns.is_empty() {
         let key = rep.min_tx()?;
@@ -687,7 +719,14 @@ fn _process_catchup_reply(rep: &mut CatchupRep, merkle: &MerkleTree, target_mt_r
     }
 
     check_cons_proofs(&merkle, &rep.consProof, target_mt_root, target_mt_size)?;
-    merkle_tree_factory::dump_new_txns(pool_name, &txns_to_drop)?;
+
+    if ledger_id == POOL_LEDGER_ID {
+        merkle_tree_factory::dump_new_txns(pool_name, &txns_to_drop)?;
+    } else {
+        LedgerMirror::open(pool_name, ledger_id)?.add_txns(first_seq_no, &txns_to_drop)?;
+        // txns are stored in the mirror, so only hashes are needed to continue catchup
+        merkle.prune()?;
+    }
     Ok(merkle)
 }
 
@@ -724,10 +763,10 @@ fn _get_msg_result_without_state_proof(msg: &str) -> IndyResult<(SJsonValue, SJs
     Ok((msg_result, msg_result_without_proof))
 }
 
-fn _check_state_proof(msg_result: &SJsonValue, f: usize, gen: &Generator, bls_keys: &HashMap<String, Option<VerKey>>, raw_msg: &str) -> bool {
+fn _check_state_proof(msg_result: &SJsonValue, request: &SJsonValue, f: usize, gen: &Generator, bls_keys: &HashMap<String, Option<VerKey>>, raw_msg: &str, protocol_version: usize) -> bool {
     debug!("TransactionHandler::process_reply: Try to verify proof and signature >>");
 
-    let res = match state_proof::parse_generic_reply_for_proof_checking(&msg_result, raw_msg) {
+    let res = match state_proof::parse_generic_reply_for_proof_checking(&msg_result, request, raw_msg, protocol_version) {
         Some(parsed_sps) => {
             debug!("TransactionHandler::process_reply: Proof and signature are present");
             state_proof::verify_parsed_sp(parsed_sps, bls_keys, f, gen)
@@ -769,12 +808,13 @@ pub mod tests {
     const REJECT_REPLY: &'static str = r#"{"op":"REJECT", "result": {"reason": "reject"}}"#;
     const NACK_REPLY: &'static str = r#"{"op":"REQNACK", "result": {"reason": "reqnack"}}"#;
     const POOL: &'static str = "pool1";
+    const TEST_PROTOCOL_VERSION: usize = 2;
 
     #[derive(Debug)]
     pub struct MockRequestHandler {}
 
     impl<T: Networker> RequestHandler<T> for MockRequestHandler {
-        fn new(_networker: Rc<RefCell<T>>, _f: usize, _cmd_ids: &Vec<i32>, _nodes: &HashMap<String, Option<VerKey>>, _generator: Option<Generator>, _pool_name: &str, _timeout: i64, _extended_timeout: i64) -> Self {
+        fn new(_networker: Rc<RefCell<T>>, _f: usize, _cmd_ids: &Vec<i32>, _nodes: &HashMap<String, Option<VerKey>>, _generator: Option<Generator>, _pool_name: &str, _timeout: i64, _extended_timeout: i64, _protocol_version: usize) -> Self {
             MockRequestHandler {}
         }
 
@@ -866,7 +906,8 @@ pub mod tests {
                                 None,
                                 POOL,
                                 0,
-                                0)
+                                0,
+                                TEST_PROTOCOL_VERSION)
     }
 
     // required because of dumping txns to cache
@@ -912,14 +953,14 @@ pub mod tests {
         #[test]
         fn request_handler_process_catchup_req_event_from_start_works() {
             let mut request_handler = _request_handler(0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![], POOL_LEDGER_ID)));
             assert_match!(RequestState::CatchupSingle(_), request_handler.request_wrapper.unwrap().state);
         }
 
         #[test]
         fn request_handler_process_catchup_req_event_from_start_works_for_no_transactions_to_catchup() {
             let mut request_handler = _request_handler(0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 0, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 0, vec![], POOL_LEDGER_ID)));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
@@ -1536,7 +1577,7 @@ pub mod tests {
                 nodes_count: 0,
             };
 
-            request_handler.process_event(Some(RequestEvent::CatchupReq(mt, 2, vec![55, 104, 239, 91, 37, 160, 29, 25, 192, 253, 166, 135, 242, 53, 75, 41, 224, 4, 130, 27, 206, 133, 87, 231, 0, 133, 55, 159, 83, 105, 7, 237])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(mt, 2, vec![55, 104, 239, 91, 37, 160, 29, 25, 192, 253, 166, 135, 242, 53, 75, 41, 224, 4, 130, 27, 206, 133, 87, 231, 0, 133, 55, 159, 83, 105, 7, 237], POOL_LEDGER_ID)));
 
             let mut txns: HashMap<String, SJsonValue> = HashMap::new();
             txns.insert("2".to_string(), serde_json::from_str::<SJsonValue>(r#"{"reqSignature":{},"txn":{"data":{"data":{"alias":"Node2","client_port":9704,"blskey":"37rAPpXVoxzKhz7d9gkUe52XuXryuLXoM6P6LbWDB7LSbG62Lsb33sfG7zqS8TK1MXwuCHj1FKNzVpsnafmqLG1vXN88rt38mNFs9TENzm4QHdBzsvCuoBnPH7rpYYDo9DZNJePaDvRvqJKByCabubJz3XXKbEeshzpz4Ma5QYpJqjk","node_port":9703,"node_ip":"10.0.0.2","services":["VALIDATOR"],"client_ip":"10.0.0.2"},"dest":"8ECVSk179mjsjKRLWiQtssMLgp6EPhWXtaYyStWPSGAb"},"metadata":{"from":"EbP4aYNeTHL6q385GuVpRV"},"type":"0"},"txnMetadata":{"seqNo":2,"txnId":"1ac8aece2a18ced660fef8694b61aac3af08ba875ce3026a160acbc3a3af35fc"},"ver":"1"}"#).unwrap());
@@ -1551,7 +1592,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_catchup_reply_event_from_catchup_single_state_works_for_error() {
             let mut request_handler = _request_handler(0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![], POOL_LEDGER_ID)));
             request_handler.process_event(Some(RequestEvent::CatchupRep(CatchupRep::default(), NODE.to_string())));
             assert_match!(RequestState::CatchupSingle(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1559,7 +1600,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_timeout_event_from_catchup_single_state_works() {
             let mut request_handler = _request_handler(0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![], POOL_LEDGER_ID)));
             request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
             assert_match!(RequestState::CatchupSingle(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1567,7 +1608,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_terminate_event_from_catchup_single_state_works() {
             let mut request_handler = _request_handler(0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![], POOL_LEDGER_ID)));
             request_handler.process_event(Some(RequestEvent::Terminate));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1575,7 +1616,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_other_event_from_catchup_single_state_works() {
             let mut request_handler = _request_handler(0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![], POOL_LEDGER_ID)));
             request_handler.process_event(Some(RequestEvent::Pong));
             assert_match!(RequestState::CatchupSingle(_), request_handler.request_wrapper.unwrap().state);
         }
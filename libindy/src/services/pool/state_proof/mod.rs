@@ -15,8 +15,9 @@ use serde_json;
 use serde_json::Value as SJsonValue;
 
 use api::ErrorCode;
-use domain::ledger::{constants, request::ProtocolVersion};
+use domain::ledger::constants;
 use errors::prelude::*;
+use services::ledger::merkletree::merkletree::MerkleTree;
 use services::pool::events::REQUESTS_FOR_STATE_PROOFS;
 
 use super::PoolService;
@@ -36,7 +37,7 @@ use self::sha3::Digest;
 
 mod node;
 
-pub fn parse_generic_reply_for_proof_checking(json_msg: &SJsonValue, raw_msg: &str) -> Option<Vec<ParsedSP>> {
+pub fn parse_generic_reply_for_proof_checking(json_msg: &SJsonValue, request: &SJsonValue, raw_msg: &str, protocol_version: usize) -> Option<Vec<ParsedSP>> {
     let type_ = if let Some(type_) = json_msg["type"].as_str() {
         trace!("TransactionHandler::parse_generic_reply_for_proof_checking: type_: {:?}", type_);
         type_
@@ -47,7 +48,7 @@ pub fn parse_generic_reply_for_proof_checking(json_msg: &SJsonValue, raw_msg: &s
 
     if REQUESTS_FOR_STATE_PROOFS.contains(&type_) {
         trace!("TransactionHandler::parse_generic_reply_for_proof_checking: built-in");
-        _parse_reply_for_builtin_sp(json_msg, type_)
+        _parse_reply_for_builtin_sp(json_msg, request, type_, protocol_version)
     } else if let Some((parser, free)) = PoolService::get_sp_parser(type_) {
         trace!("TransactionHandler::parse_generic_reply_for_proof_checking: plugged: parser {:?}, free {:?}",
                parser, free);
@@ -83,7 +84,12 @@ pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
                         f: usize,
                         gen: &Generator) -> bool {
     for parsed_sp in parsed_sps {
-        if parsed_sp.multi_signature["value"]["state_root_hash"].as_str().ne(
+        let signed_root_hash = match parsed_sp.kvs_to_verify {
+            KeyValuesInSP::MerkleTree(_) => "txn_root_hash",
+            _ => "state_root_hash"
+        };
+
+        if parsed_sp.multi_signature["value"][signed_root_hash].as_str().ne(
             &Some(&parsed_sp.root_hash)) {
             return false;
         }
@@ -99,10 +105,10 @@ pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
             return false;
         }
 
-        let proof_nodes = unwrap_or_return!(base64::decode(&parsed_sp.proof_nodes), false);
         let root_hash = unwrap_or_return!(parsed_sp.root_hash.from_base58(), false);
         match parsed_sp.kvs_to_verify {
             KeyValuesInSP::Simple(kvs) => {
+                let proof_nodes = unwrap_or_return!(base64::decode(&parsed_sp.proof_nodes), false);
                 for (k, v) in kvs.kvs {
                     let key = unwrap_or_return!(base64::decode(&k), false);
                     if !_verify_proof(proof_nodes.as_slice(),
@@ -113,6 +119,11 @@ pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
                     }
                 }
             }
+            KeyValuesInSP::MerkleTree(data) => {
+                if !_verify_merkle_tree(&parsed_sp.proof_nodes, &root_hash, &data) {
+                    return false;
+                }
+            }
             //TODO IS-713 support KeyValuesInSP::SubTrie
             kvs @ _ => {
                 warn!("Unsupported parsed state proof format for key-values {:?} ", kvs);
@@ -124,11 +135,15 @@ pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
     true
 }
 
-fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str) -> Option<Vec<ParsedSP>> {
+fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, request: &SJsonValue, type_: &str, protocol_version: usize) -> Option<Vec<ParsedSP>> {
     trace!("TransactionHandler::parse_reply_for_builtin_sp: >>> json_msg: {:?}", json_msg);
 
     assert!(REQUESTS_FOR_STATE_PROOFS.contains(&type_));
 
+    if type_ == constants::GET_TXN {
+        return _parse_reply_for_ledger_proof(json_msg, request);
+    }
+
     let proof = if let Some(proof) = json_msg["state_proof"]["proof_nodes"].as_str() {
         trace!("TransactionHandler::parse_reply_for_builtin_sp: proof: {:?}", proof);
         proof
@@ -175,6 +190,8 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str) -> Option<Vec
 
     trace!("TransactionHandler::parse_reply_for_builtin_sp: data: {:?}, parsed_data: {:?}", data, parsed_data);
 
+    let is_node_1_3 = protocol_version == 1;
+
     let key_suffix: String = match type_ {
         constants::GET_ATTR => {
             if let Some(attr_name) = json_msg["raw"].as_str()
@@ -184,7 +201,7 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str) -> Option<Vec
 
                 let mut hasher = sha2::Sha256::default();
                 hasher.process(attr_name.as_bytes());
-                let marker = if ProtocolVersion::is_node_1_3() { '\x01' } else { '1' };
+                let marker = if is_node_1_3 { '\x01' } else { '1' };
                 format!(":{}:{}", marker, hasher.fixed_result().to_hex())
             } else {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_ATTR No key suffix");
@@ -195,8 +212,8 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str) -> Option<Vec
             if let (Some(sign_type), Some(sch_seq_no)) = (json_msg["signature_type"].as_str(),
                                                           json_msg["ref"].as_u64()) {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_CRED_DEF sign_type {:?}, sch_seq_no: {:?}", sign_type, sch_seq_no);
-                let marker = if ProtocolVersion::is_node_1_3() { '\x03' } else { '3' };
-                let tag = if ProtocolVersion::is_node_1_3() { None } else { json_msg["tag"].as_str() };
+                let marker = if is_node_1_3 { '\x03' } else { '3' };
+                let tag = if is_node_1_3 { None } else { json_msg["tag"].as_str() };
                 let tag = tag.map(|t| format!(":{}", t)).unwrap_or("".to_owned());
                 format!(":{}:{}:{}{}", marker, sign_type, sch_seq_no, tag)
             } else {
@@ -212,7 +229,7 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str) -> Option<Vec
             if let (Some(name), Some(ver)) = (parsed_data["name"].as_str(),
                                               parsed_data["version"].as_str()) {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_SCHEMA name {:?}, ver: {:?}", name, ver);
-                let marker = if ProtocolVersion::is_node_1_3() { '\x02' } else { '2' };
+                let marker = if is_node_1_3 { '\x02' } else { '2' };
                 format!(":{}:{}:{}", marker, name, ver)
             } else {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_SCHEMA No key suffix");
@@ -226,7 +243,7 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str) -> Option<Vec
                 parsed_data["revocDefType"].as_str(),
                 parsed_data["tag"].as_str()) {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_REVOC_REG_DEF cred_def_id {:?}, revoc_def_type: {:?}, tag: {:?}", cred_def_id, revoc_def_type, tag);
-                let marker = if ProtocolVersion::is_node_1_3() { '\x04' } else { '4' };
+                let marker = if is_node_1_3 { '\x04' } else { '4' };
                 format!(":{}:{}:{}:{}", marker, cred_def_id, revoc_def_type, tag)
             } else {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_REVOC_REG_DEF No key suffix");
@@ -237,7 +254,7 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str) -> Option<Vec
             //{MARKER}:{REVOC_REG_DEF_ID}
             if let Some(revoc_reg_def_id) = parsed_data["revocRegDefId"].as_str() {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_REVOC_REG revoc_reg_def_id {:?}", revoc_reg_def_id);
-                let marker = if ProtocolVersion::is_node_1_3() { '\x05' } else { '5' };
+                let marker = if is_node_1_3 { '\x05' } else { '5' };
                 format!("{}:{}", marker, revoc_reg_def_id)
             } else {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_REVOC_REG No key suffix");
@@ -249,7 +266,7 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str) -> Option<Vec
             //{MARKER}:{REVOC_REG_DEF_ID}
             if let Some(revoc_reg_def_id) = parsed_data["value"]["accum_to"]["revocRegDefId"].as_str() {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_REVOC_REG_DELTA revoc_reg_def_id {:?}", revoc_reg_def_id);
-                let marker = if ProtocolVersion::is_node_1_3() { '\x06' } else { '6' };
+                let marker = if is_node_1_3 { '\x06' } else { '6' };
                 format!("{}:{}", marker, revoc_reg_def_id)
             } else {
                 trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_REVOC_REG_DELTA No key suffix");
@@ -320,6 +337,104 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str) -> Option<Vec
     }])
 }
 
+fn _parse_reply_for_ledger_proof(json_msg: &SJsonValue, request: &SJsonValue) -> Option<Vec<ParsedSP>> {
+    trace!("TransactionHandler::parse_reply_for_ledger_proof: >>> json_msg: {:?}", json_msg);
+
+    let mut txn = match json_msg["data"] {
+        SJsonValue::Object(ref map) => map.clone(),
+        SJsonValue::String(ref str) => {
+            if let Ok(SJsonValue::Object(map)) = serde_json::from_str(str) {
+                map
+            } else {
+                trace!("TransactionHandler::parse_reply_for_ledger_proof: <<< Data field is invalid json");
+                return None;
+            }
+        }
+        _ => {
+            trace!("TransactionHandler::parse_reply_for_ledger_proof: <<< No transaction");
+            return None;
+        }
+    };
+
+    // Audit path and ledger info are appended by Node to the reply and are not part of the transaction itself
+    let audit_path = if let Some(SJsonValue::Array(audit_path)) = txn.remove("auditPath") {
+        trace!("TransactionHandler::parse_reply_for_ledger_proof: audit_path: {:?}", audit_path);
+        audit_path
+    } else {
+        trace!("TransactionHandler::parse_reply_for_ledger_proof: <<< No audit path");
+        return None;
+    };
+
+    let (root_hash, ledger_size) = match (txn.remove("rootHash"), txn.remove("ledgerSize")) {
+        (Some(SJsonValue::String(root_hash)), Some(ledger_size)) if ledger_size.is_u64() => {
+            trace!("TransactionHandler::parse_reply_for_ledger_proof: root_hash: {:?}, ledger_size: {:?}", root_hash, ledger_size);
+            (root_hash, ledger_size.as_u64().unwrap())
+        }
+        _ => {
+            trace!("TransactionHandler::parse_reply_for_ledger_proof: <<< No root hash or ledger size");
+            return None;
+        }
+    };
+
+    let seq_no = if let Some(seq_no) = txn.get("txnMetadata")
+        .and_then(|metadata| metadata["seqNo"].as_u64())
+        .or(txn.get("seqNo").and_then(SJsonValue::as_u64))
+        .or(json_msg["seqNo"].as_u64()) {
+        seq_no
+    } else {
+        trace!("TransactionHandler::parse_reply_for_ledger_proof: <<< No seq_no");
+        return None;
+    };
+
+    // Echoed fields of the reply are not signed, so the proof is bound to the sent request instead
+    if request["operation"]["data"].as_u64() != Some(seq_no) {
+        trace!("TransactionHandler::parse_reply_for_ledger_proof: <<< seq_no doesn't match requested one");
+        return None;
+    }
+
+    let multi_signature = json_msg["state_proof"]["multi_signature"].clone();
+
+    let ledger_id = request["operation"]["ledgerId"].as_u64();
+    if ledger_id.is_none() || multi_signature["value"]["ledger_id"].as_u64() != ledger_id {
+        trace!("TransactionHandler::parse_reply_for_ledger_proof: <<< ledger_id doesn't match requested one");
+        return None;
+    }
+
+    trace!("parse_reply_for_ledger_proof: <<< seq_no: {:?}, ledger_size: {:?}, root_hash: {:?}", seq_no, ledger_size, root_hash);
+    Some(vec![ParsedSP {
+        root_hash,
+        proof_nodes: SJsonValue::Array(audit_path).to_string(),
+        multi_signature,
+        kvs_to_verify: KeyValuesInSP::MerkleTree(KeyValueMerkleTreeData {
+            seq_no,
+            ledger_size,
+            txn: SJsonValue::Object(txn).to_string(),
+        }),
+    }])
+}
+
+fn _verify_merkle_tree(proof_nodes: &str, root_hash: &[u8], data: &KeyValueMerkleTreeData) -> bool {
+    debug!("verify_merkle_tree >> seq_no {:?}, ledger_size {:?}", data.seq_no, data.ledger_size);
+
+    let audit_path: Vec<String> = unwrap_or_return!(serde_json::from_str(proof_nodes), false);
+    let mut hashes: Vec<Vec<u8>> = Vec::new();
+    for hash in audit_path {
+        hashes.push(unwrap_or_return!(hash.from_base58(), false));
+    }
+
+    let txn: SJsonValue = unwrap_or_return!(serde_json::from_str(&data.txn), false);
+    let leaf = unwrap_or_return!(rmp_serde::to_vec_named(&txn), false);
+
+    let res = MerkleTree::check_inclusion_proof(&root_hash.to_vec(), &leaf,
+                                                data.seq_no as usize, data.ledger_size as usize,
+                                                &hashes)
+        .map_err(|err| warn!("{:?}", err))
+        .unwrap_or(false);
+
+    debug!("verify_merkle_tree << res: {:?}", res);
+    res
+}
+
 fn _parse_reply_for_proof_signature_checking(json_msg: &SJsonValue) -> Option<(&str, Vec<&str>, Vec<u8>)> {
     match (json_msg["signature"].as_str(),
            json_msg["participants"].as_array(),
@@ -422,7 +537,6 @@ fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<String>, par
         }
 
         match xtype {
-            //TODO constants::GET_TXN => check ledger MerkleTree proofs?
             //TODO constants::GET_DDO => support DDO
             constants::GET_NYM => {
                 value["identifier"] = parsed_data["identifier"].clone();
@@ -471,6 +585,8 @@ mod tests {
 
     use self::hex::FromHex;
     use self::libc::c_char;
+    use super::rust_base58::ToBase58;
+    use utils::crypto::hash::Hash;
 
     extern crate hex;
     extern crate libc;
@@ -585,7 +701,9 @@ mod tests {
 
         PoolService::register_sp_parser("test", parse, free).unwrap();
         let mut parsed_sps = super::parse_generic_reply_for_proof_checking(&json!({"type".to_owned(): "test"}),
-                                                                           parsed_sp.to_string().as_str())
+                                                                           &SJsonValue::Null,
+                                                                           parsed_sp.to_string().as_str(),
+                                                                           2)
             .unwrap();
 
         assert_eq!(parsed_sps.len(), 1);
@@ -596,4 +714,126 @@ mod tests {
         assert_eq!(parsed_sp.kvs_to_verify,
                    KeyValuesInSP::Simple(KeyValueSimpleData { kvs: Vec::new() }));
     }
+
+    fn _get_txn_reply(seq_no: u64, audit_path: Vec<String>, ledger_size: u64, root_hash: &str) -> SJsonValue {
+        json!({
+            "type": constants::GET_TXN,
+            "seqNo": seq_no,
+            "data": {
+                "txn": {"type": "1", "data": {"dest": format!("did{}", seq_no)}},
+                "txnMetadata": {"seqNo": seq_no},
+                "ver": "1",
+                "auditPath": audit_path,
+                "ledgerSize": ledger_size,
+                "rootHash": root_hash,
+            },
+            "state_proof": {
+                "multi_signature": {"value": {"ledger_id": 1, "txn_root_hash": root_hash}}
+            }
+        })
+    }
+
+    fn _get_txn_request(seq_no: u64, ledger_id: u64) -> SJsonValue {
+        json!({
+            "operation": {"type": constants::GET_TXN, "data": seq_no, "ledgerId": ledger_id}
+        })
+    }
+
+    fn _txn(seq_no: u64) -> Vec<u8> {
+        let txn = json!({
+            "txn": {"type": "1", "data": {"dest": format!("did{}", seq_no)}},
+            "txnMetadata": {"seqNo": seq_no},
+            "ver": "1",
+        });
+        rmp_serde::to_vec_named(&txn).unwrap()
+    }
+
+    #[test]
+    fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_get_txn() {
+        let reply = _get_txn_reply(2, vec!["path1".to_string(), "path2".to_string()], 3, "rh");
+
+        let mut parsed_sps = super::parse_generic_reply_for_proof_checking(&reply, &_get_txn_request(2, 1), &reply.to_string(), 2).unwrap();
+
+        assert_eq!(parsed_sps.len(), 1);
+        let parsed_sp = parsed_sps.remove(0);
+        assert_eq!(parsed_sp.root_hash, "rh");
+        assert_eq!(parsed_sp.multi_signature, json!({"value": {"ledger_id": 1, "txn_root_hash": "rh"}}));
+        assert_eq!(parsed_sp.proof_nodes, json!(["path1", "path2"]).to_string());
+        match parsed_sp.kvs_to_verify {
+            KeyValuesInSP::MerkleTree(data) => {
+                assert_eq!(data.seq_no, 2);
+                assert_eq!(data.ledger_size, 3);
+                let txn: SJsonValue = serde_json::from_str(&data.txn).unwrap();
+                assert!(txn.get("auditPath").is_none());
+                assert!(txn.get("ledgerSize").is_none());
+                assert!(txn.get("rootHash").is_none());
+                assert_eq!(txn["txnMetadata"]["seqNo"], 2);
+            }
+            kvs @ _ => panic!("Unexpected kvs {:?}", kvs)
+        }
+    }
+
+    #[test]
+    fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_get_txn_without_data() {
+        let reply = json!({"type": constants::GET_TXN, "seqNo": 2, "data": null});
+        assert!(super::parse_generic_reply_for_proof_checking(&reply, &_get_txn_request(2, 1), &reply.to_string(), 2).is_none());
+    }
+
+    #[test]
+    fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_get_txn_without_audit_path() {
+        let mut reply = _get_txn_reply(2, vec![], 3, "rh");
+        reply["data"].as_object_mut().unwrap().remove("auditPath");
+        assert!(super::parse_generic_reply_for_proof_checking(&reply, &_get_txn_request(2, 1), &reply.to_string(), 2).is_none());
+    }
+
+    #[test]
+    fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_get_txn_for_other_seq_no() {
+        let reply = _get_txn_reply(2, vec!["path1".to_string(), "path2".to_string()], 3, "rh");
+        assert!(super::parse_generic_reply_for_proof_checking(&reply, &_get_txn_request(3, 1), &reply.to_string(), 2).is_none());
+    }
+
+    #[test]
+    fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_get_txn_for_other_ledger_id() {
+        let reply = _get_txn_reply(2, vec!["path1".to_string(), "path2".to_string()], 3, "rh");
+        assert!(super::parse_generic_reply_for_proof_checking(&reply, &_get_txn_request(2, 0), &reply.to_string(), 2).is_none());
+    }
+
+    #[test]
+    fn state_proof_verify_merkle_tree_works() {
+        let tree = MerkleTree::from_vec(vec![_txn(1), _txn(2), _txn(3)]).unwrap();
+        let root_hash = tree.root_hash().to_base58();
+        let audit_path = vec![Hash::hash_leaf(&_txn(1)).unwrap().to_vec().to_base58(),
+                              Hash::hash_leaf(&_txn(3)).unwrap().to_vec().to_base58()];
+
+        let reply = _get_txn_reply(2, audit_path, 3, &root_hash);
+        let mut parsed_sps = super::parse_generic_reply_for_proof_checking(&reply, &_get_txn_request(2, 1), &reply.to_string(), 2).unwrap();
+        let parsed_sp = parsed_sps.remove(0);
+
+        match parsed_sp.kvs_to_verify {
+            KeyValuesInSP::MerkleTree(ref data) => {
+                assert!(_verify_merkle_tree(&parsed_sp.proof_nodes, tree.root_hash(), data));
+            }
+            ref kvs @ _ => panic!("Unexpected kvs {:?}", kvs)
+        }
+    }
+
+    #[test]
+    fn state_proof_verify_merkle_tree_works_for_tampered_txn() {
+        let tree = MerkleTree::from_vec(vec![_txn(1), _txn(2), _txn(3)]).unwrap();
+        let root_hash = tree.root_hash().to_base58();
+        let audit_path = vec![Hash::hash_leaf(&_txn(1)).unwrap().to_vec().to_base58(),
+                              Hash::hash_leaf(&_txn(3)).unwrap().to_vec().to_base58()];
+
+        let mut reply = _get_txn_reply(2, audit_path, 3, &root_hash);
+        reply["data"]["txn"]["data"]["dest"] = json!("did42");
+        let mut parsed_sps = super::parse_generic_reply_for_proof_checking(&reply, &_get_txn_request(2, 1), &reply.to_string(), 2).unwrap();
+        let parsed_sp = parsed_sps.remove(0);
+
+        match parsed_sp.kvs_to_verify {
+            KeyValuesInSP::MerkleTree(ref data) => {
+                assert!(!_verify_merkle_tree(&parsed_sp.proof_nodes, tree.root_hash(), data));
+            }
+            ref kvs @ _ => panic!("Unexpected kvs {:?}", kvs)
+        }
+    }
 }
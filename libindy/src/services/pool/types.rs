@@ -208,7 +208,7 @@ impl NodeTransactionV1 {
 pub struct LedgerStatus {
     pub txnSeqNo: usize,
     pub merkleRoot: String,
-    pub ledgerId: u8,
+    pub ledgerId: usize,
     pub ppSeqNo: Option<u32>,
     pub viewNo: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -415,15 +415,17 @@ pub struct ParsedSP {
 
 /**
  Variants of representation for items to verify against SP Trie
- Right now 2 options are specified:
+ Right now 3 options are specified:
  - simple array of key-value pair
  - whole subtrie
+ - transaction included into ledger merkle tree
 */
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
 #[serde(tag = "type")]
 pub enum KeyValuesInSP {
     Simple(KeyValueSimpleData),
     SubTrie(KeyValuesSubTrieData),
+    MerkleTree(KeyValueMerkleTreeData),
 }
 
 /**
@@ -450,6 +452,23 @@ pub struct KeyValuesSubTrieData {
     pub kvs: Vec<(String /* b64-encoded key_suffix */, Option<String /* val */>)>,
 }
 
+/**
+ Merkle tree variant of `KeyValuesInSP`.
+
+ Used for replies about ledger transactions (GET_TXN). In this case `proof_nodes` of parent `ParsedSP`
+ is json array of base58-encoded hashes (audit path) and `root_hash` is the ledger root hash
+ which should be same with `txn_root_hash` in BLS MS data.
+*/
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub struct KeyValueMerkleTreeData {
+    /// sequence number of the transaction in the ledger
+    pub seq_no: u64,
+    /// size of the ledger the audit path is built for
+    pub ledger_size: u64,
+    /// transaction json as it's stored in the ledger
+    pub txn: String,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct RemoteNode {
     pub name: String,
@@ -25,6 +25,17 @@ pub fn decode_urlsafe(doc: &str) -> Result<Vec<u8>, IndyError> {
         .map_err(|err| err.into())
 }
 
+pub fn encode_urlsafe_nopad(doc: &[u8]) -> String {
+    base64::encode_config(doc, base64::URL_SAFE_NO_PAD)
+}
+
+pub fn decode_urlsafe_nopad(doc: &str) -> Result<Vec<u8>, IndyError> {
+    base64::decode_config(doc, base64::URL_SAFE_NO_PAD)
+        .context("Invalid base64URL_SAFE_NO_PAD sequence")
+        .context(IndyErrorKind::InvalidStructure)
+        .map_err(|err| err.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,4 +67,13 @@ mod tests {
         assert!(result.is_ok(), "Got error");
         assert_eq!(&[1, 2, 3], &result.unwrap()[..]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn encode_decode_urlsafe_nopad_works() {
+        let result = encode_urlsafe_nopad(&[1, 2, 3, 4]);
+        assert_eq!("AQIDBA", &result);
+
+        let result = decode_urlsafe_nopad("AQIDBA");
+        assert_eq!(&[1, 2, 3, 4], &result.unwrap()[..]);
+    }
+}
@@ -3,6 +3,8 @@ extern crate sodiumoxide;
 
 use errors::prelude::*;
 use self::sodiumoxide::crypto::box_;
+use self::sodiumoxide::crypto::scalarmult::curve25519;
+use utils::crypto::randombytes::randombytes;
 
 
 pub const NONCEBYTES: usize = box_::curve25519xsalsa20poly1305::NONCEBYTES;
@@ -36,6 +38,21 @@ pub fn gen_nonce() -> Nonce {
     Nonce(box_::gen_nonce())
 }
 
+/// Creates X25519 key agreement key pair. Seed bytes are used as secret scalar.
+pub fn create_key_pair(seed: Option<&[u8]>) -> Result<(PublicKey, SecretKey), IndyError> {
+    let sk = match seed {
+        Some(seed) => SecretKey::from_slice(seed)?,
+        None => SecretKey::from_slice(&randombytes(SECRETKEYBYTES))?
+    };
+
+    let scalar = curve25519::Scalar::from_slice(&sk[..])
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid bytes for X25519 SecretKey"))?;
+
+    let pk = PublicKey::from_slice(&curve25519::scalarmult_base(&scalar)[..])?;
+
+    Ok((pk, sk))
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -43,6 +60,31 @@ mod tests {
     use utils::crypto::ed25519_sign;
     use utils::crypto::randombytes::randombytes;
 
+    #[test]
+    fn create_key_pair_works_for_seed() {
+        let seed = randombytes(32);
+
+        let (pk1, sk1) = create_key_pair(Some(&seed)).unwrap();
+        let (pk2, sk2) = create_key_pair(Some(&seed)).unwrap();
+
+        assert_eq!(pk1, pk2);
+        assert_eq!(sk1, sk2);
+    }
+
+    #[test]
+    fn encrypt_decrypt_works_for_x25519_keys() {
+        let text = randombytes(16);
+        let nonce = gen_nonce();
+
+        let (alice_pk, alice_sk) = create_key_pair(None).unwrap();
+        let (bob_pk, bob_sk) = create_key_pair(None).unwrap();
+
+        let encrypted_text = encrypt(&alice_sk, &bob_pk, &text, &nonce).unwrap();
+        let decrypted_text = decrypt(&bob_sk, &alice_pk, &encrypted_text, &nonce).unwrap();
+
+        assert_eq!(text, decrypted_text);
+    }
+
     #[test]
     fn encrypt_decrypt_works() {
         let text = randombytes(16);
@@ -32,6 +32,18 @@ pub mod chacha20poly1305_ietf;
 pub mod hash;
 pub mod signature_serializer;
 
+#[cfg(feature = "secp256k1_openssl")]
+#[path = "secp256k1/openssl.rs"]
+pub mod secp256k1;
+
+#[cfg(feature = "pkcs8_openssl")]
+#[path = "pkcs8/openssl.rs"]
+pub mod pkcs8;
+
+#[cfg(feature = "slip10_openssl")]
+#[path = "slip10/openssl.rs"]
+pub mod slip10;
+
 pub mod verkey_builder;
 
 #[cfg(feature = "sealedbox_sodium")]
@@ -0,0 +1,193 @@
+extern crate openssl;
+
+use errors::prelude::*;
+use self::openssl::bn::{BigNum, BigNumContext, BigNumRef};
+use self::openssl::ec::{EcGroup, EcKey, EcPoint, PointConversionForm};
+use self::openssl::ecdsa::EcdsaSig;
+use self::openssl::nid::Nid;
+use self::openssl::sha::sha256;
+
+pub const SECRETKEYBYTES: usize = 32;
+pub const PUBLICKEYBYTES: usize = 33;
+pub const SIGNATUREBYTES: usize = 64;
+
+/// Creates key pair. Public key is returned in compressed SEC1 form.
+pub fn create_key_pair(seed: Option<&[u8]>) -> Result<(Vec<u8>, Vec<u8>), IndyError> {
+    let group = EcGroup::from_curve_name(Nid::SECP256K1)?;
+    let mut ctx = BigNumContext::new()?;
+
+    let mut order = BigNum::new()?;
+    group.order(&mut order, &mut ctx)?;
+
+    let sk = match seed {
+        Some(seed) => {
+            let sk = BigNum::from_slice(seed)?;
+
+            if sk.num_bits() == 0 || sk >= order {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Seed is out of secp256k1 private key range"));
+            }
+
+            sk
+        }
+        None => {
+            let mut sk = BigNum::new()?;
+
+            while sk.num_bits() == 0 {
+                order.rand_range(&mut sk)?;
+            }
+
+            sk
+        }
+    };
+
+    let mut pk = EcPoint::new(&group)?;
+    pk.mul_generator(&group, &sk, &ctx)?;
+
+    let pk = pk.to_bytes(&group, PointConversionForm::COMPRESSED, &mut ctx)?;
+    let sk = _to_bytes_padded(&sk, SECRETKEYBYTES)?;
+
+    Ok((pk, sk))
+}
+
+/// Creates ECDSA signature over SHA-256 of the document.
+/// Signature is returned as r || s with low s value.
+pub fn sign(secret_key: &[u8], doc: &[u8]) -> Result<Vec<u8>, IndyError> {
+    let group = EcGroup::from_curve_name(Nid::SECP256K1)?;
+    let mut ctx = BigNumContext::new()?;
+
+    if secret_key.len() != SECRETKEYBYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid bytes for secp256k1 SecretKey"));
+    }
+
+    let sk = BigNum::from_slice(secret_key)?;
+
+    let mut pk = EcPoint::new(&group)?;
+    pk.mul_generator(&group, &sk, &ctx)?;
+
+    let key = EcKey::from_private_components(&group, &sk, &pk)?;
+
+    let signature = EcdsaSig::sign(&sha256(doc), &key)?;
+
+    let mut order = BigNum::new()?;
+    group.order(&mut order, &mut ctx)?;
+
+    let mut half_order = BigNum::new()?;
+    half_order.rshift1(&order)?;
+
+    let mut s = BigNum::new()?;
+    if signature.s() > &half_order {
+        s.checked_sub(&order, signature.s())?;
+    } else {
+        s.checked_add(signature.s(), &BigNum::new()?)?;
+    }
+
+    let mut res = _to_bytes_padded(signature.r(), SIGNATUREBYTES / 2)?;
+    res.extend(_to_bytes_padded(&s, SIGNATUREBYTES / 2)?);
+
+    Ok(res)
+}
+
+pub fn verify(public_key: &[u8], doc: &[u8], signature: &[u8]) -> Result<bool, IndyError> {
+    if signature.len() != SIGNATUREBYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid bytes for secp256k1 Signature"));
+    }
+
+    let key = _public_key(public_key)?;
+
+    let r = BigNum::from_slice(&signature[..SIGNATUREBYTES / 2])?;
+    let s = BigNum::from_slice(&signature[SIGNATUREBYTES / 2..])?;
+    let signature = EcdsaSig::from_private_components(r, s)?;
+
+    Ok(signature.verify(&sha256(doc), &key)?)
+}
+
+pub fn validate_public_key(public_key: &[u8]) -> Result<(), IndyError> {
+    _public_key(public_key).map(|_| ())
+}
+
+fn _public_key(public_key: &[u8]) -> Result<EcKey<self::openssl::pkey::Public>, IndyError> {
+    if public_key.len() != PUBLICKEYBYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid bytes for secp256k1 PublicKey"));
+    }
+
+    let group = EcGroup::from_curve_name(Nid::SECP256K1)?;
+    let mut ctx = BigNumContext::new()?;
+
+    let point = EcPoint::from_bytes(&group, public_key, &mut ctx)
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid bytes for secp256k1 PublicKey")?;
+
+    Ok(EcKey::from_public_key(&group, &point)?)
+}
+
+fn _to_bytes_padded(n: &BigNumRef, len: usize) -> Result<Vec<u8>, IndyError> {
+    let bytes = n.to_vec();
+
+    if bytes.len() > len {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Number is too big to be serialized"));
+    }
+
+    let mut res = vec![0u8; len - bytes.len()];
+    res.extend(bytes);
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::crypto::randombytes::randombytes;
+
+    #[test]
+    fn create_key_pair_works() {
+        let (pk, sk) = create_key_pair(None).unwrap();
+        assert_eq!(PUBLICKEYBYTES, pk.len());
+        assert_eq!(SECRETKEYBYTES, sk.len());
+    }
+
+    #[test]
+    fn create_key_pair_works_for_seed() {
+        let seed = "00000000000000000000000000000My1".as_bytes();
+
+        let (pk1, sk1) = create_key_pair(Some(seed)).unwrap();
+        let (pk2, sk2) = create_key_pair(Some(seed)).unwrap();
+
+        assert_eq!(pk1, pk2);
+        assert_eq!(sk1, sk2);
+        assert_eq!(seed.to_vec(), sk1);
+    }
+
+    #[test]
+    fn create_key_pair_fails_for_zero_seed() {
+        let res = create_key_pair(Some(&[0u8; 32]));
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn sign_verify_works() {
+        let text = randombytes(16);
+        let (pk, sk) = create_key_pair(None).unwrap();
+
+        let signature = sign(&sk, &text).unwrap();
+        assert_eq!(SIGNATUREBYTES, signature.len());
+
+        assert!(verify(&pk, &text, &signature).unwrap());
+        assert!(!verify(&pk, &randombytes(16), &signature).unwrap());
+    }
+
+    #[test]
+    fn verify_works_for_other_key() {
+        let text = randombytes(16);
+        let (_, sk) = create_key_pair(None).unwrap();
+        let (other_pk, _) = create_key_pair(None).unwrap();
+
+        let signature = sign(&sk, &text).unwrap();
+
+        assert!(!verify(&other_pk, &text, &signature).unwrap());
+    }
+
+    #[test]
+    fn validate_public_key_fails_for_invalid_point() {
+        let res = validate_public_key(&[0x05; PUBLICKEYBYTES]);
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,124 @@
+extern crate openssl;
+
+use errors::prelude::*;
+use self::openssl::hash::MessageDigest;
+use self::openssl::pkey::PKey;
+use self::openssl::sign::Signer;
+
+pub const MIN_SEEDBYTES: usize = 16;
+pub const MAX_SEEDBYTES: usize = 64;
+pub const KEYBYTES: usize = 32;
+
+const ED25519_CURVE: &'static [u8] = b"ed25519 seed";
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// Derives ed25519 private key (seed) from the master seed by SLIP-0010 derivation path
+/// like "m/44'/0'/1'". ed25519 supports only hardened derivation, so each index must
+/// be marked with "'" or "h" suffix.
+pub fn derive_ed25519_key(seed: &[u8], path: &str) -> IndyResult<Vec<u8>> {
+    if seed.len() < MIN_SEEDBYTES || seed.len() > MAX_SEEDBYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid master seed length"));
+    }
+
+    let indexes = parse_path(path)?;
+
+    let (mut key, mut chain_code) = _split(&_hmac_sha512(ED25519_CURVE, &[seed])?);
+
+    for index in indexes {
+        let data = _hmac_sha512(&chain_code, &[&[0x00], &key, &_be_bytes(index)])?;
+        let (child_key, child_chain_code) = _split(&data);
+        key = child_key;
+        chain_code = child_chain_code;
+    }
+
+    Ok(key)
+}
+
+pub fn parse_path(path: &str) -> IndyResult<Vec<u32>> {
+    let mut parts = path.trim().split('/');
+
+    if parts.next() != Some("m") {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Derivation path must start with \"m\": {}", path)));
+    }
+
+    parts
+        .map(|part| {
+            let index = if part.ends_with('\'') || part.ends_with('h') || part.ends_with('H') {
+                &part[..part.len() - 1]
+            } else {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Only hardened derivation is supported: {}", path)));
+            };
+
+            let index = index.parse::<u32>()
+                .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid derivation path index: {}", part))?;
+
+            if index >= HARDENED_OFFSET {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Derivation path index is too big: {}", part)));
+            }
+
+            Ok(index + HARDENED_OFFSET)
+        })
+        .collect()
+}
+
+fn _hmac_sha512(key: &[u8], data: &[&[u8]]) -> IndyResult<Vec<u8>> {
+    let key = PKey::hmac(key)?;
+    let mut signer = Signer::new(MessageDigest::sha512(), &key)?;
+
+    for chunk in data {
+        signer.update(chunk)?;
+    }
+
+    Ok(signer.sign_to_vec()?)
+}
+
+fn _split(data: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    (data[..KEYBYTES].to_vec(), data[KEYBYTES..].to_vec())
+}
+
+fn _be_bytes(value: u32) -> [u8; 4] {
+    [(value >> 24) as u8, (value >> 16) as u8, (value >> 8) as u8, value as u8]
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate hex;
+
+    use super::*;
+    use self::hex::FromHex;
+
+    // SLIP-0010 test vector 1 for ed25519
+    const SEED: &'static str = "000102030405060708090a0b0c0d0e0f";
+
+    #[test]
+    fn derive_ed25519_key_works_for_master() {
+        let seed = Vec::from_hex(SEED).unwrap();
+        let key = derive_ed25519_key(&seed, "m").unwrap();
+        assert_eq!(Vec::from_hex("2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7").unwrap(), key);
+    }
+
+    #[test]
+    fn derive_ed25519_key_works_for_hardened_path() {
+        let seed = Vec::from_hex(SEED).unwrap();
+
+        let key = derive_ed25519_key(&seed, "m/0'").unwrap();
+        assert_eq!(Vec::from_hex("68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3").unwrap(), key);
+
+        let key = derive_ed25519_key(&seed, "m/0h/1h").unwrap();
+        assert_eq!(Vec::from_hex("b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2").unwrap(), key);
+    }
+
+    #[test]
+    fn derive_ed25519_key_works_for_non_hardened_path() {
+        let seed = Vec::from_hex(SEED).unwrap();
+        let res = derive_ed25519_key(&seed, "m/0'/1");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn parse_path_works_for_invalid_path() {
+        assert_kind!(IndyErrorKind::InvalidStructure, parse_path("0'/1'"));
+        assert_kind!(IndyErrorKind::InvalidStructure, parse_path("m/a'"));
+        assert_kind!(IndyErrorKind::InvalidStructure, parse_path("m/2147483648'"));
+    }
+}
@@ -1,11 +1,63 @@
+use std::cmp;
+use std::sync::Mutex;
 use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 
+use time;
+
 lazy_static! {
     static ref IDS_COUNTER: AtomicUsize = ATOMIC_USIZE_INIT; //TODO use AtomicI32
+    static ref LAST_REQ_ID: Mutex<u64> = Mutex::new(0);
 }
 
 pub fn get_next_id() -> i32 {
     (IDS_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32
 }
 
+// Ledger request id is based on the current time in nanoseconds, but it's bumped if the clock is coarse
+// or goes back, so ids are strictly increasing and unique within the process.
+// Ids set by callers don't affect the generator, so the last id always stays close to the current time.
+pub fn get_next_req_id() -> u64 {
+    let now = time::get_time();
+    let now = now.sec as u64 * 1_000_000_000 + now.nsec as u64;
+
+    // the value is always consistent, so the lock poisoned by a panic in other thread can be reused
+    let mut last_req_id = LAST_REQ_ID.lock().unwrap_or_else(|err| err.into_inner());
+    *last_req_id = cmp::max(now, *last_req_id + 1);
+    *last_req_id
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn get_next_req_id_works_for_concurrent_calls() {
+        let threads = (0..4)
+            .map(|_| thread::spawn(|| (0..1000).map(|_| get_next_req_id()).collect::<Vec<u64>>()))
+            .collect::<Vec<_>>();
 
+        let mut req_ids = HashSet::new();
+        for thread in threads {
+            let ids = thread.join().unwrap();
+            assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+            req_ids.extend(ids);
+        }
+
+        assert_eq!(4000, req_ids.len());
+    }
+
+    #[test]
+    fn get_next_req_id_works_for_poisoned_lock() {
+        let req_id = get_next_req_id();
+
+        let _ = thread::spawn(|| {
+            let _last_req_id = LAST_REQ_ID.lock().unwrap();
+            panic!("poison the lock");
+        }).join();
+
+        assert!(get_next_req_id() > req_id);
+    }
+}
@@ -224,6 +224,117 @@ mod high_cases {
         }
     }
 
+    mod issuer_create_credential_with_options {
+        use super::*;
+        use indy::IndyError;
+
+        fn _new_credential_offer_and_request(wallet_handle: i32, nonce_expires_at: Option<u64>) -> (String, String) {
+            let (credential_def_json, _, _, _) = anoncreds::init_common_wallet();
+
+            let credential_offer = match nonce_expires_at {
+                Some(nonce_expires_at) => anoncreds::issuer_create_credential_offer_with_nonce_tracking(wallet_handle, &anoncreds::issuer_1_gvt_cred_def_id(), nonce_expires_at).unwrap(),
+                None => anoncreds::issuer_create_credential_offer(wallet_handle, &anoncreds::issuer_1_gvt_cred_def_id()).unwrap()
+            };
+
+            let (credential_req, _) = anoncreds::prover_create_credential_req(wallet_handle,
+                                                                              DID_MY1,
+                                                                              &credential_offer,
+                                                                              credential_def_json,
+                                                                              COMMON_MASTER_SECRET).unwrap();
+            (credential_offer, credential_req)
+        }
+
+        fn _create_credential(wallet_handle: i32, credential_offer: &str, credential_req: &str) -> Result<(String, Option<String>, Option<String>), IndyError> {
+            anoncreds::issuer_create_credential_with_options(wallet_handle,
+                                                             credential_offer,
+                                                             credential_req,
+                                                             &anoncreds::gvt_credential_values_json(),
+                                                             None,
+                                                             None,
+                                                             &anoncreds::offer_nonce_tracking_options())
+        }
+
+        #[test]
+        fn issuer_create_credential_with_options_works() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let (credential_offer, credential_req) = _new_credential_offer_and_request(wallet_handle, Some(anoncreds::NONCE_EXPIRES_AT));
+
+            _create_credential(wallet_handle, &credential_offer, &credential_req).unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_create_credential_with_options_works_for_replayed_offer() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let (credential_offer, credential_req) = _new_credential_offer_and_request(wallet_handle, Some(anoncreds::NONCE_EXPIRES_AT));
+
+            _create_credential(wallet_handle, &credential_offer, &credential_req).unwrap();
+
+            let res = _create_credential(wallet_handle, &credential_offer, &credential_req);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_create_credential_with_options_works_for_untracked_offer() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let (credential_offer, credential_req) = _new_credential_offer_and_request(wallet_handle, None);
+
+            let res = _create_credential(wallet_handle, &credential_offer, &credential_req);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_create_credential_offer_with_nonce_tracking_works_for_expired_offer() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::issuer_create_credential_offer_with_nonce_tracking(wallet_handle, &anoncreds::issuer_1_gvt_cred_def_id(), anoncreds::NONCE_EXPIRED_AT);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_create_credential_works_for_offer_without_nonce_tracking() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let (credential_offer, credential_req) = _new_credential_offer_and_request(wallet_handle, None);
+
+            anoncreds::issuer_create_credential(wallet_handle,
+                                                &credential_offer,
+                                                &credential_req,
+                                                &anoncreds::gvt_credential_values_json(),
+                                                None,
+                                                None).unwrap();
+
+            anoncreds::issuer_create_credential(wallet_handle,
+                                                &credential_offer,
+                                                &credential_req,
+                                                &anoncreds::gvt_credential_values_json(),
+                                                None,
+                                                None).unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
     mod prover_store_credential {
         use super::*;
 
@@ -3121,6 +3232,435 @@ mod high_cases {
             assert!(!valid);
         }
     }
+
+    mod verifier_verify_proof_with_nonce_tracking {
+        use super::*;
+        use indy::IndyError;
+
+        fn _verify_proof(wallet_handle: i32, proof_json: &str) -> Result<bool, IndyError> {
+            anoncreds::verifier_verify_proof_with_nonce_tracking(wallet_handle,
+                                                                 &anoncreds::proof_request_attr(),
+                                                                 proof_json,
+                                                                 &anoncreds::schemas_for_proof(),
+                                                                 &anoncreds::cred_defs_for_proof(),
+                                                                 "{}",
+                                                                 "{}")
+        }
+
+        fn _track_nonce(wallet_handle: i32, nonce_expires_at: u64) -> Result<(), IndyError> {
+            anoncreds::verifier_track_proof_request_nonce(wallet_handle, &anoncreds::proof_request_attr(), nonce_expires_at)
+        }
+
+        fn _now() -> u64 {
+            time::get_time().sec as u64
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_nonce_tracking_works() {
+            let wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            _track_nonce(wallet_handle, anoncreds::NONCE_EXPIRES_AT).unwrap();
+
+            let valid = _verify_proof(wallet_handle, &anoncreds::proof_json()).unwrap();
+            assert!(valid);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_nonce_tracking_works_for_replayed_proof() {
+            let wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            _track_nonce(wallet_handle, anoncreds::NONCE_EXPIRES_AT).unwrap();
+
+            let valid = _verify_proof(wallet_handle, &anoncreds::proof_json()).unwrap();
+            assert!(valid);
+
+            let res = _verify_proof(wallet_handle, &anoncreds::proof_json());
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_nonce_tracking_works_for_untracked_proof_request() {
+            let wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            let res = _verify_proof(wallet_handle, &anoncreds::proof_json());
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn verifier_track_proof_request_nonce_works_for_expired_proof_request() {
+            let wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            let res = _track_nonce(wallet_handle, anoncreds::NONCE_EXPIRED_AT);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_nonce_tracking_works_for_replayed_proof_after_purge() {
+            let wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            _track_nonce(wallet_handle, _now() + 1).unwrap();
+
+            let valid = _verify_proof(wallet_handle, &anoncreds::proof_json()).unwrap();
+            assert!(valid);
+
+            ::std::thread::sleep(::std::time::Duration::from_secs(3));
+
+            // tracking of another proof request purges the expired nonce
+            let mut other_proof_request: serde_json::Value = serde_json::from_str(&anoncreds::proof_request_attr()).unwrap();
+            other_proof_request["nonce"] = json!("123432421213");
+            anoncreds::verifier_track_proof_request_nonce(wallet_handle, &other_proof_request.to_string(), anoncreds::NONCE_EXPIRES_AT).unwrap();
+
+            let res = _verify_proof(wallet_handle, &anoncreds::proof_json());
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_nonce_tracking_works_for_wrong_proof_does_not_consume_nonce() {
+            let wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            _track_nonce(wallet_handle, anoncreds::NONCE_EXPIRES_AT).unwrap();
+
+            let proof_json = anoncreds::proof_json().replace("1139481716457488690172217916278103335", "1111111111111111111111111111111111111");
+
+            let valid = _verify_proof(wallet_handle, &proof_json).unwrap();
+            assert!(!valid);
+
+            let valid = _verify_proof(wallet_handle, &anoncreds::proof_json()).unwrap();
+            assert!(valid);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
+    mod prover_get_credential_w3c {
+        use super::*;
+
+        #[test]
+        fn prover_get_credential_w3c_works() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let w3c_credential_json = anoncreds::prover_get_credential_w3c(wallet_handle, CREDENTIAL1_ID).unwrap();
+            let w3c_credential: serde_json::Value = serde_json::from_str(&w3c_credential_json).unwrap();
+
+            assert_eq!(json!(format!("did:sov:{}", ISSUER_DID)), w3c_credential["issuer"]);
+            assert!(time::strptime(w3c_credential["issuanceDate"].as_str().unwrap(), "%Y-%m-%dT%H:%M:%SZ").is_ok());
+            assert!(w3c_credential["credentialSubject"]["id"].as_str().unwrap().starts_with("urn:uuid:"));
+            assert_eq!(json!("Alex"), w3c_credential["credentialSubject"]["name"]);
+            assert_eq!(json!(anoncreds::issuer_1_gvt_cred_def_id()), w3c_credential["proof"]["credentialDefinition"]);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_get_credential_w3c_works_for_not_found() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::prover_get_credential_w3c(wallet_handle, "other_cred_id");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
+    mod w3c_presentation {
+        use super::*;
+
+        fn _verify_proof(proof_json: &str) -> Result<bool, indy::IndyError> {
+            anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
+                                             proof_json,
+                                             &anoncreds::schemas_for_proof(),
+                                             &anoncreds::cred_defs_for_proof(),
+                                             "{}",
+                                             "{}")
+        }
+
+        #[test]
+        fn w3c_presentation_works_for_export_parse_verify() {
+            let w3c_presentation_json = anoncreds::prover_proof_to_w3c(&anoncreds::proof_request_attr(), &anoncreds::proof_json()).unwrap();
+
+            let w3c_presentation: serde_json::Value = serde_json::from_str(&w3c_presentation_json).unwrap();
+            let w3c_credential = &w3c_presentation["verifiableCredential"][0];
+            assert!(time::strptime(w3c_credential["issuanceDate"].as_str().unwrap(), "%Y-%m-%dT%H:%M:%SZ").is_ok());
+            assert!(w3c_credential["credentialSubject"]["id"].as_str().unwrap().starts_with("urn:uuid:"));
+            assert_eq!(json!("Alex"), w3c_credential["credentialSubject"]["name"]);
+
+            let proof_json = anoncreds::verifier_proof_from_w3c(&w3c_presentation_json).unwrap();
+
+            let valid = _verify_proof(&proof_json).unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn w3c_presentation_works_for_tampered_proof_value() {
+            let w3c_presentation_json = anoncreds::prover_proof_to_w3c(&anoncreds::proof_request_attr(), &anoncreds::proof_json()).unwrap()
+                .replace("1139481716457488690172217916278103335", "1111111111111111111111111111111111111");
+
+            let proof_json = anoncreds::verifier_proof_from_w3c(&w3c_presentation_json).unwrap();
+
+            let valid = _verify_proof(&proof_json).unwrap();
+            assert!(!valid);
+        }
+
+        #[test]
+        fn w3c_presentation_works_for_missed_issuance_date() {
+            let w3c_presentation_json = anoncreds::prover_proof_to_w3c(&anoncreds::proof_request_attr(), &anoncreds::proof_json()).unwrap();
+
+            let mut w3c_presentation: serde_json::Value = serde_json::from_str(&w3c_presentation_json).unwrap();
+            w3c_presentation["verifiableCredential"][0].as_object_mut().unwrap().remove("issuanceDate");
+
+            let res = anoncreds::verifier_proof_from_w3c(&w3c_presentation.to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod prover_revocation_states {
+        use super::*;
+
+        const TIMESTAMP_1: u64 = 100;
+        const TIMESTAMP_2: u64 = 200;
+
+        struct RevocationSetup {
+            issuer_wallet_handle: i32,
+            prover_wallet_handle: i32,
+            rev_reg_id: String,
+            revoc_reg_def_json: String,
+            blob_storage_reader_handle: i32,
+            cred_rev_id: String,
+            revoc_reg_delta1_json: String,
+            revoc_reg_delta_json: String,
+        }
+
+        fn _issue_revocable_credentials() -> RevocationSetup {
+            let issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+            let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            let (_, _,
+                cred_def_id, cred_def_json,
+                rev_reg_id, revoc_reg_def_json, _,
+                blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                                   ISSUER_DID,
+                                                                                                   GVT_SCHEMA_NAME,
+                                                                                                   GVT_SCHEMA_ATTRIBUTES,
+                                                                                                   r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+            anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            let (cred_rev_id, revoc_reg_delta1_json) = anoncreds::multi_steps_create_revocation_credential(
+                COMMON_MASTER_SECRET,
+                prover_wallet_handle,
+                issuer_wallet_handle,
+                CREDENTIAL1_ID,
+                &anoncreds::gvt_credential_values_json(),
+                &cred_def_id,
+                &cred_def_json,
+                &rev_reg_id,
+                &revoc_reg_def_json,
+                blob_storage_reader_handle,
+            );
+            let revoc_reg_delta1_json = revoc_reg_delta1_json.unwrap();
+
+            // The second issuance moves the accumulator, so states of the first credential have to be updated
+            let (_, revoc_reg_delta2_json) = anoncreds::multi_steps_create_revocation_credential(
+                COMMON_MASTER_SECRET,
+                prover_wallet_handle,
+                issuer_wallet_handle,
+                CREDENTIAL2_ID,
+                &anoncreds::gvt2_credential_values_json(),
+                &cred_def_id,
+                &cred_def_json,
+                &rev_reg_id,
+                &revoc_reg_def_json,
+                blob_storage_reader_handle,
+            );
+            let revoc_reg_delta_json = anoncreds::issuer_merge_revocation_registry_deltas(&revoc_reg_delta1_json, &revoc_reg_delta2_json.unwrap()).unwrap();
+
+            RevocationSetup {
+                issuer_wallet_handle,
+                prover_wallet_handle,
+                rev_reg_id,
+                revoc_reg_def_json,
+                blob_storage_reader_handle,
+                cred_rev_id: cred_rev_id.unwrap(),
+                revoc_reg_delta1_json,
+                revoc_reg_delta_json,
+            }
+        }
+
+        fn _create_revocation_state(setup: &RevocationSetup) -> String {
+            anoncreds::create_revocation_state(setup.blob_storage_reader_handle,
+                                               &setup.revoc_reg_def_json,
+                                               &setup.revoc_reg_delta1_json,
+                                               TIMESTAMP_1,
+                                               &setup.cred_rev_id).unwrap()
+        }
+
+        fn _tear_down(setup: RevocationSetup) {
+            wallet::close_wallet(setup.issuer_wallet_handle).unwrap();
+            wallet::close_wallet(setup.prover_wallet_handle).unwrap();
+        }
+
+        fn _proof_request(non_revoked: serde_json::Value) -> String {
+            json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({ "name":"name" })
+               }),
+               "requested_predicates": json!({}),
+               "non_revoked": non_revoked
+            }).to_string()
+        }
+
+        fn _requested_credentials() -> String {
+            json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({
+                    "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true })
+                 }),
+                 "requested_predicates": json!({})
+            }).to_string()
+        }
+
+        #[test]
+        fn prover_store_revocation_state_works() {
+            utils::setup();
+
+            let setup = _issue_revocable_credentials();
+
+            let rev_state_json = _create_revocation_state(&setup);
+
+            anoncreds::prover_store_revocation_state(setup.prover_wallet_handle, &setup.rev_reg_id, &setup.cred_rev_id, &rev_state_json).unwrap();
+
+            let stored_rev_state_json = anoncreds::prover_get_revocation_state(setup.prover_wallet_handle, &setup.rev_reg_id, &setup.cred_rev_id, TIMESTAMP_1).unwrap();
+
+            let expected: serde_json::Value = serde_json::from_str(&rev_state_json).unwrap();
+            let stored: serde_json::Value = serde_json::from_str(&stored_rev_state_json).unwrap();
+            assert_eq!(expected, stored);
+
+            _tear_down(setup);
+            utils::tear_down();
+        }
+
+        #[test]
+        fn prover_store_revocation_state_works_for_updated_state() {
+            utils::setup();
+
+            let setup = _issue_revocable_credentials();
+
+            let rev_state_1_json = _create_revocation_state(&setup);
+            anoncreds::prover_store_revocation_state(setup.prover_wallet_handle, &setup.rev_reg_id, &setup.cred_rev_id, &rev_state_1_json).unwrap();
+
+            let rev_state_2_json = anoncreds::update_revocation_state(setup.blob_storage_reader_handle,
+                                                                     &rev_state_1_json,
+                                                                     &setup.revoc_reg_def_json,
+                                                                     &setup.revoc_reg_delta_json,
+                                                                     TIMESTAMP_2,
+                                                                     &setup.cred_rev_id).unwrap();
+            anoncreds::prover_store_revocation_state(setup.prover_wallet_handle, &setup.rev_reg_id, &setup.cred_rev_id, &rev_state_2_json).unwrap();
+
+            let stored_rev_state_1: RevocationState = serde_json::from_str(
+                &anoncreds::prover_get_revocation_state(setup.prover_wallet_handle, &setup.rev_reg_id, &setup.cred_rev_id, TIMESTAMP_1).unwrap()).unwrap();
+            let stored_rev_state_2: RevocationState = serde_json::from_str(
+                &anoncreds::prover_get_revocation_state(setup.prover_wallet_handle, &setup.rev_reg_id, &setup.cred_rev_id, TIMESTAMP_2).unwrap()).unwrap();
+
+            assert_eq!(TIMESTAMP_1, stored_rev_state_1.timestamp);
+            assert_eq!(TIMESTAMP_2, stored_rev_state_2.timestamp);
+            assert_ne!(serde_json::to_value(&stored_rev_state_1.rev_reg).unwrap(), serde_json::to_value(&stored_rev_state_2.rev_reg).unwrap());
+
+            _tear_down(setup);
+            utils::tear_down();
+        }
+
+        #[test]
+        fn prover_get_revocation_state_works_for_unknown_timestamp() {
+            utils::setup();
+
+            let setup = _issue_revocable_credentials();
+
+            let rev_state_json = _create_revocation_state(&setup);
+            anoncreds::prover_store_revocation_state(setup.prover_wallet_handle, &setup.rev_reg_id, &setup.cred_rev_id, &rev_state_json).unwrap();
+
+            let res = anoncreds::prover_get_revocation_state(setup.prover_wallet_handle, &setup.rev_reg_id, &setup.cred_rev_id, TIMESTAMP_2);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            _tear_down(setup);
+            utils::tear_down();
+        }
+
+        #[test]
+        fn prover_update_revocation_states_works_for_stored_state() {
+            utils::setup();
+
+            let setup = _issue_revocable_credentials();
+
+            let rev_state_json = _create_revocation_state(&setup);
+            anoncreds::prover_store_revocation_state(setup.prover_wallet_handle, &setup.rev_reg_id, &setup.cred_rev_id, &rev_state_json).unwrap();
+
+            let rev_reg_defs_json = json!({
+                setup.rev_reg_id.clone(): serde_json::from_str::<RevocationRegistryDefinition>(&setup.revoc_reg_def_json).unwrap()
+            }).to_string();
+
+            let blob_storage_reader_handles_json = json!({
+                setup.rev_reg_id.clone(): setup.blob_storage_reader_handle
+            }).to_string();
+
+            // The stored state lies inside the interval, so the ledger is never queried
+            let pool_handle = -1;
+
+            let (rev_states_json, requested_credentials_json) =
+                anoncreds::prover_update_revocation_states(setup.prover_wallet_handle,
+                                                           pool_handle,
+                                                           &_proof_request(json!({ "from": TIMESTAMP_1 - 10, "to": TIMESTAMP_1 + 10 })),
+                                                           &_requested_credentials(),
+                                                           &rev_reg_defs_json,
+                                                           &blob_storage_reader_handles_json).unwrap();
+
+            let rev_states: serde_json::Value = serde_json::from_str(&rev_states_json).unwrap();
+            let expected: serde_json::Value = serde_json::from_str(&rev_state_json).unwrap();
+            assert_eq!(expected, rev_states[&setup.rev_reg_id][TIMESTAMP_1.to_string()]);
+
+            let requested_credentials: serde_json::Value = serde_json::from_str(&requested_credentials_json).unwrap();
+            assert_eq!(json!(TIMESTAMP_1), requested_credentials["requested_attributes"]["attr1_referent"]["timestamp"]);
+
+            _tear_down(setup);
+            utils::tear_down();
+        }
+
+        #[test]
+        fn prover_update_revocation_states_works_for_unknown_credential() {
+            utils::setup();
+
+            let setup = _issue_revocable_credentials();
+
+            let requested_credentials_json = _requested_credentials().replace(CREDENTIAL1_ID, "unknown_cred_id");
+
+            let res = anoncreds::prover_update_revocation_states(setup.prover_wallet_handle,
+                                                                 -1,
+                                                                 &_proof_request(json!({ "to": TIMESTAMP_1 })),
+                                                                 &requested_credentials_json,
+                                                                 "{}",
+                                                                 "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            _tear_down(setup);
+            utils::tear_down();
+        }
+    }
 }
 
 mod medium_cases {
@@ -5916,4 +6456,169 @@ mod demos {
 
         utils::tear_down();
     }
+
+    #[test]
+    fn anoncreds_works_for_issued_credentials_registry() {
+        utils::setup();
+
+        //1. Issuer and Prover create wallets
+        let issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+        let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //2. Issuer creates Schema and Credential Definition
+        let (schema_id, _, cred_def_id, cred_def_json) = anoncreds::multi_steps_issuer_preparation(issuer_wallet_handle,
+                                                                                                   ISSUER_DID,
+                                                                                                   GVT_SCHEMA_NAME,
+                                                                                                   GVT_SCHEMA_ATTRIBUTES);
+
+        //3. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //4. Issuer issues two credentials with the same values and stores them in registry
+        for _ in 0..2 {
+            anoncreds::multi_steps_create_credential_with_options(COMMON_MASTER_SECRET,
+                                                                  prover_wallet_handle,
+                                                                  issuer_wallet_handle,
+                                                                  &anoncreds::gvt_credential_values_json(),
+                                                                  &cred_def_id,
+                                                                  &cred_def_json,
+                                                                  None,
+                                                                  None,
+                                                                  &anoncreds::store_issued_credential_options());
+        }
+
+        //5. Issuer issues credential with other values and doesn't store it
+        anoncreds::multi_steps_create_credential_with_options(COMMON_MASTER_SECRET,
+                                                              prover_wallet_handle,
+                                                              issuer_wallet_handle,
+                                                              &anoncreds::gvt2_credential_values_json(),
+                                                              &cred_def_id,
+                                                              &cred_def_json,
+                                                              None,
+                                                              None,
+                                                              "{}");
+
+        //6. Issuer searches issued credentials
+        let issued_credentials = anoncreds::issuer_get_all_issued_credentials(issuer_wallet_handle, &json!({"cred_def_id": cred_def_id}).to_string());
+        assert_eq!(2, issued_credentials.len());
+
+        for issued_credential in issued_credentials.iter() {
+            assert_eq!(schema_id, issued_credential["schema_id"].as_str().unwrap());
+            assert_eq!(DID_MY1, issued_credential["holder_did"].as_str().unwrap());
+            assert!(issued_credential["rev_reg_id"].is_null());
+            assert!(issued_credential["cred_rev_id"].is_null());
+            assert_eq!(false, issued_credential["revoked"].as_bool().unwrap());
+        }
+
+        //7. Hash of the same values is the same within the wallet
+        let values_hash = issued_credentials[0]["values_hash"].as_str().unwrap();
+        assert_eq!(values_hash, issued_credentials[1]["values_hash"].as_str().unwrap());
+
+        let issued_credentials = anoncreds::issuer_get_all_issued_credentials(issuer_wallet_handle, &json!({"values_hash": values_hash}).to_string());
+        assert_eq!(2, issued_credentials.len());
+
+        let issued_credentials = anoncreds::issuer_get_all_issued_credentials(issuer_wallet_handle, &json!({"holder_did": DID_TRUSTEE}).to_string());
+        assert_eq!(0, issued_credentials.len());
+
+        //8. Other issuer wallet produces other hash of the same values
+        let other_issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        let (_, _, other_cred_def_id, other_cred_def_json) = anoncreds::multi_steps_issuer_preparation(other_issuer_wallet_handle,
+                                                                                                       ISSUER_DID,
+                                                                                                       GVT_SCHEMA_NAME,
+                                                                                                       GVT_SCHEMA_ATTRIBUTES);
+
+        anoncreds::multi_steps_create_credential_with_options(COMMON_MASTER_SECRET,
+                                                              prover_wallet_handle,
+                                                              other_issuer_wallet_handle,
+                                                              &anoncreds::gvt_credential_values_json(),
+                                                              &other_cred_def_id,
+                                                              &other_cred_def_json,
+                                                              None,
+                                                              None,
+                                                              &anoncreds::store_issued_credential_options());
+
+        let issued_credentials = anoncreds::issuer_get_all_issued_credentials(other_issuer_wallet_handle, "{}");
+        assert_eq!(1, issued_credentials.len());
+        assert_ne!(values_hash, issued_credentials[0]["values_hash"].as_str().unwrap());
+
+        wallet::close_wallet(other_issuer_wallet_handle).unwrap();
+        wallet::close_wallet(issuer_wallet_handle).unwrap();
+        wallet::close_wallet(prover_wallet_handle).unwrap();
+
+        utils::tear_down();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_issued_credentials_registry_revoke_credential() {
+        utils::setup();
+
+        //1. Issuer and Prover create wallets
+        let issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+        let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //2. Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, _, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //3. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //4. Issuer issues two credentials stored in registry and one not stored
+        let mut cred_rev_ids: Vec<String> = Vec::new();
+
+        for options_json in vec![anoncreds::store_issued_credential_options(), anoncreds::store_issued_credential_options(), "{}".to_string()] {
+            let cred_rev_id = anoncreds::multi_steps_create_credential_with_options(COMMON_MASTER_SECRET,
+                                                                                    prover_wallet_handle,
+                                                                                    issuer_wallet_handle,
+                                                                                    &anoncreds::gvt_credential_values_json(),
+                                                                                    &cred_def_id,
+                                                                                    &cred_def_json,
+                                                                                    Some(&rev_reg_id),
+                                                                                    Some(blob_storage_reader_handle),
+                                                                                    &options_json).unwrap();
+            cred_rev_ids.push(cred_rev_id);
+        }
+
+        let issued_credentials = anoncreds::issuer_get_all_issued_credentials(issuer_wallet_handle, &json!({"rev_reg_id": rev_reg_id}).to_string());
+        assert_eq!(2, issued_credentials.len());
+
+        //5. Issuer revokes first credential
+        anoncreds::issuer_revoke_credential(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_rev_ids[0]).unwrap();
+
+        let revoked = anoncreds::issuer_get_all_issued_credentials(issuer_wallet_handle, r#"{"revoked": "true"}"#);
+        assert_eq!(1, revoked.len());
+        assert_eq!(cred_rev_ids[0], revoked[0]["cred_rev_id"].as_str().unwrap());
+        assert_eq!(rev_reg_id, revoked[0]["rev_reg_id"].as_str().unwrap());
+        assert_eq!(true, revoked[0]["revoked"].as_bool().unwrap());
+
+        let not_revoked = anoncreds::issuer_get_all_issued_credentials(issuer_wallet_handle, r#"{"revoked": "false"}"#);
+        assert_eq!(1, not_revoked.len());
+        assert_eq!(cred_rev_ids[1], not_revoked[0]["cred_rev_id"].as_str().unwrap());
+
+        //6. Issuer revokes credential not stored in registry
+        anoncreds::issuer_revoke_credential(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_rev_ids[2]).unwrap();
+
+        let revoked = anoncreds::issuer_get_all_issued_credentials(issuer_wallet_handle, r#"{"revoked": "true"}"#);
+        assert_eq!(1, revoked.len());
+
+        //7. Second revocation of the same credential fails and keeps registry unchanged
+        let res = anoncreds::issuer_revoke_credential(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_rev_ids[0]);
+        assert_code!(ErrorCode::AnoncredsInvalidUserRevocId, res);
+
+        let revoked = anoncreds::issuer_get_all_issued_credentials(issuer_wallet_handle, r#"{"revoked": "true"}"#);
+        assert_eq!(1, revoked.len());
+
+        wallet::close_wallet(issuer_wallet_handle).unwrap();
+        wallet::close_wallet(prover_wallet_handle).unwrap();
+
+        utils::tear_down();
+    }
 }
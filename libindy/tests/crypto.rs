@@ -82,6 +82,209 @@ mod high_cases {
         }
     }
 
+    mod create_bls_key {
+        use super::*;
+        use rust_base58::FromBase58;
+
+        #[test]
+        fn indy_create_bls_key_works_for_seed() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (blskey, blskey_pop) = crypto::create_bls_key(wallet_handle, Some(MY1_SEED)).unwrap();
+            assert_eq!(blskey.from_base58().unwrap().len(), 128);
+            assert!(!blskey_pop.from_base58().unwrap().is_empty());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_create_bls_key_works_without_seed() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (blskey_1, _) = crypto::create_bls_key(wallet_handle, None).unwrap();
+            let (blskey_2, _) = crypto::create_bls_key(wallet_handle, None).unwrap();
+            assert_ne!(blskey_1, blskey_2);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_create_bls_key_works_for_duplicate_seed() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            crypto::create_bls_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let res = crypto::create_bls_key(wallet_handle, Some(MY1_SEED));
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_create_bls_key_works_for_invalid_wallet_handle() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = crypto::create_bls_key(wallet_handle + 1, None);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod derived_keys {
+        use super::*;
+
+        const MASTER_SEED: &str = "000102030405060708090a0b0c0d0e0f";
+        const KEY_PATH: &str = "m/0'/1'";
+        const DID_PATH: &str = "m/0'/2'";
+
+        fn _derived_keys(wallet_handle: i32) -> Vec<serde_json::Value> {
+            let derived_keys = crypto::list_derived_keys(wallet_handle).unwrap();
+            serde_json::from_str(&derived_keys).unwrap()
+        }
+
+        #[test]
+        fn indy_set_master_seed_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            assert_eq!(MASTER_SEED, crypto::set_master_seed(wallet_handle, Some(MASTER_SEED)).unwrap());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_master_seed_works_without_seed() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let master_seed = crypto::set_master_seed(wallet_handle, None).unwrap();
+            assert_eq!(64, master_seed.len());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_master_seed_works_for_already_set_seed() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            crypto::set_master_seed(wallet_handle, Some(MASTER_SEED)).unwrap();
+
+            let res = crypto::set_master_seed(wallet_handle, None);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_create_key_works_for_derivation_path_without_master_seed() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = crypto::create_derived_key(wallet_handle, KEY_PATH);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_create_and_store_my_did_works_for_derivation_path_and_seed() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            crypto::set_master_seed(wallet_handle, Some(MASTER_SEED)).unwrap();
+
+            let res = did::create_my_did(wallet_handle, &json!({"seed": MY1_SEED, "derivation_path": DID_PATH}).to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_list_derived_keys_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            crypto::set_master_seed(wallet_handle, Some(MASTER_SEED)).unwrap();
+            crypto::create_key(wallet_handle, None).unwrap();
+
+            assert!(_derived_keys(wallet_handle).is_empty());
+
+            let verkey = crypto::create_derived_key(wallet_handle, KEY_PATH).unwrap();
+            let (did, did_verkey) = did::create_my_did(wallet_handle, &json!({"derivation_path": DID_PATH}).to_string()).unwrap();
+
+            let mut derived_keys = _derived_keys(wallet_handle);
+            derived_keys.sort_by_key(|derived_key| derived_key["derivation_path"].as_str().unwrap().to_string());
+
+            assert_eq!(2, derived_keys.len());
+            assert_eq!(json!({"verkey": verkey, "derivation_path": KEY_PATH}), derived_keys[0]);
+            assert_eq!(json!({"verkey": did_verkey, "derivation_path": DID_PATH, "did": did}), derived_keys[1]);
+
+            did::delete_my_did(wallet_handle, &did).unwrap();
+
+            let derived_keys = _derived_keys(wallet_handle);
+            assert_eq!(1, derived_keys.len());
+            assert_eq!(verkey, derived_keys[0]["verkey"].as_str().unwrap());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_derived_keys_recover_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let master_seed = crypto::set_master_seed(wallet_handle, None).unwrap();
+
+            let verkey = crypto::create_derived_key(wallet_handle, KEY_PATH).unwrap();
+            let (did, did_verkey) = did::create_my_did(wallet_handle, &json!({"derivation_path": DID_PATH}).to_string()).unwrap();
+
+            let derived_keys = _derived_keys(wallet_handle);
+
+            // Restore derived keys and DIDs in other wallet from the backed up master seed
+            let restored_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            crypto::set_master_seed(restored_wallet_handle, Some(&master_seed)).unwrap();
+
+            for derived_key in derived_keys.iter() {
+                let derivation_path = derived_key["derivation_path"].as_str().unwrap();
+
+                match derived_key["did"].as_str() {
+                    Some(derived_did) => {
+                        let (restored_did, restored_verkey) =
+                            did::create_my_did(restored_wallet_handle, &json!({"derivation_path": derivation_path}).to_string()).unwrap();
+                        assert_eq!(did, derived_did);
+                        assert_eq!(did, restored_did);
+                        assert_eq!(did_verkey, restored_verkey);
+                    }
+                    None => {
+                        let restored_verkey = crypto::create_derived_key(restored_wallet_handle, derivation_path).unwrap();
+                        assert_eq!(verkey, restored_verkey);
+                    }
+                }
+            }
+
+            let signature = crypto::sign(restored_wallet_handle, &did_verkey, MESSAGE.as_bytes()).unwrap();
+            assert!(crypto::verify(&did_verkey, MESSAGE.as_bytes(), &signature).unwrap());
+
+            assert_eq!(2, _derived_keys(restored_wallet_handle).len());
+
+            wallet::close_wallet(restored_wallet_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_derived_keys_recover_works_for_other_master_seed() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            crypto::set_master_seed(wallet_handle, Some(MASTER_SEED)).unwrap();
+            let verkey = crypto::create_derived_key(wallet_handle, KEY_PATH).unwrap();
+
+            let other_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+            crypto::set_master_seed(other_wallet_handle, None).unwrap();
+
+            let other_verkey = crypto::create_derived_key(other_wallet_handle, KEY_PATH).unwrap();
+            assert_ne!(verkey, other_verkey);
+
+            wallet::close_wallet(other_wallet_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
     mod set_key_metadata {
         use super::*;
 
@@ -684,6 +887,181 @@ mod high_cases {
         }
 
     }
+
+    mod pack_message_with_routing {
+        use super::*;
+
+        #[test]
+        fn indy_pack_message_with_routing_works() {
+            let (wallet_handle_sender, sender_verkey) = setup_with_key();
+            let wallet_handle_mediator = wallet::create_and_open_default_wallet().unwrap();
+            let mediator_verkey = crypto::create_key(wallet_handle_mediator, None).unwrap();
+            let wallet_handle_receiver = wallet::create_and_open_default_wallet().unwrap();
+            let receiver_verkey = crypto::create_key(wallet_handle_receiver, None).unwrap();
+
+            let receiver_keys = json!([receiver_verkey]).to_string();
+            let routing_keys = json!([mediator_verkey]).to_string();
+            let packed = crypto::pack_message_with_routing(wallet_handle_sender, AGENT_MESSAGE.as_bytes(), &receiver_keys, &routing_keys, Some(&sender_verkey)).unwrap();
+
+            let forward = crypto::unpack_message(wallet_handle_mediator, &packed).unwrap();
+            let forward: serde_json::Value = serde_json::from_slice(&forward).unwrap();
+            assert!(forward.get("sender_verkey").is_none());
+
+            let forward: serde_json::Value = serde_json::from_str(forward["message"].as_str().unwrap()).unwrap();
+            assert_eq!(forward["@type"], "https://didcomm.org/routing/1.0/forward");
+            assert_eq!(forward["to"], receiver_verkey);
+
+            let inner_jwe = serde_json::to_vec(&forward["msg"]).unwrap();
+            let unpacked = crypto::unpack_message(wallet_handle_receiver, &inner_jwe).unwrap();
+            let unpacked: serde_json::Value = serde_json::from_slice(&unpacked).unwrap();
+            assert_eq!(unpacked["message"], AGENT_MESSAGE);
+            assert_eq!(unpacked["sender_verkey"], sender_verkey);
+
+            wallet::close_wallet(wallet_handle_sender).unwrap();
+            wallet::close_wallet(wallet_handle_mediator).unwrap();
+            utils::tear_down_with_wallet(wallet_handle_receiver);
+        }
+
+        #[test]
+        fn indy_pack_message_with_routing_works_for_empty_routing_keys() {
+            let (wallet_handle_sender, _) = setup_with_key();
+            let wallet_handle_receiver = wallet::create_and_open_default_wallet().unwrap();
+            let receiver_verkey = crypto::create_key(wallet_handle_receiver, None).unwrap();
+
+            let receiver_keys = json!([VERKEY_TRUSTEE, receiver_verkey]).to_string();
+            let packed = crypto::pack_message_with_routing(wallet_handle_sender, AGENT_MESSAGE.as_bytes(), &receiver_keys, "[]", None).unwrap();
+
+            let unpacked = crypto::unpack_message(wallet_handle_receiver, &packed).unwrap();
+            let unpacked: serde_json::Value = serde_json::from_slice(&unpacked).unwrap();
+            assert_eq!(unpacked["message"], AGENT_MESSAGE);
+
+            wallet::close_wallet(wallet_handle_sender).unwrap();
+            utils::tear_down_with_wallet(wallet_handle_receiver);
+        }
+
+        #[test]
+        fn indy_pack_message_with_routing_fails_for_multiple_receivers() {
+            let (wallet_handle, _) = setup_with_key();
+
+            let receiver_keys = json!([VERKEY_MY1, VERKEY_MY2]).to_string();
+            let routing_keys = json!([VERKEY_TRUSTEE]).to_string();
+            let res = crypto::pack_message_with_routing(wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_keys, &routing_keys, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_pack_message_with_routing_fails_for_invalid_routing_key() {
+            let (wallet_handle, _) = setup_with_key();
+
+            let receiver_keys = json!([VERKEY_MY1]).to_string();
+            let routing_keys = json!([INVALID_BASE58_VERKEY]).to_string();
+            let res = crypto::pack_message_with_routing(wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_keys, &routing_keys, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod unpack_message_with_routing {
+        use super::*;
+
+        #[test]
+        fn indy_unpack_message_with_routing_works_for_all_layers() {
+            let (wallet_handle_sender, sender_verkey) = setup_with_key();
+            let wallet_handle_receiver = wallet::create_and_open_default_wallet().unwrap();
+            let receiver_verkey = crypto::create_key(wallet_handle_receiver, None).unwrap();
+            let mediator_verkey = crypto::create_key(wallet_handle_receiver, None).unwrap();
+            let agency_verkey = crypto::create_key(wallet_handle_receiver, None).unwrap();
+
+            let receiver_keys = json!([receiver_verkey]).to_string();
+            let routing_keys = json!([mediator_verkey, agency_verkey]).to_string();
+            let packed = crypto::pack_message_with_routing(wallet_handle_sender, AGENT_MESSAGE.as_bytes(), &receiver_keys, &routing_keys, Some(&sender_verkey)).unwrap();
+
+            let unpacked = crypto::unpack_message_with_routing(wallet_handle_receiver, &packed).unwrap();
+            let unpacked: serde_json::Value = serde_json::from_slice(&unpacked).unwrap();
+
+            assert_eq!(unpacked["message"], AGENT_MESSAGE);
+            assert_eq!(unpacked["recipient_verkey"], receiver_verkey);
+            assert_eq!(unpacked["sender_verkey"], sender_verkey);
+
+            let layers = unpacked["layers"].as_array().unwrap();
+            assert_eq!(3, layers.len());
+            assert_eq!(layers[0]["recipient_verkey"], agency_verkey);
+            assert_eq!(layers[0]["forward_to"], mediator_verkey);
+            assert_eq!(layers[1]["recipient_verkey"], mediator_verkey);
+            assert_eq!(layers[1]["forward_to"], receiver_verkey);
+            assert_eq!(layers[2]["recipient_verkey"], receiver_verkey);
+            assert_eq!(layers[2]["sender_verkey"], sender_verkey);
+            assert!(layers[2].get("forward_to").is_none());
+
+            wallet::close_wallet(wallet_handle_sender).unwrap();
+            utils::tear_down_with_wallet(wallet_handle_receiver);
+        }
+
+        #[test]
+        fn indy_unpack_message_with_routing_works_for_mediator() {
+            let (wallet_handle_sender, _) = setup_with_key();
+            let wallet_handle_mediator = wallet::create_and_open_default_wallet().unwrap();
+            let mediator_verkey = crypto::create_key(wallet_handle_mediator, None).unwrap();
+
+            let receiver_keys = json!([VERKEY_MY1]).to_string();
+            let routing_keys = json!([mediator_verkey]).to_string();
+            let packed = crypto::pack_message_with_routing(wallet_handle_sender, AGENT_MESSAGE.as_bytes(), &receiver_keys, &routing_keys, None).unwrap();
+
+            let unpacked = crypto::unpack_message_with_routing(wallet_handle_mediator, &packed).unwrap();
+            let unpacked: serde_json::Value = serde_json::from_slice(&unpacked).unwrap();
+
+            assert_eq!(unpacked["recipient_verkey"], mediator_verkey);
+
+            let forward: serde_json::Value = serde_json::from_str(unpacked["message"].as_str().unwrap()).unwrap();
+            assert_eq!(forward["to"], VERKEY_MY1);
+
+            let layers = unpacked["layers"].as_array().unwrap();
+            assert_eq!(1, layers.len());
+            assert_eq!(layers[0]["forward_to"], VERKEY_MY1);
+
+            wallet::close_wallet(wallet_handle_sender).unwrap();
+            utils::tear_down_with_wallet(wallet_handle_mediator);
+        }
+
+        #[test]
+        fn indy_unpack_message_with_routing_works_for_message_without_routing() {
+            let (wallet_handle_sender, _) = setup_with_key();
+            let wallet_handle_receiver = wallet::create_and_open_default_wallet().unwrap();
+            let receiver_verkey = crypto::create_key(wallet_handle_receiver, None).unwrap();
+
+            let receiver_keys = json!([receiver_verkey]).to_string();
+            let packed = crypto::pack_message(wallet_handle_sender, AGENT_MESSAGE.as_bytes(), &receiver_keys, None).unwrap();
+
+            let unpacked = crypto::unpack_message_with_routing(wallet_handle_receiver, &packed).unwrap();
+            let unpacked: serde_json::Value = serde_json::from_slice(&unpacked).unwrap();
+
+            assert_eq!(unpacked["message"], AGENT_MESSAGE);
+            assert_eq!(1, unpacked["layers"].as_array().unwrap().len());
+
+            wallet::close_wallet(wallet_handle_sender).unwrap();
+            utils::tear_down_with_wallet(wallet_handle_receiver);
+        }
+
+        #[test]
+        fn indy_unpack_message_with_routing_fails_no_matching_key() {
+            let (wallet_handle_sender, _) = setup_with_key();
+            let wallet_handle_receiver = wallet::create_and_open_default_wallet().unwrap();
+            crypto::create_key(wallet_handle_receiver, None).unwrap();
+
+            let receiver_keys = json!([VERKEY_MY1]).to_string();
+            let routing_keys = json!([VERKEY_TRUSTEE]).to_string();
+            let packed = crypto::pack_message_with_routing(wallet_handle_sender, AGENT_MESSAGE.as_bytes(), &receiver_keys, &routing_keys, None).unwrap();
+
+            let res = crypto::unpack_message_with_routing(wallet_handle_receiver, &packed);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle_sender).unwrap();
+            utils::tear_down_with_wallet(wallet_handle_receiver);
+        }
+    }
 }
 
 mod load {
@@ -286,7 +286,7 @@ mod high_cases {
             let (wallet_handle, pool_handle) = utils::setup_with_wallet_and_pool();
 
             let res = did::get_endpoint_for_did(wallet_handle, pool_handle, DID);
-            assert_code!(ErrorCode::CommonInvalidState, res);
+            assert_code!(ErrorCode::LedgerNotFound, res);
 
             utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
         }
@@ -314,6 +314,240 @@ mod high_cases {
         }
     }
 
+    mod set_services_for_did {
+        use super::*;
+
+        #[test]
+        fn indy_set_services_for_did_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            did::set_services_for_did(wallet_handle, DID, &_services().to_string()).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_services_for_did_works_for_replace() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            did::set_services_for_did(wallet_handle, DID, &_services().to_string()).unwrap();
+
+            let services = json!([{"type": "IndyAgent", "serviceEndpoint": "https://agent.example.com", "recipientKeys": [VERKEY_MY2]}]);
+            did::set_services_for_did(wallet_handle, DID, &services.to_string()).unwrap();
+
+            let services_json = did::get_services_for_did(wallet_handle, -1, DID).unwrap();
+            let services: Vec<serde_json::Value> = serde_json::from_str(&services_json).unwrap();
+            assert_eq!(1, services.len());
+            assert_eq!("https://agent.example.com", services[0]["serviceEndpoint"]);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_services_for_did_works_for_missed_endpoint() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let services = json!([{"type": "IndyAgent", "serviceEndpoint": "", "recipientKeys": [VERKEY]}]);
+            let res = did::set_services_for_did(wallet_handle, DID, &services.to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_services_for_did_works_for_invalid_recipient_key() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let services = json!([{"type": "IndyAgent", "serviceEndpoint": ENDPOINT, "recipientKeys": [INVALID_BASE58_VERKEY]}]);
+            let res = did::set_services_for_did(wallet_handle, DID, &services.to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_services_for_did_works_for_invalid_did() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = did::set_services_for_did(wallet_handle, INVALID_BASE58_DID, &_services().to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod get_services_for_did {
+        use super::*;
+
+        #[test]
+        fn indy_get_services_for_did_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            did::set_services_for_did(wallet_handle, DID, &_services().to_string()).unwrap();
+
+            let services_json = did::get_services_for_did(wallet_handle, -1, DID).unwrap();
+            let services: serde_json::Value = serde_json::from_str(&services_json).unwrap();
+
+            // ordered by priority
+            assert_eq!(json!([
+                {"type": "IndyAgent", "priority": 0, "recipientKeys": [VERKEY], "routingKeys": [], "serviceEndpoint": ENDPOINT},
+                {"id": "did:sov:1#backup", "type": "IndyAgent", "priority": 1, "recipientKeys": [VERKEY_MY1], "routingKeys": [VERKEY_MY2], "serviceEndpoint": "https://backup.example.com"}
+            ]), services);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_get_services_for_did_works_for_legacy_endpoint() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            did::set_endpoint_for_did(wallet_handle, DID, ENDPOINT, VERKEY).unwrap();
+
+            let services_json = did::get_services_for_did(wallet_handle, -1, DID).unwrap();
+            let services: serde_json::Value = serde_json::from_str(&services_json).unwrap();
+
+            assert_eq!(json!([
+                {"type": "IndyAgent", "priority": 0, "recipientKeys": [VERKEY], "routingKeys": [], "serviceEndpoint": ENDPOINT}
+            ]), services);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_get_services_for_did_works_from_ledger() {
+            let (wallet_handle, pool_handle, my_did, _) = utils::setup_new_identity();
+
+            let attrib_data = json!({"service": _services()}).to_string();
+            let attrib_request = ledger::build_attrib_request(&my_did, &my_did, None, Some(&attrib_data), None).unwrap();
+            ledger::sign_and_submit_request(pool_handle, wallet_handle, &my_did, &attrib_request).unwrap();
+
+            thread::sleep(std::time::Duration::from_millis(1000));
+
+            let services_json = did::get_services_for_did(wallet_handle, pool_handle, &my_did).unwrap();
+            let services: Vec<serde_json::Value> = serde_json::from_str(&services_json).unwrap();
+            assert_eq!(2, services.len());
+            assert_eq!(ENDPOINT, services[0]["serviceEndpoint"]);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
+        }
+
+        #[test]
+        fn indy_get_services_for_did_works_from_ledger_for_legacy_endpoint() {
+            let (wallet_handle, pool_handle, my_did, my_verkey) = utils::setup_new_identity();
+
+            let attrib_data = json!({"endpoint": {"ha": ENDPOINT, "verkey": my_verkey}}).to_string();
+            let attrib_request = ledger::build_attrib_request(&my_did, &my_did, None, Some(&attrib_data), None).unwrap();
+            ledger::sign_and_submit_request(pool_handle, wallet_handle, &my_did, &attrib_request).unwrap();
+
+            thread::sleep(std::time::Duration::from_millis(1000));
+
+            let services_json = did::get_services_for_did(wallet_handle, pool_handle, &my_did).unwrap();
+            let services: serde_json::Value = serde_json::from_str(&services_json).unwrap();
+            assert_eq!(json!([
+                {"type": "IndyAgent", "priority": 0, "recipientKeys": [my_verkey], "routingKeys": [], "serviceEndpoint": ENDPOINT}
+            ]), services);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
+        }
+
+        #[test]
+        fn indy_get_services_for_did_works_from_ledger_for_published_services() {
+            let (wallet_handle, pool_handle, my_did, _) = utils::setup_new_identity();
+
+            did::set_services_for_did(wallet_handle, &my_did, &_services().to_string()).unwrap();
+
+            let attrib_request = did::build_did_services_attrib_request(wallet_handle, &my_did, &my_did).unwrap();
+            ledger::sign_and_submit_request(pool_handle, wallet_handle, &my_did, &attrib_request).unwrap();
+
+            thread::sleep(std::time::Duration::from_millis(1000));
+
+            let other_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            let services_json = did::get_services_for_did(other_wallet_handle, pool_handle, &my_did).unwrap();
+            let expected_services_json = did::get_services_for_did(wallet_handle, -1, &my_did).unwrap();
+            assert_eq!(expected_services_json, services_json);
+
+            wallet::close_wallet(other_wallet_handle).unwrap();
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
+        }
+
+        #[test]
+        fn indy_get_services_for_did_works_for_unknown_did() {
+            let (wallet_handle, pool_handle) = utils::setup_with_wallet_and_pool();
+
+            let res = did::get_services_for_did(wallet_handle, pool_handle, DID);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
+        }
+
+        #[test]
+        fn indy_get_services_for_did_works_for_invalid_did() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = did::get_services_for_did(wallet_handle, -1, INVALID_BASE58_DID);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod build_did_services_attrib_request {
+        use super::*;
+
+        #[test]
+        fn indy_build_did_services_attrib_request_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            did::set_services_for_did(wallet_handle, DID_MY1, &_services().to_string()).unwrap();
+
+            let request_json = did::build_did_services_attrib_request(wallet_handle, DID_TRUSTEE, DID_MY1).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request_json).unwrap();
+
+            assert_eq!(DID_TRUSTEE, request["identifier"]);
+            assert_eq!("100", request["operation"]["type"]);
+            assert_eq!(DID_MY1, request["operation"]["dest"]);
+
+            let raw: serde_json::Value = serde_json::from_str(request["operation"]["raw"].as_str().unwrap()).unwrap();
+            let services: serde_json::Value = serde_json::from_str(&did::get_services_for_did(wallet_handle, -1, DID_MY1).unwrap()).unwrap();
+            assert_eq!(json!({"service": services}), raw);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_build_did_services_attrib_request_works_for_legacy_endpoint() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            did::set_endpoint_for_did(wallet_handle, DID_MY1, ENDPOINT, VERKEY).unwrap();
+
+            let request_json = did::build_did_services_attrib_request(wallet_handle, DID_TRUSTEE, DID_MY1).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request_json).unwrap();
+
+            let raw: serde_json::Value = serde_json::from_str(request["operation"]["raw"].as_str().unwrap()).unwrap();
+            assert_eq!(ENDPOINT, raw["service"][0]["serviceEndpoint"]);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_build_did_services_attrib_request_works_for_no_services() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = did::build_did_services_attrib_request(wallet_handle, DID_TRUSTEE, DID_MY1);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    fn _services() -> serde_json::Value {
+        json!([
+            {"id": "did:sov:1#backup", "type": "IndyAgent", "priority": 1, "recipientKeys": [VERKEY_MY1], "routingKeys": [VERKEY_MY2], "serviceEndpoint": "https://backup.example.com"},
+            {"type": "IndyAgent", "recipientKeys": [VERKEY], "serviceEndpoint": ENDPOINT}
+        ])
+    }
+
     mod set_did_metadata {
         use super::*;
 
@@ -939,4 +1173,490 @@ mod high_cases {
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
     }
+
+    mod did_key_history {
+        use super::*;
+        use utils::crypto;
+
+        fn _key_history(wallet_handle: i32, did: &str) -> Vec<serde_json::Value> {
+            let key_history = did::get_did_key_history(wallet_handle, did).unwrap();
+            serde_json::from_str(&key_history).unwrap()
+        }
+
+        #[test]
+        fn indy_get_did_key_history_works_for_not_rotated_did() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let key_history = _key_history(wallet_handle, &my_did);
+            assert_eq!(1, key_history.len());
+            assert_eq!(key_history[0]["verkey"], my_verkey);
+            assert!(key_history[0]["validFrom"].is_null());
+            assert!(key_history[0]["validTo"].is_null());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_get_did_key_history_works_for_replace_keys() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            let new_verkey = did::replace_keys_start(wallet_handle, &my_did, "{}").unwrap();
+            did::replace_keys_apply(wallet_handle, &my_did).unwrap();
+
+            let key_history = _key_history(wallet_handle, &my_did);
+            assert_eq!(2, key_history.len());
+            assert_eq!(key_history[0]["verkey"], my_verkey);
+            assert_eq!(key_history[1]["verkey"], new_verkey);
+            assert!(key_history[1]["validFrom"].is_null());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_get_did_key_history_works_for_their_did_verkey_update() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            did::store_their_did(wallet_handle, &json!({"did": DID_MY1, "verkey": VERKEY_MY1}).to_string()).unwrap();
+            did::store_their_did(wallet_handle, &json!({"did": DID_MY1, "verkey": VERKEY_MY2}).to_string()).unwrap();
+
+            assert_eq!(VERKEY_MY2, did::key_for_local_did(wallet_handle, DID_MY1).unwrap());
+
+            let key_history = _key_history(wallet_handle, DID_MY1);
+            assert_eq!(2, key_history.len());
+            assert_eq!(key_history[0]["verkey"], VERKEY_MY1);
+            assert_eq!(key_history[1]["verkey"], VERKEY_MY2);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_get_did_key_history_works_for_my_and_their_did_of_same_value() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+            assert_eq!(DID_MY1, my_did);
+
+            did::store_their_did(wallet_handle, &json!({"did": DID_MY1, "verkey": VERKEY_MY1}).to_string()).unwrap();
+            did::store_their_did(wallet_handle, &json!({"did": DID_MY1, "verkey": VERKEY_MY2}).to_string()).unwrap();
+
+            let key_history = _key_history(wallet_handle, &my_did);
+            assert_eq!(1, key_history.len());
+            assert_eq!(key_history[0]["verkey"], my_verkey);
+
+            let new_verkey = did::replace_keys_start(wallet_handle, &my_did, "{}").unwrap();
+            did::replace_keys_apply(wallet_handle, &my_did).unwrap();
+
+            let key_history = _key_history(wallet_handle, &my_did);
+            assert_eq!(2, key_history.len());
+            assert_eq!(key_history[0]["verkey"], my_verkey);
+            assert_eq!(key_history[1]["verkey"], new_verkey);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_did_key_ledger_info_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            let new_verkey = did::replace_keys_start(wallet_handle, &my_did, "{}").unwrap();
+            did::replace_keys_apply(wallet_handle, &my_did).unwrap();
+
+            did::set_did_key_ledger_info(wallet_handle, &my_did, &new_verkey, 10, 1000).unwrap();
+
+            let key_history = _key_history(wallet_handle, &my_did);
+            assert_eq!(key_history[0]["verkey"], my_verkey);
+            assert_eq!(key_history[0]["validTo"], 1000);
+            assert_eq!(key_history[1]["validFrom"], 1000);
+            assert_eq!(key_history[1]["seqNo"], 10);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_did_key_ledger_info_works_for_unknown_verkey() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let res = did::set_did_key_ledger_info(wallet_handle, &my_did, VERKEY_MY1, 10, 1000);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_verify_did_signature_at_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            let old_signature = crypto::sign(wallet_handle, &my_verkey, MESSAGE.as_bytes()).unwrap();
+
+            let new_verkey = did::replace_keys_start(wallet_handle, &my_did, "{}").unwrap();
+            did::replace_keys_apply(wallet_handle, &my_did).unwrap();
+            let new_signature = crypto::sign(wallet_handle, &new_verkey, MESSAGE.as_bytes()).unwrap();
+
+            // new verkey is pending until ledger time is set
+            assert!(did::verify_did_signature_at(wallet_handle, &my_did, 2000, MESSAGE.as_bytes(), &old_signature).unwrap());
+            assert!(!did::verify_did_signature_at(wallet_handle, &my_did, 2000, MESSAGE.as_bytes(), &new_signature).unwrap());
+
+            did::set_did_key_ledger_info(wallet_handle, &my_did, &new_verkey, 10, 1000).unwrap();
+
+            assert!(did::verify_did_signature_at(wallet_handle, &my_did, 999, MESSAGE.as_bytes(), &old_signature).unwrap());
+            assert!(!did::verify_did_signature_at(wallet_handle, &my_did, 999, MESSAGE.as_bytes(), &new_signature).unwrap());
+            assert!(!did::verify_did_signature_at(wallet_handle, &my_did, 1000, MESSAGE.as_bytes(), &old_signature).unwrap());
+            assert!(did::verify_did_signature_at(wallet_handle, &my_did, 1000, MESSAGE.as_bytes(), &new_signature).unwrap());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_verify_did_signature_at_works_for_unknown_did() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = did::verify_did_signature_at(wallet_handle, DID_MY1, 1000, MESSAGE.as_bytes(), SIGNATURE);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod set_my_did_tags {
+        use super::*;
+
+        #[test]
+        fn indy_set_my_did_tags_works() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::set_my_did_tags(wallet_handle, &my_did, r#"{"~role":"issuer"}"#).unwrap();
+
+            let (search_handle, total_count) = did::search_my_dids(wallet_handle, Some(r#"{"~role":"issuer"}"#)).unwrap();
+            assert_eq!(1, total_count);
+            did::close_my_dids_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_my_did_tags_works_for_reserved_metadata_tag() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            let res = did::set_my_did_tags(wallet_handle, &my_did, r#"{"metadata":"value"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_my_did_tags_works_for_not_found_did() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = did::set_my_did_tags(wallet_handle, DID, r#"{"~role":"issuer"}"#);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_my_did_tags_works_for_invalid_did() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = did::set_my_did_tags(wallet_handle, INVALID_BASE58_DID, r#"{"~role":"issuer"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod search_my_dids {
+        use super::*;
+
+        fn _fetch(wallet_handle: i32, search_handle: i32, count: usize) -> Vec<serde_json::Value> {
+            serde_json::from_str(&did::fetch_my_dids_search(wallet_handle, search_handle, count).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn indy_search_my_dids_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+            did::create_and_store_my_did(wallet_handle, Some(MY2_SEED)).unwrap();
+
+            let (search_handle, total_count) = did::search_my_dids(wallet_handle, None).unwrap();
+            assert_eq!(2, total_count);
+
+            assert_eq!(1, _fetch(wallet_handle, search_handle, 1).len());
+            assert_eq!(1, _fetch(wallet_handle, search_handle, 1).len());
+            assert_eq!(0, _fetch(wallet_handle, search_handle, 1).len());
+
+            did::close_my_dids_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_my_dids_works_for_tag() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+            did::create_and_store_my_did(wallet_handle, Some(MY2_SEED)).unwrap();
+
+            did::set_my_did_tags(wallet_handle, &my_did, r#"{"~role":"issuer"}"#).unwrap();
+
+            let (search_handle, total_count) = did::search_my_dids(wallet_handle, Some(r#"{"~role":"issuer"}"#)).unwrap();
+            assert_eq!(1, total_count);
+
+            let dids = _fetch(wallet_handle, search_handle, 10);
+            assert_eq!(json!([{"did": my_did, "verkey": my_verkey, "tempVerkey": null, "metadata": null}]), json!(dids));
+
+            did::close_my_dids_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_my_dids_works_for_metadata() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, _) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+            let (my_did_2, _) = did::create_and_store_my_did(wallet_handle, Some(MY2_SEED)).unwrap();
+
+            did::set_did_metadata(wallet_handle, &my_did, METADATA).unwrap();
+            did::set_did_metadata(wallet_handle, &my_did_2, "other_metadata").unwrap();
+
+            let (search_handle, total_count) = did::search_my_dids(wallet_handle, Some(&json!({"metadata": METADATA}).to_string())).unwrap();
+            assert_eq!(1, total_count);
+
+            let dids = _fetch(wallet_handle, search_handle, 10);
+            assert_eq!(my_did, dids[0]["did"].as_str().unwrap());
+            assert_eq!(METADATA, dids[0]["metadata"].as_str().unwrap());
+
+            did::close_my_dids_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_my_dids_works_for_metadata_and_tag() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::set_did_metadata(wallet_handle, &my_did, METADATA).unwrap();
+            did::set_my_did_tags(wallet_handle, &my_did, r#"{"~role":"issuer"}"#).unwrap();
+
+            let query = json!({"metadata": METADATA, "~role": "issuer"}).to_string();
+            let (search_handle, total_count) = did::search_my_dids(wallet_handle, Some(&query)).unwrap();
+            assert_eq!(1, total_count);
+            did::close_my_dids_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_my_dids_works_for_updated_metadata() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::set_my_did_tags(wallet_handle, &my_did, r#"{"~role":"issuer"}"#).unwrap();
+            did::set_did_metadata(wallet_handle, &my_did, METADATA).unwrap();
+            did::set_did_metadata(wallet_handle, &my_did, "other_metadata").unwrap();
+
+            let (search_handle, total_count) = did::search_my_dids(wallet_handle, Some(&json!({"metadata": METADATA}).to_string())).unwrap();
+            assert_eq!(0, total_count);
+            did::close_my_dids_search(search_handle).unwrap();
+
+            let query = json!({"metadata": "other_metadata", "~role": "issuer"}).to_string();
+            let (search_handle, total_count) = did::search_my_dids(wallet_handle, Some(&query)).unwrap();
+            assert_eq!(1, total_count);
+            did::close_my_dids_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_my_dids_works_for_invalid_query() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = did::search_my_dids(wallet_handle, Some(r#"{"~role":1}"#));
+            assert_code!(ErrorCode::WalletQueryError, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_fetch_my_dids_search_works_for_closed_search() {
+            let (wallet_handle, _) = utils::setup_did();
+
+            let (search_handle, _) = did::search_my_dids(wallet_handle, None).unwrap();
+            did::close_my_dids_search(search_handle).unwrap();
+
+            let res = did::fetch_my_dids_search(wallet_handle, search_handle, 1);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod delete_my_did {
+        use super::*;
+        use utils::{crypto, pairwise};
+
+        #[test]
+        fn indy_delete_my_did_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            did::set_did_metadata(wallet_handle, &my_did, METADATA).unwrap();
+
+            did::delete_my_did(wallet_handle, &my_did).unwrap();
+
+            let res = did::key_for_local_did(wallet_handle, &my_did);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            let res = did::get_did_metadata(wallet_handle, &my_did);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            let res = crypto::sign(wallet_handle, &my_verkey, MESSAGE.as_bytes());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_my_did_works_for_replaced_keys() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            let new_verkey = did::replace_keys_start(wallet_handle, &my_did, "{}").unwrap();
+            did::replace_keys_apply(wallet_handle, &my_did).unwrap();
+
+            did::delete_my_did(wallet_handle, &my_did).unwrap();
+
+            let res = crypto::sign(wallet_handle, &my_verkey, MESSAGE.as_bytes());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            let res = crypto::sign(wallet_handle, &new_verkey, MESSAGE.as_bytes());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            let res = did::get_did_key_history(wallet_handle, &my_did);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_my_did_works_for_their_did_with_same_value() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            did::store_their_did_from_parts(wallet_handle, &my_did, &my_verkey).unwrap();
+            did::set_did_metadata(wallet_handle, &my_did, METADATA).unwrap();
+
+            did::delete_my_did(wallet_handle, &my_did).unwrap();
+
+            assert_eq!(METADATA, did::get_did_metadata(wallet_handle, &my_did).unwrap());
+            assert_eq!(my_verkey, did::key_for_local_did(wallet_handle, &my_did).unwrap());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_my_did_works_for_used_in_pairwise() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+
+            let res = did::delete_my_did(wallet_handle, &my_did);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            pairwise::delete_pairwise(wallet_handle, DID_TRUSTEE).unwrap();
+            did::delete_my_did(wallet_handle, &my_did).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_my_did_works_for_pairwise_of_other_did() {
+            let (wallet_handle, my_did) = utils::setup_did();
+            let (other_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &other_did, None).unwrap();
+
+            did::delete_my_did(wallet_handle, &my_did).unwrap();
+
+            let res = did::delete_my_did(wallet_handle, &other_did);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_my_did_works_for_not_found_did() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = did::delete_my_did(wallet_handle, DID);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_my_did_works_for_invalid_did() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = did::delete_my_did(wallet_handle, INVALID_BASE58_DID);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod delete_their_did {
+        use super::*;
+        use utils::pairwise;
+
+        #[test]
+        fn indy_delete_their_did_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            did::set_did_metadata(wallet_handle, DID_TRUSTEE, METADATA).unwrap();
+
+            did::delete_their_did(wallet_handle, DID_TRUSTEE).unwrap();
+
+            let res = did::key_for_local_did(wallet_handle, DID_TRUSTEE);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            let res = did::get_did_metadata(wallet_handle, DID_TRUSTEE);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_their_did_works_for_used_in_pairwise() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+
+            let res = did::delete_their_did(wallet_handle, DID_TRUSTEE);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_their_did_works_for_not_found_did() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = did::delete_their_did(wallet_handle, DID_TRUSTEE);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
 }
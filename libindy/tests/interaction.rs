@@ -446,6 +446,74 @@ impl Prover
     }
 
 
+    // Same as make_proof, but revocation states are prepared (and stored in the wallet) by libindy
+    pub fn make_proof_with_updated_revocation_states(&self, pool : &Pool, proof_request: &str, cred_id: &str) -> (String, u64)
+    {
+        let cred_info : CredentialInfo = serde_json::from_str(&anoncreds::prover_get_credential(self.wallet_handle, cred_id).unwrap()).unwrap();
+
+        let schema_id = cred_info.schema_id;
+        let cred_def_id = cred_info.cred_def_id;
+        let rev_reg_id = cred_info.rev_reg_id.clone().unwrap();
+
+        // Prover gets Schema, CredentialDefinition and RevocationRegistryDefinition from Ledger
+        let (_, schema_json) = pool.get_schema(None, &schema_id);
+        let (_ , cred_def_json) = pool.get_cred_def(Some(&self.did), &cred_def_id);
+        let (_, revoc_reg_def_json) = pool.get_revoc_reg_def(None,&rev_reg_id);
+
+        let prover_blob_storage_reader_handle = blob_storage::open_reader(TYPE, &anoncreds::tails_writer_config()).unwrap();
+
+        let requested_credentials_json = json!({
+            "self_attested_attributes": json!({}),
+            "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": cred_info.referent, "revealed":true })
+            }),
+            "requested_predicates": json!({})
+        }).to_string();
+
+        let rev_reg_defs_json = json!({
+            rev_reg_id.clone(): serde_json::from_str::<RevocationRegistryDefinition>(&revoc_reg_def_json).unwrap()
+        }).to_string();
+
+        let blob_storage_reader_handles_json = json!({
+            rev_reg_id.clone(): prover_blob_storage_reader_handle
+        }).to_string();
+
+        // Prover fetches RevocationRegistryDelta from Ledger and creates or updates RevocationState
+        let (rev_states_json, requested_credentials_json) =
+            anoncreds::prover_update_revocation_states(self.wallet_handle,
+                                                       pool.pool_handle,
+                                                       proof_request,
+                                                       &requested_credentials_json,
+                                                       &rev_reg_defs_json,
+                                                       &blob_storage_reader_handles_json).unwrap();
+
+        let requested_credentials: Value = serde_json::from_str(&requested_credentials_json).unwrap();
+        let timestamp = requested_credentials["requested_attributes"]["attr1_referent"]["timestamp"].as_u64().unwrap();
+
+        // Resulting RevocationState is stored in the wallet
+        let stored_rev_state_json = anoncreds::prover_get_revocation_state(self.wallet_handle, &rev_reg_id, &cred_info.cred_rev_id.unwrap(), timestamp).unwrap();
+        let rev_states: Value = serde_json::from_str(&rev_states_json).unwrap();
+        assert_eq!(serde_json::from_str::<Value>(&stored_rev_state_json).unwrap(), rev_states[&rev_reg_id][timestamp.to_string()]);
+
+        let schemas_json = json!({
+            schema_id.clone(): serde_json::from_str::<Schema>(&schema_json).unwrap()
+        }).to_string();
+
+        let cred_defs_json = json!({
+            cred_def_id.clone(): serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap()
+        }).to_string();
+
+        let proof_json = anoncreds::prover_create_proof(self.wallet_handle,
+                                                        &proof_request,
+                                                        &requested_credentials_json,
+                                                        &self.master_secret_id,
+                                                        &schemas_json,
+                                                        &cred_defs_json,
+                                                        &rev_states_json).unwrap();
+
+        (proof_json, timestamp)
+    }
+
     pub fn close(&self)
     {
         wallet::close_wallet(self.wallet_handle).unwrap();
@@ -828,4 +896,83 @@ fn anoncreds_revocation_interaction_test_issuance_by_demand_three_credentials_po
 
 
     utils::tear_down();
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "revocation_tests")]
+#[test]
+fn anoncreds_revocation_interaction_test_update_revocation_states() {
+    utils::setup();
+
+    let pool = Pool::new();
+
+    let mut issuer = Issuer::new(&pool);
+
+    let mut prover = Prover::new(None);
+
+    // ISSUER post to Ledger Schema, CredentialDefinition, RevocationRegistry
+    issuer.create_initial_ledger_state(&pool, r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+    let (cred_rev_id, _) = multi_steps_create_revocation_credential(&pool, &issuer, &mut prover, &anoncreds::gvt_credential_values_json(), CREDENTIAL1_ID);
+
+    thread::sleep(std::time::Duration::from_secs(3));
+
+    let to = time::get_time().sec as u64;
+
+    let proof_request = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name"
+               })
+           }),
+           "requested_predicates": json!({}),
+           "non_revoked": json!({ "to": to })
+        }).to_string();
+
+    // Prover has no stored RevocationState yet, so it is created from the delta fetched from Ledger
+    let (proof_json, timestamp1) = prover.make_proof_with_updated_revocation_states(&pool, &proof_request, CREDENTIAL1_ID);
+
+    let verifier = Verifier::new(&proof_request);
+    verifier.verify_revealed(&proof_json, "attr1_referent", "Alex");
+    assert!(verifier.verify(&pool, &proof_json));
+
+    // Issuer revokes credential
+    issuer.revoke_credential(&pool, &cred_rev_id);
+
+    thread::sleep(std::time::Duration::from_secs(3));
+
+    let to = time::get_time().sec as u64;
+
+    let proof_request = json!({
+           "nonce":"123432421213",
+           "name":"proof_req_2",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name"
+               })
+           }),
+           "requested_predicates": json!({}),
+           "non_revoked": json!({ "to": to })
+        }).to_string();
+
+    // Stored RevocationState is updated against the new delta from Ledger
+    let (proof_json, timestamp2) = prover.make_proof_with_updated_revocation_states(&pool, &proof_request, CREDENTIAL1_ID);
+    assert!(timestamp1 < timestamp2);
+
+    let verifier = Verifier::new(&proof_request);
+    assert!(!verifier.verify(&pool, &proof_json));
+
+    // Both states stay in the wallet
+    anoncreds::prover_get_revocation_state(prover.wallet_handle, &issuer.rev_reg_id, &cred_rev_id, timestamp1).unwrap();
+    anoncreds::prover_get_revocation_state(prover.wallet_handle, &issuer.rev_reg_id, &cred_rev_id, timestamp2).unwrap();
+
+    issuer.close();
+    prover.close();
+
+    pool.close();
+
+    utils::tear_down();
+}
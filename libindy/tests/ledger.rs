@@ -32,7 +32,7 @@ mod utils;
 
 use self::indy::ErrorCode;
 #[cfg(feature = "local_nodes_pool")]
-use utils::{pool, ledger, did, anoncreds};
+use utils::{pool, ledger, did, anoncreds, crypto};
 use utils::types::*;
 use utils::constants::*;
 
@@ -164,6 +164,33 @@ mod high_cases {
             utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
         }
 
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works() {
+            let (wallet_handle, pool_handle, trustee_did) = utils::setup_trustee();
+
+            let pool_status = ledger::get_pool_status(pool_handle, wallet_handle, &trustee_did).unwrap();
+            let pool_status: serde_json::Value = serde_json::from_str(&pool_status).unwrap();
+
+            assert_eq!(4, pool_status["nodes"].as_object().unwrap().len());
+            assert_eq!(json!(true), pool_status["nodes"]["Node1"]["reachable"]);
+            assert_eq!(json!(4), pool_status["consensus"]["reachableNodes"]);
+            assert_eq!(json!(true), pool_status["consensus"]["fTolerated"]);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works_for_invalid_pool_handle() {
+            let (wallet_handle, pool_handle, trustee_did) = utils::setup_trustee();
+
+            let res = ledger::get_pool_status(pool_handle + 1, wallet_handle, &trustee_did);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_submit_action_works_for_not_supported_request_type() {
@@ -308,6 +335,255 @@ mod high_cases {
 
             utils::tear_down_with_wallet(wallet_handle);
         }
+
+        fn _author_request() -> String {
+            json!({
+                "reqId": 1496822211362017764u64,
+                "identifier": DID_MY1,
+                "operation": {"type": "1", "dest": DID_MY2, "verkey": VERKEY_MY2}
+            }).to_string()
+        }
+
+        #[test]
+        fn indy_multi_sign_request_works_for_endorser() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (author_did, _) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let request = ledger::append_request_endorser(&_author_request(), &trustee_did).unwrap();
+            let request = ledger::sign_request(wallet_handle, &author_did, &request).unwrap();
+            let request = ledger::multi_sign_request(wallet_handle, &trustee_did, &request).unwrap();
+
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            let signatures = request["signatures"].as_object().unwrap();
+
+            assert!(request["signature"].is_null());
+            assert_eq!(2, signatures.len());
+            assert!(signatures.contains_key(&author_did));
+            assert!(signatures.contains_key(&trustee_did));
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_multi_sign_request_works_for_endorser_and_other_co_signer() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (author_did, _) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+            let (co_signer_did, _) = did::create_and_store_my_did(wallet_handle, Some(MY2_SEED)).unwrap();
+
+            let request = ledger::append_request_endorser(&_author_request(), &trustee_did).unwrap();
+            let request = ledger::multi_sign_request(wallet_handle, &author_did, &request).unwrap();
+            let request = ledger::multi_sign_request(wallet_handle, &co_signer_did, &request).unwrap();
+            let request = ledger::multi_sign_request(wallet_handle, &trustee_did, &request).unwrap();
+
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            let signatures = request["signatures"].as_object().unwrap();
+
+            assert_eq!(3, signatures.len());
+            assert!(signatures.contains_key(&co_signer_did));
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_multi_sign_request_works_for_signed_request_without_endorser() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (author_did, _) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let signed_request = ledger::sign_request(wallet_handle, &author_did, &_author_request()).unwrap();
+            let signed_request: serde_json::Value = serde_json::from_str(&signed_request).unwrap();
+
+            let request = ledger::multi_sign_request(wallet_handle, &trustee_did, &signed_request.to_string()).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            let signatures = request["signatures"].as_object().unwrap();
+
+            assert_eq!(signed_request["signature"], request["signature"]);
+            assert_eq!(1, signatures.len());
+            assert!(signatures.contains_key(&trustee_did));
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod sign_request_for_pool {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_sign_request_for_pool_works_for_other_global_protocol_version() {
+            let (wallet_handle, pool_handle, trustee_did) = utils::setup_trustee();
+            let (did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            pool::set_protocol_version(1).unwrap();
+            let nym_request = ledger::build_nym_request(&trustee_did, &did, None, None, None).unwrap();
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let signed_request = ledger::sign_request(wallet_handle, &trustee_did, &nym_request).unwrap();
+            let res = ledger::submit_request(pool_handle, &signed_request);
+            assert_code!(ErrorCode::PoolIncompatibleProtocolVersion, res);
+
+            let signed_request = ledger::sign_request_for_pool(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&signed_request).unwrap();
+            assert_eq!(PROTOCOL_VERSION as u64, request["protocolVersion"].as_u64().unwrap());
+
+            let nym_response = ledger::submit_request(pool_handle, &signed_request).unwrap();
+            pool::check_response_type(&nym_response, ResponseType::REPLY);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_sign_request_for_pool_works_for_invalid_pool_handle() {
+            let (wallet_handle, pool_handle, trustee_did) = utils::setup_trustee();
+
+            let res = ledger::sign_request_for_pool(pool_handle + 1, wallet_handle, &trustee_did, REQUEST);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
+        }
+    }
+
+    mod multi_sign_request_for_pool {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_multi_sign_request_for_pool_works_for_other_global_protocol_version() {
+            let (wallet_handle, pool_handle, trustee_did) = utils::setup_trustee();
+            let (did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            pool::set_protocol_version(1).unwrap();
+            let nym_request = ledger::build_nym_request(&trustee_did, &did, None, None, None).unwrap();
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let signed_request = ledger::multi_sign_request_for_pool(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&signed_request).unwrap();
+            assert_eq!(PROTOCOL_VERSION as u64, request["protocolVersion"].as_u64().unwrap());
+            assert!(request["signatures"].as_object().unwrap().contains_key(&trustee_did));
+
+            let nym_response = ledger::submit_request(pool_handle, &signed_request).unwrap();
+            pool::check_response_type(&nym_response, ResponseType::REPLY);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_multi_sign_request_for_pool_works_for_request_signed_for_other_version() {
+            let (wallet_handle, pool_handle, trustee_did) = utils::setup_trustee();
+
+            pool::set_protocol_version(1).unwrap();
+            let nym_request = ledger::build_nym_request(&trustee_did, DID, None, None, None).unwrap();
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let signed_request = ledger::multi_sign_request(wallet_handle, &trustee_did, &nym_request).unwrap();
+
+            let res = ledger::multi_sign_request_for_pool(pool_handle, wallet_handle, &trustee_did, &signed_request);
+            assert_code!(ErrorCode::PoolIncompatibleProtocolVersion, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
+        }
+    }
+
+    mod signing_bundle {
+        use super::*;
+
+        fn _signers(trustee_verkey: &str, my1_verkey: &str) -> String {
+            json!({
+                DID_TRUSTEE: {"verkey": trustee_verkey, "role": "TRUSTEE"},
+                DID_MY1: {"verkey": my1_verkey, "role": "TRUSTEE"},
+            }).to_string()
+        }
+
+        #[test]
+        fn indy_signing_bundle_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (did, verkey) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (did2, verkey2) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let bundle = ledger::build_signing_bundle(REQUEST, &_signers(&verkey, &verkey2), None).unwrap();
+
+            // each trustee signs own copy of the bundle
+            let bundle1 = ledger::sign_signing_bundle(wallet_handle, &did, &bundle).unwrap();
+            let bundle2 = ledger::sign_signing_bundle(wallet_handle, &did2, &bundle).unwrap();
+
+            let status = ledger::check_signing_bundle(&bundle1).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(false, status["complete"]);
+            assert_eq!(json!([DID_MY1]), status["unsigned"]);
+
+            let bundle = ledger::merge_signing_bundles(&format!("[{},{}]", bundle1, bundle2)).unwrap();
+
+            let status = ledger::check_signing_bundle(&bundle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(true, status["complete"]);
+
+            let signatures = status["request"]["signatures"].as_object().unwrap();
+            assert_eq!(signatures[DID_TRUSTEE], r#"65hzs4nsdQsTUqLCLy2qisbKLfwYKZSWoyh1C6CU59p5pfG3EHQXGAsjW4Qw4QdwkrvjSgQuyv8qyABcXRBznFKW"#);
+            assert_eq!(signatures[DID_MY1], r#"49aXkbrtTE3e522AefE76J51WzUiakw3ZbxxWzf44cv7RS21n8mMr4vJzi4TymuqDupzCz7wEtuGz6rA94Y73kKR"#);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_signing_bundle_works_for_constraint() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (did, verkey) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (_, verkey2) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let constraint = r#"{"constraint_id": "ROLE", "role": "0", "sig_count": 1}"#;
+            let bundle = ledger::build_signing_bundle(REQUEST, &_signers(&verkey, &verkey2), Some(constraint)).unwrap();
+            let bundle = ledger::sign_signing_bundle(wallet_handle, &did, &bundle).unwrap();
+
+            let status = ledger::check_signing_bundle(&bundle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(true, status["complete"]);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_sign_signing_bundle_works_for_not_a_signer() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (_, verkey) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (_, verkey2) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+            let (did3, _) = did::create_and_store_my_did(wallet_handle, Some(MY2_SEED)).unwrap();
+
+            let bundle = ledger::build_signing_bundle(REQUEST, &_signers(&verkey, &verkey2), None).unwrap();
+
+            let res = ledger::sign_signing_bundle(wallet_handle, &did3, &bundle);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_check_signing_bundle_works_for_invalid_signature() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (did, verkey) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (did2, verkey2) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let bundle = ledger::build_signing_bundle(REQUEST, &_signers(&verkey, &verkey2), None).unwrap();
+            let bundle = ledger::sign_signing_bundle(wallet_handle, &did, &bundle).unwrap();
+
+            let mut bundle: serde_json::Value = serde_json::from_str(&bundle).unwrap();
+            bundle["signatures"][&did2] = bundle["signatures"][&did].clone();
+
+            let res = ledger::check_signing_bundle(&bundle.to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
     }
 
 
@@ -754,6 +1030,44 @@ mod high_cases {
             check_request(&request, expected_result);
         }
 
+        #[test]
+        fn indy_build_node_request_works_for_created_bls_key() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (blskey, blskey_pop) = crypto::create_bls_key(wallet_handle, None).unwrap();
+
+            let mut node_data: serde_json::Value = serde_json::from_str(NODE_DATA).unwrap();
+            node_data["blskey"] = json!(blskey);
+            node_data["blskey_pop"] = json!(blskey_pop);
+
+            let request = ledger::build_node_request(IDENTIFIER, DEST, &node_data.to_string()).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            assert_eq!(request["operation"]["data"], node_data);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_build_node_request_with_bls_key_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (blskey, _) = crypto::create_bls_key(wallet_handle, None).unwrap();
+
+            let request = ledger::build_node_request_with_bls_key(wallet_handle, IDENTIFIER, DEST, NODE_DATA, &blskey).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            let data = &request["operation"]["data"];
+            assert_eq!(constants::NODE, request["operation"]["type"].as_str().unwrap());
+            assert_eq!(DEST, request["operation"]["dest"].as_str().unwrap());
+            assert_eq!("Node5", data["alias"].as_str().unwrap());
+            assert_eq!(blskey, data["blskey"].as_str().unwrap());
+
+            // pop is checked by the builder, so the data can be reused as is
+            ledger::build_node_request(IDENTIFIER, DEST, &data.to_string()).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_send_node_request_works_without_signature() {
@@ -1522,6 +1836,56 @@ mod high_cases {
         }
     }
 
+    mod custom_requests {
+        use super::*;
+
+        fn _txn_type(name: &str, code: &str, kind: &str) -> String {
+            json!({
+                "name": name,
+                "code": code,
+                "kind": kind,
+                "schema": {"data": {"type": "string"}}
+            }).to_string()
+        }
+
+        #[test]
+        fn indy_build_custom_request_works() {
+            ledger::register_custom_txn_type(&_txn_type("INTEGRATION_WRITE", "30001", "write")).unwrap();
+
+            let request = ledger::build_custom_request(Some(DID_TRUSTEE), "INTEGRATION_WRITE", r#"{"data": "some data"}"#).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            assert_eq!(json!({"type": "30001", "data": "some data"}), request["operation"]);
+            assert_eq!(json!(DID_TRUSTEE), request["identifier"]);
+        }
+
+        #[test]
+        fn indy_build_custom_request_works_for_invalid_operation() {
+            ledger::register_custom_txn_type(&_txn_type("INTEGRATION_INVALID", "30002", "read")).unwrap();
+
+            let res = ledger::build_custom_request(None, "INTEGRATION_INVALID", r#"{"data": 1}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_register_transaction_parser_for_sp_works_for_custom_write_txn_type() {
+            extern crate libc;
+
+            use self::libc::c_char;
+
+            extern fn parse(msg: *const c_char, parsed: *mut *const c_char) -> i32 {
+                unsafe { *parsed = msg; }
+                ErrorCode::Success as i32
+            }
+            extern fn free(_buf: *const c_char) -> i32 { ErrorCode::Success as i32 }
+
+            ledger::register_custom_txn_type(&_txn_type("INTEGRATION_SP", "30003", "write")).unwrap();
+
+            let res = ledger::register_transaction_parser_for_sp("INTEGRATION_SP", parse, free);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+        }
+    }
+
     mod get_response_metadata {
         use super::*;
 
@@ -2374,8 +2738,10 @@ mod medium_cases {
 
         #[test]
         fn indy_build_node_request_works_for_wrong_service() {
-            let data = r#"{"node_ip":"10.0.0.100", "node_port": 1, "client_ip": "10.0.0.100", "client_port": 1, "alias":"some", "services": ["SERVICE"], "blskey": "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW", "blskey_pop": "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW"}"#;
-            let res = ledger::build_node_request(IDENTIFIER, DEST, data);
+            let mut node_data: serde_json::Value = serde_json::from_str(NODE_DATA).unwrap();
+            node_data["services"] = json!(["SERVICE"]);
+
+            let res = ledger::build_node_request(IDENTIFIER, DEST, &node_data.to_string());
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
@@ -2425,13 +2791,34 @@ mod medium_cases {
 
             let (my_did, _) = did::create_store_and_publish_my_did_from_steward(wallet_handle, pool_handle).unwrap();
 
-            let node_data = r#"{"node_ip":"10.0.0.100", "node_port": 1, "client_ip": "10.0.0.100", "client_port": 2, "alias":"some", "services": ["VALIDATOR"], "blskey": "4N8aUNHSgjQVgkpm8nhNEfDf6txHznoYREg9kirmJrkivgL4oSEimFF6nsQ6M41QvhM2Z33nves5vfSn9n1UwNFJBYtWVnHYMATn76vLuL3zU88KyeAYcHfsih3He6UHcXDxcaecHVz6jhCYz1P2UZn2bDVruL5wXpehgBfBaLKm3Ba", "blskey_pop": "RPLagxaR5xdimFzwmzYnz4ZhWtYQEj8iR5ZU53T2gitPCyCHQneUn2Huc4oeLd2B2HzkGnjAff4hWTJT6C7qHYB1Mv2wU5iHHGFWkhnTX9WsEAbunJCV2qcaXScKj4tTfvdDKfLiVuU2av6hbsMztirRze7LvYBkRHV3tGwyCptsrP"}"#;
+            // the builder rejects not corresponding pop, so it's added to the built request
+            let node_data = r#"{"node_ip":"10.0.0.100", "node_port": 1, "client_ip": "10.0.0.100", "client_port": 2, "alias":"some", "services": ["VALIDATOR"], "blskey": "4N8aUNHSgjQVgkpm8nhNEfDf6txHznoYREg9kirmJrkivgL4oSEimFF6nsQ6M41QvhM2Z33nves5vfSn9n1UwNFJBYtWVnHYMATn76vLuL3zU88KyeAYcHfsih3He6UHcXDxcaecHVz6jhCYz1P2UZn2bDVruL5wXpehgBfBaLKm3Ba"}"#;
             let node_request = ledger::build_node_request(&my_did, DEST, node_data).unwrap();
-            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &my_did, &node_request).unwrap();
+            let mut node_request: serde_json::Value = serde_json::from_str(&node_request).unwrap();
+            node_request["operation"]["data"]["blskey_pop"] = json!("RPLagxaR5xdimFzwmzYnz4ZhWtYQEj8iR5ZU53T2gitPCyCHQneUn2Huc4oeLd2B2HzkGnjAff4hWTJT6C7qHYB1Mv2wU5iHHGFWkhnTX9WsEAbunJCV2qcaXScKj4tTfvdDKfLiVuU2av6hbsMztirRze7LvYBkRHV3tGwyCptsrP");
+
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &my_did, &node_request.to_string()).unwrap();
             pool::check_response_type(&response, ResponseType::REQNACK);
 
             utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle);
         }
+
+        #[test]
+        fn indy_build_node_request_works_for_pop_not_correspond_blskey() {
+            let node_data = r#"{"node_ip":"10.0.0.100", "node_port": 1, "client_ip": "10.0.0.100", "client_port": 2, "alias":"some", "services": ["VALIDATOR"], "blskey": "4N8aUNHSgjQVgkpm8nhNEfDf6txHznoYREg9kirmJrkivgL4oSEimFF6nsQ6M41QvhM2Z33nves5vfSn9n1UwNFJBYtWVnHYMATn76vLuL3zU88KyeAYcHfsih3He6UHcXDxcaecHVz6jhCYz1P2UZn2bDVruL5wXpehgBfBaLKm3Ba", "blskey_pop": "RPLagxaR5xdimFzwmzYnz4ZhWtYQEj8iR5ZU53T2gitPCyCHQneUn2Huc4oeLd2B2HzkGnjAff4hWTJT6C7qHYB1Mv2wU5iHHGFWkhnTX9WsEAbunJCV2qcaXScKj4tTfvdDKfLiVuU2av6hbsMztirRze7LvYBkRHV3tGwyCptsrP"}"#;
+            let res = ledger::build_node_request(IDENTIFIER, DEST, node_data);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_node_request_with_bls_key_works_for_unknown_key() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = ledger::build_node_request_with_bls_key(wallet_handle, IDENTIFIER, DEST, NODE_DATA, "4N8aUNHSgjQVgkpm8nhNEfDf6txHznoYREg9kirmJrkivgL4oSEimFF6nsQ6M41QvhM2Z33nves5vfSn9n1UwNFJBYtWVnHYMATn76vLuL3zU88KyeAYcHfsih3He6UHcXDxcaecHVz6jhCYz1P2UZn2bDVruL5wXpehgBfBaLKm3Ba");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
     }
 
     mod cred_def_requests {
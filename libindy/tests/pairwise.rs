@@ -308,5 +308,273 @@ mod high_cases {
             utils::tear_down_with_wallet(wallet_handle);
         }
     }
-}
 
+    mod set_pairwise_tags {
+        use super::*;
+
+        #[test]
+        fn indy_set_pairwise_tags_works() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+
+            pairwise::set_pairwise_tags(wallet_handle, DID_TRUSTEE, r#"{"~label":"Trustee"}"#).unwrap();
+
+            let pairwise_info: serde_json::Value = serde_json::from_str(&pairwise::get_pairwise(wallet_handle, DID_TRUSTEE).unwrap()).unwrap();
+            assert_eq!(json!({"my_did": my_did, "tags": {"~label": "Trustee"}}), pairwise_info);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_pairwise_tags_works_for_reset() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+
+            pairwise::set_pairwise_tags(wallet_handle, DID_TRUSTEE, r#"{"~label":"Trustee"}"#).unwrap();
+            pairwise::set_pairwise_tags(wallet_handle, DID_TRUSTEE, "{}").unwrap();
+
+            let pairwise_info = pairwise::get_pairwise(wallet_handle, DID_TRUSTEE).unwrap();
+            assert_eq!(format!(r#"{{"my_did":"{}"}}"#, my_did), pairwise_info);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_pairwise_tags_works_for_reserved_my_did_tag() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+
+            let res = pairwise::set_pairwise_tags(wallet_handle, DID_TRUSTEE, &json!({"my_did": DID_MY1}).to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_pairwise_tags_works_for_invalid_tags() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+
+            let res = pairwise::set_pairwise_tags(wallet_handle, DID_TRUSTEE, r#"{"~label":1}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_pairwise_tags_works_for_not_created_pairwise() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = pairwise::set_pairwise_tags(wallet_handle, DID_TRUSTEE, r#"{"~label":"Trustee"}"#);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod delete_pairwise {
+        use super::*;
+
+        #[test]
+        fn indy_delete_pairwise_works() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+
+            pairwise::delete_pairwise(wallet_handle, DID_TRUSTEE).unwrap();
+
+            assert!(!pairwise::pairwise_exists(wallet_handle, DID_TRUSTEE).unwrap());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_pairwise_works_for_recreate() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+            pairwise::delete_pairwise(wallet_handle, DID_TRUSTEE).unwrap();
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, Some(METADATA)).unwrap();
+
+            let pairwise_info = pairwise::get_pairwise(wallet_handle, DID_TRUSTEE).unwrap();
+            assert_eq!(format!(r#"{{"my_did":"{}","metadata":"{}"}}"#, my_did, METADATA), pairwise_info);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_pairwise_works_for_not_created_pairwise() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = pairwise::delete_pairwise(wallet_handle, DID_TRUSTEE);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_delete_pairwise_works_for_invalid_wallet_handle() {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+
+            let res = pairwise::delete_pairwise(wallet_handle + 1, DID_TRUSTEE);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod search_pairwise {
+        use super::*;
+
+        fn _setup_pairwise() -> (i32, String) {
+            let (wallet_handle, my_did) = utils::setup_did();
+            let (my_did_1, _) = did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            did::store_their_did_from_parts(wallet_handle, DID_MY2, VERKEY_MY2).unwrap();
+
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+            pairwise::create_pairwise(wallet_handle, DID_MY2, &my_did_1, None).unwrap();
+
+            pairwise::set_pairwise_tags(wallet_handle, DID_TRUSTEE, r#"{"~label":"Trustee"}"#).unwrap();
+
+            (wallet_handle, my_did)
+        }
+
+        fn _fetch(search_handle: i32, count: usize) -> Vec<serde_json::Value> {
+            serde_json::from_str(&pairwise::fetch_pairwise_search(search_handle, count).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn indy_search_pairwise_works() {
+            let (wallet_handle, _) = _setup_pairwise();
+
+            let (search_handle, total_count) = pairwise::search_pairwise(wallet_handle, None).unwrap();
+            assert_eq!(2, total_count);
+
+            assert_eq!(1, _fetch(search_handle, 1).len());
+            assert_eq!(1, _fetch(search_handle, 1).len());
+            assert_eq!(0, _fetch(search_handle, 1).len());
+
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_pairwise_works_for_my_did() {
+            let (wallet_handle, my_did) = _setup_pairwise();
+
+            let (search_handle, total_count) = pairwise::search_pairwise(wallet_handle, Some(&json!({"my_did": my_did}).to_string())).unwrap();
+            assert_eq!(1, total_count);
+
+            let list_pairwise = _fetch(search_handle, 10);
+            assert_eq!(json!([{"my_did": my_did, "their_did": DID_TRUSTEE, "tags": {"~label": "Trustee"}}]), json!(list_pairwise));
+
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_pairwise_works_for_tag() {
+            let (wallet_handle, _) = _setup_pairwise();
+
+            let (search_handle, total_count) = pairwise::search_pairwise(wallet_handle, Some(r#"{"~label":"Trustee"}"#)).unwrap();
+            assert_eq!(1, total_count);
+
+            let list_pairwise = _fetch(search_handle, 10);
+            assert_eq!(1, list_pairwise.len());
+            assert_eq!(DID_TRUSTEE, list_pairwise[0]["their_did"].as_str().unwrap());
+
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_pairwise_works_for_reset_tags() {
+            let (wallet_handle, _) = _setup_pairwise();
+
+            pairwise::set_pairwise_tags(wallet_handle, DID_TRUSTEE, "{}").unwrap();
+
+            let (search_handle, total_count) = pairwise::search_pairwise(wallet_handle, Some(r#"{"~label":"Trustee"}"#)).unwrap();
+            assert_eq!(0, total_count);
+            assert_eq!(0, _fetch(search_handle, 10).len());
+
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_pairwise_works_for_deleted_pairwise() {
+            let (wallet_handle, my_did) = _setup_pairwise();
+
+            pairwise::delete_pairwise(wallet_handle, DID_TRUSTEE).unwrap();
+
+            let (search_handle, total_count) = pairwise::search_pairwise(wallet_handle, Some(&json!({"my_did": my_did}).to_string())).unwrap();
+            assert_eq!(0, total_count);
+
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_pairwise_works_for_invalid_query() {
+            let (wallet_handle, _) = _setup_pairwise();
+
+            let res = pairwise::search_pairwise(wallet_handle, Some(r#"{"my_did":1}"#));
+            assert_code!(ErrorCode::WalletQueryError, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_fetch_pairwise_search_works_for_closed_search() {
+            let (wallet_handle, _) = _setup_pairwise();
+
+            let (search_handle, _) = pairwise::search_pairwise(wallet_handle, None).unwrap();
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let res = pairwise::fetch_pairwise_search(search_handle, 1);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_close_pairwise_search_works_for_twice() {
+            let (wallet_handle, _) = _setup_pairwise();
+
+            let (search_handle, _) = pairwise::search_pairwise(wallet_handle, None).unwrap();
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let res = pairwise::close_pairwise_search(search_handle);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+}
@@ -497,6 +497,23 @@ mod medium_cases {
         fn open_pool_ledger_works_for_incompatible_protocol_version() {
             utils::setup();
 
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(POOL, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(POOL, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::open_pool_ledger(POOL, Some(r#"{"protocol_version": 1}"#));
+            assert_code!(ErrorCode::PoolIncompatibleProtocolVersion, res);
+
+            utils::tear_down();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_protocol_version_of_genesis_txns() {
+            utils::setup();
+
             pool::set_protocol_version(1).unwrap();
 
             let txn_file_path = pool::create_genesis_txn_file_for_test_pool(POOL, None, None);
@@ -504,7 +521,11 @@ mod medium_cases {
             pool::create_pool_ledger_config(POOL, Some(pool_config.as_str())).unwrap();
 
             let res = pool::open_pool_ledger(POOL, None);
-            assert_code!(ErrorCode::PoolIncompatibleProtocolVersion, res);
+
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let pool_handle = res.unwrap();
+            pool::close(pool_handle).unwrap();
 
             utils::tear_down();
         }
@@ -33,6 +33,8 @@ pub const CREDENTIAL2_ID: &'static str = "credential2_id";
 pub const CREDENTIAL3_ID: &'static str = "credential3_id";
 pub const DELIMITER: &'static str = ":";
 pub const CRED_DEF_MARKER: &'static str = "3";
+pub const NONCE_EXPIRES_AT: u64 = 4102444800; // 2100-01-01
+pub const NONCE_EXPIRED_AT: u64 = 1;
 
 macro_rules! map (
     { $($key:expr => $value:expr),+ } => {
@@ -65,11 +67,55 @@ pub fn issuer_create_credential_offer(wallet_handle: i32, cred_def_id: &str) ->
     anoncreds::issuer_create_credential_offer(wallet_handle, cred_def_id).wait()
 }
 
+pub fn issuer_create_credential_offer_with_nonce_tracking(wallet_handle: i32, cred_def_id: &str, nonce_expires_at: u64) -> Result<String, IndyError> {
+    anoncreds::issuer_create_credential_offer_with_nonce_tracking(wallet_handle, cred_def_id, nonce_expires_at).wait()
+}
+
 pub fn issuer_create_credential(wallet_handle: i32, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str,
                                 rev_reg_id: Option<&str>, blob_storage_reader_handle: Option<i32>) -> Result<(String, Option<String>, Option<String>), IndyError> {
     anoncreds::issuer_create_credential(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle.unwrap_or(-1)).wait() // TODO OPTIONAL blob_storage_reader_handle
 }
 
+pub fn issuer_create_credential_with_options(wallet_handle: i32, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str,
+                                             rev_reg_id: Option<&str>, blob_storage_reader_handle: Option<i32>,
+                                             options_json: &str) -> Result<(String, Option<String>, Option<String>), IndyError> {
+    anoncreds::issuer_create_credential_with_options(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id,
+                                                     blob_storage_reader_handle.unwrap_or(-1), options_json).wait()
+}
+
+pub fn issuer_search_issued_credentials(wallet_handle: i32, query_json: Option<&str>) -> Result<(i32, usize), IndyError> {
+    anoncreds::issuer_search_issued_credentials(wallet_handle, query_json).wait()
+}
+
+pub fn issuer_fetch_issued_credentials(search_handle: i32, count: usize) -> Result<String, IndyError> {
+    anoncreds::issuer_fetch_issued_credentials(search_handle, count).wait()
+}
+
+pub fn issuer_close_issued_credentials_search(search_handle: i32) -> Result<(), IndyError> {
+    anoncreds::issuer_close_issued_credentials_search(search_handle).wait()
+}
+
+pub fn issuer_get_all_issued_credentials(wallet_handle: i32, query_json: &str) -> Vec<serde_json::Value> {
+    let (search_handle, total_count) = issuer_search_issued_credentials(wallet_handle, Some(query_json)).unwrap();
+
+    let issued_credentials_json = issuer_fetch_issued_credentials(search_handle, total_count + 1).unwrap();
+
+    issuer_close_issued_credentials_search(search_handle).unwrap();
+
+    let issued_credentials: Vec<serde_json::Value> = serde_json::from_str(&issued_credentials_json).unwrap();
+    assert_eq!(total_count, issued_credentials.len());
+
+    issued_credentials
+}
+
+pub fn store_issued_credential_options() -> String {
+    json!({"store_issued_credential": true}).to_string()
+}
+
+pub fn offer_nonce_tracking_options() -> String {
+    json!({"track_offer_nonce": true}).to_string()
+}
+
 pub fn issuer_revoke_credential(wallet_handle: i32, blob_storage_reader_handle: i32, rev_reg_id: &str, cred_revoc_id: &str) -> Result<String, IndyError> {
     anoncreds::issuer_revoke_credential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id).wait()
 }
@@ -142,6 +188,16 @@ pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas
     anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
 }
 
+pub fn verifier_track_proof_request_nonce(wallet_handle: i32, proof_request_json: &str, nonce_expires_at: u64) -> Result<(), IndyError> {
+    anoncreds::verifier_track_proof_request_nonce(wallet_handle, proof_request_json, nonce_expires_at).wait()
+}
+
+pub fn verifier_verify_proof_with_nonce_tracking(wallet_handle: i32, proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                                 cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<bool, IndyError> {
+    anoncreds::verifier_verify_proof_with_nonce_tracking(wallet_handle, proof_request_json, proof_json, schemas_json, cred_defs_json,
+                                                         rev_reg_defs_json, rev_regs_json).wait()
+}
+
 pub fn create_revocation_state(blob_storage_reader_handle: i32, rev_reg_def_json: &str,
                                rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
     anoncreds::create_revocation_state(blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
@@ -152,6 +208,32 @@ pub fn update_revocation_state(tails_reader_handle: i32, rev_state_json: &str, r
     anoncreds::update_revocation_state(tails_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
 }
 
+pub fn prover_store_revocation_state(wallet_handle: i32, rev_reg_id: &str, cred_rev_id: &str, rev_state_json: &str) -> Result<(), IndyError> {
+    anoncreds::prover_store_revocation_state(wallet_handle, rev_reg_id, cred_rev_id, rev_state_json).wait()
+}
+
+pub fn prover_get_revocation_state(wallet_handle: i32, rev_reg_id: &str, cred_rev_id: &str, timestamp: u64) -> Result<String, IndyError> {
+    anoncreds::prover_get_revocation_state(wallet_handle, rev_reg_id, cred_rev_id, timestamp).wait()
+}
+
+pub fn prover_update_revocation_states(wallet_handle: i32, pool_handle: i32, proof_req_json: &str, requested_credentials_json: &str,
+                                       rev_reg_defs_json: &str, blob_storage_reader_handles_json: &str) -> Result<(String, String), IndyError> {
+    anoncreds::prover_update_revocation_states(wallet_handle, pool_handle, proof_req_json, requested_credentials_json,
+                                               rev_reg_defs_json, blob_storage_reader_handles_json).wait()
+}
+
+pub fn prover_get_credential_w3c(wallet_handle: i32, cred_id: &str) -> Result<String, IndyError> {
+    anoncreds::prover_get_credential_w3c(wallet_handle, cred_id).wait()
+}
+
+pub fn prover_proof_to_w3c(proof_req_json: &str, proof_json: &str) -> Result<String, IndyError> {
+    anoncreds::prover_proof_to_w3c(proof_req_json, proof_json).wait()
+}
+
+pub fn verifier_proof_from_w3c(w3c_presentation_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_proof_from_w3c(w3c_presentation_json).wait()
+}
+
 pub fn default_cred_def_config() -> String {
     serde_json::to_string(&CredentialDefinitionConfig { support_revocation: false }).unwrap()
 }
@@ -692,6 +774,37 @@ pub fn multi_steps_create_credential(prover_master_secret_id: &str,
                             None).unwrap();
 }
 
+pub fn multi_steps_create_credential_with_options(prover_master_secret_id: &str,
+                                                  prover_wallet_handle: i32,
+                                                  issuer_wallet_handle: i32,
+                                                  cred_values: &str,
+                                                  cred_def_id: &str,
+                                                  cred_def_json: &str,
+                                                  rev_reg_id: Option<&str>,
+                                                  blob_storage_reader_handle: Option<i32>,
+                                                  options_json: &str) -> Option<String> {
+    // Issuer creates Credential Offer
+    let cred_offer_json = issuer_create_credential_offer(issuer_wallet_handle, cred_def_id).unwrap();
+
+    // Prover creates Credential Request
+    let (cred_req, _) = prover_create_credential_req(prover_wallet_handle,
+                                                     DID_MY1,
+                                                     &cred_offer_json,
+                                                     cred_def_json,
+                                                     prover_master_secret_id).unwrap();
+
+    // Issuer creates Credential
+    let (_, cred_rev_id, _) = issuer_create_credential_with_options(issuer_wallet_handle,
+                                                                    &cred_offer_json,
+                                                                    &cred_req,
+                                                                    cred_values,
+                                                                    rev_reg_id,
+                                                                    blob_storage_reader_handle,
+                                                                    options_json).unwrap();
+
+    cred_rev_id
+}
+
 pub fn multi_steps_create_revocation_credential(prover_master_secret_id: &str,
                                                 prover_wallet_handle: i32,
                                                 issuer_wallet_handle: i32,
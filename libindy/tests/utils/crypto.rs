@@ -9,6 +9,24 @@ pub fn create_key(wallet_handle: i32, seed: Option<&str>) -> Result<String, Indy
     crypto::create_key(wallet_handle, Some(&key_json)).wait()
 }
 
+pub fn create_derived_key(wallet_handle: i32, derivation_path: &str) -> Result<String, IndyError> {
+    let key_json = json!({"derivation_path": derivation_path}).to_string();
+    crypto::create_key(wallet_handle, Some(&key_json)).wait()
+}
+
+pub fn set_master_seed(wallet_handle: i32, seed: Option<&str>) -> Result<String, IndyError> {
+    crypto::set_master_seed(wallet_handle, seed).wait()
+}
+
+pub fn list_derived_keys(wallet_handle: i32) -> Result<String, IndyError> {
+    crypto::list_derived_keys(wallet_handle).wait()
+}
+
+pub fn create_bls_key(wallet_handle: i32, seed: Option<&str>) -> Result<(String, String), IndyError> {
+    let key_json = json!({"seed": seed}).to_string();
+    crypto::create_bls_key(wallet_handle, Some(&key_json)).wait()
+}
+
 pub fn set_key_metadata(wallet_handle: i32, verkey: &str, metadata: &str) -> Result<(), IndyError> {
     crypto::set_key_metadata(wallet_handle, verkey, metadata).wait()
 }
@@ -47,4 +65,11 @@ pub fn pack_message(wallet_handle: i32, message: &[u8], receiver_keys: &str, sen
 
 pub fn unpack_message(wallet_handle: i32, jwe: &[u8]) -> Result<Vec<u8>, IndyError> {
     crypto::unpack_message(wallet_handle, jwe).wait()
-}
\ No newline at end of file
+}
+pub fn pack_message_with_routing(wallet_handle: i32, message: &[u8], receiver_keys: &str, routing_keys: &str, sender: Option<&str>) -> Result<Vec<u8>, IndyError> {
+    crypto::pack_message_with_routing(wallet_handle, message, receiver_keys, routing_keys, sender).wait()
+}
+
+pub fn unpack_message_with_routing(wallet_handle: i32, jwe: &[u8]) -> Result<Vec<u8>, IndyError> {
+    crypto::unpack_message_with_routing(wallet_handle, jwe).wait()
+}
@@ -79,6 +79,18 @@ pub fn get_endpoint_for_did(wallet_handle: i32, pool_handle: i32, did: &str) ->
     did::get_endpoint_for_did(wallet_handle, pool_handle, did).wait()
 }
 
+pub fn set_services_for_did(wallet_handle: i32, did: &str, services_json: &str) -> Result<(), IndyError> {
+    did::set_services_for_did(wallet_handle, did, services_json).wait()
+}
+
+pub fn get_services_for_did(wallet_handle: i32, pool_handle: i32, did: &str) -> Result<String, IndyError> {
+    did::get_services_for_did(wallet_handle, pool_handle, did).wait()
+}
+
+pub fn build_did_services_attrib_request(wallet_handle: i32, submitter_did: &str, did: &str) -> Result<String, IndyError> {
+    did::build_did_services_attrib_request(wallet_handle, submitter_did, did).wait()
+}
+
 pub fn set_did_metadata(wallet_handle: i32, did: &str, metadata: &str) -> Result<(), IndyError> {
     did::set_did_metadata(wallet_handle, did, metadata).wait()
 }
@@ -93,4 +105,40 @@ pub fn get_my_did_with_metadata(wallet_handle: i32, did: &str) -> Result<String,
 
 pub fn abbreviate_verkey(did: &str, verkey: &str) -> Result<String, IndyError> {
     did::abbreviate_verkey(did, verkey).wait()
-}
\ No newline at end of file
+}
+
+pub fn get_did_key_history(wallet_handle: i32, did: &str) -> Result<String, IndyError> {
+    did::get_did_key_history(wallet_handle, did).wait()
+}
+
+pub fn set_did_key_ledger_info(wallet_handle: i32, did: &str, verkey: &str, seq_no: u64, txn_time: u64) -> Result<(), IndyError> {
+    did::set_did_key_ledger_info(wallet_handle, did, verkey, seq_no, txn_time).wait()
+}
+
+pub fn verify_did_signature_at(wallet_handle: i32, did: &str, timestamp: u64, message: &[u8], signature: &[u8]) -> Result<bool, IndyError> {
+    did::verify_did_signature_at(wallet_handle, did, timestamp, message, signature).wait()
+}
+
+pub fn set_my_did_tags(wallet_handle: i32, did: &str, tags_json: &str) -> Result<(), IndyError> {
+    did::set_my_did_tags(wallet_handle, did, tags_json).wait()
+}
+
+pub fn search_my_dids(wallet_handle: i32, query_json: Option<&str>) -> Result<(i32, usize), IndyError> {
+    did::search_my_dids(wallet_handle, query_json).wait()
+}
+
+pub fn fetch_my_dids_search(wallet_handle: i32, search_handle: i32, count: usize) -> Result<String, IndyError> {
+    did::fetch_my_dids_search(wallet_handle, search_handle, count).wait()
+}
+
+pub fn close_my_dids_search(search_handle: i32) -> Result<(), IndyError> {
+    did::close_my_dids_search(search_handle).wait()
+}
+
+pub fn delete_my_did(wallet_handle: i32, did: &str) -> Result<(), IndyError> {
+    did::delete_my_did(wallet_handle, did).wait()
+}
+
+pub fn delete_their_did(wallet_handle: i32, did: &str) -> Result<(), IndyError> {
+    did::delete_their_did(wallet_handle, did).wait()
+}
@@ -46,6 +46,30 @@ pub fn multi_sign_request(wallet_handle: i32, submitter_did: &str, request_json:
     ledger::multi_sign_request(wallet_handle, submitter_did, request_json).wait()
 }
 
+pub fn sign_request_for_pool(pool_handle: i32, wallet_handle: i32, submitter_did: &str, request_json: &str) -> Result<String, IndyError> {
+    ledger::sign_request_for_pool(pool_handle, wallet_handle, submitter_did, request_json).wait()
+}
+
+pub fn multi_sign_request_for_pool(pool_handle: i32, wallet_handle: i32, submitter_did: &str, request_json: &str) -> Result<String, IndyError> {
+    ledger::multi_sign_request_for_pool(pool_handle, wallet_handle, submitter_did, request_json).wait()
+}
+
+pub fn build_signing_bundle(request_json: &str, signers_json: &str, constraint_json: Option<&str>) -> Result<String, IndyError> {
+    ledger::build_signing_bundle(request_json, signers_json, constraint_json).wait()
+}
+
+pub fn sign_signing_bundle(wallet_handle: i32, signer_did: &str, bundle_json: &str) -> Result<String, IndyError> {
+    ledger::sign_signing_bundle(wallet_handle, signer_did, bundle_json).wait()
+}
+
+pub fn merge_signing_bundles(bundles_json: &str) -> Result<String, IndyError> {
+    ledger::merge_signing_bundles(bundles_json).wait()
+}
+
+pub fn check_signing_bundle(bundle_json: &str) -> Result<String, IndyError> {
+    ledger::check_signing_bundle(bundle_json).wait()
+}
+
 pub fn extract_seq_no_from_reply(reply: &str) -> Result<u64, &'static str> {
     let metadata = get_response_metadata(reply).map_err(|_| "Can not get Metadata from Reply")?;
 
@@ -115,10 +139,18 @@ pub fn build_node_request(submitter_did: &str, target_did: &str, data: &str) ->
     ledger::build_node_request(submitter_did, target_did, data).wait()
 }
 
+pub fn build_node_request_with_bls_key(wallet_handle: i32, submitter_did: &str, target_did: &str, data: &str, blskey: &str) -> Result<String, IndyError> {
+    ledger::build_node_request_with_bls_key(wallet_handle, submitter_did, target_did, data, blskey).wait()
+}
+
 pub fn build_get_validator_info_request(submitter_did: &str) -> Result<String, IndyError> {
     ledger::build_get_validator_info_request(submitter_did).wait()
 }
 
+pub fn get_pool_status(pool_handle: i32, wallet_handle: i32, submitter_did: &str) -> Result<String, IndyError> {
+    ledger::get_pool_status(pool_handle, wallet_handle, submitter_did).wait()
+}
+
 pub fn build_get_txn_request(submitter_did: Option<&str>, data: i32, ledger_type: Option<&str>) -> Result<String, IndyError> {
     ledger::build_get_txn_request(submitter_did, ledger_type, data).wait()
 }
@@ -196,6 +228,14 @@ pub fn register_transaction_parser_for_sp(txn_type: &str, parse: CustomTransacti
     super::results::result_to_empty(err, receiver)
 }
 
+pub fn register_custom_txn_type(txn_type_json: &str) -> Result<(), IndyError> {
+    ledger::register_custom_txn_type(txn_type_json).wait()
+}
+
+pub fn build_custom_request(submitter_did: Option<&str>, txn_type: &str, operation_json: &str) -> Result<String, IndyError> {
+    ledger::build_custom_request(submitter_did, txn_type, operation_json).wait()
+}
+
 pub fn get_response_metadata(response: &str) -> Result<String, IndyError> {
     ledger::get_response_metadata(response).wait()
 }
@@ -293,4 +333,7 @@ pub fn post_entities() -> (&'static str, &'static str, &'static str) {
 
         (SCHEMA_ID, CRED_DEF_ID, REV_REG_DEF_ID)
     }
-}
\ No newline at end of file
+}
+pub fn append_request_endorser(request_json: &str, endorser_did: &str) -> Result<String, IndyError> {
+    ledger::append_request_endorser(request_json, endorser_did).wait()
+}
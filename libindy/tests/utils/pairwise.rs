@@ -22,4 +22,23 @@ pub fn get_pairwise(wallet_handle: i32, their_did: &str) -> Result<String, IndyE
 
 pub fn set_pairwise_metadata(wallet_handle: i32, their_did: &str, metadata: Option<&str>) -> Result<(), IndyError> {
     pairwise::set_pairwise_metadata(wallet_handle, their_did, metadata).wait()
-}
\ No newline at end of file
+}
+pub fn set_pairwise_tags(wallet_handle: i32, their_did: &str, tags_json: &str) -> Result<(), IndyError> {
+    pairwise::set_pairwise_tags(wallet_handle, their_did, tags_json).wait()
+}
+
+pub fn delete_pairwise(wallet_handle: i32, their_did: &str) -> Result<(), IndyError> {
+    pairwise::delete_pairwise(wallet_handle, their_did).wait()
+}
+
+pub fn search_pairwise(wallet_handle: i32, query_json: Option<&str>) -> Result<(i32, usize), IndyError> {
+    pairwise::search_pairwise(wallet_handle, query_json).wait()
+}
+
+pub fn fetch_pairwise_search(search_handle: i32, count: usize) -> Result<String, IndyError> {
+    pairwise::fetch_pairwise_search(search_handle, count).wait()
+}
+
+pub fn close_pairwise_search(search_handle: i32) -> Result<(), IndyError> {
+    pairwise::close_pairwise_search(search_handle).wait()
+}
@@ -39,6 +39,13 @@ extern {
                                                cred_def_id: CString,
                                                cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_issuer_create_credential_offer_with_nonce_tracking(command_handle: Handle,
+                                                                   wallet_handle: Handle,
+                                                                   cred_def_id: CString,
+                                                                   nonce_expires_at: u64,
+                                                                   cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_issuer_create_credential(command_handle: Handle,
                                          wallet_handle: Handle,
@@ -49,6 +56,34 @@ extern {
                                          blob_storage_reader_handle: Handle,
                                          cb: Option<ResponseStringStringStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_issuer_create_credential_with_options(command_handle: Handle,
+                                                      wallet_handle: Handle,
+                                                      cred_offer_json: CString,
+                                                      cred_req_json: CString,
+                                                      cred_values_json: CString,
+                                                      rev_reg_id: CString,
+                                                      blob_storage_reader_handle: Handle,
+                                                      options_json: CString,
+                                                      cb: Option<ResponseStringStringStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_issuer_search_issued_credentials(command_handle: Handle,
+                                                 wallet_handle: Handle,
+                                                 query_json: CString,
+                                                 cb: Option<ResponseI32UsizeCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_issuer_fetch_issued_credentials(command_handle: Handle,
+                                                search_handle: Handle,
+                                                count: usize,
+                                                cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_issuer_close_issued_credentials_search(command_handle: Handle,
+                                                       search_handle: Handle,
+                                                       cb: Option<ResponseEmptyCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_issuer_revoke_credential(command_handle: Handle,
                                          wallet_handle: Handle,
@@ -163,6 +198,24 @@ extern {
                                       rev_regs_json: CString,
                                       cb: Option<ResponseBoolCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_verifier_track_proof_request_nonce(command_handle: Handle,
+                                                   wallet_handle: Handle,
+                                                   proof_request_json: CString,
+                                                   nonce_expires_at: u64,
+                                                   cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_verifier_verify_proof_with_nonce_tracking(command_handle: Handle,
+                                                          wallet_handle: Handle,
+                                                          proof_request_json: CString,
+                                                          proof_json: CString,
+                                                          schemas_json: CString,
+                                                          credential_defs_json: CString,
+                                                          rev_reg_defs_json: CString,
+                                                          rev_regs_json: CString,
+                                                          cb: Option<ResponseBoolCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_create_revocation_state(command_handle: Handle,
                                         blob_storage_reader_handle: Handle,
@@ -181,5 +234,47 @@ extern {
                                         timestamp: u64,
                                         cred_rev_id: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
-}
 
+    #[no_mangle]
+    pub fn indy_prover_store_revocation_state(command_handle: Handle,
+                                              wallet_handle: Handle,
+                                              rev_reg_id: CString,
+                                              cred_rev_id: CString,
+                                              rev_state_json: CString,
+                                              cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_prover_get_revocation_state(command_handle: Handle,
+                                            wallet_handle: Handle,
+                                            rev_reg_id: CString,
+                                            cred_rev_id: CString,
+                                            timestamp: u64,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_prover_update_revocation_states(command_handle: Handle,
+                                                wallet_handle: Handle,
+                                                pool_handle: Handle,
+                                                proof_req_json: CString,
+                                                requested_credentials_json: CString,
+                                                rev_reg_defs_json: CString,
+                                                blob_storage_reader_handles_json: CString,
+                                                cb: Option<ResponseStringStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_prover_get_credential_w3c(command_handle: Handle,
+                                          wallet_handle: Handle,
+                                          cred_id: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_prover_proof_to_w3c(command_handle: Handle,
+                                    proof_req_json: CString,
+                                    proof_json: CString,
+                                    cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_verifier_proof_from_w3c(command_handle: Handle,
+                                        w3c_presentation_json: CString,
+                                        cb: Option<ResponseStringCB>) -> Error;
+}
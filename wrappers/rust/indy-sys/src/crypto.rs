@@ -10,6 +10,23 @@ extern {
                            key_json: CString,
                            cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_create_bls_key(command_handle: Handle,
+                               wallet_handle: Handle,
+                               key_json: CString,
+                               cb: Option<ResponseStringStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_set_master_seed(command_handle: Handle,
+                                wallet_handle: Handle,
+                                seed: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_list_derived_keys(command_handle: Handle,
+                                  wallet_handle: Handle,
+                                  cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_set_key_metadata(command_handle: Handle,
                                  wallet_handle: Handle,
@@ -87,5 +104,22 @@ extern {
                                jwe_msg: BString,
                                jwe_len: u32,
                                cb: Option<ResponseSliceCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_pack_message_with_routing(command_handle: Handle,
+                                          wallet_handle: Handle,
+                                          message: BString,
+                                          message_len: u32,
+                                          receiver_keys: CString,
+                                          routing_keys: CString,
+                                          sender: CString,
+                                          cb: Option<ResponseSliceCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_unpack_message_with_routing(command_handle: Handle,
+                                            wallet_handle: Handle,
+                                            jwe_msg: BString,
+                                            jwe_len: u32,
+                                            cb: Option<ResponseSliceCB>) -> Error;
 }
 
@@ -1,6 +1,6 @@
 use super::*;
 
-use {CString, Error, Handle};
+use {BString, CString, Error, Handle};
 
 extern {
 
@@ -57,6 +57,27 @@ extern {
                                      did: CString,
                                      cb: Option<ResponseStringStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_set_services_for_did(command_handle: Handle,
+                                     wallet_handle: Handle,
+                                     did: CString,
+                                     services_json: CString,
+                                     cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_get_services_for_did(command_handle: Handle,
+                                     wallet_handle: Handle,
+                                     pool_handle: Handle,
+                                     did: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_did_services_attrib_request(command_handle: Handle,
+                                                  wallet_handle: Handle,
+                                                  submitter_did: CString,
+                                                  did: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_set_did_metadata(command_handle: Handle,
                                  wallet_handle: Handle,
@@ -86,5 +107,67 @@ extern {
                                   did: CString,
                                   full_verkey: CString,
                                   cb: Option<ResponseStringCB>) -> Error;
-}
 
+    #[no_mangle]
+    pub fn indy_get_did_key_history(command_handle: Handle,
+                                    wallet_handle: Handle,
+                                    did: CString,
+                                    cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_set_did_key_ledger_info(command_handle: Handle,
+                                        wallet_handle: Handle,
+                                        did: CString,
+                                        verkey: CString,
+                                        seq_no: u64,
+                                        txn_time: u64,
+                                        cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_verify_did_signature_at(command_handle: Handle,
+                                        wallet_handle: Handle,
+                                        did: CString,
+                                        timestamp: u64,
+                                        message_raw: BString,
+                                        message_len: u32,
+                                        signature_raw: BString,
+                                        signature_len: u32,
+                                        cb: Option<ResponseBoolCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_set_my_did_tags(command_handle: Handle,
+                                wallet_handle: Handle,
+                                did: CString,
+                                tags_json: CString,
+                                cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_search_my_dids(command_handle: Handle,
+                               wallet_handle: Handle,
+                               query_json: CString,
+                               cb: Option<ResponseI32UsizeCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_fetch_my_dids_search(command_handle: Handle,
+                                     wallet_handle: Handle,
+                                     search_handle: Handle,
+                                     count: usize,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_close_my_dids_search(command_handle: Handle,
+                                     search_handle: Handle,
+                                     cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_delete_my_did(command_handle: Handle,
+                              wallet_handle: Handle,
+                              did: CString,
+                              cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_delete_their_did(command_handle: Handle,
+                                 wallet_handle: Handle,
+                                 did: CString,
+                                 cb: Option<ResponseEmptyCB>) -> Error;
+}
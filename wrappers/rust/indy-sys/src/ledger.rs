@@ -39,6 +39,61 @@ extern {
                                    request_json: CString,
                                    cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_sign_request_for_pool(command_handle: Handle,
+                                      pool_handle: Handle,
+                                      wallet_handle: Handle,
+                                      submitter_did: CString,
+                                      request_json: CString,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_multi_sign_request_for_pool(command_handle: Handle,
+                                            pool_handle: Handle,
+                                            wallet_handle: Handle,
+                                            submitter_did: CString,
+                                            request_json: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_append_request_endorser(command_handle: Handle,
+                                        request_json: CString,
+                                        endorser_did: CString,
+                                        cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_set_request_req_id(command_handle: Handle,
+                                   request_json: CString,
+                                   req_id: u64,
+                                   cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_generate_req_id(req_id_p: *mut u64) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_signing_bundle(command_handle: Handle,
+                                     request_json: CString,
+                                     signers_json: CString,
+                                     constraint_json: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_sign_signing_bundle(command_handle: Handle,
+                                    wallet_handle: Handle,
+                                    signer_did: CString,
+                                    bundle_json: CString,
+                                    cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_merge_signing_bundles(command_handle: Handle,
+                                      bundles_json: CString,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_check_signing_bundle(command_handle: Handle,
+                                     bundle_json: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_get_ddo_request(command_handle: Handle,
                                       submitter_did: CString,
@@ -119,11 +174,27 @@ extern {
                                    data: CString,
                                    cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_build_node_request_with_bls_key(command_handle: Handle,
+                                                wallet_handle: Handle,
+                                                submitter_did: CString,
+                                                target_did: CString,
+                                                data: CString,
+                                                blskey: CString,
+                                                cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_get_validator_info_request(command_handle: Handle,
                                                  submitter_did: CString,
                                                  cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_get_pool_status(command_handle: Handle,
+                                pool_handle: Handle,
+                                wallet_handle: Handle,
+                                submitter_did: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_get_txn_request(command_handle: Handle,
                                       submitter_did: CString,
@@ -217,6 +288,18 @@ extern {
                                                    free: Option<CustomFree>,
                                                    cb: Option<ResponseEmptyCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_register_custom_txn_type(command_handle: Handle,
+                                         txn_type_json: CString,
+                                         cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_custom_request(command_handle: Handle,
+                                     submitter_did: CString,
+                                     txn_type: CString,
+                                     operation_json: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_get_response_metadata(command_handle: Handle,
                                       response: CString,
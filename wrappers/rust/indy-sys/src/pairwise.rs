@@ -35,5 +35,34 @@ extern {
                                       their_did: CString,
                                       metadata: CString,
                                       cb: Option<ResponseEmptyCB>) -> Error;
-}
 
+    #[no_mangle]
+    pub fn indy_set_pairwise_tags(command_handle: Handle,
+                                  wallet_handle: Handle,
+                                  their_did: CString,
+                                  tags_json: CString,
+                                  cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_delete_pairwise(command_handle: Handle,
+                                wallet_handle: Handle,
+                                their_did: CString,
+                                cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_search_pairwise(command_handle: Handle,
+                                wallet_handle: Handle,
+                                query_json: CString,
+                                cb: Option<ResponseI32UsizeCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_fetch_pairwise_search(command_handle: Handle,
+                                      search_handle: Handle,
+                                      count: usize,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_close_pairwise_search(command_handle: Handle,
+                                      search_handle: Handle,
+                                      cb: Option<ResponseEmptyCB>) -> Error;
+}
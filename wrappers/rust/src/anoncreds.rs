@@ -188,6 +188,33 @@ fn _issuer_create_credential_offer(command_handle: IndyHandle, wallet_handle: In
     })
 }
 
+/// Same as issuer_create_credential_offer but additionally records nonce of the offer
+/// in the wallet together with its expiry time. Credential for such offer can be issued only once
+/// and only before the expiry time (see `track_offer_nonce` option of issuer_create_credential_with_options).
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by Wallet::open_wallet)
+/// * `cred_def_id`: id of credential definition stored in the wallet
+/// * `nonce_expires_at`: time (total number of seconds from Unix Epoch) after which credential offer is considered expired
+///
+/// # Returns
+/// * `credential_offer_json` - see issuer_create_credential_offer
+pub fn issuer_create_credential_offer_with_nonce_tracking(wallet_handle: IndyHandle, cred_def_id: &str, nonce_expires_at: u64) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_create_credential_offer_with_nonce_tracking(command_handle, wallet_handle, cred_def_id, nonce_expires_at, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_create_credential_offer_with_nonce_tracking(command_handle: IndyHandle, wallet_handle: IndyHandle, cred_def_id: &str, nonce_expires_at: u64, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_issuer_create_credential_offer_with_nonce_tracking(command_handle, wallet_handle, cred_def_id.as_ptr(), nonce_expires_at, cb)
+    })
+}
+
 /// Check Cred Request for the given Cred Offer and issue Credential for the given Cred Request.
 ///
 /// Cred Request must match Cred Offer. The credential definition and revocation registry definition
@@ -244,6 +271,123 @@ fn _issuer_create_credential(command_handle: IndyHandle, wallet_handle: IndyHand
     })
 }
 
+/// Same as issuer_create_credential but accepts additional issuance options.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by Wallet::open_wallet).
+/// * `cred_offer_json`: a cred offer created by create_credential_offer
+/// * `cred_req_json`: a credential request created by store_credential
+/// * `cred_values_json`: a credential containing attribute values for each of requested attribute names (see issuer_create_credential)
+/// * `rev_reg_id`: id of revocation registry stored in the wallet
+/// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `options_json`: issuance options
+///     {
+///         "track_offer_nonce": Optional<bool>, // enables credential offer replay protection for offers
+///                                              // created by issuer_create_credential_offer_with_nonce_tracking. false by default
+///         "store_issued_credential": Optional<bool>, // store metadata of issued credential in the wallet
+///                                                    // (see issuer_search_issued_credentials). false by default
+///     }
+///
+/// # Returns
+/// * `cred_json`, `cred_revoc_id`, `revoc_reg_delta_json` (see issuer_create_credential)
+pub fn issuer_create_credential_with_options(wallet_handle: IndyHandle, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str, rev_reg_id: Option<&str>, blob_storage_reader_handle: IndyHandle, options_json: &str) -> Box<Future<Item=(String, Option<String>, Option<String>), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_opt_string_opt_string();
+
+    let err = _issuer_create_credential_with_options(command_handle, wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle, options_json, cb);
+
+    ResultHandler::str_optstr_optstr(command_handle, err, receiver)
+}
+
+fn _issuer_create_credential_with_options(command_handle: IndyHandle, wallet_handle: IndyHandle, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str, rev_reg_id: Option<&str>, blob_storage_reader_handle: IndyHandle, options_json: &str, cb: Option<ResponseStringStringStringCB>) -> ErrorCode {
+    let cred_offer_json = c_str!(cred_offer_json);
+    let cred_req_json = c_str!(cred_req_json);
+    let cred_values_json = c_str!(cred_values_json);
+    let rev_reg_id_str = opt_c_str!(rev_reg_id);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_issuer_create_credential_with_options(command_handle, wallet_handle, cred_offer_json.as_ptr(), cred_req_json.as_ptr(), cred_values_json.as_ptr(), opt_c_ptr!(rev_reg_id, rev_reg_id_str), blob_storage_reader_handle, options_json.as_ptr(), cb)
+    })
+}
+
+/// Search for metadata of issued credentials stored in the wallet
+/// (see `store_issued_credential` option of issuer_create_credential_with_options).
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by Wallet::open_wallet).
+/// * `query_json`: Wql query filter for issued credentials searching based on tags:
+///     {
+///         "schema_id": string,
+///         "cred_def_id": string,
+///         "rev_reg_id": string, // "None" for credentials without revocation support
+///         "cred_rev_id": string, // "None" for credentials without revocation support
+///         "holder_did": string,
+///         "values_hash": string,
+///         "revoked": "true" | "false",
+///         "~issued_at": string, // issue time padded with zeros to 20 digits, can be used with $gt/$lt
+///     }
+///
+/// # Returns
+/// * `search_handle`: Search handle that can be used later to fetch records by small batches (with issuer_fetch_issued_credentials)
+/// * `total_count`: Total count of records
+pub fn issuer_search_issued_credentials(wallet_handle: IndyHandle, query_json: Option<&str>) -> Box<Future<Item=(IndyHandle, usize), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle_usize();
+
+    let err = _issuer_search_issued_credentials(command_handle, wallet_handle, query_json, cb);
+
+    ResultHandler::handle_usize(command_handle, err, receiver)
+}
+
+fn _issuer_search_issued_credentials(command_handle: IndyHandle, wallet_handle: IndyHandle, query_json: Option<&str>, cb: Option<ResponseI32UsizeCB>) -> ErrorCode {
+    let query_json_str = opt_c_str!(query_json);
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_issuer_search_issued_credentials(command_handle, wallet_handle, opt_c_ptr!(query_json, query_json_str), cb)
+    })
+}
+
+/// Fetch next issued credentials for search.
+///
+/// # Arguments
+/// * `search_handle`: Search handle (created by issuer_search_issued_credentials)
+/// * `count`: Count of records to fetch
+///
+/// # Returns
+/// * `issued_credentials_json`: List of issued credentials metadata (see indy_issuer_fetch_issued_credentials)
+pub fn issuer_fetch_issued_credentials(search_handle: IndyHandle, count: usize) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_fetch_issued_credentials(command_handle, search_handle, count, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_fetch_issued_credentials(command_handle: IndyHandle, search_handle: IndyHandle, count: usize, cb: Option<ResponseStringCB>) -> ErrorCode {
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_issuer_fetch_issued_credentials(command_handle, search_handle, count, cb)
+    })
+}
+
+/// Close issued credentials search (make search handle invalid)
+///
+/// # Arguments
+/// * `search_handle`: Search handle (created by issuer_search_issued_credentials)
+pub fn issuer_close_issued_credentials_search(search_handle: IndyHandle) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _issuer_close_issued_credentials_search(command_handle, search_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _issuer_close_issued_credentials_search(command_handle: IndyHandle, search_handle: IndyHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_issuer_close_issued_credentials_search(command_handle, search_handle, cb)
+    })
+}
+
 /// Revoke a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -1061,6 +1205,60 @@ fn _verifier_verify_proof(command_handle: IndyHandle, proof_request_json: &str,
     })
 }
 
+/// Records nonce of the proof request in the wallet together with its expiry time,
+/// so proofs for the request can be verified by verifier_verify_proof_with_nonce_tracking.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by Wallet::open_wallet).
+/// * `proof_request_json`: proof request json (see verifier_verify_proof)
+/// * `nonce_expires_at`: time (total number of seconds from Unix Epoch) after which proof request is considered expired
+pub fn verifier_track_proof_request_nonce(wallet_handle: IndyHandle, proof_request_json: &str, nonce_expires_at: u64) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _verifier_track_proof_request_nonce(command_handle, wallet_handle, proof_request_json, nonce_expires_at, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _verifier_track_proof_request_nonce(command_handle: IndyHandle, wallet_handle: IndyHandle, proof_request_json: &str, nonce_expires_at: u64, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_track_proof_request_nonce(command_handle, wallet_handle, proof_request_json.as_ptr(), nonce_expires_at, cb)
+    })
+}
+
+/// Same as verifier_verify_proof but additionally protects against proof replay.
+/// Nonce of the proof request must be recorded by verifier_track_proof_request_nonce and not be expired.
+/// It is marked as used after successful verification, so another proof for the same request is rejected.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by Wallet::open_wallet).
+/// * `proof_request_json`, `proof_json`, `schemas_json`, `credential_defs_json`, `rev_reg_defs_json`, `rev_regs_json`: see verifier_verify_proof
+///
+/// # Returns
+/// * `valid`: true - if signature is valid, false - otherwise
+pub fn verifier_verify_proof_with_nonce_tracking(wallet_handle: IndyHandle, proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Box<Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verifier_verify_proof_with_nonce_tracking(command_handle, wallet_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_with_nonce_tracking(command_handle: IndyHandle, wallet_handle: IndyHandle, proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_regs_json = c_str!(rev_regs_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_with_nonce_tracking(command_handle, wallet_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_reg_defs_json.as_ptr(), rev_regs_json.as_ptr(), cb)
+    })
+}
+
 
 /// Create revocation state for a credential in the particular time moment.
 ///
@@ -1132,3 +1330,208 @@ fn _update_revocation_state(command_handle: IndyHandle, blob_storage_reader_hand
       anoncreds::indy_update_revocation_state(command_handle, blob_storage_reader_handle, rev_state_json.as_ptr(), rev_reg_def_json.as_ptr(), rev_reg_delta_json.as_ptr(), timestamp, cred_rev_id.as_ptr(), cb)
     })
 }
+
+/// Stores revocation state of a credential in the wallet.
+/// Stored states are looked up by revocation registry id, credential revocation id and timestamp
+/// and are reused by `prover_update_revocation_states`.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by open_wallet).
+/// * `rev_reg_id`: revocation registry id
+/// * `cred_rev_id`: user credential revocation id in revocation registry
+/// * `rev_state_json`: revocation state json
+/// {
+///     "rev_reg": <revocation registry>,
+///     "witness": <witness>,
+///     "timestamp" : integer
+/// }
+pub fn prover_store_revocation_state(wallet_handle: IndyHandle, rev_reg_id: &str, cred_rev_id: &str, rev_state_json: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _prover_store_revocation_state(command_handle, wallet_handle, rev_reg_id, cred_rev_id, rev_state_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _prover_store_revocation_state(command_handle: IndyHandle, wallet_handle: IndyHandle, rev_reg_id: &str, cred_rev_id: &str, rev_state_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let rev_reg_id = c_str!(rev_reg_id);
+    let cred_rev_id = c_str!(cred_rev_id);
+    let rev_state_json = c_str!(rev_state_json);
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_prover_store_revocation_state(command_handle, wallet_handle, rev_reg_id.as_ptr(), cred_rev_id.as_ptr(), rev_state_json.as_ptr(), cb)
+    })
+}
+
+/// Gets revocation state of a credential stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by open_wallet).
+/// * `rev_reg_id`: revocation registry id
+/// * `cred_rev_id`: user credential revocation id in revocation registry
+/// * `timestamp`: time represented as a total number of seconds from Unix Epoch
+///
+/// # Returns
+/// * `revocation_state_json`:
+/// {
+///     "rev_reg": <revocation registry>,
+///     "witness": <witness>,
+///     "timestamp" : integer
+/// }
+pub fn prover_get_revocation_state(wallet_handle: IndyHandle, rev_reg_id: &str, cred_rev_id: &str, timestamp: u64) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_get_revocation_state(command_handle, wallet_handle, rev_reg_id, cred_rev_id, timestamp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_get_revocation_state(command_handle: IndyHandle, wallet_handle: IndyHandle, rev_reg_id: &str, cred_rev_id: &str, timestamp: u64, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let rev_reg_id = c_str!(rev_reg_id);
+    let cred_rev_id = c_str!(cred_rev_id);
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_prover_get_revocation_state(command_handle, wallet_handle, rev_reg_id.as_ptr(), cred_rev_id.as_ptr(), timestamp, cb)
+    })
+}
+
+/// Prepares revocation states needed to create a proof for the given proof request.
+///
+/// States with a timestamp inside the requested `non_revoked` interval are taken from the wallet.
+/// Otherwise the revocation registry delta is fetched from the ledger and the freshest stored state
+/// is updated (or a new one is created). Resulting states are stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by open_wallet).
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+/// * `proof_req_json`: proof request json (see prover_create_proof)
+/// * `requested_credentials_json`: requested credentials json (see prover_create_proof), timestamps are ignored
+/// * `rev_reg_defs_json`: revocation registry definitions of requested credentials
+/// {
+///     "rev_reg_def1_id": <rev_reg_def1_json>,
+///     "rev_reg_def2_id": <rev_reg_def2_json>,
+/// }
+/// * `blob_storage_reader_handles_json`: blob storage reader handles to read tails of revocation registries
+/// {
+///     "rev_reg_def1_id": <blob_storage_reader_handle>,
+///     "rev_reg_def2_id": <blob_storage_reader_handle>,
+/// }
+///
+/// # Returns
+/// * `rev_states_json`: revocation states to pass to prover_create_proof
+/// {
+///     "rev_reg_def1_id": {
+///         "timestamp1": <rev_state1>,
+///         "timestamp2": <rev_state2>,
+///     },
+/// }
+/// * `requested_credentials_json`: requested credentials json with timestamps of the used states
+pub fn prover_update_revocation_states(wallet_handle: IndyHandle, pool_handle: IndyHandle, proof_req_json: &str, requested_credentials_json: &str, rev_reg_defs_json: &str, blob_storage_reader_handles_json: &str) -> Box<Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _prover_update_revocation_states(command_handle, wallet_handle, pool_handle, proof_req_json, requested_credentials_json, rev_reg_defs_json, blob_storage_reader_handles_json, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _prover_update_revocation_states(command_handle: IndyHandle, wallet_handle: IndyHandle, pool_handle: IndyHandle, proof_req_json: &str, requested_credentials_json: &str, rev_reg_defs_json: &str, blob_storage_reader_handles_json: &str, cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let proof_req_json = c_str!(proof_req_json);
+    let requested_credentials_json = c_str!(requested_credentials_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let blob_storage_reader_handles_json = c_str!(blob_storage_reader_handles_json);
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_prover_update_revocation_states(command_handle, wallet_handle, pool_handle, proof_req_json.as_ptr(), requested_credentials_json.as_ptr(), rev_reg_defs_json.as_ptr(), blob_storage_reader_handles_json.as_ptr(), cb)
+    })
+}
+
+/// Gets credential stored in the wallet as W3C Verifiable Credential (JSON-LD).
+/// CL signature of the credential is carried in the `proof` object.
+/// `credentialSubject.id` is a random URN generated on every call.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by open_wallet).
+/// * `cred_id`: Identifier by which requested credential is stored in the wallet
+///
+/// # Returns
+/// * `w3c_credential_json`: verifiable credential json
+/// {
+///     "@context": ["https://www.w3.org/2018/credentials/v1", {"@vocab": "urn:indy:anoncreds:"}],
+///     "type": ["VerifiableCredential", "IndyCredential"],
+///     "issuer": "did:sov:<issuer_did>",
+///     "issuanceDate": <time of export, RFC 3339>,
+///     "credentialSchema": {"id": <schema_id>, "type": "IndyCredentialSchema"},
+///     "credentialSubject": {"id": "urn:uuid:<random uuid>", "key1":"raw_value1", "key2":"raw_value2"},
+///     "proof": {
+///         "type": "CLSignature2019",
+///         "credentialDefinition": <cred_def_id>,
+///         "revocationRegistry": Optional<rev_reg_id>,
+///         "encoding": {"key1":"encoded_value1", "key2":"encoded_value2"},
+///         "signature": <credential_signature>,
+///         "signatureCorrectnessProof": <signature_correctness_proof>
+///     }
+/// }
+pub fn prover_get_credential_w3c(wallet_handle: IndyHandle, cred_id: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_get_credential_w3c(command_handle, wallet_handle, cred_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_get_credential_w3c(command_handle: IndyHandle, wallet_handle: IndyHandle, cred_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_id = c_str!(cred_id);
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_prover_get_credential_w3c(command_handle, wallet_handle, cred_id.as_ptr(), cb)
+    })
+}
+
+/// Converts a proof created by prover_create_proof to W3C Verifiable Presentation (JSON-LD).
+///
+/// # Arguments
+/// * `proof_req_json`: proof request json the proof was created for (see prover_create_proof)
+/// * `proof_json`: proof json (see prover_create_proof)
+///
+/// # Returns
+/// * `w3c_presentation_json`: verifiable presentation json which can be converted back by verifier_proof_from_w3c
+pub fn prover_proof_to_w3c(proof_req_json: &str, proof_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_proof_to_w3c(command_handle, proof_req_json, proof_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_proof_to_w3c(command_handle: IndyHandle, proof_req_json: &str, proof_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_req_json = c_str!(proof_req_json);
+    let proof_json = c_str!(proof_json);
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_prover_proof_to_w3c(command_handle, proof_req_json.as_ptr(), proof_json.as_ptr(), cb)
+    })
+}
+
+/// Parses W3C Verifiable Presentation created by prover_proof_to_w3c
+/// into the proof json accepted by verifier_verify_proof.
+///
+/// # Arguments
+/// * `w3c_presentation_json`: verifiable presentation json (see prover_proof_to_w3c)
+///
+/// # Returns
+/// * `proof_json`: proof json (see prover_create_proof)
+pub fn verifier_proof_from_w3c(w3c_presentation_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_proof_from_w3c(command_handle, w3c_presentation_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_proof_from_w3c(command_handle: IndyHandle, w3c_presentation_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let w3c_presentation_json = c_str!(w3c_presentation_json);
+
+    ErrorCode::from(unsafe {
+      anoncreds::indy_verifier_proof_from_w3c(command_handle, w3c_presentation_json.as_ptr(), cb)
+    })
+}
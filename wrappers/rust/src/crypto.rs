@@ -3,7 +3,8 @@ use ffi::{ResponseEmptyCB,
           ResponseStringCB,
           ResponseSliceCB,
           ResponseBoolCB,
-          ResponseStringSliceCB};
+          ResponseStringSliceCB,
+          ResponseStringStringCB};
 
 use futures::Future;
 
@@ -24,6 +25,9 @@ use utils::callbacks::{ClosureHandler, ResultHandler};
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+///     "derivation_path": string, (optional) SLIP-0010 path (like "m/44'/0'/0'") to derive key from the wallet master seed
+///                                (see set_master_seed). Only hardened indexes and ed25519 keys are supported.
+///                                Can't be used together with seed.
 /// }
 /// # Returns
 /// verkey of generated key pair, also used as key identifier
@@ -41,6 +45,79 @@ fn _create_key(command_handle: IndyHandle, wallet_handle: IndyHandle, my_key_jso
     ErrorCode::from(unsafe { crypto::indy_create_key(command_handle, wallet_handle, my_key_json.as_ptr(), cb) })
 }
 
+/// Sets the master seed of the wallet used for deterministic derivation of keys and DIDs
+/// (see derivation_path of create_key and Did::new). The master seed can be set only once for the wallet.
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `seed` - Optional hex encoded master seed from 16 to 64 bytes. If none then random 32 bytes seed is created.
+/// # Returns
+/// hex encoded master seed that should be backed up to restore derived keys
+pub fn set_master_seed(wallet_handle: IndyHandle, seed: Option<&str>) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _set_master_seed(command_handle, wallet_handle, seed, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _set_master_seed(command_handle: IndyHandle, wallet_handle: IndyHandle, seed: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let seed_str = opt_c_str!(seed);
+
+    ErrorCode::from(unsafe { crypto::indy_set_master_seed(command_handle, wallet_handle, opt_c_ptr!(seed, seed_str), cb) })
+}
+
+/// Lists keys and DIDs derived from the wallet master seed with their derivation paths
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// # Returns
+/// list of derived keys as json
+/// [{
+///     "verkey": string, // verkey of derived key
+///     "derivation_path": string, // SLIP-0010 path the key has been derived with
+///     "did": string, // (optional) DID the key has been created for
+/// }]
+pub fn list_derived_keys(wallet_handle: IndyHandle) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _list_derived_keys(command_handle, wallet_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _list_derived_keys(command_handle: IndyHandle, wallet_handle: IndyHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { crypto::indy_list_derived_keys(command_handle, wallet_handle, cb) })
+}
+
+/// Creates BLS keys pair, stores it in the wallet and computes proof of possession for it.
+/// The result can be used as "blskey" and "blskey_pop" of NODE transaction
+/// (see Ledger::build_node_request and Ledger::build_node_request_with_bls_key).
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `key_json` - Optional key information as json. If none then defaults are used.
+///
+/// # Example
+/// key_json
+/// {
+///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
+///                                Can be UTF-8, base64 or hex string.
+/// }
+/// # Returns
+/// * `blskey` - Base58 encoded BLS ver key, also used as key identifier
+/// * `blskey_pop` - Base58 encoded proof of possession for the BLS key
+pub fn create_bls_key(wallet_handle: IndyHandle, key_json: Option<&str>) -> Box<Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _create_bls_key(command_handle, wallet_handle, key_json, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _create_bls_key(command_handle: IndyHandle, wallet_handle: IndyHandle, key_json: Option<&str>, cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let key_json = opt_c_str_json!(key_json);
+
+    ErrorCode::from(unsafe { crypto::indy_create_bls_key(command_handle, wallet_handle, key_json.as_ptr(), cb) })
+}
+
 /// Saves/replaces the metadata for the `verkey` in the wallet
 /// # Arguments
 /// * `wallet_handle` - wallet handle (created by Wallet::open)
@@ -343,3 +420,65 @@ fn _unpack_message(command_handle: IndyHandle, wallet_handle: IndyHandle, jwe: &
     })
 }
 
+
+/// Packs a message for the receiver and wraps it into forward envelopes for each mediator on the route
+/// (Experimental)
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open).
+/// * `message`: a pointer to the first byte of the message to be encrypted
+/// * `receiver_keys`: a JSON array as a string containing the receiver verkey
+/// * `routing_keys`: a JSON array as a string containing mediator verkeys ordered from the closest to the receiver
+/// * `sender` : a string of the sender's verkey When None is used in this parameter, anoncrypt is used
+/// # Returns
+/// a json structure in the form of a JWE of the outermost forward envelope
+pub fn pack_message_with_routing(wallet_handle: IndyHandle, message: &[u8], receiver_keys: &str, routing_keys: &str, sender: Option<&str>) -> Box<Future<Item=Vec<u8>, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _pack_message_with_routing(command_handle, wallet_handle, message, receiver_keys, routing_keys, sender, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _pack_message_with_routing(command_handle: IndyHandle, wallet_handle: IndyHandle, message: &[u8], receiver_keys: &str, routing_keys: &str, sender: Option<&str>, cb: Option<ResponseSliceCB>) -> ErrorCode {
+    let receiver_keys = c_str!(receiver_keys);
+    let routing_keys = c_str!(routing_keys);
+    let sender_str = opt_c_str!(sender);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_pack_message_with_routing(command_handle,
+                                               wallet_handle,
+                                               message.as_ptr() as *const u8,
+                                               message.len() as u32,
+                                               receiver_keys.as_ptr(),
+                                               routing_keys.as_ptr(),
+                                               opt_c_ptr!(sender, sender_str),
+                                               cb)
+    })
+}
+
+/// Unpacks a message packed using indy_pack_message_with_routing and follows forward envelopes
+/// addressed to the keys from the wallet (Experimental)
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open).
+/// * `jwe`: a pointer to the first byte of the JWE string
+/// # Returns
+/// a json structure that contains the innermost unpacked message and the list of unpacked layers
+pub fn unpack_message_with_routing(wallet_handle: IndyHandle, jwe: &[u8]) -> Box<Future<Item=Vec<u8>, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _unpack_message_with_routing(command_handle, wallet_handle, jwe, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _unpack_message_with_routing(command_handle: IndyHandle, wallet_handle: IndyHandle, jwe: &[u8], cb: Option<ResponseSliceCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        crypto::indy_unpack_message_with_routing(command_handle,
+                                                 wallet_handle,
+                                                 jwe.as_ptr() as *const u8,
+                                                 jwe.len() as u32,
+                                                 cb)
+    })
+}
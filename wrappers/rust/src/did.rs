@@ -5,7 +5,9 @@ use std::ffi::CString;
 use futures::Future;
 
 use ffi::did;
-use ffi::{ResponseEmptyCB,
+use ffi::{ResponseBoolCB,
+          ResponseEmptyCB,
+          ResponseI32UsizeCB,
           ResponseStringCB,
           ResponseStringStringCB};
 
@@ -33,6 +35,9 @@ use utils::callbacks::{ClosureHandler, ResultHandler};
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
 ///               currently only 'ed25519' value is supported for this field)
 ///     "cid": bool, (optional; if not set then false is used;)
+///     "derivation_path": string, (optional) SLIP-0010 path (like "m/44'/0'/0'") to derive keys from the wallet master seed
+///                                (see crypto::set_master_seed). Only hardened indexes and ed25519 keys are supported.
+///                                Can't be used together with seed.
 /// }
 ///
 /// # Returns
@@ -66,6 +71,9 @@ fn _create_and_store_my_did(command_handle: IndyHandle, wallet_handle: IndyHandl
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
 ///               currently only 'ed25519' value is supported for this field)
+///     "derivation_path": string, (optional) SLIP-0010 path (like "m/44'/0'/0'") to derive keys from the wallet master seed
+///                                (see crypto::set_master_seed). Only hardened indexes and ed25519 keys are supported.
+///                                Can't be used together with seed.
 /// }
 ///
 /// # Returns
@@ -241,6 +249,76 @@ fn _get_endpoint_for_did(command_handle: IndyHandle, wallet_handle: IndyHandle,
     ErrorCode::from(unsafe { did::indy_get_endpoint_for_did(command_handle, wallet_handle, pool_handle, did.as_ptr(), cb) })
 }
 
+/// Set/replaces the list of services for the given DID.
+///
+/// # Arguments
+/// * `wallet_handle` - Wallet handle (created by Wallet::open).
+/// * `did` - The DID to set services for.
+/// * `services_json` - The list of DID services.
+pub fn set_services_for_did(wallet_handle: IndyHandle, did: &str, services_json: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_services_for_did(command_handle, wallet_handle, did, services_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_services_for_did(command_handle: IndyHandle, wallet_handle: IndyHandle, did: &str, services_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let services_json = c_str!(services_json);
+
+    ErrorCode::from(unsafe { did::indy_set_services_for_did(command_handle, wallet_handle, did.as_ptr(), services_json.as_ptr(), cb) })
+}
+
+/// Returns the list of services for the given DID.
+///
+/// Legacy endpoint of the DID is returned as a service if no services are set.
+///
+/// # Arguments
+/// * `wallet_handle` - Wallet handle (created by Wallet::open).
+/// * `pool_handle` - Pool handle (created by open_pool).
+/// * `did` - The DID to resolve services.
+///
+/// # Returns
+/// * `services_json` - The list of DID services ordered by priority.
+pub fn get_services_for_did(wallet_handle: IndyHandle, pool_handle: IndyHandle, did: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_services_for_did(command_handle, wallet_handle, pool_handle, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_services_for_did(command_handle: IndyHandle, wallet_handle: IndyHandle, pool_handle: IndyHandle, did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_get_services_for_did(command_handle, wallet_handle, pool_handle, did.as_ptr(), cb) })
+}
+
+/// Builds an ATTRIB request publishing the services of the given DID stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - Wallet handle (created by Wallet::open).
+/// * `submitter_did` - DID of the submitter stored in secured Wallet.
+/// * `did` - The DID to publish services of.
+///
+/// # Returns
+/// * `request_json` - Request result as json.
+pub fn build_did_services_attrib_request(wallet_handle: IndyHandle, submitter_did: &str, did: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_did_services_attrib_request(command_handle, wallet_handle, submitter_did, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_did_services_attrib_request(command_handle: IndyHandle, wallet_handle: IndyHandle, submitter_did: &str, did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_build_did_services_attrib_request(command_handle, wallet_handle, submitter_did.as_ptr(), did.as_ptr(), cb) })
+}
+
 /// Saves/replaces the meta information for the giving DID in the wallet.
 ///
 /// # Arguments
@@ -356,3 +434,199 @@ fn _abbreviate_verkey(command_handle: IndyHandle, tgt_did: &str, verkey: &str, c
 
     ErrorCode::from(unsafe { did::indy_abbreviate_verkey(command_handle, tgt_did.as_ptr(), verkey.as_ptr(), cb) })
 }
+
+/// Returns verkey rotation history of the DID.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handler (created by Wallet::open).
+/// * `did` - DID (my or their) stored in the wallet
+///
+/// # Returns
+/// * `key_history_json` - list of verkeys with their validity bounds from the oldest to the current one
+pub fn get_did_key_history(wallet_handle: IndyHandle, did: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_did_key_history(command_handle, wallet_handle, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_did_key_history(command_handle: IndyHandle, wallet_handle: IndyHandle, did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_get_did_key_history(command_handle, wallet_handle, did.as_ptr(), cb) })
+}
+
+/// Saves ledger information of the transaction that has written the verkey of the DID.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handler (created by Wallet::open).
+/// * `did` - DID (my or their) stored in the wallet
+/// * `verkey` - verkey from the DID key history
+/// * `seq_no` - sequence number of NYM transaction
+/// * `txn_time` - time of NYM transaction
+pub fn set_did_key_ledger_info(wallet_handle: IndyHandle, did: &str, verkey: &str, seq_no: u64, txn_time: u64) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_did_key_ledger_info(command_handle, wallet_handle, did, verkey, seq_no, txn_time, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_did_key_ledger_info(command_handle: IndyHandle, wallet_handle: IndyHandle, did: &str, verkey: &str, seq_no: u64, txn_time: u64, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let verkey = c_str!(verkey);
+
+    ErrorCode::from(unsafe { did::indy_set_did_key_ledger_info(command_handle, wallet_handle, did.as_ptr(), verkey.as_ptr(), seq_no, txn_time, cb) })
+}
+
+/// Verifies a signature made by the DID with the verkey that was valid at the given time.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handler (created by Wallet::open).
+/// * `did` - DID (my or their) stored in the wallet
+/// * `timestamp` - unix timestamp (in seconds) the message has been signed at
+/// * `message` - the data that was signed
+/// * `signature` - the signature to verify
+///
+/// # Returns
+/// true if signature is valid for the verkey of the DID at the given time, false otherwise
+pub fn verify_did_signature_at(wallet_handle: IndyHandle, did: &str, timestamp: u64, message: &[u8], signature: &[u8]) -> Box<Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verify_did_signature_at(command_handle, wallet_handle, did, timestamp, message, signature, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verify_did_signature_at(command_handle: IndyHandle, wallet_handle: IndyHandle, did: &str, timestamp: u64, message: &[u8], signature: &[u8], cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe {
+        did::indy_verify_did_signature_at(command_handle, wallet_handle, did.as_ptr(), timestamp,
+                                          message.as_ptr() as *const u8,
+                                          message.len() as u32,
+                                          signature.as_ptr() as *const u8,
+                                          signature.len() as u32,
+                                          cb)
+    })
+}
+
+/// Replaces tags of my DID record.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handler (created by Wallet::open).
+/// * `did` - DID stored in the wallet
+/// * `tags_json` - the record tags used for search, "metadata" tag name is reserved
+pub fn set_my_did_tags(wallet_handle: IndyHandle, did: &str, tags_json: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_my_did_tags(command_handle, wallet_handle, did, tags_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_my_did_tags(command_handle: IndyHandle, wallet_handle: IndyHandle, did: &str, tags_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let tags_json = c_str!(tags_json);
+
+    ErrorCode::from(unsafe { did::indy_set_my_did_tags(command_handle, wallet_handle, did.as_ptr(), tags_json.as_ptr(), cb) })
+}
+
+/// Search for my DIDs stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handler (created by Wallet::open).
+/// * `query_json` - Wql query filter over "metadata" tag and tags set by set_my_did_tags
+///
+/// # Returns
+/// * `search_handle` - Search handle that can be used later to fetch records by small batches (with fetch_my_dids_search)
+/// * `total_count` - Total count of records
+pub fn search_my_dids(wallet_handle: IndyHandle, query_json: Option<&str>) -> Box<Future<Item=(IndyHandle, usize), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle_usize();
+
+    let err = _search_my_dids(command_handle, wallet_handle, query_json, cb);
+
+    ResultHandler::handle_usize(command_handle, err, receiver)
+}
+
+fn _search_my_dids(command_handle: IndyHandle, wallet_handle: IndyHandle, query_json: Option<&str>, cb: Option<ResponseI32UsizeCB>) -> ErrorCode {
+    let query_json_str = opt_c_str!(query_json);
+
+    ErrorCode::from(unsafe { did::indy_search_my_dids(command_handle, wallet_handle, opt_c_ptr!(query_json, query_json_str), cb) })
+}
+
+/// Fetch next DIDs for search.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handler (created by Wallet::open).
+/// * `search_handle` - Search handle (created by search_my_dids)
+/// * `count` - Count of DIDs to fetch
+///
+/// # Returns
+/// * `dids` - list of DIDs with verkeys and metadata
+pub fn fetch_my_dids_search(wallet_handle: IndyHandle, search_handle: IndyHandle, count: usize) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _fetch_my_dids_search(command_handle, wallet_handle, search_handle, count, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _fetch_my_dids_search(command_handle: IndyHandle, wallet_handle: IndyHandle, search_handle: IndyHandle, count: usize, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { did::indy_fetch_my_dids_search(command_handle, wallet_handle, search_handle, count, cb) })
+}
+
+/// Close DIDs search (make search handle invalid)
+///
+/// # Arguments
+/// * `search_handle` - Search handle (created by search_my_dids)
+pub fn close_my_dids_search(search_handle: IndyHandle) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _close_my_dids_search(command_handle, search_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _close_my_dids_search(command_handle: IndyHandle, search_handle: IndyHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { did::indy_close_my_dids_search(command_handle, search_handle, cb) })
+}
+
+/// Deletes my DID with its keys, metadata, endpoint and key history from the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handler (created by Wallet::open).
+/// * `did` - DID stored in the wallet
+pub fn delete_my_did(wallet_handle: IndyHandle, did: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _delete_my_did(command_handle, wallet_handle, did, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _delete_my_did(command_handle: IndyHandle, wallet_handle: IndyHandle, did: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_delete_my_did(command_handle, wallet_handle, did.as_ptr(), cb) })
+}
+
+/// Deletes their DID with its metadata, endpoint and key history from the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handler (created by Wallet::open).
+/// * `did` - DID stored in the wallet
+pub fn delete_their_did(wallet_handle: IndyHandle, did: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _delete_their_did(command_handle, wallet_handle, did, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _delete_their_did(command_handle: IndyHandle, wallet_handle: IndyHandle, did: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_delete_their_did(command_handle, wallet_handle, did.as_ptr(), cb) })
+}
@@ -6,7 +6,8 @@ use std::ptr::null;
 use futures::Future;
 
 use ffi::ledger;
-use ffi::{ResponseStringCB,
+use ffi::{ResponseEmptyCB,
+          ResponseStringCB,
           ResponseStringStringCB,
           ResponseStringStringU64CB};
 
@@ -143,6 +144,229 @@ fn _multi_sign_request(command_handle: IndyHandle, wallet_handle: IndyHandle, su
     ErrorCode::from(unsafe { ledger::indy_multi_sign_request(command_handle, wallet_handle, submitter_did.as_ptr(), request_json.as_ptr(), cb) })
 }
 
+/// Signs request message for the opened pool.
+///
+/// Sets PROTOCOL_VERSION of the pool to the request and signs it with submitter
+/// sign key (see sign_request), so the signed request is accepted by the pool
+/// even if its PROTOCOL_VERSION differs from the global one (see Pool::set_protocol_version).
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `submitter_did` - Id of Identity stored in secured Wallet.
+/// * `request_json` - Request data json.
+///
+/// # Returns
+/// Signed request json.
+pub fn sign_request_for_pool(pool_handle: IndyHandle, wallet_handle: IndyHandle, submitter_did: &str, request_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _sign_request_for_pool(command_handle, pool_handle, wallet_handle, submitter_did, request_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _sign_request_for_pool(command_handle: IndyHandle, pool_handle: IndyHandle, wallet_handle: IndyHandle, submitter_did: &str, request_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let request_json = c_str!(request_json);
+
+    ErrorCode::from(unsafe { ledger::indy_sign_request_for_pool(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), request_json.as_ptr(), cb) })
+}
+
+/// Multi signs request message for the opened pool.
+///
+/// Sets PROTOCOL_VERSION of the pool to the request and signs it with submitter
+/// sign key (see multi_sign_request), so the signed request is accepted by the pool
+/// even if its PROTOCOL_VERSION differs from the global one (see Pool::set_protocol_version).
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `submitter_did` - Id of Identity stored in secured Wallet.
+/// * `request_json` - Request data json.
+///
+/// # Returns
+/// Signed request json.
+pub fn multi_sign_request_for_pool(pool_handle: IndyHandle, wallet_handle: IndyHandle, submitter_did: &str, request_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _multi_sign_request_for_pool(command_handle, pool_handle, wallet_handle, submitter_did, request_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _multi_sign_request_for_pool(command_handle: IndyHandle, pool_handle: IndyHandle, wallet_handle: IndyHandle, submitter_did: &str, request_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let request_json = c_str!(request_json);
+
+    ErrorCode::from(unsafe { ledger::indy_multi_sign_request_for_pool(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), request_json.as_ptr(), cb) })
+}
+
+/// Appends endorser to the request.
+///
+/// Endorser must be appended before the request is signed.
+///
+/// # Arguments
+/// * `request_json` - Request data json.
+/// * `endorser_did` - DID of the endorser that will submit the transaction.
+///
+/// # Returns
+/// Updated request result as json.
+pub fn append_request_endorser(request_json: &str, endorser_did: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _append_request_endorser(command_handle, request_json, endorser_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _append_request_endorser(command_handle: IndyHandle, request_json: &str, endorser_did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let endorser_did = c_str!(endorser_did);
+
+    ErrorCode::from(unsafe { ledger::indy_append_request_endorser(command_handle, request_json.as_ptr(), endorser_did.as_ptr(), cb) })
+}
+
+/// Sets request id supplied by a caller to the request.
+///
+/// By default builders generate unique request ids (see `generate_req_id`).
+/// Request id must be set before the request is signed.
+/// The id doesn't affect ids generated by libindy, so the caller is responsible for its uniqueness.
+///
+/// # Arguments
+/// * `request_json` - Request data json.
+/// * `req_id` - Request id to set.
+///
+/// # Returns
+/// Request with the request id as json.
+pub fn set_request_req_id(request_json: &str, req_id: u64) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _set_request_req_id(command_handle, request_json, req_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _set_request_req_id(command_handle: IndyHandle, request_json: &str, req_id: u64, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+
+    ErrorCode::from(unsafe { ledger::indy_set_request_req_id(command_handle, request_json.as_ptr(), req_id, cb) })
+}
+
+/// Generates a request id unique within the process.
+///
+/// All request builders use the same generator, so requests built outside of libindy
+/// can use the function to avoid request id collisions.
+///
+/// # Returns
+/// Generated request id.
+pub fn generate_req_id() -> Result<u64, IndyError> {
+    let mut req_id: u64 = 0;
+
+    let res = ErrorCode::from(unsafe { ledger::indy_generate_req_id(&mut req_id) });
+
+    match res {
+        ErrorCode::Success => Ok(req_id),
+        err => Err(IndyError::new(err))
+    }
+}
+
+/// Builds a signing bundle for collecting signatures of the request on several machines.
+///
+/// # Arguments
+/// * `request_json` - Not signed request data json.
+/// * `signers_json` - DIDs expected to sign the request: {"<did>": {"verkey": string, "role": (optional) string}}.
+///                    Roles are declared by the caller and aren't verified against the ledger.
+/// * `constraint_json` - (optional) auth rule constraint signatures must satisfy.
+///                       If not set all signers must sign the request.
+///                       `need_to_be_owner` can't be checked offline and is ignored.
+///
+/// # Returns
+/// Signing bundle json.
+pub fn build_signing_bundle(request_json: &str, signers_json: &str, constraint_json: Option<&str>) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_signing_bundle(command_handle, request_json, signers_json, constraint_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_signing_bundle(command_handle: IndyHandle, request_json: &str, signers_json: &str, constraint_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let signers_json = c_str!(signers_json);
+    let constraint_json_str = opt_c_str!(constraint_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_signing_bundle(command_handle, request_json.as_ptr(), signers_json.as_ptr(), opt_c_ptr!(constraint_json, constraint_json_str), cb)
+    })
+}
+
+/// Signs the request of the signing bundle and adds the signature to the bundle.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `signer_did` - DID of the signer stored in the wallet.
+/// * `bundle_json` - Signing bundle json.
+///
+/// # Returns
+/// Signing bundle json with the added signature.
+pub fn sign_signing_bundle(wallet_handle: IndyHandle, signer_did: &str, bundle_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _sign_signing_bundle(command_handle, wallet_handle, signer_did, bundle_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _sign_signing_bundle(command_handle: IndyHandle, wallet_handle: IndyHandle, signer_did: &str, bundle_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let signer_did = c_str!(signer_did);
+    let bundle_json = c_str!(bundle_json);
+
+    ErrorCode::from(unsafe { ledger::indy_sign_signing_bundle(command_handle, wallet_handle, signer_did.as_ptr(), bundle_json.as_ptr(), cb) })
+}
+
+/// Merges signatures collected in several signing bundles of the same request.
+///
+/// # Arguments
+/// * `bundles_json` - Array of signing bundles jsons.
+///
+/// # Returns
+/// Signing bundle json with all collected signatures.
+pub fn merge_signing_bundles(bundles_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _merge_signing_bundles(command_handle, bundles_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _merge_signing_bundles(command_handle: IndyHandle, bundles_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let bundles_json = c_str!(bundles_json);
+
+    ErrorCode::from(unsafe { ledger::indy_merge_signing_bundles(command_handle, bundles_json.as_ptr(), cb) })
+}
+
+/// Verifies signatures collected in the signing bundle and checks whether they are enough to send the request.
+///
+/// # Arguments
+/// * `bundle_json` - Signing bundle json.
+///
+/// # Returns
+/// Bundle status json: {"complete": bool, "signed": [did], "unsigned": [did], "request": (only for complete bundle) multi-signed request}.
+pub fn check_signing_bundle(bundle_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _check_signing_bundle(command_handle, bundle_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _check_signing_bundle(command_handle: IndyHandle, bundle_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let bundle_json = c_str!(bundle_json);
+
+    ErrorCode::from(unsafe { ledger::indy_check_signing_bundle(command_handle, bundle_json.as_ptr(), cb) })
+}
+
 /// Builds a request to get a DDO.
 ///
 /// # Arguments
@@ -178,6 +402,7 @@ fn _build_get_ddo_request(command_handle: IndyHandle, submitter_did: Option<&str
 ///                             TRUSTEE
 ///                             STEWARD
 ///                             TRUST_ANCHOR
+///                             ENDORSER
 ///                             NETWORK_MONITOR
 ///                             empty string to reset role
 ///
@@ -547,6 +772,36 @@ fn _build_node_request(command_handle: IndyHandle, submitter_did: &str, target_d
     ErrorCode::from(unsafe { ledger::indy_build_node_request(command_handle, submitter_did.as_ptr(), target_did.as_ptr(), data.as_ptr(), cb) })
 }
 
+/// Builds a NODE request with BLS key and its proof of possession taken from the wallet.
+/// The BLS key must be created by Crypto::create_bls_key.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `submitter_did` - DID of the submitter stored in secured Wallet.
+/// * `target_did` - Target Node's DID.  It differs from submitter_did field.
+/// * `data` - Data associated with the Node (see build_node_request).
+///            "blskey" and "blskey_pop" fields are overwritten by values from the wallet.
+/// * `blskey` - BLS key stored in the wallet (as returned by Crypto::create_bls_key).
+///
+/// # Returns
+/// Request result as json.
+pub fn build_node_request_with_bls_key(wallet_handle: IndyHandle, submitter_did: &str, target_did: &str, data: &str, blskey: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_node_request_with_bls_key(command_handle, wallet_handle, submitter_did, target_did, data, blskey, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_node_request_with_bls_key(command_handle: IndyHandle, wallet_handle: IndyHandle, submitter_did: &str, target_did: &str, data: &str, blskey: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let target_did = c_str!(target_did);
+    let data = c_str!(data);
+    let blskey = c_str!(blskey);
+
+    ErrorCode::from(unsafe { ledger::indy_build_node_request_with_bls_key(command_handle, wallet_handle, submitter_did.as_ptr(), target_did.as_ptr(), data.as_ptr(), blskey.as_ptr(), cb) })
+}
+
 /// Builds a GET_VALIDATOR_INFO request.
 ///
 /// # Arguments
@@ -570,6 +825,33 @@ fn _build_get_validator_info_request(command_handle: IndyHandle, submitter_did:
     })
 }
 
+/// Gets structured status of the validator pool.
+///
+/// Sends GET_VALIDATOR_INFO request to all nodes of the pool ledger and combines their replies
+/// into a report about every node (reachability, last ordered batch, catchup status, software version, primary)
+/// and the pool consensus (whether f faulty nodes are tolerated).
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `submitter_did` - DID of the request sender with TRUSTEE, STEWARD or NETWORK_MONITOR role.
+///
+/// # Returns
+/// Pool status as json.
+pub fn get_pool_status(pool_handle: IndyHandle, wallet_handle: IndyHandle, submitter_did: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pool_status(command_handle, pool_handle, wallet_handle, submitter_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pool_status(command_handle: IndyHandle, pool_handle: IndyHandle, wallet_handle: IndyHandle, submitter_did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+
+    ErrorCode::from(unsafe { ledger::indy_get_pool_status(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), cb) })
+}
+
 /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
 ///
 /// # Arguments
@@ -994,6 +1276,59 @@ fn _get_response_metadata(command_handle: IndyHandle, response: &str, cb: Option
     ErrorCode::from(unsafe { ledger::indy_get_response_metadata(command_handle, response.as_ptr(), cb) })
 }
 
+/// Registers custom transaction type provided by a ledger plugin.
+///
+/// Registered type can be used by its name in `build_custom_request`, `build_auth_rule_request`,
+/// `build_get_auth_rule_request` and for state proof parser registration.
+///
+/// # Arguments
+/// * `txn_type_json` - transaction type description:
+/// {
+///     "name": string - transaction type name (must not coincide with default ones),
+///     "code": string - numeric transaction type code,
+///     "kind": string - "read" or "write",
+///     "schema": Option<object> - schema of request `operation` fields:
+///         {<field name>: {"type": "string" | "number" | "boolean" | "object" | "array" | "any", "optional": Option<bool>}}
+/// }
+pub fn register_custom_txn_type(txn_type_json: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_custom_txn_type(command_handle, txn_type_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_custom_txn_type(command_handle: IndyHandle, txn_type_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let txn_type_json = c_str!(txn_type_json);
+
+    ErrorCode::from(unsafe { ledger::indy_register_custom_txn_type(command_handle, txn_type_json.as_ptr(), cb) })
+}
+
+/// Builds a request of custom transaction type (see `register_custom_txn_type`).
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the request sender (required for write transactions).
+/// * `txn_type` - name or code of registered custom transaction type.
+/// * `operation_json` - request operation fields validated against the transaction type schema.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_custom_request(submitter_did: Option<&str>, txn_type: &str, operation_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_custom_request(command_handle, submitter_did, txn_type, operation_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_custom_request(command_handle: IndyHandle, submitter_did: Option<&str>, txn_type: &str, operation_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let txn_type = c_str!(txn_type);
+    let operation_json = c_str!(operation_json);
+
+    ErrorCode::from(unsafe { ledger::indy_build_custom_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), txn_type.as_ptr(), operation_json.as_ptr(), cb) })
+}
+
 /// Builds a AUTH_RULE request. Request to change authentication rules for a ledger transaction.
 ///
 /// # Arguments
@@ -10,7 +10,8 @@ use utils::callbacks::{ClosureHandler, ResultHandler};
 use ffi::pairwise;
 use ffi::{ResponseEmptyCB,
           ResponseStringCB,
-          ResponseBoolCB};
+          ResponseBoolCB,
+          ResponseI32UsizeCB};
 
 pub fn is_pairwise_exists(wallet_handle: IndyHandle, their_did: &str) -> Box<Future<Item=bool, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
@@ -92,3 +93,80 @@ fn _set_pairwise_metadata(command_handle: IndyHandle, wallet_handle: IndyHandle,
         pairwise::indy_set_pairwise_metadata(command_handle, wallet_handle, their_did.as_ptr(), opt_c_ptr!(metadata, metadata_str), cb)
     })
 }
+
+pub fn set_pairwise_tags(wallet_handle: IndyHandle, their_did: &str, tags_json: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_pairwise_tags(command_handle, wallet_handle, their_did, tags_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_pairwise_tags(command_handle: IndyHandle, wallet_handle: IndyHandle, their_did: &str, tags_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let their_did = c_str!(their_did);
+    let tags_json = c_str!(tags_json);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_set_pairwise_tags(command_handle, wallet_handle, their_did.as_ptr(), tags_json.as_ptr(), cb)
+    })
+}
+
+pub fn delete_pairwise(wallet_handle: IndyHandle, their_did: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _delete_pairwise(command_handle, wallet_handle, their_did, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _delete_pairwise(command_handle: IndyHandle, wallet_handle: IndyHandle, their_did: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let their_did = c_str!(their_did);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_delete_pairwise(command_handle, wallet_handle, their_did.as_ptr(), cb)
+    })
+}
+
+pub fn search_pairwise(wallet_handle: IndyHandle, query_json: Option<&str>) -> Box<Future<Item=(IndyHandle, usize), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle_usize();
+
+    let err = _search_pairwise(command_handle, wallet_handle, query_json, cb);
+
+    ResultHandler::handle_usize(command_handle, err, receiver)
+}
+
+fn _search_pairwise(command_handle: IndyHandle, wallet_handle: IndyHandle, query_json: Option<&str>, cb: Option<ResponseI32UsizeCB>) -> ErrorCode {
+    let query_json_str = opt_c_str!(query_json);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_search_pairwise(command_handle, wallet_handle, opt_c_ptr!(query_json, query_json_str), cb)
+    })
+}
+
+pub fn fetch_pairwise_search(search_handle: IndyHandle, count: usize) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _fetch_pairwise_search(command_handle, search_handle, count, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _fetch_pairwise_search(command_handle: IndyHandle, search_handle: IndyHandle, count: usize, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        pairwise::indy_fetch_pairwise_search(command_handle, search_handle, count, cb)
+    })
+}
+
+pub fn close_pairwise_search(search_handle: IndyHandle) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _close_pairwise_search(command_handle, search_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _close_pairwise_search(command_handle: IndyHandle, search_handle: IndyHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        pairwise::indy_close_pairwise_search(command_handle, search_handle, cb)
+    })
+}
@@ -138,6 +138,11 @@ fn _delete_pool_ledger(command_handle: IndyHandle, pool_name: &str, cb: Option<R
 ///
 /// There is a global property PROTOCOL_VERSION that used in every request to the pool and
 /// specified version of Indy Node which Libindy works.
+/// Each opened pool has its own version (see open_pool_ledger) and unsigned requests are
+/// switched to the version of the pool they are submitted to. This version is used by the
+/// request builders, for the format of credential definition ids and for the pools with
+/// empty genesis transactions. Use sign_request_for_pool and multi_sign_request_for_pool
+/// to sign requests for a pool of another version.
 ///
 /// By default PROTOCOL_VERSION=1.
 ///